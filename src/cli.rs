@@ -0,0 +1,595 @@
+// the options are parsed by hand (like GNU readelf does with getopt), so we
+// dont need to pull a whole argument parser crate just for some flags
+
+pub const USAGE: &str = "Usage: readelf <option(s)> elf-file(s)
+ Display information about the contents of ELF format files
+ Options are:
+  -h --file-header       Display the ELF file header
+  -l --program-headers   Display the program headers
+     --segments          An alias for --program-headers
+  -S --section-headers   Display the sections' header
+     --sections          An alias for --section-headers
+  -e --headers           Equivalent to: -h -l -S
+  -d --dynamic           Display the dynamic section (if present)
+  -r --relocs            Display the relocations (if present)
+  -s --syms              Display the symbol table
+     --symbols           An alias for --syms
+     --dyn-syms          Display the dynamic symbol table
+  -n --notes             Display the core notes (if present)
+  -V --version-info      Display the version sections (if present)
+  -I --histogram         Display histogram of bucket list lengths
+  -c --archive-index     Display the symbol/file index in an archive
+  -x --hex-dump=<number|name>
+                         Dump the contents of section <number|name> as bytes
+  -p --string-dump=<number|name>
+                         Dump the contents of section <number|name> as strings
+  -R --relocated-dump=<number|name>
+                         Dump the relocated contents of section <number|name>
+  -z --decompress        Decompress section before dumping it
+  -w --debug-dump[a/=abbrev,i/=info,l/=rawline,L/=decodedline,s/=str,
+                  r/=aranges,R/=Ranges,o/=loc,f/=frames,F/=frames-interp]
+                         Display the contents of DWARF debug sections
+  -W --wide              Allow output width to exceed 80 characters
+     --strict            Warn about header values that are valid but unknown
+     --lenient           Warn about broken values and show what can still be read
+     --lint              Check the header and its tables for inconsistencies
+  -H --help              Display this information
+  -v --version           Display the version number of readelf";
+
+#[derive(Debug, Default)]
+pub struct Options {
+    pub file_header: bool,
+    pub program_headers: bool,
+    pub section_headers: bool,
+    pub dynamic: bool,
+    pub relocs: bool,
+    pub syms: bool,
+    pub dyn_syms: bool,
+    pub notes: bool,
+    // .gnu.version, .gnu.version_d and .gnu.version_r
+    pub version_info: bool,
+    // the chain lengths of the buckets of .hash and .gnu.hash
+    pub histogram: bool,
+    // the symbol index of the static archives (the members get the other
+    // dumps)
+    pub archive_index: bool,
+    // the sections asked for with -x, -p and -R, in the order of the command
+    // line (they are dumped in the order of the sections anyway)
+    pub dumps: Vec<SectionDump>,
+    // the compressed sections (SHF_COMPRESSED and .zdebug_*) are dumped
+    // decompressed instead of as they are in the file
+    pub decompress: bool,
+    // the DWARF sections asked for with -w and --debug-dump
+    pub debug_dumps: DebugDumps,
+    pub wide: bool,
+    // warns about the values that are accepted but have no meaning for us,
+    // like an EI_OSABI that no one has defined
+    pub strict: bool,
+    // goes on past the problems that dont stop the rest of the file from
+    // being read (like garbage in the padding of e_ident), as warnings
+    pub lenient: bool,
+    // looks for values that disagree with each other (overlapping tables,
+    // an entry point out of the code...), the exit status says if any was found
+    pub lint: bool,
+    pub help: bool,
+    pub version: bool,
+    pub files: Vec<String>,
+}
+
+impl Options {
+    // returns true when at least one dump was requested, otherwise we have
+    // nothing to show and the usage should be printed instead
+    pub fn has_dump(&self) -> bool {
+        self.archive_index || self.has_object_dump()
+    }
+
+    // the dumps of the ELF files themselves, without them the members of an
+    // archive are not even read
+    pub fn has_object_dump(&self) -> bool {
+        self.file_header
+            || self.program_headers
+            || self.section_headers
+            || self.dynamic
+            || self.relocs
+            || self.syms
+            || self.dyn_syms
+            || self.notes
+            || self.version_info
+            || self.histogram
+            || !self.dumps.is_empty()
+            || !self.debug_dumps.is_empty()
+            || self.lint
+    }
+
+    fn set_headers(&mut self) {
+        self.file_header = true;
+        self.program_headers = true;
+        self.section_headers = true;
+    }
+
+    fn add_dump(&mut self, kind: DumpKind, section: &str) {
+        self.dumps.push(SectionDump {
+            kind,
+            section: SectionSelector::parse(section),
+        });
+    }
+}
+
+// what -w shows of the DWARF sections, each one picked by a letter or a name
+// like GNU readelf does
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DebugDumps {
+    pub abbrev: bool,
+    pub info: bool,
+    pub raw_line: bool,
+    pub decoded_line: bool,
+    pub str: bool,
+    pub aranges: bool,
+    pub ranges: bool,
+    pub loc: bool,
+    pub frames: bool,
+    pub frames_interp: bool,
+}
+
+impl DebugDumps {
+    pub fn is_empty(&self) -> bool {
+        *self == DebugDumps::default()
+    }
+
+    // the interpreted frames are only shown when asked for, like binutils
+    fn all() -> Self {
+        DebugDumps {
+            abbrev: true,
+            info: true,
+            raw_line: true,
+            decoded_line: true,
+            str: true,
+            aranges: true,
+            ranges: true,
+            loc: true,
+            frames: true,
+            frames_interp: false,
+        }
+    }
+
+    // the letters of -w, without any it is all of them
+    fn add_letters(&mut self, letters: &str) -> Result<(), CliErrors> {
+        if letters.is_empty() {
+            *self = DebugDumps::all();
+            return Ok(());
+        }
+
+        for letter in letters.chars() {
+            let dump = match letter {
+                'a' => &mut self.abbrev,
+                'i' => &mut self.info,
+                'l' => &mut self.raw_line,
+                'L' => &mut self.decoded_line,
+                's' => &mut self.str,
+                'r' => &mut self.aranges,
+                'R' => &mut self.ranges,
+                'o' => &mut self.loc,
+                'f' => &mut self.frames,
+                'F' => &mut self.frames_interp,
+                _ => return Err(CliErrors::UnrecognizedDebugOption(letter.to_string())),
+            };
+            *dump = true;
+        }
+        Ok(())
+    }
+
+    // the comma separated names of --debug-dump, without any it is all of
+    // them
+    fn add_names(&mut self, names: Option<&str>) -> Result<(), CliErrors> {
+        let Some(names) = names else {
+            *self = DebugDumps::all();
+            return Ok(());
+        };
+
+        for name in names.split(',') {
+            let dump = match name {
+                "abbrev" => &mut self.abbrev,
+                "info" => &mut self.info,
+                "rawline" | "line" => &mut self.raw_line,
+                "decodedline" => &mut self.decoded_line,
+                "str" => &mut self.str,
+                "aranges" => &mut self.aranges,
+                "Ranges" | "ranges" | "rnglists" => &mut self.ranges,
+                "loc" | "loclists" => &mut self.loc,
+                "frames" => &mut self.frames,
+                "frames-interp" => &mut self.frames_interp,
+                _ => return Err(CliErrors::UnrecognizedDebugOption(name.to_string())),
+            };
+            *dump = true;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DumpKind {
+    Hex,
+    Relocated,
+    Strings,
+}
+
+// a section is picked by its index or by its name, like getopt's strtoul
+// does it: anything that is a whole number (in decimal, 0x hex or 0 octal) is
+// an index
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SectionSelector {
+    Index(usize),
+    Name(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SectionDump {
+    pub kind: DumpKind,
+    pub section: SectionSelector,
+}
+
+impl SectionSelector {
+    fn parse(arg: &str) -> Self {
+        let index = if let Some(hex) = arg.strip_prefix("0x").or_else(|| arg.strip_prefix("0X")) {
+            usize::from_str_radix(hex, 16)
+        } else if let Some(octal) = arg.strip_prefix('0').filter(|octal| !octal.is_empty()) {
+            usize::from_str_radix(octal, 8)
+        } else {
+            arg.parse()
+        };
+
+        match index {
+            Ok(index) => SectionSelector::Index(index),
+            Err(_) => SectionSelector::Name(arg.to_string()),
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum CliErrors {
+    #[error("unrecognized option '{0}'")]
+    UnrecognizedOption(String),
+    #[error("invalid option -- '{0}'")]
+    InvalidShortOption(char),
+    #[error("option requires an argument -- '{0}'")]
+    MissingShortArgument(char),
+    #[error("option '--{0}' requires an argument")]
+    MissingLongArgument(String),
+    #[error("Unrecognized debug option '{0}'")]
+    UnrecognizedDebugOption(String),
+    #[error("Nothing to do.")]
+    NothingToDo,
+}
+
+pub fn parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<Options, CliErrors> {
+    let mut options = Options::default();
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        if let Some(long) = arg.strip_prefix("--") {
+            // the options with an argument take it after a '=' or as the next
+            // argument, like getopt_long
+            let (name, value) = match long.split_once('=') {
+                Some((name, value)) => (name, Some(value.to_string())),
+                None => (long, None),
+            };
+
+            if let Some(kind) = long_dump_kind(name) {
+                let value = value
+                    .or_else(|| args.next())
+                    .ok_or_else(|| CliErrors::MissingLongArgument(name.to_string()))?;
+                options.add_dump(kind, &value);
+                continue;
+            }
+
+            // its argument is optional, so it can only come after a '='
+            if name == "debug-dump" {
+                options.debug_dumps.add_names(value.as_deref())?;
+                continue;
+            }
+
+            match long {
+                "file-header" => options.file_header = true,
+                "program-headers" | "segments" => options.program_headers = true,
+                "section-headers" | "sections" => options.section_headers = true,
+                "headers" => options.set_headers(),
+                "dynamic" => options.dynamic = true,
+                "relocs" => options.relocs = true,
+                "syms" | "symbols" => options.syms = true,
+                "dyn-syms" => options.dyn_syms = true,
+                "notes" => options.notes = true,
+                "version-info" => options.version_info = true,
+                "histogram" => options.histogram = true,
+                "archive-index" => options.archive_index = true,
+                "decompress" => options.decompress = true,
+                "wide" => options.wide = true,
+                "strict" => options.strict = true,
+                "lenient" => options.lenient = true,
+                "lint" => options.lint = true,
+                "help" => options.help = true,
+                "version" => options.version = true,
+                _ => return Err(CliErrors::UnrecognizedOption(arg)),
+            }
+        } else if let Some(short) = arg.strip_prefix('-').filter(|s| !s.is_empty()) {
+            // short options can be grouped, like -hS
+            for (i, c) in short.char_indices() {
+                // and the ones with an argument take the rest of the group
+                // (like -x.text) or the next argument
+                if let Some(kind) = short_dump_kind(c) {
+                    let rest = &short[i + c.len_utf8()..];
+                    let value = Some(rest.to_string())
+                        .filter(|rest| !rest.is_empty())
+                        .or_else(|| args.next())
+                        .ok_or(CliErrors::MissingShortArgument(c))?;
+                    options.add_dump(kind, &value);
+                    break;
+                }
+
+                // -w takes the rest of the group as its letters
+                if c == 'w' {
+                    options
+                        .debug_dumps
+                        .add_letters(&short[i + c.len_utf8()..])?;
+                    break;
+                }
+
+                match c {
+                    'h' => options.file_header = true,
+                    'l' => options.program_headers = true,
+                    'S' => options.section_headers = true,
+                    'e' => options.set_headers(),
+                    'd' => options.dynamic = true,
+                    'r' => options.relocs = true,
+                    's' => options.syms = true,
+                    'n' => options.notes = true,
+                    'V' => options.version_info = true,
+                    'I' => options.histogram = true,
+                    'c' => options.archive_index = true,
+                    'z' => options.decompress = true,
+                    'W' => options.wide = true,
+                    'H' => options.help = true,
+                    'v' => options.version = true,
+                    _ => return Err(CliErrors::InvalidShortOption(c)),
+                }
+            }
+        } else {
+            options.files.push(arg);
+        }
+    }
+
+    if !options.help && !options.version && (!options.has_dump() || options.files.is_empty()) {
+        return Err(CliErrors::NothingToDo);
+    }
+
+    Ok(options)
+}
+
+fn short_dump_kind(c: char) -> Option<DumpKind> {
+    match c {
+        'x' => Some(DumpKind::Hex),
+        'R' => Some(DumpKind::Relocated),
+        'p' => Some(DumpKind::Strings),
+        _ => None,
+    }
+}
+
+fn long_dump_kind(name: &str) -> Option<DumpKind> {
+    match name {
+        "hex-dump" => Some(DumpKind::Hex),
+        "relocated-dump" => Some(DumpKind::Relocated),
+        "string-dump" => Some(DumpKind::Strings),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CliErrors, DumpKind, SectionDump, SectionSelector, parse_args};
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_args_accepts_short_and_long_file_header() {
+        let short = parse_args(args(&["-h", "a.out"])).unwrap();
+        let long = parse_args(args(&["--file-header", "a.out"])).unwrap();
+
+        assert!(short.file_header && long.file_header);
+        assert_eq!(short.files, ["a.out"]);
+    }
+
+    #[test]
+    fn parse_args_accepts_grouped_short_options() {
+        let options = parse_args(args(&["-SW", "a.out"])).unwrap();
+
+        assert!(options.section_headers && options.wide);
+        assert!(!options.file_header);
+    }
+
+    #[test]
+    fn parse_args_accepts_decompress_with_the_dumps() {
+        let short = parse_args(args(&["-zx.debug_info", "a.out"])).unwrap();
+        let long = parse_args(args(&["--decompress", "-p", ".debug_str", "a.out"])).unwrap();
+
+        assert!(short.decompress && long.decompress);
+        assert_eq!(short.dumps.len(), 1);
+    }
+
+    #[test]
+    fn parse_args_expands_headers_into_the_three_header_dumps() {
+        let options = parse_args(args(&["-e", "a.out"])).unwrap();
+
+        assert!(options.file_header && options.program_headers && options.section_headers);
+    }
+
+    #[test]
+    fn parse_args_accepts_the_symbol_table_options() {
+        let options = parse_args(args(&["-s", "--dyn-syms", "a.out"])).unwrap();
+        let alias = parse_args(args(&["--symbols", "a.out"])).unwrap();
+
+        assert!(options.syms && options.dyn_syms);
+        assert!(alias.syms && !alias.dyn_syms);
+    }
+
+    #[test]
+    fn parse_args_accepts_the_version_info_option() {
+        let short = parse_args(args(&["-V", "a.out"])).unwrap();
+        let long = parse_args(args(&["--version-info", "a.out"])).unwrap();
+
+        assert!(short.version_info && long.version_info);
+        assert!(!short.version);
+    }
+
+    #[test]
+    fn parse_args_accepts_the_histogram_option() {
+        let short = parse_args(args(&["-I", "a.out"])).unwrap();
+        let long = parse_args(args(&["--histogram", "a.out"])).unwrap();
+
+        assert!(short.histogram && long.histogram);
+    }
+
+    #[test]
+    fn parse_args_accepts_the_archive_index_option() {
+        let short = parse_args(args(&["-c", "libfoo.a"])).unwrap();
+        let long = parse_args(args(&["--archive-index", "libfoo.a"])).unwrap();
+
+        assert!(short.archive_index && long.archive_index);
+        assert!(short.has_dump() && !short.has_object_dump());
+    }
+
+    #[test]
+    fn parse_args_accepts_the_dynamic_section_option() {
+        let short = parse_args(args(&["-d", "a.out"])).unwrap();
+        let long = parse_args(args(&["--dynamic", "a.out"])).unwrap();
+
+        assert!(short.dynamic && long.dynamic);
+    }
+
+    #[test]
+    fn parse_args_accepts_the_relocations_option() {
+        let options = parse_args(args(&["-rW", "a.out"])).unwrap();
+        let long = parse_args(args(&["--relocs", "a.out"])).unwrap();
+
+        assert!(options.relocs && options.wide && long.relocs);
+    }
+
+    #[test]
+    fn parse_args_accepts_the_notes_option() {
+        let short = parse_args(args(&["-n", "a.out"])).unwrap();
+        let long = parse_args(args(&["--notes", "a.out"])).unwrap();
+
+        assert!(short.notes && long.notes);
+    }
+
+    #[test]
+    fn parse_args_does_not_count_strict_as_a_dump() {
+        let alone = parse_args(args(&["--strict", "a.out"]));
+        let options = parse_args(args(&["--strict", "-h", "a.out"])).unwrap();
+
+        assert!(matches!(alone, Err(CliErrors::NothingToDo)));
+        assert!(options.strict && options.file_header);
+    }
+
+    #[test]
+    fn parse_args_accepts_the_lenient_option() {
+        let options = parse_args(args(&["--lenient", "-S", "a.out"])).unwrap();
+
+        assert!(options.lenient && options.section_headers);
+    }
+
+    #[test]
+    fn parse_args_counts_lint_as_a_dump() {
+        let options = parse_args(args(&["--lint", "a.out"])).unwrap();
+
+        assert!(options.lint && options.has_dump());
+    }
+
+    #[test]
+    fn parse_args_returns_nothing_to_do_without_files() {
+        let result = parse_args(args(&["-h"]));
+
+        assert!(matches!(result, Err(CliErrors::NothingToDo)));
+    }
+
+    #[test]
+    fn parse_args_returns_invalid_short_option_for_unknown_letters() {
+        let result = parse_args(args(&["-q", "a.out"]));
+
+        assert!(matches!(result, Err(CliErrors::InvalidShortOption('q'))));
+    }
+
+    #[test]
+    fn parse_args_accepts_the_section_dumps_in_every_form() {
+        let options = parse_args(args(&[
+            "-x",
+            ".text",
+            "-p.comment",
+            "-WR0x3",
+            "--hex-dump=010",
+            "--string-dump",
+            "12",
+            "a.out",
+        ]))
+        .unwrap();
+
+        let dump = |kind, section| SectionDump { kind, section };
+        assert_eq!(
+            options.dumps,
+            [
+                dump(DumpKind::Hex, SectionSelector::Name(".text".to_string())),
+                dump(DumpKind::Strings, SectionSelector::Name(".comment".to_string())),
+                dump(DumpKind::Relocated, SectionSelector::Index(3)),
+                dump(DumpKind::Hex, SectionSelector::Index(8)),
+                dump(DumpKind::Strings, SectionSelector::Index(12)),
+            ]
+        );
+        assert!(options.wide);
+        assert_eq!(options.files, ["a.out"]);
+    }
+
+    #[test]
+    fn parse_args_returns_missing_argument_when_a_dump_has_no_section() {
+        assert!(matches!(
+            parse_args(args(&["-x"])),
+            Err(CliErrors::MissingShortArgument('x'))
+        ));
+        assert!(matches!(
+            parse_args(args(&["--string-dump"])),
+            Err(CliErrors::MissingLongArgument(name)) if name == "string-dump"
+        ));
+    }
+
+    #[test]
+    fn parse_args_accepts_the_debug_dumps_by_letter_and_by_name() {
+        let letters = parse_args(args(&["-wiL", "a.out"])).unwrap();
+        let names = parse_args(args(&["--debug-dump=info,decodedline", "a.out"])).unwrap();
+        assert_eq!(letters.debug_dumps, names.debug_dumps);
+        assert!(letters.debug_dumps.info && letters.debug_dumps.decoded_line);
+        assert!(!letters.debug_dumps.raw_line);
+
+        let frames = parse_args(args(&["--debug-dump=frames-interp", "a.out"])).unwrap();
+        assert!(frames.debug_dumps.frames_interp && !frames.debug_dumps.frames);
+
+        let all = parse_args(args(&["-Ww", "a.out"])).unwrap();
+        assert!(all.wide && all.debug_dumps.loc && all.debug_dumps.abbrev);
+        assert_eq!(
+            parse_args(args(&["--debug-dump", "a.out"]))
+                .unwrap()
+                .debug_dumps,
+            all.debug_dumps
+        );
+    }
+
+    #[test]
+    fn parse_args_returns_unrecognized_debug_option_for_unknown_sections() {
+        assert!(matches!(
+            parse_args(args(&["-wiq", "a.out"])),
+            Err(CliErrors::UnrecognizedDebugOption(name)) if name == "q"
+        ));
+        assert!(matches!(
+            parse_args(args(&["--debug-dump=info,macro", "a.out"])),
+            Err(CliErrors::UnrecognizedDebugOption(name)) if name == "macro"
+        ));
+    }
+}
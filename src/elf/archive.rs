@@ -0,0 +1,506 @@
+use crate::elf::error::ElfError;
+use crate::elf::strtab::get_string;
+use std::ops::Range;
+
+// static libraries (libfoo.a) are ar archives of ELF files: a magic string,
+// then each member with a header of 60 chars (its name, size and some unix
+// metadata as text) and its data, padded to an even offset. A few members
+// are not files but tables for the others:
+//
+// - "/" (or "/SYM64/", with 64-bit offsets) is the symbol index that the
+//   linker uses to find the member that defines a symbol without reading
+//   them all
+// - "//" has the names that dont fit in the 16 chars of the header, that
+//   refers to them as "/offset". BSD archives put them in the member
+//   instead, as "#1/length" and the name at the start of the data
+//
+// thin archives only have the headers of the members, their data is still
+// in the files that were added (and the name is the path to it)
+
+pub const ARMAG: &[u8; 8] = b"!<arch>\n";
+pub const THINMAG: &[u8; 8] = b"!<thin>\n";
+
+const HEADER_SIZE: usize = 60;
+
+pub fn is_archive(bytes: &[u8]) -> bool {
+    bytes.starts_with(ARMAG) || bytes.starts_with(THINMAG)
+}
+
+#[derive(Debug, Clone)]
+pub struct ArchiveMember {
+    // the offset of its header, the one that the symbol index uses
+    pub offset: u64,
+    pub name: String,
+    // the size of the file (without the name of the BSD long names)
+    pub size: u64,
+    // where the data is in the archive, None for the members of the thin
+    // archives
+    pub data: Option<Range<usize>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ArchiveIndex {
+    pub is_64bit: bool,
+    // the offset of the member that defines each symbol, in the order of
+    // the names
+    pub offsets: Vec<u64>,
+    // less than the offsets when the names run out before them
+    pub names: Vec<String>,
+    // the bytes of the names
+    pub strings_size: u64,
+}
+
+impl ArchiveIndex {
+    // each symbol with the offset of the header of its member
+    pub fn symbols(&self) -> impl Iterator<Item = (&str, u64)> {
+        self.names
+            .iter()
+            .map(String::as_str)
+            .zip(self.offsets.iter().copied())
+    }
+}
+
+#[derive(Debug)]
+pub struct Archive<'data> {
+    bytes: &'data [u8],
+    pub is_thin: bool,
+    // None for the archives without one (ar S), and for the BSD ones, whose
+    // __.SYMDEF is not decoded
+    pub index: Option<ArchiveIndex>,
+    // only the files, the index and the long names are not members
+    pub members: Vec<ArchiveMember>,
+    // the problems of the index, that dont stop the members from being read
+    pub warnings: Vec<ElfError>,
+}
+
+impl<'data> Archive<'data> {
+    pub fn parse(bytes: &'data [u8]) -> Result<Self, ElfError> {
+        if !is_archive(bytes) {
+            let magic = bytes.get(..8).unwrap_or(bytes);
+            return Err(ElfError::invalid_value(
+                0,
+                "ar magic",
+                "ar magic",
+                "\"!<arch>\\n\" or \"!<thin>\\n\"",
+                magic.iter().fold(0u64, |value, &b| (value << 8) | b as u64),
+            ));
+        }
+
+        let mut archive = Archive {
+            bytes,
+            is_thin: bytes.starts_with(THINMAG),
+            index: None,
+            members: Vec::new(),
+            warnings: Vec::new(),
+        };
+
+        let mut long_names: &[u8] = &[];
+        let mut offset = ARMAG.len();
+
+        while offset < bytes.len() {
+            let (raw_name, size) = parse_header(bytes, offset)?;
+            let start = offset + HEADER_SIZE;
+
+            // the thin archives still have the data of their own tables
+            let is_table = matches!(raw_name, "/" | "//" | "/SYM64/");
+            let end = if archive.is_thin && !is_table {
+                start
+            } else {
+                start
+                    .checked_add(size as usize)
+                    .filter(|&end| end <= bytes.len())
+                    .ok_or_else(|| {
+                        ElfError::out_of_bounds(
+                            (offset + 48) as u64,
+                            "ar_hdr",
+                            "ar_size",
+                            (start as u64, size),
+                            bytes.len(),
+                        )
+                    })?
+            };
+            let data = &bytes[start..end];
+
+            match raw_name {
+                "/" | "/SYM64/" if archive.members.is_empty() && archive.index.is_none() => {
+                    let is_64bit = raw_name == "/SYM64/";
+                    archive.index = Some(archive.parse_index(data, start, is_64bit)?);
+                }
+                "//" => long_names = data,
+                _ => {
+                    let (name, skip) = member_name(raw_name, long_names, data, offset)?;
+
+                    // the index of the BSD archives
+                    if !matches!(name.as_str(), "__.SYMDEF" | "__.SYMDEF SORTED") {
+                        archive.members.push(ArchiveMember {
+                            offset: offset as u64,
+                            name,
+                            size: size - skip as u64,
+                            data: (!archive.is_thin).then_some(start + skip..end),
+                        });
+                    }
+                }
+            }
+
+            offset = end + (end & 1);
+        }
+
+        Ok(archive)
+    }
+
+    pub fn bytes(&self) -> &'data [u8] {
+        self.bytes
+    }
+
+    // None for the members of the thin archives
+    pub fn member_data(&self, member: &ArchiveMember) -> Option<&'data [u8]> {
+        member.data.clone().and_then(|range| self.bytes.get(range))
+    }
+
+    // the member of an offset of the symbol index
+    pub fn member_at(&self, offset: u64) -> Option<&ArchiveMember> {
+        self.members.iter().find(|member| member.offset == offset)
+    }
+
+    // a big endian count, the offsets and the NUL terminated names of the
+    // symbols, in the same order
+    fn parse_index(
+        &mut self,
+        data: &[u8],
+        start: usize,
+        is_64bit: bool,
+    ) -> Result<ArchiveIndex, ElfError> {
+        let width = if is_64bit { 8 } else { 4 };
+        let structure = if is_64bit { "/SYM64/" } else { "/" };
+        let read = |entry: &[u8]| entry.iter().fold(0u64, |value, &b| (value << 8) | b as u64);
+
+        let count = data
+            .get(..width)
+            .map(read)
+            .ok_or_else(|| ElfError::truncated(start as u64, structure, width, data.len()))?;
+
+        let strings_start = (count as usize)
+            .checked_mul(width)
+            .and_then(|size| size.checked_add(width))
+            .filter(|&end| end <= data.len())
+            .ok_or_else(|| {
+                ElfError::invalid_value(
+                    start as u64,
+                    structure,
+                    "count",
+                    format!("at most {} offsets in the index", data.len() / width - 1),
+                    count,
+                )
+            })?;
+
+        let offsets = data[width..strings_start]
+            .chunks_exact(width)
+            .map(read)
+            .collect();
+        let strings = &data[strings_start..];
+
+        let mut names = Vec::new();
+        let mut used = 0;
+        while names.len() < count as usize && used < strings.len() {
+            // the last name can be cut by the end of the index
+            let rest = &strings[used..];
+            let length = rest.iter().position(|&b| b == 0).unwrap_or(rest.len());
+            let name = get_string(strings, used)
+                .map(|name| name.into_owned())
+                .unwrap_or_else(|| String::from_utf8_lossy(rest).into_owned());
+
+            names.push(name);
+            used += length + 1;
+        }
+
+        if names.len() < count as usize {
+            self.warnings.push(ElfError::invalid_value(
+                start as u64,
+                structure,
+                "count",
+                format!("at most {} (the names of the index)", names.len()),
+                count,
+            ));
+        }
+
+        // the names are padded to the width of the offsets, and anything
+        // after that has no offset
+        let padded = used.next_multiple_of(if is_64bit { 8 } else { 2 });
+        if padded < strings.len() {
+            self.warnings.push(ElfError::invalid_value(
+                (start + strings_start + padded) as u64,
+                structure,
+                "names",
+                "no bytes after the names of the symbols",
+                (strings.len() - padded) as u64,
+            ));
+        }
+
+        Ok(ArchiveIndex {
+            is_64bit,
+            offsets,
+            names,
+            strings_size: strings.len() as u64,
+        })
+    }
+}
+
+// the name (as it is, without the padding) and size of the header at offset
+fn parse_header(bytes: &[u8], offset: usize) -> Result<(&str, u64), ElfError> {
+    let header = bytes.get(offset..offset + HEADER_SIZE).ok_or_else(|| {
+        ElfError::truncated(offset as u64, "ar_hdr", HEADER_SIZE, bytes.len() - offset)
+    })?;
+
+    // offset: 0x3A -> 0x3B, always "`\n"
+    if &header[58..60] != b"`\n" {
+        return Err(ElfError::invalid_value(
+            (offset + 58) as u64,
+            "ar_hdr",
+            "ar_fmag",
+            "\"`\\n\"",
+            u16::from_be_bytes([header[58], header[59]]),
+        ));
+    }
+
+    // offset: 0x0 -> 0xF, padded with spaces
+    let name = std::str::from_utf8(&header[0..16])
+        .map_err(|err| {
+            ElfError::invalid_value(
+                (offset + err.valid_up_to()) as u64,
+                "ar_hdr",
+                "ar_name",
+                "ascii text",
+                header[err.valid_up_to()],
+            )
+        })?
+        .trim_end_matches(' ');
+
+    // offset: 0x30 -> 0x39, in decimal, padded with spaces
+    let size = decimal_field(&header[48..58]).map_err(|bad| {
+        ElfError::invalid_value(
+            (offset + 48) as u64,
+            "ar_hdr",
+            "ar_size",
+            "a decimal digit",
+            bad,
+        )
+    })?;
+
+    Ok((name, size))
+}
+
+// the value of a decimal field, or the first char that is not a digit
+fn decimal_field(field: &[u8]) -> Result<u64, u8> {
+    let digits = field.iter().position(|&b| b == b' ').unwrap_or(field.len());
+    if digits == 0 {
+        return Err(b' ');
+    }
+
+    field[..digits].iter().try_fold(0u64, |value, &b| {
+        if b.is_ascii_digit() {
+            Ok(value * 10 + (b - b'0') as u64)
+        } else {
+            Err(b)
+        }
+    })
+}
+
+// the real name of a member and the bytes of its data that it uses (the BSD
+// long names are at the start of it)
+fn member_name(
+    raw_name: &str,
+    long_names: &[u8],
+    data: &[u8],
+    offset: usize,
+) -> Result<(String, usize), ElfError> {
+    let bad_name = |expected: &str, actual: u64| {
+        ElfError::invalid_value(offset as u64, "ar_hdr", "ar_name", expected, actual)
+    };
+
+    // GNU: "/offset" in "//", where the names end with "/\n" (the paths of
+    // the thin archives have slashes of their own)
+    if let Some(index) = raw_name.strip_prefix('/').filter(|index| !index.is_empty()) {
+        let index: usize = index
+            .parse()
+            .map_err(|_| bad_name("/ and an offset in the long names table", 0))?;
+        let rest = long_names
+            .get(index..)
+            .filter(|rest| !rest.is_empty())
+            .ok_or_else(|| bad_name("an offset in the long names table", index as u64))?;
+
+        let end = rest.iter().position(|&b| b == b'\n').unwrap_or(rest.len());
+        let name = String::from_utf8_lossy(&rest[..end]);
+        return Ok((name.strip_suffix('/').unwrap_or(&name).to_string(), 0));
+    }
+
+    // BSD: "#1/length", with the name (padded with NULs) before the data
+    if let Some(length) = raw_name.strip_prefix("#1/") {
+        let length: usize = length
+            .parse()
+            .map_err(|_| bad_name("#1/ and the length of the name", 0))?;
+        let name = data
+            .get(..length)
+            .ok_or_else(|| bad_name("a name inside of the member", length as u64))?;
+
+        let end = name.iter().position(|&b| b == 0).unwrap_or(name.len());
+        return Ok((String::from_utf8_lossy(&name[..end]).into_owned(), length));
+    }
+
+    // GNU ends the short names with a slash, BSD doesnt
+    Ok((
+        raw_name.strip_suffix('/').unwrap_or(raw_name).to_string(),
+        0,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Archive, is_archive};
+    use crate::elf::error::{ElfError, ElfErrorKind};
+
+    fn header(name: &str, size: usize) -> Vec<u8> {
+        format!("{name:<16}{:<12}{:<6}{:<6}{:<8}{size:<10}`\n", 0, 0, 0, 644).into_bytes()
+    }
+
+    fn member(bytes: &mut Vec<u8>, name: &str, data: &[u8]) {
+        bytes.extend_from_slice(&header(name, data.len()));
+        bytes.extend_from_slice(data);
+        if !bytes.len().is_multiple_of(2) {
+            bytes.push(b'\n');
+        }
+    }
+
+    // the index of 2 symbols, a long names table, a member with a short
+    // name and one with a long name
+    fn create_archive() -> Vec<u8> {
+        let mut bytes = b"!<arch>\n".to_vec();
+
+        // the offsets of the members are patched once they are known
+        let mut index = 2u32.to_be_bytes().to_vec();
+        index.extend_from_slice(&[0; 8]);
+        index.extend_from_slice(b"alpha\0beta\0\0");
+        member(&mut bytes, "/", &index);
+        member(&mut bytes, "//", b"a_very_long_member_name.o/\n\0");
+
+        let first = bytes.len() as u32;
+        member(&mut bytes, "a.o/", b"\x7fELF one");
+        let second = bytes.len() as u32;
+        member(&mut bytes, "/0", b"\x7fELF two");
+
+        bytes[72..76].copy_from_slice(&first.to_be_bytes());
+        bytes[76..80].copy_from_slice(&second.to_be_bytes());
+        bytes
+    }
+
+    #[test]
+    fn parse_reads_the_members_with_their_long_names() {
+        let bytes = create_archive();
+        assert!(is_archive(&bytes));
+
+        let archive = Archive::parse(&bytes).unwrap();
+
+        assert!(!archive.is_thin);
+        assert!(archive.warnings.is_empty());
+        let names: Vec<_> = archive
+            .members
+            .iter()
+            .map(|member| member.name.as_str())
+            .collect();
+        assert_eq!(names, ["a.o", "a_very_long_member_name.o"]);
+        assert_eq!(
+            archive.member_data(&archive.members[1]),
+            Some(&b"\x7fELF two"[..])
+        );
+    }
+
+    #[test]
+    fn parse_decodes_the_symbol_index() {
+        let bytes = create_archive();
+
+        let archive = Archive::parse(&bytes).unwrap();
+        let index = archive.index.as_ref().unwrap();
+        let symbols: Vec<_> = index.symbols().collect();
+
+        assert!(!index.is_64bit);
+        assert_eq!(index.strings_size, 12);
+        assert_eq!(symbols[0], ("alpha", archive.members[0].offset));
+        assert_eq!(symbols[1], ("beta", archive.members[1].offset));
+        assert_eq!(
+            archive.member_at(symbols[1].1).unwrap().name,
+            "a_very_long_member_name.o"
+        );
+    }
+
+    #[test]
+    fn parse_decodes_the_bsd_long_names() {
+        let mut bytes = b"!<arch>\n".to_vec();
+        member(&mut bytes, "#1/20", b"__.SYMDEF SORTED\0\0\0\0\0\0\0\0");
+        member(
+            &mut bytes,
+            "#1/28",
+            b"a_very_long_member_name.o\0\0\0\x7fELF",
+        );
+
+        let archive = Archive::parse(&bytes).unwrap();
+
+        assert_eq!(archive.members.len(), 1);
+        assert_eq!(archive.members[0].name, "a_very_long_member_name.o");
+        assert_eq!(archive.members[0].size, 4);
+        assert_eq!(
+            archive.member_data(&archive.members[0]),
+            Some(&b"\x7fELF"[..])
+        );
+    }
+
+    #[test]
+    fn parse_keeps_only_the_headers_of_the_thin_members() {
+        let mut bytes = b"!<thin>\n".to_vec();
+        member(&mut bytes, "//", b"dir/a.o/\n");
+        // the size is the one of the file, but the data is not here
+        bytes.extend_from_slice(&header("/0", 1234));
+
+        let archive = Archive::parse(&bytes).unwrap();
+
+        assert!(archive.is_thin);
+        assert_eq!(archive.members[0].name, "dir/a.o");
+        assert_eq!(archive.members[0].size, 1234);
+        assert_eq!(archive.member_data(&archive.members[0]), None);
+    }
+
+    #[test]
+    fn parse_warns_when_the_index_has_more_offsets_than_names() {
+        let mut bytes = b"!<arch>\n".to_vec();
+        let mut index = 3u32.to_be_bytes().to_vec();
+        index.extend_from_slice(&[0; 12]);
+        index.extend_from_slice(b"alpha\0beta\0");
+        member(&mut bytes, "/", &index);
+
+        let archive = Archive::parse(&bytes).unwrap();
+
+        assert!(matches!(
+            archive.warnings.as_slice(),
+            [ElfError {
+                field: "count",
+                kind: ElfErrorKind::InvalidValue { actual: 3, .. },
+                ..
+            }]
+        ));
+    }
+
+    #[test]
+    fn parse_returns_invalid_fmag_err_for_a_broken_header() {
+        let mut bytes = create_archive();
+        bytes[8 + 58] = b'x';
+
+        let err = Archive::parse(&bytes).unwrap_err();
+
+        assert!(matches!(
+            err,
+            ElfError {
+                offset: 66,
+                field: "ar_fmag",
+                ..
+            }
+        ));
+    }
+}
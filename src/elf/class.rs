@@ -0,0 +1,130 @@
+use crate::elf::def::elfconsts::*;
+use crate::elf::error::ElfError;
+use crate::utils::endianess::EndianRead;
+use crate::utils::parser::EndianParseable;
+
+// ELFCLASS32 files have the same structures of the 64-bit ones, only with
+// narrower fields (and, for the symbols and program headers, in another
+// order). They are parsed with their own layout and then widened into the
+// Elf64_* structs, so the rest of the crate (the output included) works with
+// the same types no matter the class, like the Elf_Internal_* of binutils
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ElfClass {
+    Elf32,
+    Elf64,
+}
+
+impl ElfClass {
+    // EI_CLASS was already validated at parse time, so anything that is not
+    // ELFCLASS32 is a 64-bit file
+    pub fn from_ident(class: u8) -> Self {
+        if class as u32 == ELFCLASS32 {
+            ElfClass::Elf32
+        } else {
+            ElfClass::Elf64
+        }
+    }
+
+    pub fn is_64bit(self) -> bool {
+        self == ElfClass::Elf64
+    }
+
+    // the size of the addresses, offsets and of every "word" that changes
+    // with the class (like the RELR entries and the note properties)
+    pub fn address_size(self) -> usize {
+        match self {
+            ElfClass::Elf32 => 4,
+            ElfClass::Elf64 => 8,
+        }
+    }
+
+    // the number of hex digits that binutils uses to show an address
+    pub fn address_width(self) -> usize {
+        self.address_size() * 2
+    }
+
+    // reads an address (or any other word of the size of the class) from
+    // the start of bytes
+    pub fn read_address(self, bytes: &[u8], is_little_endian: bool) -> u64 {
+        match self {
+            ElfClass::Elf32 => u32::read_from(&bytes[0..4], is_little_endian) as u64,
+            ElfClass::Elf64 => u64::read_from(&bytes[0..8], is_little_endian),
+        }
+    }
+}
+
+// implemented by the Elf64_* structs that have an Elf32_* version, the
+// entries of the tables are read with the layout of the class of the file
+pub trait ClassParseable: EndianParseable {
+    type Elf32: EndianParseable + Into<Self>;
+
+    // the size of each entry in the file
+    const ELF32_SIZE: usize;
+    const ELF64_SIZE: usize;
+
+    // the name of the struct of each class, used by the errors
+    const ELF32_NAME: &'static str;
+    const ELF64_NAME: &'static str;
+
+    fn size_of(class: ElfClass) -> usize {
+        match class {
+            ElfClass::Elf32 => Self::ELF32_SIZE,
+            ElfClass::Elf64 => Self::ELF64_SIZE,
+        }
+    }
+
+    fn name_of(class: ElfClass) -> &'static str {
+        match class {
+            ElfClass::Elf32 => Self::ELF32_NAME,
+            ElfClass::Elf64 => Self::ELF64_NAME,
+        }
+    }
+
+    fn parse_class(
+        bytes: &[u8],
+        class: ElfClass,
+        is_little_endian: bool,
+    ) -> Result<Self, ElfError> {
+        match class {
+            ElfClass::Elf32 => Self::Elf32::parse_endian(bytes, is_little_endian).map(Into::into),
+            ElfClass::Elf64 => Self::parse_endian(bytes, is_little_endian),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ClassParseable, ElfClass};
+    use crate::elf::def::elf64strc::Elf64_Sym;
+
+    #[test]
+    fn from_ident_returns_the_class_of_the_file() {
+        assert_eq!(ElfClass::from_ident(1), ElfClass::Elf32);
+        assert_eq!(ElfClass::from_ident(2), ElfClass::Elf64);
+        assert_eq!(ElfClass::Elf32.address_size(), 4);
+        assert_eq!(ElfClass::Elf64.address_width(), 16);
+    }
+
+    #[test]
+    fn read_address_reads_a_word_of_the_size_of_the_class() {
+        let bytes = [0x10, 0x20, 0, 0, 1, 0, 0, 0];
+
+        assert_eq!(ElfClass::Elf32.read_address(&bytes, true), 0x2010);
+        assert_eq!(ElfClass::Elf64.read_address(&bytes, true), 0x1_0000_2010);
+    }
+
+    #[test]
+    fn parse_class_widens_the_32_bit_entries() {
+        // st_name, st_value, st_size, st_info, st_other and st_shndx
+        let bytes = [1, 0, 0, 0, 0x20, 0x10, 0, 0, 8, 0, 0, 0, 0x12, 0, 5, 0];
+
+        let sym = Elf64_Sym::parse_class(&bytes, ElfClass::Elf32, true).unwrap();
+
+        assert_eq!(Elf64_Sym::size_of(ElfClass::Elf32), 16);
+        assert_eq!(sym.st_name, 1);
+        assert_eq!(sym.st_value, 0x1020);
+        assert_eq!(sym.st_size, 8);
+        assert_eq!(sym.st_info, 0x12);
+        assert_eq!(sym.st_shndx, 5);
+    }
+}
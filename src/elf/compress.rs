@@ -0,0 +1,305 @@
+use crate::elf::class::{ClassParseable, ElfClass};
+use crate::elf::def::elf32strc::Elf32_Chdr;
+use crate::elf::def::elf64strc::Elf64_Chdr;
+use crate::elf::def::elfconsts::*;
+use crate::elf::error::ElfError;
+use crate::elf::section::Elf64Section;
+use crate::utils::endianess::EndianRead;
+use crate::utils::parser::EndianParseable;
+
+impl EndianParseable for Elf64_Chdr {
+    fn parse_endian(bytes: &[u8], is_little_endian: bool) -> Result<Self, ElfError> {
+        // offset: 0x0 -> 0x18 (relative to the start of the section)
+        if bytes.len() < 24 {
+            return Err(ElfError::truncated(0, "Elf64_Chdr", 24, bytes.len()));
+        }
+
+        Ok(Elf64_Chdr {
+            // offset: 0x0 -> 0x3, ELFCOMPRESS_*
+            ch_type: u32::read_from(&bytes[0..4], is_little_endian),
+            // offset: 0x4 -> 0x7
+            ch_reserved: u32::read_from(&bytes[4..8], is_little_endian),
+            // offset: 0x8 -> 0xF, the size of the data once decompressed
+            ch_size: u64::read_from(&bytes[8..16], is_little_endian),
+            // offset: 0x10 -> 0x17, the sh_addralign of the decompressed data
+            ch_addralign: u64::read_from(&bytes[16..24], is_little_endian),
+        })
+    }
+}
+
+impl EndianParseable for Elf32_Chdr {
+    fn parse_endian(bytes: &[u8], is_little_endian: bool) -> Result<Self, ElfError> {
+        // offset: 0x0 -> 0xC (relative to the start of the section)
+        if bytes.len() < 12 {
+            return Err(ElfError::truncated(0, "Elf32_Chdr", 12, bytes.len()));
+        }
+
+        Ok(Elf32_Chdr {
+            // offset: 0x0 -> 0x3
+            ch_type: u32::read_from(&bytes[0..4], is_little_endian),
+            // offset: 0x4 -> 0x7
+            ch_size: u32::read_from(&bytes[4..8], is_little_endian),
+            // offset: 0x8 -> 0xB
+            ch_addralign: u32::read_from(&bytes[8..12], is_little_endian),
+        })
+    }
+}
+
+impl From<Elf32_Chdr> for Elf64_Chdr {
+    fn from(chdr: Elf32_Chdr) -> Self {
+        Elf64_Chdr {
+            ch_type: chdr.ch_type,
+            ch_reserved: 0,
+            ch_size: chdr.ch_size as u64,
+            ch_addralign: chdr.ch_addralign as u64,
+        }
+    }
+}
+
+impl ClassParseable for Elf64_Chdr {
+    type Elf32 = Elf32_Chdr;
+
+    const ELF32_SIZE: usize = 12;
+    const ELF64_SIZE: usize = 24;
+    const ELF32_NAME: &'static str = "Elf32_Chdr";
+    const ELF64_NAME: &'static str = "Elf64_Chdr";
+}
+
+// the .zdebug_* sections of the older toolchains start with this, the size
+// of the decompressed data (8 bytes, always big endian) and a zlib stream
+const ZDEBUG_MAGIC: &[u8] = b"ZLIB";
+const ZDEBUG_HEADER_SIZE: usize = 12;
+
+// the two ways that the data of a section can be compressed
+#[derive(Debug, Clone, Copy)]
+pub enum Compression {
+    // SHF_COMPRESSED, the data starts with an Elf*_Chdr
+    Chdr(Elf64_Chdr),
+    // a .zdebug_* section, always zlib
+    Zdebug { size: u64 },
+}
+
+// the Elf*_Chdr of a SHF_COMPRESSED section (data is the whole section), None
+// for the other sections
+pub fn compression_header(
+    data: &[u8],
+    section: &Elf64Section,
+    class: ElfClass,
+    is_little_endian: bool,
+) -> Result<Option<Elf64_Chdr>, ElfError> {
+    if section.header.sh_flags & SHF_COMPRESSED as u64 == 0 {
+        return Ok(None);
+    }
+
+    Elf64_Chdr::parse_class(data, class, is_little_endian)
+        .map(Some)
+        .map_err(|err| err.at(section.header.sh_offset))
+}
+
+// how the section is compressed, if it is. A .zdebug_* section without the
+// "ZLIB" magic is left alone, like binutils does
+pub fn section_compression(
+    data: &[u8],
+    section: &Elf64Section,
+    class: ElfClass,
+    is_little_endian: bool,
+) -> Result<Option<Compression>, ElfError> {
+    if let Some(chdr) = compression_header(data, section, class, is_little_endian)? {
+        return Ok(Some(Compression::Chdr(chdr)));
+    }
+
+    if section.name.starts_with(".zdebug")
+        && data.len() >= ZDEBUG_HEADER_SIZE
+        && data.starts_with(ZDEBUG_MAGIC)
+    {
+        let size = u64::read_from(&data[4..12], false);
+        return Ok(Some(Compression::Zdebug { size }));
+    }
+
+    Ok(None)
+}
+
+// the data of the section once decompressed, None when it is not compressed
+pub fn decompress_section(
+    data: &[u8],
+    section: &Elf64Section,
+    class: ElfClass,
+    is_little_endian: bool,
+) -> Result<Option<Vec<u8>>, ElfError> {
+    let offset = section.header.sh_offset;
+    let structure = Elf64_Chdr::name_of(class);
+
+    let (stream, ch_type, size) = match section_compression(data, section, class, is_little_endian)?
+    {
+        None => return Ok(None),
+        Some(Compression::Chdr(chdr)) => (
+            &data[Elf64_Chdr::size_of(class)..],
+            chdr.ch_type,
+            chdr.ch_size,
+        ),
+        Some(Compression::Zdebug { size }) => (&data[ZDEBUG_HEADER_SIZE..], ELFCOMPRESS_ZLIB, size),
+    };
+    let start = offset + (data.len() - stream.len()) as u64;
+
+    let decompressed = match ch_type {
+        ELFCOMPRESS_ZLIB => inflate(stream),
+        ELFCOMPRESS_ZSTD => unzstd(stream),
+        _ => {
+            return Err(ElfError::invalid_value(
+                offset,
+                structure,
+                "ch_type",
+                "ELFCOMPRESS_ZLIB or ELFCOMPRESS_ZSTD",
+                ch_type,
+            ));
+        }
+    }
+    .map_err(|reason| ElfError::decompression(start, structure, reason))?;
+
+    // ch_size is at 0x4 in the 32-bit header and at 0x8 in the 64-bit one
+    if decompressed.len() as u64 != size {
+        return Err(ElfError::invalid_value(
+            offset + class.address_size() as u64,
+            structure,
+            "ch_size",
+            format!(
+                "the size of the decompressed data ({:#x})",
+                decompressed.len()
+            ),
+            size,
+        ));
+    }
+
+    Ok(Some(decompressed))
+}
+
+#[cfg(feature = "compression")]
+fn inflate(stream: &[u8]) -> Result<Vec<u8>, String> {
+    miniz_oxide::inflate::decompress_to_vec_zlib(stream).map_err(|err| err.to_string())
+}
+
+#[cfg(feature = "compression")]
+fn unzstd(stream: &[u8]) -> Result<Vec<u8>, String> {
+    use std::io::Read;
+
+    let mut decoder =
+        ruzstd::decoding::StreamingDecoder::new(stream).map_err(|err| err.to_string())?;
+    let mut data = Vec::new();
+    decoder
+        .read_to_end(&mut data)
+        .map_err(|err| err.to_string())?;
+
+    Ok(data)
+}
+
+// without the feature the compressed sections can still be dumped as they
+// are in the file, only not decompressed
+#[cfg(not(feature = "compression"))]
+fn inflate(_: &[u8]) -> Result<Vec<u8>, String> {
+    Err("zlib support was not built in (the compression feature)".to_string())
+}
+
+#[cfg(not(feature = "compression"))]
+fn unzstd(_: &[u8]) -> Result<Vec<u8>, String> {
+    Err("zstd support was not built in (the compression feature)".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Compression, decompress_section, section_compression};
+    use crate::elf::class::ElfClass;
+    use crate::elf::def::elf64strc::Elf64_Shdr;
+    use crate::test_utils::create_section;
+
+    // an Elf32_Chdr (little endian) with ch_type and ch_size
+    fn create_chdr32(ch_type: u32, ch_size: u32) -> Vec<u8> {
+        [ch_type, ch_size, 1]
+            .iter()
+            .flat_map(|word| word.to_le_bytes())
+            .collect()
+    }
+
+    #[test]
+    fn section_compression_reads_the_chdr_and_the_zdebug_magic() {
+        let mut section = create_section(".debug_info", 1);
+        section.header = Elf64_Shdr {
+            sh_flags: 0x800, // SHF_COMPRESSED
+            ..section.header
+        };
+        let data = create_chdr32(2, 0x40);
+        let compression = section_compression(&data, &section, ElfClass::Elf32, true).unwrap();
+        assert!(matches!(
+            compression,
+            Some(Compression::Chdr(chdr)) if chdr.ch_type == 2 && chdr.ch_size == 0x40
+        ));
+
+        let data = b"ZLIB\0\0\0\0\0\0\x01\x02\x78\x9c";
+        let section = create_section(".zdebug_info", 1);
+        let compression = section_compression(data, &section, ElfClass::Elf64, true).unwrap();
+        assert!(matches!(
+            compression,
+            Some(Compression::Zdebug { size: 0x102 })
+        ));
+
+        // without the flag or the name the data is not compressed
+        let section = create_section(".debug_info", 1);
+        let compression = section_compression(data, &section, ElfClass::Elf64, true).unwrap();
+        assert!(compression.is_none());
+    }
+
+    #[test]
+    fn decompress_section_rejects_the_unknown_compressions() {
+        let mut section = create_section(".debug", 1);
+        section.header = Elf64_Shdr {
+            sh_flags: 0x800,
+            sh_offset: 0x100,
+            ..section.header
+        };
+        let data = create_chdr32(7, 0);
+
+        let err = decompress_section(&data, &section, ElfClass::Elf32, true).unwrap_err();
+
+        assert_eq!(err.offset, 0x100);
+        assert_eq!(err.field, "ch_type");
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn decompress_section_inflates_zlib_and_zstd() {
+        use crate::elf::error::ElfErrorKind;
+
+        let text = b"the same words, the same words, the same words";
+
+        let mut zlib = create_chdr32(1, text.len() as u32);
+        zlib.extend(miniz_oxide::deflate::compress_to_vec_zlib(text, 6));
+        let mut section = create_section(".debug_str", 1);
+        section.header = Elf64_Shdr {
+            sh_flags: 0x800,
+            sh_offset: 0x100,
+            ..section.header
+        };
+        let data = decompress_section(&zlib, &section, ElfClass::Elf32, true).unwrap();
+        assert_eq!(data.as_deref(), Some(&text[..]));
+
+        let mut zstd = create_chdr32(2, text.len() as u32);
+        zstd.extend(ruzstd::encoding::compress_to_vec(
+            &text[..],
+            ruzstd::encoding::CompressionLevel::Fastest,
+        ));
+        let data = decompress_section(&zstd, &section, ElfClass::Elf32, true).unwrap();
+        assert_eq!(data.as_deref(), Some(&text[..]));
+
+        // a ch_size that doesnt match is an error of the header
+        let mut zlib = create_chdr32(1, 3);
+        zlib.extend(miniz_oxide::deflate::compress_to_vec_zlib(text, 6));
+        let err = decompress_section(&zlib, &section, ElfClass::Elf32, true).unwrap_err();
+        assert_eq!((err.offset, err.field), (0x104, "ch_size"));
+
+        // and a broken stream an error of the data after it
+        let mut broken = create_chdr32(1, 3);
+        broken.extend([0x78, 0x9c, 0xff, 0xff]);
+        let err = decompress_section(&broken, &section, ElfClass::Elf32, true).unwrap_err();
+        assert_eq!(err.offset, 0x10c);
+        assert!(matches!(err.kind, ElfErrorKind::Decompression(_)));
+    }
+}
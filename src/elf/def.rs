@@ -1,10 +1,19 @@
 /* automatically generated by rust-bindgen 0.71.1 */
+// generated once from glibc's /usr/include/elf.h and kept here, so building
+// (or running) readelf needs neither libclang nor the glibc headers. New
+// values are added by hand, with the same names and layout of elf.h. The
+// consts also have typed versions in elf::types
 #[allow(non_camel_case_types)]
 pub mod elf64fields {
     pub type Elf64_Half = u16;
     pub type Elf64_Word = u32;
+    pub type Elf64_Xword = u64;
+    pub type Elf64_Sxword = i64;
     pub type Elf64_Addr = u64;
     pub type Elf64_Off = u64;
+    pub type Elf64_Section = u16;
+    pub type Elf64_Relr = Elf64_Xword;
+    pub type Elf64_Versym = Elf64_Half;
 }
 
 #[allow(non_camel_case_types)]
@@ -62,4 +71,1973 @@ pub mod elf64strc {
         ["Offset of field: Elf64_Ehdr::e_shstrndx"]
             [::std::mem::offset_of!(Elf64_Ehdr, e_shstrndx) - 62usize];
     };
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
+    pub struct Elf64_Shdr {
+        pub sh_name: Elf64_Word,
+        pub sh_type: Elf64_Word,
+        pub sh_flags: Elf64_Xword,
+        pub sh_addr: Elf64_Addr,
+        pub sh_offset: Elf64_Off,
+        pub sh_size: Elf64_Xword,
+        pub sh_link: Elf64_Word,
+        pub sh_info: Elf64_Word,
+        pub sh_addralign: Elf64_Xword,
+        pub sh_entsize: Elf64_Xword,
+    }
+    #[allow(clippy::unnecessary_operation, clippy::identity_op)]
+    const _: () = {
+        ["Size of Elf64_Shdr"][::std::mem::size_of::<Elf64_Shdr>() - 64usize];
+        ["Alignment of Elf64_Shdr"][::std::mem::align_of::<Elf64_Shdr>() - 8usize];
+        ["Offset of field: Elf64_Shdr::sh_name"]
+            [::std::mem::offset_of!(Elf64_Shdr, sh_name) - 0usize];
+        ["Offset of field: Elf64_Shdr::sh_type"]
+            [::std::mem::offset_of!(Elf64_Shdr, sh_type) - 4usize];
+        ["Offset of field: Elf64_Shdr::sh_flags"]
+            [::std::mem::offset_of!(Elf64_Shdr, sh_flags) - 8usize];
+        ["Offset of field: Elf64_Shdr::sh_addr"]
+            [::std::mem::offset_of!(Elf64_Shdr, sh_addr) - 16usize];
+        ["Offset of field: Elf64_Shdr::sh_offset"]
+            [::std::mem::offset_of!(Elf64_Shdr, sh_offset) - 24usize];
+        ["Offset of field: Elf64_Shdr::sh_size"]
+            [::std::mem::offset_of!(Elf64_Shdr, sh_size) - 32usize];
+        ["Offset of field: Elf64_Shdr::sh_link"]
+            [::std::mem::offset_of!(Elf64_Shdr, sh_link) - 40usize];
+        ["Offset of field: Elf64_Shdr::sh_info"]
+            [::std::mem::offset_of!(Elf64_Shdr, sh_info) - 44usize];
+        ["Offset of field: Elf64_Shdr::sh_addralign"]
+            [::std::mem::offset_of!(Elf64_Shdr, sh_addralign) - 48usize];
+        ["Offset of field: Elf64_Shdr::sh_entsize"]
+            [::std::mem::offset_of!(Elf64_Shdr, sh_entsize) - 56usize];
+    };
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
+    pub struct Elf64_Phdr {
+        pub p_type: Elf64_Word,
+        pub p_flags: Elf64_Word,
+        pub p_offset: Elf64_Off,
+        pub p_vaddr: Elf64_Addr,
+        pub p_paddr: Elf64_Addr,
+        pub p_filesz: Elf64_Xword,
+        pub p_memsz: Elf64_Xword,
+        pub p_align: Elf64_Xword,
+    }
+    #[allow(clippy::unnecessary_operation, clippy::identity_op)]
+    const _: () = {
+        ["Size of Elf64_Phdr"][::std::mem::size_of::<Elf64_Phdr>() - 56usize];
+        ["Alignment of Elf64_Phdr"][::std::mem::align_of::<Elf64_Phdr>() - 8usize];
+        ["Offset of field: Elf64_Phdr::p_type"]
+            [::std::mem::offset_of!(Elf64_Phdr, p_type) - 0usize];
+        ["Offset of field: Elf64_Phdr::p_flags"]
+            [::std::mem::offset_of!(Elf64_Phdr, p_flags) - 4usize];
+        ["Offset of field: Elf64_Phdr::p_offset"]
+            [::std::mem::offset_of!(Elf64_Phdr, p_offset) - 8usize];
+        ["Offset of field: Elf64_Phdr::p_vaddr"]
+            [::std::mem::offset_of!(Elf64_Phdr, p_vaddr) - 16usize];
+        ["Offset of field: Elf64_Phdr::p_paddr"]
+            [::std::mem::offset_of!(Elf64_Phdr, p_paddr) - 24usize];
+        ["Offset of field: Elf64_Phdr::p_filesz"]
+            [::std::mem::offset_of!(Elf64_Phdr, p_filesz) - 32usize];
+        ["Offset of field: Elf64_Phdr::p_memsz"]
+            [::std::mem::offset_of!(Elf64_Phdr, p_memsz) - 40usize];
+        ["Offset of field: Elf64_Phdr::p_align"]
+            [::std::mem::offset_of!(Elf64_Phdr, p_align) - 48usize];
+    };
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
+    pub struct Elf64_Sym {
+        pub st_name: Elf64_Word,
+        pub st_info: ::std::os::raw::c_uchar,
+        pub st_other: ::std::os::raw::c_uchar,
+        pub st_shndx: Elf64_Section,
+        pub st_value: Elf64_Addr,
+        pub st_size: Elf64_Xword,
+    }
+    #[allow(clippy::unnecessary_operation, clippy::identity_op)]
+    const _: () = {
+        ["Size of Elf64_Sym"][::std::mem::size_of::<Elf64_Sym>() - 24usize];
+        ["Alignment of Elf64_Sym"][::std::mem::align_of::<Elf64_Sym>() - 8usize];
+        ["Offset of field: Elf64_Sym::st_name"]
+            [::std::mem::offset_of!(Elf64_Sym, st_name) - 0usize];
+        ["Offset of field: Elf64_Sym::st_info"]
+            [::std::mem::offset_of!(Elf64_Sym, st_info) - 4usize];
+        ["Offset of field: Elf64_Sym::st_other"]
+            [::std::mem::offset_of!(Elf64_Sym, st_other) - 5usize];
+        ["Offset of field: Elf64_Sym::st_shndx"]
+            [::std::mem::offset_of!(Elf64_Sym, st_shndx) - 6usize];
+        ["Offset of field: Elf64_Sym::st_value"]
+            [::std::mem::offset_of!(Elf64_Sym, st_value) - 8usize];
+        ["Offset of field: Elf64_Sym::st_size"]
+            [::std::mem::offset_of!(Elf64_Sym, st_size) - 16usize];
+    };
+    #[repr(C)]
+    #[derive(Copy, Clone)]
+    pub struct Elf64_Dyn {
+        pub d_tag: Elf64_Sxword,
+        pub d_un: Elf64_Dyn__bindgen_ty_1,
+    }
+    #[repr(C)]
+    #[derive(Copy, Clone)]
+    pub union Elf64_Dyn__bindgen_ty_1 {
+        pub d_val: Elf64_Xword,
+        pub d_ptr: Elf64_Addr,
+    }
+    #[allow(clippy::unnecessary_operation, clippy::identity_op)]
+    const _: () = {
+        ["Size of Elf64_Dyn__bindgen_ty_1"]
+            [::std::mem::size_of::<Elf64_Dyn__bindgen_ty_1>() - 8usize];
+        ["Alignment of Elf64_Dyn__bindgen_ty_1"]
+            [::std::mem::align_of::<Elf64_Dyn__bindgen_ty_1>() - 8usize];
+        ["Offset of field: Elf64_Dyn__bindgen_ty_1::d_val"]
+            [::std::mem::offset_of!(Elf64_Dyn__bindgen_ty_1, d_val) - 0usize];
+        ["Offset of field: Elf64_Dyn__bindgen_ty_1::d_ptr"]
+            [::std::mem::offset_of!(Elf64_Dyn__bindgen_ty_1, d_ptr) - 0usize];
+    };
+    #[allow(clippy::unnecessary_operation, clippy::identity_op)]
+    const _: () = {
+        ["Size of Elf64_Dyn"][::std::mem::size_of::<Elf64_Dyn>() - 16usize];
+        ["Alignment of Elf64_Dyn"][::std::mem::align_of::<Elf64_Dyn>() - 8usize];
+        ["Offset of field: Elf64_Dyn::d_tag"][::std::mem::offset_of!(Elf64_Dyn, d_tag) - 0usize];
+        ["Offset of field: Elf64_Dyn::d_un"][::std::mem::offset_of!(Elf64_Dyn, d_un) - 8usize];
+    };
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
+    pub struct Elf64_Rel {
+        pub r_offset: Elf64_Addr,
+        pub r_info: Elf64_Xword,
+    }
+    #[allow(clippy::unnecessary_operation, clippy::identity_op)]
+    const _: () = {
+        ["Size of Elf64_Rel"][::std::mem::size_of::<Elf64_Rel>() - 16usize];
+        ["Alignment of Elf64_Rel"][::std::mem::align_of::<Elf64_Rel>() - 8usize];
+        ["Offset of field: Elf64_Rel::r_offset"]
+            [::std::mem::offset_of!(Elf64_Rel, r_offset) - 0usize];
+        ["Offset of field: Elf64_Rel::r_info"][::std::mem::offset_of!(Elf64_Rel, r_info) - 8usize];
+    };
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
+    pub struct Elf64_Rela {
+        pub r_offset: Elf64_Addr,
+        pub r_info: Elf64_Xword,
+        pub r_addend: Elf64_Sxword,
+    }
+    #[allow(clippy::unnecessary_operation, clippy::identity_op)]
+    const _: () = {
+        ["Size of Elf64_Rela"][::std::mem::size_of::<Elf64_Rela>() - 24usize];
+        ["Alignment of Elf64_Rela"][::std::mem::align_of::<Elf64_Rela>() - 8usize];
+        ["Offset of field: Elf64_Rela::r_offset"]
+            [::std::mem::offset_of!(Elf64_Rela, r_offset) - 0usize];
+        ["Offset of field: Elf64_Rela::r_info"]
+            [::std::mem::offset_of!(Elf64_Rela, r_info) - 8usize];
+        ["Offset of field: Elf64_Rela::r_addend"]
+            [::std::mem::offset_of!(Elf64_Rela, r_addend) - 16usize];
+    };
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
+    pub struct Elf64_Nhdr {
+        pub n_namesz: Elf64_Word,
+        pub n_descsz: Elf64_Word,
+        pub n_type: Elf64_Word,
+    }
+    #[allow(clippy::unnecessary_operation, clippy::identity_op)]
+    const _: () = {
+        ["Size of Elf64_Nhdr"][::std::mem::size_of::<Elf64_Nhdr>() - 12usize];
+        ["Alignment of Elf64_Nhdr"][::std::mem::align_of::<Elf64_Nhdr>() - 4usize];
+        ["Offset of field: Elf64_Nhdr::n_namesz"]
+            [::std::mem::offset_of!(Elf64_Nhdr, n_namesz) - 0usize];
+        ["Offset of field: Elf64_Nhdr::n_descsz"]
+            [::std::mem::offset_of!(Elf64_Nhdr, n_descsz) - 4usize];
+        ["Offset of field: Elf64_Nhdr::n_type"]
+            [::std::mem::offset_of!(Elf64_Nhdr, n_type) - 8usize];
+    };
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
+    pub struct Elf64_Chdr {
+        pub ch_type: Elf64_Word,
+        pub ch_reserved: Elf64_Word,
+        pub ch_size: Elf64_Xword,
+        pub ch_addralign: Elf64_Xword,
+    }
+    #[allow(clippy::unnecessary_operation, clippy::identity_op)]
+    const _: () = {
+        ["Size of Elf64_Chdr"][::std::mem::size_of::<Elf64_Chdr>() - 24usize];
+        ["Alignment of Elf64_Chdr"][::std::mem::align_of::<Elf64_Chdr>() - 8usize];
+        ["Offset of field: Elf64_Chdr::ch_type"]
+            [::std::mem::offset_of!(Elf64_Chdr, ch_type) - 0usize];
+        ["Offset of field: Elf64_Chdr::ch_reserved"]
+            [::std::mem::offset_of!(Elf64_Chdr, ch_reserved) - 4usize];
+        ["Offset of field: Elf64_Chdr::ch_size"]
+            [::std::mem::offset_of!(Elf64_Chdr, ch_size) - 8usize];
+        ["Offset of field: Elf64_Chdr::ch_addralign"]
+            [::std::mem::offset_of!(Elf64_Chdr, ch_addralign) - 16usize];
+    };
+    // the symbol versioning structures have the same layout in both classes,
+    // so there are no Elf32_ versions of them
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
+    pub struct Elf64_Verdef {
+        pub vd_version: Elf64_Half,
+        pub vd_flags: Elf64_Half,
+        pub vd_ndx: Elf64_Half,
+        pub vd_cnt: Elf64_Half,
+        pub vd_hash: Elf64_Word,
+        pub vd_aux: Elf64_Word,
+        pub vd_next: Elf64_Word,
+    }
+    #[allow(clippy::unnecessary_operation, clippy::identity_op)]
+    const _: () = {
+        ["Size of Elf64_Verdef"][::std::mem::size_of::<Elf64_Verdef>() - 20usize];
+        ["Alignment of Elf64_Verdef"][::std::mem::align_of::<Elf64_Verdef>() - 4usize];
+        ["Offset of field: Elf64_Verdef::vd_version"]
+            [::std::mem::offset_of!(Elf64_Verdef, vd_version) - 0usize];
+        ["Offset of field: Elf64_Verdef::vd_flags"]
+            [::std::mem::offset_of!(Elf64_Verdef, vd_flags) - 2usize];
+        ["Offset of field: Elf64_Verdef::vd_ndx"]
+            [::std::mem::offset_of!(Elf64_Verdef, vd_ndx) - 4usize];
+        ["Offset of field: Elf64_Verdef::vd_cnt"]
+            [::std::mem::offset_of!(Elf64_Verdef, vd_cnt) - 6usize];
+        ["Offset of field: Elf64_Verdef::vd_hash"]
+            [::std::mem::offset_of!(Elf64_Verdef, vd_hash) - 8usize];
+        ["Offset of field: Elf64_Verdef::vd_aux"]
+            [::std::mem::offset_of!(Elf64_Verdef, vd_aux) - 12usize];
+        ["Offset of field: Elf64_Verdef::vd_next"]
+            [::std::mem::offset_of!(Elf64_Verdef, vd_next) - 16usize];
+    };
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
+    pub struct Elf64_Verdaux {
+        pub vda_name: Elf64_Word,
+        pub vda_next: Elf64_Word,
+    }
+    #[allow(clippy::unnecessary_operation, clippy::identity_op)]
+    const _: () = {
+        ["Size of Elf64_Verdaux"][::std::mem::size_of::<Elf64_Verdaux>() - 8usize];
+        ["Alignment of Elf64_Verdaux"][::std::mem::align_of::<Elf64_Verdaux>() - 4usize];
+        ["Offset of field: Elf64_Verdaux::vda_name"]
+            [::std::mem::offset_of!(Elf64_Verdaux, vda_name) - 0usize];
+        ["Offset of field: Elf64_Verdaux::vda_next"]
+            [::std::mem::offset_of!(Elf64_Verdaux, vda_next) - 4usize];
+    };
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
+    pub struct Elf64_Verneed {
+        pub vn_version: Elf64_Half,
+        pub vn_cnt: Elf64_Half,
+        pub vn_file: Elf64_Word,
+        pub vn_aux: Elf64_Word,
+        pub vn_next: Elf64_Word,
+    }
+    #[allow(clippy::unnecessary_operation, clippy::identity_op)]
+    const _: () = {
+        ["Size of Elf64_Verneed"][::std::mem::size_of::<Elf64_Verneed>() - 16usize];
+        ["Alignment of Elf64_Verneed"][::std::mem::align_of::<Elf64_Verneed>() - 4usize];
+        ["Offset of field: Elf64_Verneed::vn_version"]
+            [::std::mem::offset_of!(Elf64_Verneed, vn_version) - 0usize];
+        ["Offset of field: Elf64_Verneed::vn_cnt"]
+            [::std::mem::offset_of!(Elf64_Verneed, vn_cnt) - 2usize];
+        ["Offset of field: Elf64_Verneed::vn_file"]
+            [::std::mem::offset_of!(Elf64_Verneed, vn_file) - 4usize];
+        ["Offset of field: Elf64_Verneed::vn_aux"]
+            [::std::mem::offset_of!(Elf64_Verneed, vn_aux) - 8usize];
+        ["Offset of field: Elf64_Verneed::vn_next"]
+            [::std::mem::offset_of!(Elf64_Verneed, vn_next) - 12usize];
+    };
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
+    pub struct Elf64_Vernaux {
+        pub vna_hash: Elf64_Word,
+        pub vna_flags: Elf64_Half,
+        pub vna_other: Elf64_Half,
+        pub vna_name: Elf64_Word,
+        pub vna_next: Elf64_Word,
+    }
+    #[allow(clippy::unnecessary_operation, clippy::identity_op)]
+    const _: () = {
+        ["Size of Elf64_Vernaux"][::std::mem::size_of::<Elf64_Vernaux>() - 16usize];
+        ["Alignment of Elf64_Vernaux"][::std::mem::align_of::<Elf64_Vernaux>() - 4usize];
+        ["Offset of field: Elf64_Vernaux::vna_hash"]
+            [::std::mem::offset_of!(Elf64_Vernaux, vna_hash) - 0usize];
+        ["Offset of field: Elf64_Vernaux::vna_flags"]
+            [::std::mem::offset_of!(Elf64_Vernaux, vna_flags) - 4usize];
+        ["Offset of field: Elf64_Vernaux::vna_other"]
+            [::std::mem::offset_of!(Elf64_Vernaux, vna_other) - 6usize];
+        ["Offset of field: Elf64_Vernaux::vna_name"]
+            [::std::mem::offset_of!(Elf64_Vernaux, vna_name) - 8usize];
+        ["Offset of field: Elf64_Vernaux::vna_next"]
+            [::std::mem::offset_of!(Elf64_Vernaux, vna_next) - 12usize];
+    };
+}
+
+#[allow(non_camel_case_types)]
+pub mod elf32fields {
+    pub type Elf32_Half = u16;
+    pub type Elf32_Word = u32;
+    pub type Elf32_Sword = i32;
+    pub type Elf32_Addr = u32;
+    pub type Elf32_Off = u32;
+    pub type Elf32_Section = u16;
+    pub type Elf32_Relr = Elf32_Word;
+}
+
+#[allow(non_camel_case_types)]
+pub mod elf32strc {
+    use super::elf32fields::*;
+
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
+    pub struct Elf32_Ehdr {
+        pub e_ident: [::std::os::raw::c_uchar; 16usize],
+        pub e_type: Elf32_Half,
+        pub e_machine: Elf32_Half,
+        pub e_version: Elf32_Word,
+        pub e_entry: Elf32_Addr,
+        pub e_phoff: Elf32_Off,
+        pub e_shoff: Elf32_Off,
+        pub e_flags: Elf32_Word,
+        pub e_ehsize: Elf32_Half,
+        pub e_phentsize: Elf32_Half,
+        pub e_phnum: Elf32_Half,
+        pub e_shentsize: Elf32_Half,
+        pub e_shnum: Elf32_Half,
+        pub e_shstrndx: Elf32_Half,
+    }
+    #[allow(clippy::unnecessary_operation, clippy::identity_op)]
+    const _: () = {
+        ["Size of Elf32_Ehdr"][::std::mem::size_of::<Elf32_Ehdr>() - 52usize];
+        ["Alignment of Elf32_Ehdr"][::std::mem::align_of::<Elf32_Ehdr>() - 4usize];
+        ["Offset of field: Elf32_Ehdr::e_ident"]
+            [::std::mem::offset_of!(Elf32_Ehdr, e_ident) - 0usize];
+        ["Offset of field: Elf32_Ehdr::e_type"]
+            [::std::mem::offset_of!(Elf32_Ehdr, e_type) - 16usize];
+        ["Offset of field: Elf32_Ehdr::e_machine"]
+            [::std::mem::offset_of!(Elf32_Ehdr, e_machine) - 18usize];
+        ["Offset of field: Elf32_Ehdr::e_version"]
+            [::std::mem::offset_of!(Elf32_Ehdr, e_version) - 20usize];
+        ["Offset of field: Elf32_Ehdr::e_entry"]
+            [::std::mem::offset_of!(Elf32_Ehdr, e_entry) - 24usize];
+        ["Offset of field: Elf32_Ehdr::e_phoff"]
+            [::std::mem::offset_of!(Elf32_Ehdr, e_phoff) - 28usize];
+        ["Offset of field: Elf32_Ehdr::e_shoff"]
+            [::std::mem::offset_of!(Elf32_Ehdr, e_shoff) - 32usize];
+        ["Offset of field: Elf32_Ehdr::e_flags"]
+            [::std::mem::offset_of!(Elf32_Ehdr, e_flags) - 36usize];
+        ["Offset of field: Elf32_Ehdr::e_ehsize"]
+            [::std::mem::offset_of!(Elf32_Ehdr, e_ehsize) - 40usize];
+        ["Offset of field: Elf32_Ehdr::e_phentsize"]
+            [::std::mem::offset_of!(Elf32_Ehdr, e_phentsize) - 42usize];
+        ["Offset of field: Elf32_Ehdr::e_phnum"]
+            [::std::mem::offset_of!(Elf32_Ehdr, e_phnum) - 44usize];
+        ["Offset of field: Elf32_Ehdr::e_shentsize"]
+            [::std::mem::offset_of!(Elf32_Ehdr, e_shentsize) - 46usize];
+        ["Offset of field: Elf32_Ehdr::e_shnum"]
+            [::std::mem::offset_of!(Elf32_Ehdr, e_shnum) - 48usize];
+        ["Offset of field: Elf32_Ehdr::e_shstrndx"]
+            [::std::mem::offset_of!(Elf32_Ehdr, e_shstrndx) - 50usize];
+    };
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
+    pub struct Elf32_Shdr {
+        pub sh_name: Elf32_Word,
+        pub sh_type: Elf32_Word,
+        pub sh_flags: Elf32_Word,
+        pub sh_addr: Elf32_Addr,
+        pub sh_offset: Elf32_Off,
+        pub sh_size: Elf32_Word,
+        pub sh_link: Elf32_Word,
+        pub sh_info: Elf32_Word,
+        pub sh_addralign: Elf32_Word,
+        pub sh_entsize: Elf32_Word,
+    }
+    #[allow(clippy::unnecessary_operation, clippy::identity_op)]
+    const _: () = {
+        ["Size of Elf32_Shdr"][::std::mem::size_of::<Elf32_Shdr>() - 40usize];
+        ["Alignment of Elf32_Shdr"][::std::mem::align_of::<Elf32_Shdr>() - 4usize];
+        ["Offset of field: Elf32_Shdr::sh_name"]
+            [::std::mem::offset_of!(Elf32_Shdr, sh_name) - 0usize];
+        ["Offset of field: Elf32_Shdr::sh_type"]
+            [::std::mem::offset_of!(Elf32_Shdr, sh_type) - 4usize];
+        ["Offset of field: Elf32_Shdr::sh_flags"]
+            [::std::mem::offset_of!(Elf32_Shdr, sh_flags) - 8usize];
+        ["Offset of field: Elf32_Shdr::sh_addr"]
+            [::std::mem::offset_of!(Elf32_Shdr, sh_addr) - 12usize];
+        ["Offset of field: Elf32_Shdr::sh_offset"]
+            [::std::mem::offset_of!(Elf32_Shdr, sh_offset) - 16usize];
+        ["Offset of field: Elf32_Shdr::sh_size"]
+            [::std::mem::offset_of!(Elf32_Shdr, sh_size) - 20usize];
+        ["Offset of field: Elf32_Shdr::sh_link"]
+            [::std::mem::offset_of!(Elf32_Shdr, sh_link) - 24usize];
+        ["Offset of field: Elf32_Shdr::sh_info"]
+            [::std::mem::offset_of!(Elf32_Shdr, sh_info) - 28usize];
+        ["Offset of field: Elf32_Shdr::sh_addralign"]
+            [::std::mem::offset_of!(Elf32_Shdr, sh_addralign) - 32usize];
+        ["Offset of field: Elf32_Shdr::sh_entsize"]
+            [::std::mem::offset_of!(Elf32_Shdr, sh_entsize) - 36usize];
+    };
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
+    pub struct Elf32_Phdr {
+        pub p_type: Elf32_Word,
+        pub p_offset: Elf32_Off,
+        pub p_vaddr: Elf32_Addr,
+        pub p_paddr: Elf32_Addr,
+        pub p_filesz: Elf32_Word,
+        pub p_memsz: Elf32_Word,
+        pub p_flags: Elf32_Word,
+        pub p_align: Elf32_Word,
+    }
+    #[allow(clippy::unnecessary_operation, clippy::identity_op)]
+    const _: () = {
+        ["Size of Elf32_Phdr"][::std::mem::size_of::<Elf32_Phdr>() - 32usize];
+        ["Alignment of Elf32_Phdr"][::std::mem::align_of::<Elf32_Phdr>() - 4usize];
+        ["Offset of field: Elf32_Phdr::p_type"]
+            [::std::mem::offset_of!(Elf32_Phdr, p_type) - 0usize];
+        ["Offset of field: Elf32_Phdr::p_offset"]
+            [::std::mem::offset_of!(Elf32_Phdr, p_offset) - 4usize];
+        ["Offset of field: Elf32_Phdr::p_vaddr"]
+            [::std::mem::offset_of!(Elf32_Phdr, p_vaddr) - 8usize];
+        ["Offset of field: Elf32_Phdr::p_paddr"]
+            [::std::mem::offset_of!(Elf32_Phdr, p_paddr) - 12usize];
+        ["Offset of field: Elf32_Phdr::p_filesz"]
+            [::std::mem::offset_of!(Elf32_Phdr, p_filesz) - 16usize];
+        ["Offset of field: Elf32_Phdr::p_memsz"]
+            [::std::mem::offset_of!(Elf32_Phdr, p_memsz) - 20usize];
+        ["Offset of field: Elf32_Phdr::p_flags"]
+            [::std::mem::offset_of!(Elf32_Phdr, p_flags) - 24usize];
+        ["Offset of field: Elf32_Phdr::p_align"]
+            [::std::mem::offset_of!(Elf32_Phdr, p_align) - 28usize];
+    };
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
+    pub struct Elf32_Sym {
+        pub st_name: Elf32_Word,
+        pub st_value: Elf32_Addr,
+        pub st_size: Elf32_Word,
+        pub st_info: ::std::os::raw::c_uchar,
+        pub st_other: ::std::os::raw::c_uchar,
+        pub st_shndx: Elf32_Section,
+    }
+    #[allow(clippy::unnecessary_operation, clippy::identity_op)]
+    const _: () = {
+        ["Size of Elf32_Sym"][::std::mem::size_of::<Elf32_Sym>() - 16usize];
+        ["Alignment of Elf32_Sym"][::std::mem::align_of::<Elf32_Sym>() - 4usize];
+        ["Offset of field: Elf32_Sym::st_name"]
+            [::std::mem::offset_of!(Elf32_Sym, st_name) - 0usize];
+        ["Offset of field: Elf32_Sym::st_value"]
+            [::std::mem::offset_of!(Elf32_Sym, st_value) - 4usize];
+        ["Offset of field: Elf32_Sym::st_size"]
+            [::std::mem::offset_of!(Elf32_Sym, st_size) - 8usize];
+        ["Offset of field: Elf32_Sym::st_info"]
+            [::std::mem::offset_of!(Elf32_Sym, st_info) - 12usize];
+        ["Offset of field: Elf32_Sym::st_other"]
+            [::std::mem::offset_of!(Elf32_Sym, st_other) - 13usize];
+        ["Offset of field: Elf32_Sym::st_shndx"]
+            [::std::mem::offset_of!(Elf32_Sym, st_shndx) - 14usize];
+    };
+    #[repr(C)]
+    #[derive(Copy, Clone)]
+    pub struct Elf32_Dyn {
+        pub d_tag: Elf32_Sword,
+        pub d_un: Elf32_Dyn__bindgen_ty_1,
+    }
+    #[repr(C)]
+    #[derive(Copy, Clone)]
+    pub union Elf32_Dyn__bindgen_ty_1 {
+        pub d_val: Elf32_Word,
+        pub d_ptr: Elf32_Addr,
+    }
+    #[allow(clippy::unnecessary_operation, clippy::identity_op)]
+    const _: () = {
+        ["Size of Elf32_Dyn__bindgen_ty_1"]
+            [::std::mem::size_of::<Elf32_Dyn__bindgen_ty_1>() - 4usize];
+        ["Alignment of Elf32_Dyn__bindgen_ty_1"]
+            [::std::mem::align_of::<Elf32_Dyn__bindgen_ty_1>() - 4usize];
+        ["Offset of field: Elf32_Dyn__bindgen_ty_1::d_val"]
+            [::std::mem::offset_of!(Elf32_Dyn__bindgen_ty_1, d_val) - 0usize];
+        ["Offset of field: Elf32_Dyn__bindgen_ty_1::d_ptr"]
+            [::std::mem::offset_of!(Elf32_Dyn__bindgen_ty_1, d_ptr) - 0usize];
+    };
+    #[allow(clippy::unnecessary_operation, clippy::identity_op)]
+    const _: () = {
+        ["Size of Elf32_Dyn"][::std::mem::size_of::<Elf32_Dyn>() - 8usize];
+        ["Alignment of Elf32_Dyn"][::std::mem::align_of::<Elf32_Dyn>() - 4usize];
+        ["Offset of field: Elf32_Dyn::d_tag"][::std::mem::offset_of!(Elf32_Dyn, d_tag) - 0usize];
+        ["Offset of field: Elf32_Dyn::d_un"][::std::mem::offset_of!(Elf32_Dyn, d_un) - 4usize];
+    };
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
+    pub struct Elf32_Rel {
+        pub r_offset: Elf32_Addr,
+        pub r_info: Elf32_Word,
+    }
+    #[allow(clippy::unnecessary_operation, clippy::identity_op)]
+    const _: () = {
+        ["Size of Elf32_Rel"][::std::mem::size_of::<Elf32_Rel>() - 8usize];
+        ["Alignment of Elf32_Rel"][::std::mem::align_of::<Elf32_Rel>() - 4usize];
+        ["Offset of field: Elf32_Rel::r_offset"]
+            [::std::mem::offset_of!(Elf32_Rel, r_offset) - 0usize];
+        ["Offset of field: Elf32_Rel::r_info"][::std::mem::offset_of!(Elf32_Rel, r_info) - 4usize];
+    };
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
+    pub struct Elf32_Rela {
+        pub r_offset: Elf32_Addr,
+        pub r_info: Elf32_Word,
+        pub r_addend: Elf32_Sword,
+    }
+    #[allow(clippy::unnecessary_operation, clippy::identity_op)]
+    const _: () = {
+        ["Size of Elf32_Rela"][::std::mem::size_of::<Elf32_Rela>() - 12usize];
+        ["Alignment of Elf32_Rela"][::std::mem::align_of::<Elf32_Rela>() - 4usize];
+        ["Offset of field: Elf32_Rela::r_offset"]
+            [::std::mem::offset_of!(Elf32_Rela, r_offset) - 0usize];
+        ["Offset of field: Elf32_Rela::r_info"]
+            [::std::mem::offset_of!(Elf32_Rela, r_info) - 4usize];
+        ["Offset of field: Elf32_Rela::r_addend"]
+            [::std::mem::offset_of!(Elf32_Rela, r_addend) - 8usize];
+    };
+    #[repr(C)]
+    #[derive(Debug, Copy, Clone)]
+    pub struct Elf32_Chdr {
+        pub ch_type: Elf32_Word,
+        pub ch_size: Elf32_Word,
+        pub ch_addralign: Elf32_Word,
+    }
+    #[allow(clippy::unnecessary_operation, clippy::identity_op)]
+    const _: () = {
+        ["Size of Elf32_Chdr"][::std::mem::size_of::<Elf32_Chdr>() - 12usize];
+        ["Alignment of Elf32_Chdr"][::std::mem::align_of::<Elf32_Chdr>() - 4usize];
+        ["Offset of field: Elf32_Chdr::ch_type"]
+            [::std::mem::offset_of!(Elf32_Chdr, ch_type) - 0usize];
+        ["Offset of field: Elf32_Chdr::ch_size"]
+            [::std::mem::offset_of!(Elf32_Chdr, ch_size) - 4usize];
+        ["Offset of field: Elf32_Chdr::ch_addralign"]
+            [::std::mem::offset_of!(Elf32_Chdr, ch_addralign) - 8usize];
+    };
+}
+
+#[allow(non_upper_case_globals, dead_code)]
+pub mod elfconsts {
+    pub const ELFCLASSNONE: u32 = 0;
+    pub const ELFCLASS32: u32 = 1;
+    pub const ELFCLASS64: u32 = 2;
+
+    pub const SHN_UNDEF: u32 = 0;
+    pub const SHN_LORESERVE: u32 = 65280;
+    pub const SHN_LOPROC: u32 = 65280;
+    pub const SHN_HIPROC: u32 = 65311;
+    pub const SHN_LOOS: u32 = 65312;
+    pub const SHN_HIOS: u32 = 65343;
+    pub const SHN_ABS: u32 = 65521;
+    pub const SHN_COMMON: u32 = 65522;
+    pub const SHN_XINDEX: u32 = 65535;
+    pub const SHN_X86_64_LCOMMON: u32 = 65282;
+    pub const SHN_MIPS_SCOMMON: u32 = 65283;
+    pub const SHN_MIPS_SUNDEFINED: u32 = 65284;
+
+    pub const STB_LOCAL: u32 = 0;
+    pub const STB_GLOBAL: u32 = 1;
+    pub const STB_WEAK: u32 = 2;
+    pub const STB_LOOS: u32 = 10;
+    pub const STB_GNU_UNIQUE: u32 = 10;
+    pub const STB_HIOS: u32 = 12;
+    pub const STB_LOPROC: u32 = 13;
+    pub const STB_HIPROC: u32 = 15;
+
+    pub const STN_UNDEF: u32 = 0;
+
+    pub const STT_NOTYPE: u32 = 0;
+    pub const STT_OBJECT: u32 = 1;
+    pub const STT_FUNC: u32 = 2;
+    pub const STT_SECTION: u32 = 3;
+    pub const STT_FILE: u32 = 4;
+    pub const STT_COMMON: u32 = 5;
+    pub const STT_TLS: u32 = 6;
+    pub const STT_RELC: u32 = 8;
+    pub const STT_SRELC: u32 = 9;
+    pub const STT_LOOS: u32 = 10;
+    pub const STT_GNU_IFUNC: u32 = 10;
+    pub const STT_HIOS: u32 = 12;
+    pub const STT_LOPROC: u32 = 13;
+    pub const STT_HIPROC: u32 = 15;
+    pub const STT_ARM_TFUNC: u32 = 13;
+    pub const STT_SPARC_REGISTER: u32 = 13;
+
+    pub const STV_DEFAULT: u32 = 0;
+    pub const STV_INTERNAL: u32 = 1;
+    pub const STV_HIDDEN: u32 = 2;
+    pub const STV_PROTECTED: u32 = 3;
+
+    pub const STO_AARCH64_VARIANT_PCS: u32 = 128;
+    pub const STO_RISCV_VARIANT_CC: u32 = 128;
+
+    pub const SHT_NULL: u32 = 0;
+    pub const SHT_PROGBITS: u32 = 1;
+    pub const SHT_SYMTAB: u32 = 2;
+    pub const SHT_STRTAB: u32 = 3;
+    pub const SHT_RELA: u32 = 4;
+    pub const SHT_HASH: u32 = 5;
+    pub const SHT_DYNAMIC: u32 = 6;
+    pub const SHT_NOTE: u32 = 7;
+    pub const SHT_NOBITS: u32 = 8;
+    pub const SHT_REL: u32 = 9;
+    pub const SHT_SHLIB: u32 = 10;
+    pub const SHT_DYNSYM: u32 = 11;
+    pub const SHT_INIT_ARRAY: u32 = 14;
+    pub const SHT_FINI_ARRAY: u32 = 15;
+    pub const SHT_PREINIT_ARRAY: u32 = 16;
+    pub const SHT_GROUP: u32 = 17;
+    pub const SHT_SYMTAB_SHNDX: u32 = 18;
+    pub const SHT_RELR: u32 = 19;
+    pub const SHT_LOOS: u32 = 1610612736;
+    pub const SHT_GNU_ATTRIBUTES: u32 = 1879048181;
+    pub const SHT_GNU_HASH: u32 = 1879048182;
+    pub const SHT_GNU_LIBLIST: u32 = 1879048183;
+    pub const SHT_GNU_verdef: u32 = 1879048189;
+    pub const SHT_GNU_verneed: u32 = 1879048190;
+    pub const SHT_GNU_versym: u32 = 1879048191;
+    pub const SHT_HIOS: u32 = 1879048191;
+    pub const SHT_LOPROC: u32 = 1879048192;
+    pub const SHT_HIPROC: u32 = 2147483647;
+    pub const SHT_LOUSER: u32 = 2147483648;
+    pub const SHT_HIUSER: u32 = 2415919103;
+
+    pub const SHF_WRITE: u32 = 1;
+    pub const SHF_ALLOC: u32 = 2;
+    pub const SHF_EXECINSTR: u32 = 4;
+    pub const SHF_MERGE: u32 = 16;
+    pub const SHF_STRINGS: u32 = 32;
+    pub const SHF_INFO_LINK: u32 = 64;
+    pub const SHF_LINK_ORDER: u32 = 128;
+    pub const SHF_OS_NONCONFORMING: u32 = 256;
+    pub const SHF_GROUP: u32 = 512;
+    pub const SHF_TLS: u32 = 1024;
+    pub const SHF_COMPRESSED: u32 = 2048;
+    pub const SHF_MASKOS: u32 = 267386880;
+    pub const SHF_MASKPROC: u32 = 4026531840;
+    pub const SHF_GNU_RETAIN: u32 = 2097152;
+    pub const SHF_GNU_MBIND: u32 = 16777216;
+    pub const SHF_EXCLUDE: u32 = 2147483648;
+    pub const SHF_X86_64_LARGE: u32 = 268435456;
+    pub const SHF_ARM_PURECODE: u32 = 536870912;
+    pub const SHF_PPC_VLE: u32 = 268435456;
+
+    pub const ELFCOMPRESS_ZLIB: u32 = 1;
+    pub const ELFCOMPRESS_ZSTD: u32 = 2;
+
+    pub const VER_DEF_NONE: u32 = 0;
+    pub const VER_DEF_CURRENT: u32 = 1;
+    pub const VER_FLG_BASE: u32 = 1;
+    pub const VER_FLG_WEAK: u32 = 2;
+    pub const VER_FLG_INFO: u32 = 4;
+    pub const VER_NDX_LOCAL: u32 = 0;
+    pub const VER_NDX_GLOBAL: u32 = 1;
+    pub const VER_NDX_LORESERVE: u32 = 65280;
+    pub const VER_NDX_ELIMINATE: u32 = 65281;
+    pub const VER_NEED_NONE: u32 = 0;
+    pub const VER_NEED_CURRENT: u32 = 1;
+    pub const VERSYM_HIDDEN: u32 = 32768;
+    pub const VERSYM_VERSION: u32 = 32767;
+
+    pub const PN_XNUM: u32 = 65535;
+
+    pub const PT_NULL: u32 = 0;
+    pub const PT_LOAD: u32 = 1;
+    pub const PT_DYNAMIC: u32 = 2;
+    pub const PT_INTERP: u32 = 3;
+    pub const PT_NOTE: u32 = 4;
+    pub const PT_SHLIB: u32 = 5;
+    pub const PT_PHDR: u32 = 6;
+    pub const PT_TLS: u32 = 7;
+    pub const PT_LOOS: u32 = 1610612736;
+    pub const PT_GNU_EH_FRAME: u32 = 1685382480;
+    pub const PT_GNU_STACK: u32 = 1685382481;
+    pub const PT_GNU_RELRO: u32 = 1685382482;
+    pub const PT_GNU_PROPERTY: u32 = 1685382483;
+    pub const PT_GNU_SFRAME: u32 = 1685382484;
+    pub const PT_GNU_MBIND_LO: u32 = 1685382485;
+    pub const PT_GNU_MBIND_HI: u32 = 1685386580;
+    pub const PT_OPENBSD_RANDOMIZE: u32 = 1705237478;
+    pub const PT_OPENBSD_WXNEEDED: u32 = 1705237479;
+    pub const PT_OPENBSD_BOOTDATA: u32 = 1705253862;
+    pub const PT_HIOS: u32 = 1879048191;
+    pub const PT_LOPROC: u32 = 1879048192;
+    pub const PT_HIPROC: u32 = 2147483647;
+
+    pub const PF_X: u32 = 1;
+    pub const PF_W: u32 = 2;
+    pub const PF_R: u32 = 4;
+
+    pub const ET_NONE: u32 = 0;
+    pub const ET_REL: u32 = 1;
+    pub const ET_EXEC: u32 = 2;
+    pub const ET_DYN: u32 = 3;
+    pub const ET_CORE: u32 = 4;
+    pub const ET_NUM: u32 = 5;
+    pub const ET_LOOS: u32 = 65024;
+    pub const ET_HIOS: u32 = 65279;
+    pub const ET_LOPROC: u32 = 65280;
+    pub const ET_HIPROC: u32 = 65535;
+
+    pub const DT_NULL: u32 = 0;
+    pub const DT_NEEDED: u32 = 1;
+    pub const DT_PLTRELSZ: u32 = 2;
+    pub const DT_PLTGOT: u32 = 3;
+    pub const DT_HASH: u32 = 4;
+    pub const DT_STRTAB: u32 = 5;
+    pub const DT_SYMTAB: u32 = 6;
+    pub const DT_RELA: u32 = 7;
+    pub const DT_RELASZ: u32 = 8;
+    pub const DT_RELAENT: u32 = 9;
+    pub const DT_STRSZ: u32 = 10;
+    pub const DT_SYMENT: u32 = 11;
+    pub const DT_INIT: u32 = 12;
+    pub const DT_FINI: u32 = 13;
+    pub const DT_SONAME: u32 = 14;
+    pub const DT_RPATH: u32 = 15;
+    pub const DT_SYMBOLIC: u32 = 16;
+    pub const DT_REL: u32 = 17;
+    pub const DT_RELSZ: u32 = 18;
+    pub const DT_RELENT: u32 = 19;
+    pub const DT_PLTREL: u32 = 20;
+    pub const DT_DEBUG: u32 = 21;
+    pub const DT_TEXTREL: u32 = 22;
+    pub const DT_JMPREL: u32 = 23;
+    pub const DT_BIND_NOW: u32 = 24;
+    pub const DT_INIT_ARRAY: u32 = 25;
+    pub const DT_FINI_ARRAY: u32 = 26;
+    pub const DT_INIT_ARRAYSZ: u32 = 27;
+    pub const DT_FINI_ARRAYSZ: u32 = 28;
+    pub const DT_RUNPATH: u32 = 29;
+    pub const DT_FLAGS: u32 = 30;
+    pub const DT_ENCODING: u32 = 32;
+    pub const DT_PREINIT_ARRAY: u32 = 32;
+    pub const DT_PREINIT_ARRAYSZ: u32 = 33;
+    pub const DT_SYMTAB_SHNDX: u32 = 34;
+    pub const DT_RELRSZ: u32 = 35;
+    pub const DT_RELR: u32 = 36;
+    pub const DT_RELRENT: u32 = 37;
+    pub const DT_LOOS: u32 = 1610612749;
+    pub const DT_GNU_PRELINKED: u32 = 1879047669;
+    pub const DT_GNU_CONFLICTSZ: u32 = 1879047670;
+    pub const DT_GNU_LIBLISTSZ: u32 = 1879047671;
+    pub const DT_CHECKSUM: u32 = 1879047672;
+    pub const DT_PLTPADSZ: u32 = 1879047673;
+    pub const DT_MOVEENT: u32 = 1879047674;
+    pub const DT_MOVESZ: u32 = 1879047675;
+    pub const DT_FEATURE_1: u32 = 1879047676;
+    pub const DT_POSFLAG_1: u32 = 1879047677;
+    pub const DT_SYMINSZ: u32 = 1879047678;
+    pub const DT_SYMINENT: u32 = 1879047679;
+    pub const DT_GNU_HASH: u32 = 1879047925;
+    pub const DT_TLSDESC_PLT: u32 = 1879047926;
+    pub const DT_TLSDESC_GOT: u32 = 1879047927;
+    pub const DT_GNU_CONFLICT: u32 = 1879047928;
+    pub const DT_GNU_LIBLIST: u32 = 1879047929;
+    pub const DT_CONFIG: u32 = 1879047930;
+    pub const DT_DEPAUDIT: u32 = 1879047931;
+    pub const DT_AUDIT: u32 = 1879047932;
+    pub const DT_PLTPAD: u32 = 1879047933;
+    pub const DT_MOVETAB: u32 = 1879047934;
+    pub const DT_SYMINFO: u32 = 1879047935;
+    pub const DT_VERSYM: u32 = 1879048176;
+    pub const DT_RELACOUNT: u32 = 1879048185;
+    pub const DT_RELCOUNT: u32 = 1879048186;
+    pub const DT_FLAGS_1: u32 = 1879048187;
+    pub const DT_VERDEF: u32 = 1879048188;
+    pub const DT_VERDEFNUM: u32 = 1879048189;
+    pub const DT_VERNEED: u32 = 1879048190;
+    pub const DT_VERNEEDNUM: u32 = 1879048191;
+    pub const DT_HIOS: u32 = 1879044096;
+    pub const DT_LOPROC: u32 = 1879048192;
+    pub const DT_HIPROC: u32 = 2147483647;
+    pub const DT_AUXILIARY: u32 = 2147483645;
+    pub const DT_FILTER: u32 = 2147483647;
+    pub const DT_SPARC_REGISTER: u32 = 1879048193;
+    pub const DT_PPC_GOT: u32 = 1879048192;
+    pub const DT_PPC_OPT: u32 = 1879048193;
+    pub const DT_PPC64_GLINK: u32 = 1879048192;
+    pub const DT_PPC64_OPD: u32 = 1879048193;
+    pub const DT_PPC64_OPDSZ: u32 = 1879048194;
+    pub const DT_PPC64_OPT: u32 = 1879048195;
+    pub const DT_AARCH64_BTI_PLT: u32 = 1879048193;
+    pub const DT_AARCH64_PAC_PLT: u32 = 1879048195;
+    pub const DT_AARCH64_VARIANT_PCS: u32 = 1879048197;
+    pub const DT_RISCV_VARIANT_CC: u32 = 1879048193;
+
+    pub const DF_ORIGIN: u32 = 1;
+    pub const DF_SYMBOLIC: u32 = 2;
+    pub const DF_TEXTREL: u32 = 4;
+    pub const DF_BIND_NOW: u32 = 8;
+    pub const DF_STATIC_TLS: u32 = 16;
+
+    pub const DF_1_NOW: u32 = 1;
+    pub const DF_1_GLOBAL: u32 = 2;
+    pub const DF_1_GROUP: u32 = 4;
+    pub const DF_1_NODELETE: u32 = 8;
+    pub const DF_1_LOADFLTR: u32 = 16;
+    pub const DF_1_INITFIRST: u32 = 32;
+    pub const DF_1_NOOPEN: u32 = 64;
+    pub const DF_1_ORIGIN: u32 = 128;
+    pub const DF_1_DIRECT: u32 = 256;
+    pub const DF_1_TRANS: u32 = 512;
+    pub const DF_1_INTERPOSE: u32 = 1024;
+    pub const DF_1_NODEFLIB: u32 = 2048;
+    pub const DF_1_NODUMP: u32 = 4096;
+    pub const DF_1_CONFALT: u32 = 8192;
+    pub const DF_1_ENDFILTEE: u32 = 16384;
+    pub const DF_1_DISPRELDNE: u32 = 32768;
+    pub const DF_1_DISPRELPND: u32 = 65536;
+    pub const DF_1_NODIRECT: u32 = 131072;
+    pub const DF_1_IGNMULDEF: u32 = 262144;
+    pub const DF_1_NOKSYMS: u32 = 524288;
+    pub const DF_1_NOHDR: u32 = 1048576;
+    pub const DF_1_EDITED: u32 = 2097152;
+    pub const DF_1_NORELOC: u32 = 4194304;
+    pub const DF_1_SYMINTPOSE: u32 = 8388608;
+    pub const DF_1_GLOBAUDIT: u32 = 16777216;
+    pub const DF_1_SINGLETON: u32 = 33554432;
+    pub const DF_1_STUB: u32 = 67108864;
+    pub const DF_1_PIE: u32 = 134217728;
+    pub const DF_1_KMOD: u32 = 268435456;
+    pub const DF_1_WEAKFILTER: u32 = 536870912;
+    pub const DF_1_NOCOMMON: u32 = 1073741824;
+
+    pub const DTF_1_PARINIT: u32 = 1;
+    pub const DTF_1_CONFEXP: u32 = 2;
+
+    pub const DF_P1_LAZYLOAD: u32 = 1;
+    pub const DF_P1_GROUPPERM: u32 = 2;
+
+    pub const R_X86_64_NONE: u32 = 0;
+    pub const R_X86_64_64: u32 = 1;
+    pub const R_X86_64_PC32: u32 = 2;
+    pub const R_X86_64_GOT32: u32 = 3;
+    pub const R_X86_64_PLT32: u32 = 4;
+    pub const R_X86_64_COPY: u32 = 5;
+    pub const R_X86_64_GLOB_DAT: u32 = 6;
+    pub const R_X86_64_JUMP_SLOT: u32 = 7;
+    pub const R_X86_64_RELATIVE: u32 = 8;
+    pub const R_X86_64_GOTPCREL: u32 = 9;
+    pub const R_X86_64_32: u32 = 10;
+    pub const R_X86_64_32S: u32 = 11;
+    pub const R_X86_64_16: u32 = 12;
+    pub const R_X86_64_PC16: u32 = 13;
+    pub const R_X86_64_8: u32 = 14;
+    pub const R_X86_64_PC8: u32 = 15;
+    pub const R_X86_64_DTPMOD64: u32 = 16;
+    pub const R_X86_64_DTPOFF64: u32 = 17;
+    pub const R_X86_64_TPOFF64: u32 = 18;
+    pub const R_X86_64_TLSGD: u32 = 19;
+    pub const R_X86_64_TLSLD: u32 = 20;
+    pub const R_X86_64_DTPOFF32: u32 = 21;
+    pub const R_X86_64_GOTTPOFF: u32 = 22;
+    pub const R_X86_64_TPOFF32: u32 = 23;
+    pub const R_X86_64_PC64: u32 = 24;
+    pub const R_X86_64_GOTOFF64: u32 = 25;
+    pub const R_X86_64_GOTPC32: u32 = 26;
+    pub const R_X86_64_GOT64: u32 = 27;
+    pub const R_X86_64_GOTPCREL64: u32 = 28;
+    pub const R_X86_64_GOTPC64: u32 = 29;
+    pub const R_X86_64_GOTPLT64: u32 = 30;
+    pub const R_X86_64_PLTOFF64: u32 = 31;
+    pub const R_X86_64_SIZE32: u32 = 32;
+    pub const R_X86_64_SIZE64: u32 = 33;
+    pub const R_X86_64_GOTPC32_TLSDESC: u32 = 34;
+    pub const R_X86_64_TLSDESC_CALL: u32 = 35;
+    pub const R_X86_64_TLSDESC: u32 = 36;
+    pub const R_X86_64_IRELATIVE: u32 = 37;
+    pub const R_X86_64_RELATIVE64: u32 = 38;
+    pub const R_X86_64_GOTPCRELX: u32 = 41;
+    pub const R_X86_64_REX_GOTPCRELX: u32 = 42;
+
+    pub const R_AARCH64_NONE: u32 = 0;
+    pub const R_AARCH64_P32_ABS32: u32 = 1;
+    pub const R_AARCH64_P32_COPY: u32 = 180;
+    pub const R_AARCH64_P32_GLOB_DAT: u32 = 181;
+    pub const R_AARCH64_P32_JUMP_SLOT: u32 = 182;
+    pub const R_AARCH64_P32_RELATIVE: u32 = 183;
+    pub const R_AARCH64_P32_TLS_DTPMOD: u32 = 184;
+    pub const R_AARCH64_P32_TLS_DTPREL: u32 = 185;
+    pub const R_AARCH64_P32_TLS_TPREL: u32 = 186;
+    pub const R_AARCH64_P32_TLSDESC: u32 = 187;
+    pub const R_AARCH64_P32_IRELATIVE: u32 = 188;
+    pub const R_AARCH64_ABS64: u32 = 257;
+    pub const R_AARCH64_ABS32: u32 = 258;
+    pub const R_AARCH64_ABS16: u32 = 259;
+    pub const R_AARCH64_PREL64: u32 = 260;
+    pub const R_AARCH64_PREL32: u32 = 261;
+    pub const R_AARCH64_PREL16: u32 = 262;
+    pub const R_AARCH64_MOVW_UABS_G0: u32 = 263;
+    pub const R_AARCH64_MOVW_UABS_G0_NC: u32 = 264;
+    pub const R_AARCH64_MOVW_UABS_G1: u32 = 265;
+    pub const R_AARCH64_MOVW_UABS_G1_NC: u32 = 266;
+    pub const R_AARCH64_MOVW_UABS_G2: u32 = 267;
+    pub const R_AARCH64_MOVW_UABS_G2_NC: u32 = 268;
+    pub const R_AARCH64_MOVW_UABS_G3: u32 = 269;
+    pub const R_AARCH64_MOVW_SABS_G0: u32 = 270;
+    pub const R_AARCH64_MOVW_SABS_G1: u32 = 271;
+    pub const R_AARCH64_MOVW_SABS_G2: u32 = 272;
+    pub const R_AARCH64_LD_PREL_LO19: u32 = 273;
+    pub const R_AARCH64_ADR_PREL_LO21: u32 = 274;
+    pub const R_AARCH64_ADR_PREL_PG_HI21: u32 = 275;
+    pub const R_AARCH64_ADR_PREL_PG_HI21_NC: u32 = 276;
+    pub const R_AARCH64_ADD_ABS_LO12_NC: u32 = 277;
+    pub const R_AARCH64_LDST8_ABS_LO12_NC: u32 = 278;
+    pub const R_AARCH64_TSTBR14: u32 = 279;
+    pub const R_AARCH64_CONDBR19: u32 = 280;
+    pub const R_AARCH64_JUMP26: u32 = 282;
+    pub const R_AARCH64_CALL26: u32 = 283;
+    pub const R_AARCH64_LDST16_ABS_LO12_NC: u32 = 284;
+    pub const R_AARCH64_LDST32_ABS_LO12_NC: u32 = 285;
+    pub const R_AARCH64_LDST64_ABS_LO12_NC: u32 = 286;
+    pub const R_AARCH64_MOVW_PREL_G0: u32 = 287;
+    pub const R_AARCH64_MOVW_PREL_G0_NC: u32 = 288;
+    pub const R_AARCH64_MOVW_PREL_G1: u32 = 289;
+    pub const R_AARCH64_MOVW_PREL_G1_NC: u32 = 290;
+    pub const R_AARCH64_MOVW_PREL_G2: u32 = 291;
+    pub const R_AARCH64_MOVW_PREL_G2_NC: u32 = 292;
+    pub const R_AARCH64_MOVW_PREL_G3: u32 = 293;
+    pub const R_AARCH64_LDST128_ABS_LO12_NC: u32 = 299;
+    pub const R_AARCH64_MOVW_GOTOFF_G0: u32 = 300;
+    pub const R_AARCH64_MOVW_GOTOFF_G0_NC: u32 = 301;
+    pub const R_AARCH64_MOVW_GOTOFF_G1: u32 = 302;
+    pub const R_AARCH64_MOVW_GOTOFF_G1_NC: u32 = 303;
+    pub const R_AARCH64_MOVW_GOTOFF_G2: u32 = 304;
+    pub const R_AARCH64_MOVW_GOTOFF_G2_NC: u32 = 305;
+    pub const R_AARCH64_MOVW_GOTOFF_G3: u32 = 306;
+    pub const R_AARCH64_GOTREL64: u32 = 307;
+    pub const R_AARCH64_GOTREL32: u32 = 308;
+    pub const R_AARCH64_GOT_LD_PREL19: u32 = 309;
+    pub const R_AARCH64_LD64_GOTOFF_LO15: u32 = 310;
+    pub const R_AARCH64_ADR_GOT_PAGE: u32 = 311;
+    pub const R_AARCH64_LD64_GOT_LO12_NC: u32 = 312;
+    pub const R_AARCH64_LD64_GOTPAGE_LO15: u32 = 313;
+    pub const R_AARCH64_TLSGD_ADR_PREL21: u32 = 512;
+    pub const R_AARCH64_TLSGD_ADR_PAGE21: u32 = 513;
+    pub const R_AARCH64_TLSGD_ADD_LO12_NC: u32 = 514;
+    pub const R_AARCH64_TLSGD_MOVW_G1: u32 = 515;
+    pub const R_AARCH64_TLSGD_MOVW_G0_NC: u32 = 516;
+    pub const R_AARCH64_TLSLD_ADR_PREL21: u32 = 517;
+    pub const R_AARCH64_TLSLD_ADR_PAGE21: u32 = 518;
+    pub const R_AARCH64_TLSLD_ADD_LO12_NC: u32 = 519;
+    pub const R_AARCH64_TLSLD_MOVW_G1: u32 = 520;
+    pub const R_AARCH64_TLSLD_MOVW_G0_NC: u32 = 521;
+    pub const R_AARCH64_TLSLD_LD_PREL19: u32 = 522;
+    pub const R_AARCH64_TLSLD_MOVW_DTPREL_G2: u32 = 523;
+    pub const R_AARCH64_TLSLD_MOVW_DTPREL_G1: u32 = 524;
+    pub const R_AARCH64_TLSLD_MOVW_DTPREL_G1_NC: u32 = 525;
+    pub const R_AARCH64_TLSLD_MOVW_DTPREL_G0: u32 = 526;
+    pub const R_AARCH64_TLSLD_MOVW_DTPREL_G0_NC: u32 = 527;
+    pub const R_AARCH64_TLSLD_ADD_DTPREL_HI12: u32 = 528;
+    pub const R_AARCH64_TLSLD_ADD_DTPREL_LO12: u32 = 529;
+    pub const R_AARCH64_TLSLD_ADD_DTPREL_LO12_NC: u32 = 530;
+    pub const R_AARCH64_TLSLD_LDST8_DTPREL_LO12: u32 = 531;
+    pub const R_AARCH64_TLSLD_LDST8_DTPREL_LO12_NC: u32 = 532;
+    pub const R_AARCH64_TLSLD_LDST16_DTPREL_LO12: u32 = 533;
+    pub const R_AARCH64_TLSLD_LDST16_DTPREL_LO12_NC: u32 = 534;
+    pub const R_AARCH64_TLSLD_LDST32_DTPREL_LO12: u32 = 535;
+    pub const R_AARCH64_TLSLD_LDST32_DTPREL_LO12_NC: u32 = 536;
+    pub const R_AARCH64_TLSLD_LDST64_DTPREL_LO12: u32 = 537;
+    pub const R_AARCH64_TLSLD_LDST64_DTPREL_LO12_NC: u32 = 538;
+    pub const R_AARCH64_TLSIE_MOVW_GOTTPREL_G1: u32 = 539;
+    pub const R_AARCH64_TLSIE_MOVW_GOTTPREL_G0_NC: u32 = 540;
+    pub const R_AARCH64_TLSIE_ADR_GOTTPREL_PAGE21: u32 = 541;
+    pub const R_AARCH64_TLSIE_LD64_GOTTPREL_LO12_NC: u32 = 542;
+    pub const R_AARCH64_TLSIE_LD_GOTTPREL_PREL19: u32 = 543;
+    pub const R_AARCH64_TLSLE_MOVW_TPREL_G2: u32 = 544;
+    pub const R_AARCH64_TLSLE_MOVW_TPREL_G1: u32 = 545;
+    pub const R_AARCH64_TLSLE_MOVW_TPREL_G1_NC: u32 = 546;
+    pub const R_AARCH64_TLSLE_MOVW_TPREL_G0: u32 = 547;
+    pub const R_AARCH64_TLSLE_MOVW_TPREL_G0_NC: u32 = 548;
+    pub const R_AARCH64_TLSLE_ADD_TPREL_HI12: u32 = 549;
+    pub const R_AARCH64_TLSLE_ADD_TPREL_LO12: u32 = 550;
+    pub const R_AARCH64_TLSLE_ADD_TPREL_LO12_NC: u32 = 551;
+    pub const R_AARCH64_TLSLE_LDST8_TPREL_LO12: u32 = 552;
+    pub const R_AARCH64_TLSLE_LDST8_TPREL_LO12_NC: u32 = 553;
+    pub const R_AARCH64_TLSLE_LDST16_TPREL_LO12: u32 = 554;
+    pub const R_AARCH64_TLSLE_LDST16_TPREL_LO12_NC: u32 = 555;
+    pub const R_AARCH64_TLSLE_LDST32_TPREL_LO12: u32 = 556;
+    pub const R_AARCH64_TLSLE_LDST32_TPREL_LO12_NC: u32 = 557;
+    pub const R_AARCH64_TLSLE_LDST64_TPREL_LO12: u32 = 558;
+    pub const R_AARCH64_TLSLE_LDST64_TPREL_LO12_NC: u32 = 559;
+    pub const R_AARCH64_TLSDESC_LD_PREL19: u32 = 560;
+    pub const R_AARCH64_TLSDESC_ADR_PREL21: u32 = 561;
+    pub const R_AARCH64_TLSDESC_ADR_PAGE21: u32 = 562;
+    pub const R_AARCH64_TLSDESC_LD64_LO12: u32 = 563;
+    pub const R_AARCH64_TLSDESC_ADD_LO12: u32 = 564;
+    pub const R_AARCH64_TLSDESC_OFF_G1: u32 = 565;
+    pub const R_AARCH64_TLSDESC_OFF_G0_NC: u32 = 566;
+    pub const R_AARCH64_TLSDESC_LDR: u32 = 567;
+    pub const R_AARCH64_TLSDESC_ADD: u32 = 568;
+    pub const R_AARCH64_TLSDESC_CALL: u32 = 569;
+    pub const R_AARCH64_TLSLE_LDST128_TPREL_LO12: u32 = 570;
+    pub const R_AARCH64_TLSLE_LDST128_TPREL_LO12_NC: u32 = 571;
+    pub const R_AARCH64_TLSLD_LDST128_DTPREL_LO12: u32 = 572;
+    pub const R_AARCH64_TLSLD_LDST128_DTPREL_LO12_NC: u32 = 573;
+    pub const R_AARCH64_COPY: u32 = 1024;
+    pub const R_AARCH64_GLOB_DAT: u32 = 1025;
+    pub const R_AARCH64_JUMP_SLOT: u32 = 1026;
+    pub const R_AARCH64_RELATIVE: u32 = 1027;
+    pub const R_AARCH64_TLS_DTPMOD: u32 = 1028;
+    pub const R_AARCH64_TLS_DTPREL: u32 = 1029;
+    pub const R_AARCH64_TLS_TPREL: u32 = 1030;
+    pub const R_AARCH64_TLSDESC: u32 = 1031;
+    pub const R_AARCH64_IRELATIVE: u32 = 1032;
+
+    pub const R_RISCV_NONE: u32 = 0;
+    pub const R_RISCV_32: u32 = 1;
+    pub const R_RISCV_64: u32 = 2;
+    pub const R_RISCV_RELATIVE: u32 = 3;
+    pub const R_RISCV_COPY: u32 = 4;
+    pub const R_RISCV_JUMP_SLOT: u32 = 5;
+    pub const R_RISCV_TLS_DTPMOD32: u32 = 6;
+    pub const R_RISCV_TLS_DTPMOD64: u32 = 7;
+    pub const R_RISCV_TLS_DTPREL32: u32 = 8;
+    pub const R_RISCV_TLS_DTPREL64: u32 = 9;
+    pub const R_RISCV_TLS_TPREL32: u32 = 10;
+    pub const R_RISCV_TLS_TPREL64: u32 = 11;
+    pub const R_RISCV_BRANCH: u32 = 16;
+    pub const R_RISCV_JAL: u32 = 17;
+    pub const R_RISCV_CALL: u32 = 18;
+    pub const R_RISCV_CALL_PLT: u32 = 19;
+    pub const R_RISCV_GOT_HI20: u32 = 20;
+    pub const R_RISCV_TLS_GOT_HI20: u32 = 21;
+    pub const R_RISCV_TLS_GD_HI20: u32 = 22;
+    pub const R_RISCV_PCREL_HI20: u32 = 23;
+    pub const R_RISCV_PCREL_LO12_I: u32 = 24;
+    pub const R_RISCV_PCREL_LO12_S: u32 = 25;
+    pub const R_RISCV_HI20: u32 = 26;
+    pub const R_RISCV_LO12_I: u32 = 27;
+    pub const R_RISCV_LO12_S: u32 = 28;
+    pub const R_RISCV_TPREL_HI20: u32 = 29;
+    pub const R_RISCV_TPREL_LO12_I: u32 = 30;
+    pub const R_RISCV_TPREL_LO12_S: u32 = 31;
+    pub const R_RISCV_TPREL_ADD: u32 = 32;
+    pub const R_RISCV_ADD8: u32 = 33;
+    pub const R_RISCV_ADD16: u32 = 34;
+    pub const R_RISCV_ADD32: u32 = 35;
+    pub const R_RISCV_ADD64: u32 = 36;
+    pub const R_RISCV_SUB8: u32 = 37;
+    pub const R_RISCV_SUB16: u32 = 38;
+    pub const R_RISCV_SUB32: u32 = 39;
+    pub const R_RISCV_SUB64: u32 = 40;
+    pub const R_RISCV_GNU_VTINHERIT: u32 = 41;
+    pub const R_RISCV_GNU_VTENTRY: u32 = 42;
+    pub const R_RISCV_ALIGN: u32 = 43;
+    pub const R_RISCV_RVC_BRANCH: u32 = 44;
+    pub const R_RISCV_RVC_JUMP: u32 = 45;
+    pub const R_RISCV_RVC_LUI: u32 = 46;
+    pub const R_RISCV_GPREL_I: u32 = 47;
+    pub const R_RISCV_GPREL_S: u32 = 48;
+    pub const R_RISCV_TPREL_I: u32 = 49;
+    pub const R_RISCV_TPREL_S: u32 = 50;
+    pub const R_RISCV_RELAX: u32 = 51;
+    pub const R_RISCV_SUB6: u32 = 52;
+    pub const R_RISCV_SET6: u32 = 53;
+    pub const R_RISCV_SET8: u32 = 54;
+    pub const R_RISCV_SET16: u32 = 55;
+    pub const R_RISCV_SET32: u32 = 56;
+    pub const R_RISCV_32_PCREL: u32 = 57;
+    pub const R_RISCV_IRELATIVE: u32 = 58;
+
+    pub const R_PPC64_NONE: u32 = 0;
+    pub const R_PPC64_ADDR32: u32 = 1;
+    pub const R_PPC64_ADDR24: u32 = 2;
+    pub const R_PPC64_ADDR16: u32 = 3;
+    pub const R_PPC64_ADDR16_LO: u32 = 4;
+    pub const R_PPC64_ADDR16_HI: u32 = 5;
+    pub const R_PPC64_ADDR16_HA: u32 = 6;
+    pub const R_PPC64_ADDR14: u32 = 7;
+    pub const R_PPC64_ADDR14_BRTAKEN: u32 = 8;
+    pub const R_PPC64_ADDR14_BRNTAKEN: u32 = 9;
+    pub const R_PPC64_REL24: u32 = 10;
+    pub const R_PPC64_REL14: u32 = 11;
+    pub const R_PPC64_REL14_BRTAKEN: u32 = 12;
+    pub const R_PPC64_REL14_BRNTAKEN: u32 = 13;
+    pub const R_PPC64_GOT16: u32 = 14;
+    pub const R_PPC64_GOT16_LO: u32 = 15;
+    pub const R_PPC64_GOT16_HI: u32 = 16;
+    pub const R_PPC64_GOT16_HA: u32 = 17;
+    pub const R_PPC64_COPY: u32 = 19;
+    pub const R_PPC64_GLOB_DAT: u32 = 20;
+    pub const R_PPC64_JMP_SLOT: u32 = 21;
+    pub const R_PPC64_RELATIVE: u32 = 22;
+    pub const R_PPC64_UADDR32: u32 = 24;
+    pub const R_PPC64_UADDR16: u32 = 25;
+    pub const R_PPC64_REL32: u32 = 26;
+    pub const R_PPC64_PLT32: u32 = 27;
+    pub const R_PPC64_PLTREL32: u32 = 28;
+    pub const R_PPC64_PLT16_LO: u32 = 29;
+    pub const R_PPC64_PLT16_HI: u32 = 30;
+    pub const R_PPC64_PLT16_HA: u32 = 31;
+    pub const R_PPC64_SECTOFF: u32 = 33;
+    pub const R_PPC64_SECTOFF_LO: u32 = 34;
+    pub const R_PPC64_SECTOFF_HI: u32 = 35;
+    pub const R_PPC64_SECTOFF_HA: u32 = 36;
+    pub const R_PPC64_ADDR30: u32 = 37;
+    pub const R_PPC64_ADDR64: u32 = 38;
+    pub const R_PPC64_ADDR16_HIGHER: u32 = 39;
+    pub const R_PPC64_ADDR16_HIGHERA: u32 = 40;
+    pub const R_PPC64_ADDR16_HIGHEST: u32 = 41;
+    pub const R_PPC64_ADDR16_HIGHESTA: u32 = 42;
+    pub const R_PPC64_UADDR64: u32 = 43;
+    pub const R_PPC64_REL64: u32 = 44;
+    pub const R_PPC64_PLT64: u32 = 45;
+    pub const R_PPC64_PLTREL64: u32 = 46;
+    pub const R_PPC64_TOC16: u32 = 47;
+    pub const R_PPC64_TOC16_LO: u32 = 48;
+    pub const R_PPC64_TOC16_HI: u32 = 49;
+    pub const R_PPC64_TOC16_HA: u32 = 50;
+    pub const R_PPC64_TOC: u32 = 51;
+    pub const R_PPC64_PLTGOT16: u32 = 52;
+    pub const R_PPC64_PLTGOT16_LO: u32 = 53;
+    pub const R_PPC64_PLTGOT16_HI: u32 = 54;
+    pub const R_PPC64_PLTGOT16_HA: u32 = 55;
+    pub const R_PPC64_ADDR16_DS: u32 = 56;
+    pub const R_PPC64_ADDR16_LO_DS: u32 = 57;
+    pub const R_PPC64_GOT16_DS: u32 = 58;
+    pub const R_PPC64_GOT16_LO_DS: u32 = 59;
+    pub const R_PPC64_PLT16_LO_DS: u32 = 60;
+    pub const R_PPC64_SECTOFF_DS: u32 = 61;
+    pub const R_PPC64_SECTOFF_LO_DS: u32 = 62;
+    pub const R_PPC64_TOC16_DS: u32 = 63;
+    pub const R_PPC64_TOC16_LO_DS: u32 = 64;
+    pub const R_PPC64_PLTGOT16_DS: u32 = 65;
+    pub const R_PPC64_PLTGOT16_LO_DS: u32 = 66;
+    pub const R_PPC64_TLS: u32 = 67;
+    pub const R_PPC64_DTPMOD64: u32 = 68;
+    pub const R_PPC64_TPREL16: u32 = 69;
+    pub const R_PPC64_TPREL16_LO: u32 = 70;
+    pub const R_PPC64_TPREL16_HI: u32 = 71;
+    pub const R_PPC64_TPREL16_HA: u32 = 72;
+    pub const R_PPC64_TPREL64: u32 = 73;
+    pub const R_PPC64_DTPREL16: u32 = 74;
+    pub const R_PPC64_DTPREL16_LO: u32 = 75;
+    pub const R_PPC64_DTPREL16_HI: u32 = 76;
+    pub const R_PPC64_DTPREL16_HA: u32 = 77;
+    pub const R_PPC64_DTPREL64: u32 = 78;
+    pub const R_PPC64_GOT_TLSGD16: u32 = 79;
+    pub const R_PPC64_GOT_TLSGD16_LO: u32 = 80;
+    pub const R_PPC64_GOT_TLSGD16_HI: u32 = 81;
+    pub const R_PPC64_GOT_TLSGD16_HA: u32 = 82;
+    pub const R_PPC64_GOT_TLSLD16: u32 = 83;
+    pub const R_PPC64_GOT_TLSLD16_LO: u32 = 84;
+    pub const R_PPC64_GOT_TLSLD16_HI: u32 = 85;
+    pub const R_PPC64_GOT_TLSLD16_HA: u32 = 86;
+    pub const R_PPC64_GOT_TPREL16_DS: u32 = 87;
+    pub const R_PPC64_GOT_TPREL16_LO_DS: u32 = 88;
+    pub const R_PPC64_GOT_TPREL16_HI: u32 = 89;
+    pub const R_PPC64_GOT_TPREL16_HA: u32 = 90;
+    pub const R_PPC64_GOT_DTPREL16_DS: u32 = 91;
+    pub const R_PPC64_GOT_DTPREL16_LO_DS: u32 = 92;
+    pub const R_PPC64_GOT_DTPREL16_HI: u32 = 93;
+    pub const R_PPC64_GOT_DTPREL16_HA: u32 = 94;
+    pub const R_PPC64_TPREL16_DS: u32 = 95;
+    pub const R_PPC64_TPREL16_LO_DS: u32 = 96;
+    pub const R_PPC64_TPREL16_HIGHER: u32 = 97;
+    pub const R_PPC64_TPREL16_HIGHERA: u32 = 98;
+    pub const R_PPC64_TPREL16_HIGHEST: u32 = 99;
+    pub const R_PPC64_TPREL16_HIGHESTA: u32 = 100;
+    pub const R_PPC64_DTPREL16_DS: u32 = 101;
+    pub const R_PPC64_DTPREL16_LO_DS: u32 = 102;
+    pub const R_PPC64_DTPREL16_HIGHER: u32 = 103;
+    pub const R_PPC64_DTPREL16_HIGHERA: u32 = 104;
+    pub const R_PPC64_DTPREL16_HIGHEST: u32 = 105;
+    pub const R_PPC64_DTPREL16_HIGHESTA: u32 = 106;
+    pub const R_PPC64_TLSGD: u32 = 107;
+    pub const R_PPC64_TLSLD: u32 = 108;
+    pub const R_PPC64_TOCSAVE: u32 = 109;
+    pub const R_PPC64_ADDR16_HIGH: u32 = 110;
+    pub const R_PPC64_ADDR16_HIGHA: u32 = 111;
+    pub const R_PPC64_TPREL16_HIGH: u32 = 112;
+    pub const R_PPC64_TPREL16_HIGHA: u32 = 113;
+    pub const R_PPC64_DTPREL16_HIGH: u32 = 114;
+    pub const R_PPC64_DTPREL16_HIGHA: u32 = 115;
+    pub const R_PPC64_JMP_IREL: u32 = 247;
+    pub const R_PPC64_IRELATIVE: u32 = 248;
+    pub const R_PPC64_REL16: u32 = 249;
+    pub const R_PPC64_REL16_LO: u32 = 250;
+    pub const R_PPC64_REL16_HI: u32 = 251;
+    pub const R_PPC64_REL16_HA: u32 = 252;
+
+    pub const R_390_NONE: u32 = 0;
+    pub const R_390_8: u32 = 1;
+    pub const R_390_12: u32 = 2;
+    pub const R_390_16: u32 = 3;
+    pub const R_390_32: u32 = 4;
+    pub const R_390_PC32: u32 = 5;
+    pub const R_390_GOT12: u32 = 6;
+    pub const R_390_GOT32: u32 = 7;
+    pub const R_390_PLT32: u32 = 8;
+    pub const R_390_COPY: u32 = 9;
+    pub const R_390_GLOB_DAT: u32 = 10;
+    pub const R_390_JMP_SLOT: u32 = 11;
+    pub const R_390_RELATIVE: u32 = 12;
+    pub const R_390_GOTOFF32: u32 = 13;
+    pub const R_390_GOTPC: u32 = 14;
+    pub const R_390_GOT16: u32 = 15;
+    pub const R_390_PC16: u32 = 16;
+    pub const R_390_PC16DBL: u32 = 17;
+    pub const R_390_PLT16DBL: u32 = 18;
+    pub const R_390_PC32DBL: u32 = 19;
+    pub const R_390_PLT32DBL: u32 = 20;
+    pub const R_390_GOTPCDBL: u32 = 21;
+    pub const R_390_64: u32 = 22;
+    pub const R_390_PC64: u32 = 23;
+    pub const R_390_GOT64: u32 = 24;
+    pub const R_390_PLT64: u32 = 25;
+    pub const R_390_GOTENT: u32 = 26;
+    pub const R_390_GOTOFF16: u32 = 27;
+    pub const R_390_GOTOFF64: u32 = 28;
+    pub const R_390_GOTPLT12: u32 = 29;
+    pub const R_390_GOTPLT16: u32 = 30;
+    pub const R_390_GOTPLT32: u32 = 31;
+    pub const R_390_GOTPLT64: u32 = 32;
+    pub const R_390_GOTPLTENT: u32 = 33;
+    pub const R_390_PLTOFF16: u32 = 34;
+    pub const R_390_PLTOFF32: u32 = 35;
+    pub const R_390_PLTOFF64: u32 = 36;
+    pub const R_390_TLS_LOAD: u32 = 37;
+    pub const R_390_TLS_GDCALL: u32 = 38;
+    pub const R_390_TLS_LDCALL: u32 = 39;
+    pub const R_390_TLS_GD32: u32 = 40;
+    pub const R_390_TLS_GD64: u32 = 41;
+    pub const R_390_TLS_GOTIE12: u32 = 42;
+    pub const R_390_TLS_GOTIE32: u32 = 43;
+    pub const R_390_TLS_GOTIE64: u32 = 44;
+    pub const R_390_TLS_LDM32: u32 = 45;
+    pub const R_390_TLS_LDM64: u32 = 46;
+    pub const R_390_TLS_IE32: u32 = 47;
+    pub const R_390_TLS_IE64: u32 = 48;
+    pub const R_390_TLS_IEENT: u32 = 49;
+    pub const R_390_TLS_LE32: u32 = 50;
+    pub const R_390_TLS_LE64: u32 = 51;
+    pub const R_390_TLS_LDO32: u32 = 52;
+    pub const R_390_TLS_LDO64: u32 = 53;
+    pub const R_390_TLS_DTPMOD: u32 = 54;
+    pub const R_390_TLS_DTPOFF: u32 = 55;
+    pub const R_390_TLS_TPOFF: u32 = 56;
+    pub const R_390_20: u32 = 57;
+    pub const R_390_GOT20: u32 = 58;
+    pub const R_390_GOTPLT20: u32 = 59;
+    pub const R_390_TLS_GOTIE20: u32 = 60;
+    pub const R_390_IRELATIVE: u32 = 61;
+
+    pub const R_386_NONE: u32 = 0;
+    pub const R_386_32: u32 = 1;
+    pub const R_386_PC32: u32 = 2;
+    pub const R_386_GOT32: u32 = 3;
+    pub const R_386_PLT32: u32 = 4;
+    pub const R_386_COPY: u32 = 5;
+    pub const R_386_GLOB_DAT: u32 = 6;
+    pub const R_386_JMP_SLOT: u32 = 7;
+    pub const R_386_RELATIVE: u32 = 8;
+    pub const R_386_GOTOFF: u32 = 9;
+    pub const R_386_GOTPC: u32 = 10;
+    pub const R_386_32PLT: u32 = 11;
+    pub const R_386_TLS_TPOFF: u32 = 14;
+    pub const R_386_TLS_IE: u32 = 15;
+    pub const R_386_TLS_GOTIE: u32 = 16;
+    pub const R_386_TLS_LE: u32 = 17;
+    pub const R_386_TLS_GD: u32 = 18;
+    pub const R_386_TLS_LDM: u32 = 19;
+    pub const R_386_16: u32 = 20;
+    pub const R_386_PC16: u32 = 21;
+    pub const R_386_8: u32 = 22;
+    pub const R_386_PC8: u32 = 23;
+    pub const R_386_TLS_GD_32: u32 = 24;
+    pub const R_386_TLS_GD_PUSH: u32 = 25;
+    pub const R_386_TLS_GD_CALL: u32 = 26;
+    pub const R_386_TLS_GD_POP: u32 = 27;
+    pub const R_386_TLS_LDM_32: u32 = 28;
+    pub const R_386_TLS_LDM_PUSH: u32 = 29;
+    pub const R_386_TLS_LDM_CALL: u32 = 30;
+    pub const R_386_TLS_LDM_POP: u32 = 31;
+    pub const R_386_TLS_LDO_32: u32 = 32;
+    pub const R_386_TLS_IE_32: u32 = 33;
+    pub const R_386_TLS_LE_32: u32 = 34;
+    pub const R_386_TLS_DTPMOD32: u32 = 35;
+    pub const R_386_TLS_DTPOFF32: u32 = 36;
+    pub const R_386_TLS_TPOFF32: u32 = 37;
+    pub const R_386_SIZE32: u32 = 38;
+    pub const R_386_TLS_GOTDESC: u32 = 39;
+    pub const R_386_TLS_DESC_CALL: u32 = 40;
+    pub const R_386_TLS_DESC: u32 = 41;
+    pub const R_386_IRELATIVE: u32 = 42;
+    pub const R_386_GOT32X: u32 = 43;
+
+    pub const R_ARM_NONE: u32 = 0;
+    pub const R_ARM_PC24: u32 = 1;
+    pub const R_ARM_ABS32: u32 = 2;
+    pub const R_ARM_REL32: u32 = 3;
+    pub const R_ARM_PC13: u32 = 4;
+    pub const R_ARM_ABS16: u32 = 5;
+    pub const R_ARM_ABS12: u32 = 6;
+    pub const R_ARM_THM_ABS5: u32 = 7;
+    pub const R_ARM_ABS8: u32 = 8;
+    pub const R_ARM_SBREL32: u32 = 9;
+    pub const R_ARM_THM_PC22: u32 = 10;
+    pub const R_ARM_THM_PC8: u32 = 11;
+    pub const R_ARM_AMP_VCALL9: u32 = 12;
+    pub const R_ARM_SWI24: u32 = 13;
+    pub const R_ARM_TLS_DESC: u32 = 13;
+    pub const R_ARM_THM_SWI8: u32 = 14;
+    pub const R_ARM_XPC25: u32 = 15;
+    pub const R_ARM_THM_XPC22: u32 = 16;
+    pub const R_ARM_TLS_DTPMOD32: u32 = 17;
+    pub const R_ARM_TLS_DTPOFF32: u32 = 18;
+    pub const R_ARM_TLS_TPOFF32: u32 = 19;
+    pub const R_ARM_COPY: u32 = 20;
+    pub const R_ARM_GLOB_DAT: u32 = 21;
+    pub const R_ARM_JUMP_SLOT: u32 = 22;
+    pub const R_ARM_RELATIVE: u32 = 23;
+    pub const R_ARM_GOTOFF: u32 = 24;
+    pub const R_ARM_GOTPC: u32 = 25;
+    pub const R_ARM_GOT32: u32 = 26;
+    pub const R_ARM_PLT32: u32 = 27;
+    pub const R_ARM_CALL: u32 = 28;
+    pub const R_ARM_JUMP24: u32 = 29;
+    pub const R_ARM_THM_JUMP24: u32 = 30;
+    pub const R_ARM_BASE_ABS: u32 = 31;
+    pub const R_ARM_ALU_PCREL_7_0: u32 = 32;
+    pub const R_ARM_ALU_PCREL_15_8: u32 = 33;
+    pub const R_ARM_ALU_PCREL_23_15: u32 = 34;
+    pub const R_ARM_LDR_SBREL_11_0: u32 = 35;
+    pub const R_ARM_ALU_SBREL_19_12: u32 = 36;
+    pub const R_ARM_ALU_SBREL_27_20: u32 = 37;
+    pub const R_ARM_TARGET1: u32 = 38;
+    pub const R_ARM_SBREL31: u32 = 39;
+    pub const R_ARM_V4BX: u32 = 40;
+    pub const R_ARM_TARGET2: u32 = 41;
+    pub const R_ARM_PREL31: u32 = 42;
+    pub const R_ARM_MOVW_ABS_NC: u32 = 43;
+    pub const R_ARM_MOVT_ABS: u32 = 44;
+    pub const R_ARM_MOVW_PREL_NC: u32 = 45;
+    pub const R_ARM_MOVT_PREL: u32 = 46;
+    pub const R_ARM_THM_MOVW_ABS_NC: u32 = 47;
+    pub const R_ARM_THM_MOVT_ABS: u32 = 48;
+    pub const R_ARM_THM_MOVW_PREL_NC: u32 = 49;
+    pub const R_ARM_THM_MOVT_PREL: u32 = 50;
+    pub const R_ARM_THM_JUMP19: u32 = 51;
+    pub const R_ARM_THM_JUMP6: u32 = 52;
+    pub const R_ARM_THM_ALU_PREL_11_0: u32 = 53;
+    pub const R_ARM_THM_PC12: u32 = 54;
+    pub const R_ARM_ABS32_NOI: u32 = 55;
+    pub const R_ARM_REL32_NOI: u32 = 56;
+    pub const R_ARM_ALU_PC_G0_NC: u32 = 57;
+    pub const R_ARM_ALU_PC_G0: u32 = 58;
+    pub const R_ARM_ALU_PC_G1_NC: u32 = 59;
+    pub const R_ARM_ALU_PC_G1: u32 = 60;
+    pub const R_ARM_ALU_PC_G2: u32 = 61;
+    pub const R_ARM_LDR_PC_G1: u32 = 62;
+    pub const R_ARM_LDR_PC_G2: u32 = 63;
+    pub const R_ARM_LDRS_PC_G0: u32 = 64;
+    pub const R_ARM_LDRS_PC_G1: u32 = 65;
+    pub const R_ARM_LDRS_PC_G2: u32 = 66;
+    pub const R_ARM_LDC_PC_G0: u32 = 67;
+    pub const R_ARM_LDC_PC_G1: u32 = 68;
+    pub const R_ARM_LDC_PC_G2: u32 = 69;
+    pub const R_ARM_ALU_SB_G0_NC: u32 = 70;
+    pub const R_ARM_ALU_SB_G0: u32 = 71;
+    pub const R_ARM_ALU_SB_G1_NC: u32 = 72;
+    pub const R_ARM_ALU_SB_G1: u32 = 73;
+    pub const R_ARM_ALU_SB_G2: u32 = 74;
+    pub const R_ARM_LDR_SB_G0: u32 = 75;
+    pub const R_ARM_LDR_SB_G1: u32 = 76;
+    pub const R_ARM_LDR_SB_G2: u32 = 77;
+    pub const R_ARM_LDRS_SB_G0: u32 = 78;
+    pub const R_ARM_LDRS_SB_G1: u32 = 79;
+    pub const R_ARM_LDRS_SB_G2: u32 = 80;
+    pub const R_ARM_LDC_SB_G0: u32 = 81;
+    pub const R_ARM_LDC_SB_G1: u32 = 82;
+    pub const R_ARM_LDC_SB_G2: u32 = 83;
+    pub const R_ARM_MOVW_BREL_NC: u32 = 84;
+    pub const R_ARM_MOVT_BREL: u32 = 85;
+    pub const R_ARM_MOVW_BREL: u32 = 86;
+    pub const R_ARM_THM_MOVW_BREL_NC: u32 = 87;
+    pub const R_ARM_THM_MOVT_BREL: u32 = 88;
+    pub const R_ARM_THM_MOVW_BREL: u32 = 89;
+    pub const R_ARM_TLS_GOTDESC: u32 = 90;
+    pub const R_ARM_TLS_CALL: u32 = 91;
+    pub const R_ARM_TLS_DESCSEQ: u32 = 92;
+    pub const R_ARM_THM_TLS_CALL: u32 = 93;
+    pub const R_ARM_PLT32_ABS: u32 = 94;
+    pub const R_ARM_GOT_ABS: u32 = 95;
+    pub const R_ARM_GOT_PREL: u32 = 96;
+    pub const R_ARM_GOT_BREL12: u32 = 97;
+    pub const R_ARM_GOTOFF12: u32 = 98;
+    pub const R_ARM_GOTRELAX: u32 = 99;
+    pub const R_ARM_GNU_VTENTRY: u32 = 100;
+    pub const R_ARM_GNU_VTINHERIT: u32 = 101;
+    pub const R_ARM_THM_PC11: u32 = 102;
+    pub const R_ARM_THM_PC9: u32 = 103;
+    pub const R_ARM_TLS_GD32: u32 = 104;
+    pub const R_ARM_TLS_LDM32: u32 = 105;
+    pub const R_ARM_TLS_LDO32: u32 = 106;
+    pub const R_ARM_TLS_IE32: u32 = 107;
+    pub const R_ARM_TLS_LE32: u32 = 108;
+    pub const R_ARM_TLS_LDO12: u32 = 109;
+    pub const R_ARM_TLS_LE12: u32 = 110;
+    pub const R_ARM_TLS_IE12GP: u32 = 111;
+    pub const R_ARM_ME_TOO: u32 = 128;
+    pub const R_ARM_THM_TLS_DESCSEQ: u32 = 129;
+    pub const R_ARM_THM_TLS_DESCSEQ16: u32 = 129;
+    pub const R_ARM_THM_TLS_DESCSEQ32: u32 = 130;
+    pub const R_ARM_THM_GOT_BREL12: u32 = 131;
+    pub const R_ARM_IRELATIVE: u32 = 160;
+    pub const R_ARM_RXPC25: u32 = 249;
+    pub const R_ARM_RSBREL32: u32 = 250;
+    pub const R_ARM_THM_RPC22: u32 = 251;
+    pub const R_ARM_RREL32: u32 = 252;
+    pub const R_ARM_RABS22: u32 = 253;
+    pub const R_ARM_RPC24: u32 = 254;
+    pub const R_ARM_RBASE: u32 = 255;
+
+    pub const EM_NONE: u32 = 0;
+    pub const EM_M32: u32 = 1;
+    pub const EM_SPARC: u32 = 2;
+    pub const EM_386: u32 = 3;
+    pub const EM_68K: u32 = 4;
+    pub const EM_88K: u32 = 5;
+    pub const EM_IAMCU: u32 = 6;
+    pub const EM_860: u32 = 7;
+    pub const EM_MIPS: u32 = 8;
+    pub const EM_S370: u32 = 9;
+    pub const EM_MIPS_RS3_LE: u32 = 10;
+    pub const EM_PARISC: u32 = 15;
+    pub const EM_VPP500: u32 = 17;
+    pub const EM_SPARC32PLUS: u32 = 18;
+    pub const EM_960: u32 = 19;
+    pub const EM_PPC: u32 = 20;
+    pub const EM_PPC64: u32 = 21;
+    pub const EM_S390: u32 = 22;
+    pub const EM_SPU: u32 = 23;
+    pub const EM_V800: u32 = 36;
+    pub const EM_FR20: u32 = 37;
+    pub const EM_RH32: u32 = 38;
+    pub const EM_RCE: u32 = 39;
+    pub const EM_ARM: u32 = 40;
+    pub const EM_FAKE_ALPHA: u32 = 41;
+    pub const EM_SH: u32 = 42;
+    pub const EM_SPARCV9: u32 = 43;
+    pub const EM_TRICORE: u32 = 44;
+    pub const EM_ARC: u32 = 45;
+    pub const EM_H8_300: u32 = 46;
+    pub const EM_H8_300H: u32 = 47;
+    pub const EM_H8S: u32 = 48;
+    pub const EM_H8_500: u32 = 49;
+    pub const EM_IA_64: u32 = 50;
+    pub const EM_MIPS_X: u32 = 51;
+    pub const EM_COLDFIRE: u32 = 52;
+    pub const EM_68HC12: u32 = 53;
+    pub const EM_MMA: u32 = 54;
+    pub const EM_PCP: u32 = 55;
+    pub const EM_NCPU: u32 = 56;
+    pub const EM_NDR1: u32 = 57;
+    pub const EM_STARCORE: u32 = 58;
+    pub const EM_ME16: u32 = 59;
+    pub const EM_ST100: u32 = 60;
+    pub const EM_TINYJ: u32 = 61;
+    pub const EM_X86_64: u32 = 62;
+    pub const EM_PDSP: u32 = 63;
+    pub const EM_PDP10: u32 = 64;
+    pub const EM_PDP11: u32 = 65;
+    pub const EM_FX66: u32 = 66;
+    pub const EM_ST9PLUS: u32 = 67;
+    pub const EM_ST7: u32 = 68;
+    pub const EM_68HC16: u32 = 69;
+    pub const EM_68HC11: u32 = 70;
+    pub const EM_68HC08: u32 = 71;
+    pub const EM_68HC05: u32 = 72;
+    pub const EM_SVX: u32 = 73;
+    pub const EM_ST19: u32 = 74;
+    pub const EM_VAX: u32 = 75;
+    pub const EM_CRIS: u32 = 76;
+    pub const EM_JAVELIN: u32 = 77;
+    pub const EM_FIREPATH: u32 = 78;
+    pub const EM_ZSP: u32 = 79;
+    pub const EM_MMIX: u32 = 80;
+    pub const EM_HUANY: u32 = 81;
+    pub const EM_PRISM: u32 = 82;
+    pub const EM_AVR: u32 = 83;
+    pub const EM_FR30: u32 = 84;
+    pub const EM_D10V: u32 = 85;
+    pub const EM_D30V: u32 = 86;
+    pub const EM_V850: u32 = 87;
+    pub const EM_M32R: u32 = 88;
+    pub const EM_MN10300: u32 = 89;
+    pub const EM_MN10200: u32 = 90;
+    pub const EM_PJ: u32 = 91;
+    pub const EM_OPENRISC: u32 = 92;
+    pub const EM_ARC_COMPACT: u32 = 93;
+    pub const EM_XTENSA: u32 = 94;
+    pub const EM_VIDEOCORE: u32 = 95;
+    pub const EM_TMM_GPP: u32 = 96;
+    pub const EM_NS32K: u32 = 97;
+    pub const EM_TPC: u32 = 98;
+    pub const EM_SNP1K: u32 = 99;
+    pub const EM_ST200: u32 = 100;
+    pub const EM_IP2K: u32 = 101;
+    pub const EM_MAX: u32 = 102;
+    pub const EM_CR: u32 = 103;
+    pub const EM_F2MC16: u32 = 104;
+    pub const EM_MSP430: u32 = 105;
+    pub const EM_BLACKFIN: u32 = 106;
+    pub const EM_SE_C33: u32 = 107;
+    pub const EM_SEP: u32 = 108;
+    pub const EM_ARCA: u32 = 109;
+    pub const EM_UNICORE: u32 = 110;
+    pub const EM_EXCESS: u32 = 111;
+    pub const EM_DXP: u32 = 112;
+    pub const EM_ALTERA_NIOS2: u32 = 113;
+    pub const EM_CRX: u32 = 114;
+    pub const EM_XGATE: u32 = 115;
+    pub const EM_C166: u32 = 116;
+    pub const EM_M16C: u32 = 117;
+    pub const EM_DSPIC30F: u32 = 118;
+    pub const EM_CE: u32 = 119;
+    pub const EM_M32C: u32 = 120;
+    pub const EM_TSK3000: u32 = 131;
+    pub const EM_RS08: u32 = 132;
+    pub const EM_SHARC: u32 = 133;
+    pub const EM_ECOG2: u32 = 134;
+    pub const EM_SCORE7: u32 = 135;
+    pub const EM_DSP24: u32 = 136;
+    pub const EM_VIDEOCORE3: u32 = 137;
+    pub const EM_LATTICEMICO32: u32 = 138;
+    pub const EM_SE_C17: u32 = 139;
+    pub const EM_TI_C6000: u32 = 140;
+    pub const EM_TI_C2000: u32 = 141;
+    pub const EM_TI_C5500: u32 = 142;
+    pub const EM_TI_ARP32: u32 = 143;
+    pub const EM_TI_PRU: u32 = 144;
+    pub const EM_MMDSP_PLUS: u32 = 160;
+    pub const EM_CYPRESS_M8C: u32 = 161;
+    pub const EM_R32C: u32 = 162;
+    pub const EM_TRIMEDIA: u32 = 163;
+    pub const EM_QDSP6: u32 = 164;
+    pub const EM_8051: u32 = 165;
+    pub const EM_STXP7X: u32 = 166;
+    pub const EM_NDS32: u32 = 167;
+    pub const EM_ECOG1X: u32 = 168;
+    pub const EM_MAXQ30: u32 = 169;
+    pub const EM_XIMO16: u32 = 170;
+    pub const EM_MANIK: u32 = 171;
+    pub const EM_CRAYNV2: u32 = 172;
+    pub const EM_RX: u32 = 173;
+    pub const EM_METAG: u32 = 174;
+    pub const EM_MCST_ELBRUS: u32 = 175;
+    pub const EM_ECOG16: u32 = 176;
+    pub const EM_CR16: u32 = 177;
+    pub const EM_ETPU: u32 = 178;
+    pub const EM_SLE9X: u32 = 179;
+    pub const EM_L10M: u32 = 180;
+    pub const EM_K10M: u32 = 181;
+    pub const EM_AARCH64: u32 = 183;
+    pub const EM_AVR32: u32 = 185;
+    pub const EM_STM8: u32 = 186;
+    pub const EM_TILE64: u32 = 187;
+    pub const EM_TILEPRO: u32 = 188;
+    pub const EM_MICROBLAZE: u32 = 189;
+    pub const EM_CUDA: u32 = 190;
+    pub const EM_TILEGX: u32 = 191;
+    pub const EM_CLOUDSHIELD: u32 = 192;
+    pub const EM_COREA_1ST: u32 = 193;
+    pub const EM_COREA_2ND: u32 = 194;
+    pub const EM_ARCV2: u32 = 195;
+    pub const EM_OPEN8: u32 = 196;
+    pub const EM_RL78: u32 = 197;
+    pub const EM_VIDEOCORE5: u32 = 198;
+    pub const EM_78KOR: u32 = 199;
+    pub const EM_56800EX: u32 = 200;
+    pub const EM_BA1: u32 = 201;
+    pub const EM_BA2: u32 = 202;
+    pub const EM_XCORE: u32 = 203;
+    pub const EM_MCHP_PIC: u32 = 204;
+    pub const EM_INTELGT: u32 = 205;
+    pub const EM_KM32: u32 = 210;
+    pub const EM_KMX32: u32 = 211;
+    pub const EM_EMX16: u32 = 212;
+    pub const EM_EMX8: u32 = 213;
+    pub const EM_KVARC: u32 = 214;
+    pub const EM_CDP: u32 = 215;
+    pub const EM_COGE: u32 = 216;
+    pub const EM_COOL: u32 = 217;
+    pub const EM_NORC: u32 = 218;
+    pub const EM_CSR_KALIMBA: u32 = 219;
+    pub const EM_Z80: u32 = 220;
+    pub const EM_VISIUM: u32 = 221;
+    pub const EM_FT32: u32 = 222;
+    pub const EM_MOXIE: u32 = 223;
+    pub const EM_AMDGPU: u32 = 224;
+    pub const EM_RISCV: u32 = 243;
+    pub const EM_BPF: u32 = 247;
+    pub const EM_CSKY: u32 = 252;
+    pub const EM_LOONGARCH: u32 = 258;
+    pub const EM_NUM: u32 = 259;
+    pub const EM_ARC_A5: u32 = 93;
+    pub const EM_ALPHA: u32 = 36902;
+
+    // not in elf.h, these are the other machines known by binutils (the
+    // big values are the unofficial ones, used before a number was given)
+    pub const EM_OLD_SPARCV9: u32 = 11;
+    pub const EM_INTEL182: u32 = 182;
+    pub const EM_ARM184: u32 = 184;
+    pub const EM_LANAI: u32 = 244;
+    pub const EM_CEVA: u32 = 245;
+    pub const EM_CEVA_X2: u32 = 246;
+    pub const EM_GRAPHCORE: u32 = 248;
+    pub const EM_IMG1: u32 = 249;
+    pub const EM_NFP: u32 = 250;
+    pub const EM_VE: u32 = 251;
+    pub const EM_ARC_COMPACT3_64: u32 = 253;
+    pub const EM_MCS6502: u32 = 254;
+    pub const EM_ARC_COMPACT3: u32 = 255;
+    pub const EM_KVX: u32 = 256;
+    pub const EM_65816: u32 = 257;
+    pub const EM_AVR_OLD: u32 = 4183;
+    pub const EM_MSP430_OLD: u32 = 4185;
+    pub const EM_ADAPTEVA_EPIPHANY: u32 = 4643;
+    pub const EM_MT: u32 = 9520;
+    pub const EM_CYGNUS_FR30: u32 = 13104;
+    pub const EM_WEBASSEMBLY: u32 = 16727;
+    pub const EM_XC16X: u32 = 18056;
+    pub const EM_S12Z: u32 = 19951;
+    pub const EM_CYGNUS_FRV: u32 = 21569;
+    pub const EM_DLX: u32 = 23205;
+    pub const EM_CYGNUS_D10V: u32 = 30288;
+    pub const EM_CYGNUS_D30V: u32 = 30326;
+    pub const EM_IP2K_OLD: u32 = 33303;
+    pub const EM_CYGNUS_M32R: u32 = 36929;
+    pub const EM_CYGNUS_V850: u32 = 36992;
+    pub const EM_S390_OLD: u32 = 41872;
+    pub const EM_XTENSA_OLD: u32 = 43975;
+    pub const EM_XSTORMY16: u32 = 44357;
+    pub const EM_MICROBLAZE_OLD: u32 = 47787;
+    pub const EM_CYGNUS_MN10300: u32 = 48879;
+    pub const EM_CYGNUS_MN10200: u32 = 57005;
+    pub const EM_CYGNUS_MEP: u32 = 61453;
+    pub const EM_IQ2000: u32 = 65210;
+    pub const EM_NIOS32: u32 = 65211;
+
+    pub const ELFOSABI_NONE: u32 = 0;
+    pub const ELFOSABI_SYSV: u32 = 0;
+    pub const ELFOSABI_HPUX: u32 = 1;
+    pub const ELFOSABI_NETBSD: u32 = 2;
+    pub const ELFOSABI_GNU: u32 = 3;
+    pub const ELFOSABI_LINUX: u32 = 3;
+    pub const ELFOSABI_SOLARIS: u32 = 6;
+    pub const ELFOSABI_AIX: u32 = 7;
+    pub const ELFOSABI_IRIX: u32 = 8;
+    pub const ELFOSABI_FREEBSD: u32 = 9;
+    pub const ELFOSABI_TRU64: u32 = 10;
+    pub const ELFOSABI_MODESTO: u32 = 11;
+    pub const ELFOSABI_OPENBSD: u32 = 12;
+    pub const ELFOSABI_OPENVMS: u32 = 13;
+    pub const ELFOSABI_NSK: u32 = 14;
+    pub const ELFOSABI_AROS: u32 = 15;
+    pub const ELFOSABI_FENIXOS: u32 = 16;
+    pub const ELFOSABI_CLOUDABI: u32 = 17;
+    pub const ELFOSABI_OPENVOS: u32 = 18;
+    pub const ELFOSABI_ARM_AEABI: u32 = 64;
+    pub const ELFOSABI_ARM: u32 = 97;
+    pub const ELFOSABI_STANDALONE: u32 = 255;
+    // not in elf.h, the values from 64 are machine specific
+    pub const ELFOSABI_ARM_FDPIC: u32 = 65;
+    pub const ELFOSABI_C6000_ELFABI: u32 = 64;
+    pub const ELFOSABI_C6000_LINUX: u32 = 65;
+    pub const ELFOSABI_AMDGPU_HSA: u32 = 64;
+    pub const ELFOSABI_AMDGPU_PAL: u32 = 65;
+    pub const ELFOSABI_AMDGPU_MESA3D: u32 = 66;
+
+    pub const NT_PRSTATUS: u32 = 1;
+    pub const NT_FPREGSET: u32 = 2;
+    pub const NT_PRPSINFO: u32 = 3;
+    pub const NT_TASKSTRUCT: u32 = 4;
+    pub const NT_AUXV: u32 = 6;
+    pub const NT_PSTATUS: u32 = 10;
+    pub const NT_FPREGS: u32 = 12;
+    pub const NT_PSINFO: u32 = 13;
+    pub const NT_LWPSTATUS: u32 = 16;
+    pub const NT_LWPSINFO: u32 = 17;
+    pub const NT_WIN32PSTATUS: u32 = 18;
+    pub const NT_PPC_VMX: u32 = 256;
+    pub const NT_PPC_VSX: u32 = 258;
+    pub const NT_386_TLS: u32 = 512;
+    pub const NT_386_IOPERM: u32 = 513;
+    pub const NT_X86_XSTATE: u32 = 514;
+    pub const NT_S390_HIGH_GPRS: u32 = 768;
+    pub const NT_ARM_VFP: u32 = 1024;
+    pub const NT_ARM_TLS: u32 = 1025;
+    pub const NT_ARM_HW_BREAK: u32 = 1026;
+    pub const NT_ARM_HW_WATCH: u32 = 1027;
+    pub const NT_ARM_SYSTEM_CALL: u32 = 1028;
+    pub const NT_ARM_SVE: u32 = 1029;
+    pub const NT_ARM_PAC_MASK: u32 = 1030;
+    pub const NT_ARM_TAGGED_ADDR_CTRL: u32 = 1033;
+    pub const NT_SIGINFO: u32 = 1397311305;
+    pub const NT_FILE: u32 = 1179208773;
+    pub const NT_PRXFPREG: u32 = 1189489535;
+    pub const NT_VERSION: u32 = 1;
+    pub const NT_ARCH: u32 = 2;
+    pub const NT_GO_BUILDID: u32 = 4;
+    pub const NT_FDO_PACKAGING_METADATA: u32 = 3405650558;
+
+    pub const NT_GNU_ABI_TAG: u32 = 1;
+    pub const NT_GNU_HWCAP: u32 = 2;
+    pub const NT_GNU_BUILD_ID: u32 = 3;
+    pub const NT_GNU_GOLD_VERSION: u32 = 4;
+    pub const NT_GNU_PROPERTY_TYPE_0: u32 = 5;
+    pub const NT_GNU_BUILD_ATTRIBUTE_OPEN: u32 = 256;
+    pub const NT_GNU_BUILD_ATTRIBUTE_FUNC: u32 = 257;
+
+    pub const ELF_NOTE_OS_LINUX: u32 = 0;
+    pub const ELF_NOTE_OS_GNU: u32 = 1;
+    pub const ELF_NOTE_OS_SOLARIS2: u32 = 2;
+    pub const ELF_NOTE_OS_FREEBSD: u32 = 3;
+    pub const ELF_NOTE_OS_NETBSD: u32 = 4;
+    pub const ELF_NOTE_OS_SYLLABLE: u32 = 5;
+    pub const ELF_NOTE_OS_NACL: u32 = 6;
+
+    pub const NT_FREEBSD_ABI_TAG: u32 = 1;
+    pub const NT_FREEBSD_NOINIT_TAG: u32 = 2;
+    pub const NT_FREEBSD_ARCH_TAG: u32 = 3;
+    pub const NT_FREEBSD_FEATURE_CTL: u32 = 4;
+    pub const NT_FREEBSD_THRMISC: u32 = 7;
+    pub const NT_FREEBSD_PROCSTAT_PROC: u32 = 8;
+    pub const NT_FREEBSD_PROCSTAT_FILES: u32 = 9;
+    pub const NT_FREEBSD_PROCSTAT_VMMAP: u32 = 10;
+    pub const NT_FREEBSD_PROCSTAT_GROUPS: u32 = 11;
+    pub const NT_FREEBSD_PROCSTAT_UMASK: u32 = 12;
+    pub const NT_FREEBSD_PROCSTAT_RLIMIT: u32 = 13;
+    pub const NT_FREEBSD_PROCSTAT_OSREL: u32 = 14;
+    pub const NT_FREEBSD_PROCSTAT_PSSTRINGS: u32 = 15;
+    pub const NT_FREEBSD_PROCSTAT_AUXV: u32 = 16;
+    pub const NT_FREEBSD_PTLWPINFO: u32 = 17;
+    pub const NT_FREEBSD_X86_SEGBASES: u32 = 512;
+
+    pub const NT_FREEBSD_FCTL_ASLR_DISABLE: u32 = 1;
+    pub const NT_FREEBSD_FCTL_PROTMAX_DISABLE: u32 = 2;
+    pub const NT_FREEBSD_FCTL_STKGAP_DISABLE: u32 = 4;
+    pub const NT_FREEBSD_FCTL_WXNEEDED: u32 = 8;
+    pub const NT_FREEBSD_FCTL_LA48: u32 = 16;
+    pub const NT_FREEBSD_FCTL_ASG_DISABLE: u32 = 32;
+
+    pub const NT_STAPSDT: u32 = 3;
+
+    pub const GNU_BUILD_ATTRIBUTE_TYPE_NUMERIC: u32 = 42;
+    pub const GNU_BUILD_ATTRIBUTE_TYPE_STRING: u32 = 36;
+    pub const GNU_BUILD_ATTRIBUTE_TYPE_BOOL_TRUE: u32 = 43;
+    pub const GNU_BUILD_ATTRIBUTE_TYPE_BOOL_FALSE: u32 = 33;
+    pub const GNU_BUILD_ATTRIBUTE_VERSION: u32 = 1;
+    pub const GNU_BUILD_ATTRIBUTE_STACK_PROT: u32 = 2;
+    pub const GNU_BUILD_ATTRIBUTE_RELRO: u32 = 3;
+    pub const GNU_BUILD_ATTRIBUTE_STACK_SIZE: u32 = 4;
+    pub const GNU_BUILD_ATTRIBUTE_TOOL: u32 = 5;
+    pub const GNU_BUILD_ATTRIBUTE_ABI: u32 = 6;
+    pub const GNU_BUILD_ATTRIBUTE_PIC: u32 = 7;
+    pub const GNU_BUILD_ATTRIBUTE_SHORT_ENUM: u32 = 8;
+
+    pub const GNU_PROPERTY_STACK_SIZE: u32 = 1;
+    pub const GNU_PROPERTY_NO_COPY_ON_PROTECTED: u32 = 2;
+    pub const GNU_PROPERTY_UINT32_AND_LO: u32 = 2952790016;
+    pub const GNU_PROPERTY_UINT32_AND_HI: u32 = 2952822783;
+    pub const GNU_PROPERTY_UINT32_OR_LO: u32 = 2952822784;
+    pub const GNU_PROPERTY_UINT32_OR_HI: u32 = 2952855551;
+    pub const GNU_PROPERTY_1_NEEDED: u32 = 2952822784;
+    pub const GNU_PROPERTY_1_NEEDED_INDIRECT_EXTERN_ACCESS: u32 = 1;
+    pub const GNU_PROPERTY_LOPROC: u32 = 3221225472;
+    pub const GNU_PROPERTY_HIPROC: u32 = 3758096383;
+    pub const GNU_PROPERTY_LOUSER: u32 = 3758096384;
+    pub const GNU_PROPERTY_HIUSER: u32 = 4294967295;
+    pub const GNU_PROPERTY_AARCH64_FEATURE_1_AND: u32 = 3221225472;
+    pub const GNU_PROPERTY_AARCH64_FEATURE_1_BTI: u32 = 1;
+    pub const GNU_PROPERTY_AARCH64_FEATURE_1_PAC: u32 = 2;
+    pub const GNU_PROPERTY_X86_COMPAT_ISA_1_USED: u32 = 3221225472;
+    pub const GNU_PROPERTY_X86_COMPAT_ISA_1_NEEDED: u32 = 3221225473;
+    pub const GNU_PROPERTY_X86_FEATURE_1_AND: u32 = 3221225474;
+    pub const GNU_PROPERTY_X86_COMPAT_2_ISA_1_NEEDED: u32 = 3221258240;
+    pub const GNU_PROPERTY_X86_FEATURE_2_NEEDED: u32 = 3221258241;
+    pub const GNU_PROPERTY_X86_ISA_1_NEEDED: u32 = 3221258242;
+    pub const GNU_PROPERTY_X86_COMPAT_2_ISA_1_USED: u32 = 3221291008;
+    pub const GNU_PROPERTY_X86_FEATURE_2_USED: u32 = 3221291009;
+    pub const GNU_PROPERTY_X86_ISA_1_USED: u32 = 3221291010;
+    pub const GNU_PROPERTY_X86_ISA_1_BASELINE: u32 = 1;
+    pub const GNU_PROPERTY_X86_ISA_1_V2: u32 = 2;
+    pub const GNU_PROPERTY_X86_ISA_1_V3: u32 = 4;
+    pub const GNU_PROPERTY_X86_ISA_1_V4: u32 = 8;
+    pub const GNU_PROPERTY_X86_FEATURE_1_IBT: u32 = 1;
+    pub const GNU_PROPERTY_X86_FEATURE_1_SHSTK: u32 = 2;
+    pub const GNU_PROPERTY_X86_FEATURE_1_LAM_U48: u32 = 4;
+    pub const GNU_PROPERTY_X86_FEATURE_1_LAM_U57: u32 = 8;
+    pub const GNU_PROPERTY_X86_FEATURE_2_X86: u32 = 1;
+    pub const GNU_PROPERTY_X86_FEATURE_2_X87: u32 = 2;
+    pub const GNU_PROPERTY_X86_FEATURE_2_MMX: u32 = 4;
+    pub const GNU_PROPERTY_X86_FEATURE_2_XMM: u32 = 8;
+    pub const GNU_PROPERTY_X86_FEATURE_2_YMM: u32 = 16;
+    pub const GNU_PROPERTY_X86_FEATURE_2_ZMM: u32 = 32;
+    pub const GNU_PROPERTY_X86_FEATURE_2_FXSR: u32 = 64;
+    pub const GNU_PROPERTY_X86_FEATURE_2_XSAVE: u32 = 128;
+    pub const GNU_PROPERTY_X86_FEATURE_2_XSAVEOPT: u32 = 256;
+    pub const GNU_PROPERTY_X86_FEATURE_2_XSAVEC: u32 = 512;
+    pub const GNU_PROPERTY_X86_FEATURE_2_TMM: u32 = 1024;
+    pub const GNU_PROPERTY_X86_FEATURE_2_MASK: u32 = 2048;
+
+    // the e_flags of each machine, the names are the ones of elf.h (or of
+    // binutils include/elf/*.h when elf.h does not have them)
+    pub const EF_SPARCV9_MM: u32 = 3;
+    pub const EF_SPARCV9_TSO: u32 = 0;
+    pub const EF_SPARCV9_PSO: u32 = 1;
+    pub const EF_SPARCV9_RMO: u32 = 2;
+    pub const EF_SPARC_LEDATA: u32 = 8388608;
+    pub const EF_SPARC_EXT_MASK: u32 = 16776960;
+    pub const EF_SPARC_32PLUS: u32 = 256;
+    pub const EF_SPARC_SUN_US1: u32 = 512;
+    pub const EF_SPARC_HAL_R1: u32 = 1024;
+    pub const EF_SPARC_SUN_US3: u32 = 2048;
+    pub const EF_MIPS_NOREORDER: u32 = 1;
+    pub const EF_MIPS_PIC: u32 = 2;
+    pub const EF_MIPS_CPIC: u32 = 4;
+    pub const EF_MIPS_XGOT: u32 = 8;
+    pub const EF_MIPS_UCODE: u32 = 16;
+    pub const EF_MIPS_ABI2: u32 = 32;
+    pub const EF_MIPS_ABI_ON32: u32 = 64;
+    pub const EF_MIPS_OPTIONS_FIRST: u32 = 128;
+    pub const EF_MIPS_32BITMODE: u32 = 256;
+    pub const EF_MIPS_FP64: u32 = 512;
+    pub const EF_MIPS_NAN2008: u32 = 1024;
+    pub const EF_MIPS_ABI: u32 = 61440;
+    pub const EF_MIPS_MACH: u32 = 16711680;
+    pub const EF_MIPS_ARCH_ASE_MICROMIPS: u32 = 33554432;
+    pub const EF_MIPS_ARCH_ASE_M16: u32 = 67108864;
+    pub const EF_MIPS_ARCH_ASE_MDMX: u32 = 134217728;
+    pub const EF_MIPS_ARCH: u32 = 4026531840;
+    pub const EF_MIPS_ARCH_1: u32 = 0;
+    pub const EF_MIPS_ARCH_2: u32 = 268435456;
+    pub const EF_MIPS_ARCH_3: u32 = 536870912;
+    pub const EF_MIPS_ARCH_4: u32 = 805306368;
+    pub const EF_MIPS_ARCH_5: u32 = 1073741824;
+    pub const EF_MIPS_ARCH_32: u32 = 1342177280;
+    pub const EF_MIPS_ARCH_64: u32 = 1610612736;
+    pub const EF_MIPS_ARCH_32R2: u32 = 1879048192;
+    pub const EF_MIPS_ARCH_64R2: u32 = 2147483648;
+    pub const EF_MIPS_ARCH_32R6: u32 = 2415919104;
+    pub const EF_MIPS_ARCH_64R6: u32 = 2684354560;
+    pub const E_MIPS_ABI_O32: u32 = 4096;
+    pub const E_MIPS_ABI_O64: u32 = 8192;
+    pub const E_MIPS_ABI_EABI32: u32 = 12288;
+    pub const E_MIPS_ABI_EABI64: u32 = 16384;
+    pub const EF_PPC64_ABI: u32 = 3;
+    pub const EF_ARM_RELEXEC: u32 = 1;
+    pub const EF_ARM_HASENTRY: u32 = 2;
+    pub const EF_ARM_INTERWORK: u32 = 4;
+    pub const EF_ARM_APCS_26: u32 = 8;
+    pub const EF_ARM_APCS_FLOAT: u32 = 16;
+    pub const EF_ARM_PIC: u32 = 32;
+    pub const EF_ARM_ALIGN8: u32 = 64;
+    pub const EF_ARM_NEW_ABI: u32 = 128;
+    pub const EF_ARM_OLD_ABI: u32 = 256;
+    pub const EF_ARM_SOFT_FLOAT: u32 = 512;
+    pub const EF_ARM_VFP_FLOAT: u32 = 1024;
+    pub const EF_ARM_MAVERICK_FLOAT: u32 = 2048;
+    pub const EF_ARM_ABI_FLOAT_SOFT: u32 = 512;
+    pub const EF_ARM_ABI_FLOAT_HARD: u32 = 1024;
+    pub const EF_ARM_SYMSARESORTED: u32 = 4;
+    pub const EF_ARM_DYNSYMSUSESEGIDX: u32 = 8;
+    pub const EF_ARM_MAPSYMSFIRST: u32 = 16;
+    pub const EF_ARM_BE8: u32 = 8388608;
+    pub const EF_ARM_LE8: u32 = 4194304;
+    pub const EF_ARM_EABIMASK: u32 = 4278190080;
+    pub const EF_ARM_EABI_UNKNOWN: u32 = 0;
+    pub const EF_ARM_EABI_VER1: u32 = 16777216;
+    pub const EF_ARM_EABI_VER2: u32 = 33554432;
+    pub const EF_ARM_EABI_VER3: u32 = 50331648;
+    pub const EF_ARM_EABI_VER4: u32 = 67108864;
+    pub const EF_ARM_EABI_VER5: u32 = 83886080;
+    pub const EF_RISCV_RVC: u32 = 1;
+    pub const EF_RISCV_FLOAT_ABI: u32 = 6;
+    pub const EF_RISCV_FLOAT_ABI_SOFT: u32 = 0;
+    pub const EF_RISCV_FLOAT_ABI_SINGLE: u32 = 2;
+    pub const EF_RISCV_FLOAT_ABI_DOUBLE: u32 = 4;
+    pub const EF_RISCV_FLOAT_ABI_QUAD: u32 = 6;
+    pub const EF_RISCV_RVE: u32 = 8;
+    pub const EF_RISCV_TSO: u32 = 16;
+    pub const EF_LARCH_ABI_MODIFIER_MASK: u32 = 7;
+    pub const EF_LARCH_ABI_SOFT_FLOAT: u32 = 1;
+    pub const EF_LARCH_ABI_SINGLE_FLOAT: u32 = 2;
+    pub const EF_LARCH_ABI_DOUBLE_FLOAT: u32 = 3;
+    pub const EF_LARCH_OBJABI_MASK: u32 = 192;
+    pub const EF_LARCH_OBJABI_V0: u32 = 0;
+    pub const EF_LARCH_OBJABI_V1: u32 = 64;
+    pub const EF_AVR_MACH: u32 = 127;
+    pub const EF_AVR_LINKRELAX_PREPARED: u32 = 128;
+
+    // the OS specific types, their meaning depends on EI_OSABI (and, for
+    // HP-UX, on the machine too). Most of them are not in elf.h, the names
+    // are the ones of binutils include/elf/{common,hppa,solaris}.h
+    pub const SHT_GNU_INCREMENTAL_INPUTS: u32 = 1879000832;
+    pub const SHT_SUNW_ancillary: u32 = 1879048174;
+    pub const SHT_SUNW_capchain: u32 = 1879048175;
+    pub const SHT_SUNW_capinfo: u32 = 1879048176;
+    pub const SHT_SUNW_symsort: u32 = 1879048177;
+    pub const SHT_SUNW_tlssort: u32 = 1879048178;
+    pub const SHT_SUNW_LDYNSYM: u32 = 1879048179;
+    pub const SHT_SUNW_dof: u32 = 1879048180;
+    pub const SHT_SUNW_cap: u32 = 1879048181;
+    pub const SHT_SUNW_SIGNATURE: u32 = 1879048182;
+    pub const SHT_SUNW_ANNOTATE: u32 = 1879048183;
+    pub const SHT_SUNW_DEBUGSTR: u32 = 1879048184;
+    pub const SHT_SUNW_DEBUG: u32 = 1879048185;
+    pub const SHT_SUNW_move: u32 = 1879048186;
+    pub const SHT_SUNW_COMDAT: u32 = 1879048187;
+    pub const SHT_SUNW_syminfo: u32 = 1879048188;
+    pub const SHT_SUNW_verdef: u32 = 1879048189;
+    pub const SHT_SUNW_verneed: u32 = 1879048190;
+    pub const SHT_SUNW_versym: u32 = 1879048191;
+    pub const PT_SUNW_UNWIND: u32 = 1684333904;
+    pub const PT_SUNWBSS: u32 = 1879048186;
+    pub const PT_SUNWSTACK: u32 = 1879048187;
+    pub const PT_SUNWDTRACE: u32 = 1879048188;
+    pub const PT_SUNWCAP: u32 = 1879048189;
+    pub const PT_HP_TLS: u32 = 1610612736;
+    pub const PT_HP_CORE_NONE: u32 = 1610612737;
+    pub const PT_HP_CORE_VERSION: u32 = 1610612738;
+    pub const PT_HP_CORE_KERNEL: u32 = 1610612739;
+    pub const PT_HP_CORE_COMM: u32 = 1610612740;
+    pub const PT_HP_CORE_PROC: u32 = 1610612741;
+    pub const PT_HP_CORE_LOADABLE: u32 = 1610612742;
+    pub const PT_HP_CORE_STACK: u32 = 1610612743;
+    pub const PT_HP_CORE_SHM: u32 = 1610612744;
+    pub const PT_HP_CORE_MMF: u32 = 1610612745;
+    pub const PT_HP_PARALLEL: u32 = 1610612752;
+    pub const PT_HP_FASTBIND: u32 = 1610612753;
+    pub const PT_HP_OPT_ANNOT: u32 = 1610612754;
+    pub const PT_HP_HSL_ANNOT: u32 = 1610612755;
+    pub const PT_HP_STACK: u32 = 1610612756;
+    pub const PT_HP_CORE_UTSNAME: u32 = 1610612757;
+    pub const STT_HP_OPAQUE: u32 = 11;
+    pub const STT_HP_STUB: u32 = 12;
+    pub const DT_SUNW_AUXILIARY: u32 = 1610612749;
+    pub const DT_SUNW_RTLDINF: u32 = 1610612750;
+    pub const DT_SUNW_FILTER: u32 = 1610612751;
+    pub const DT_SUNW_CAP: u32 = 1610612752;
+    pub const DT_SUNW_SYMTAB: u32 = 1610612753;
+    pub const DT_SUNW_SYMSZ: u32 = 1610612754;
+    pub const DT_SUNW_SORTENT: u32 = 1610612755;
+    pub const DT_SUNW_SYMSORT: u32 = 1610612756;
+    pub const DT_SUNW_SYMSORTSZ: u32 = 1610612757;
+    pub const DT_SUNW_TLSSORT: u32 = 1610612758;
+    pub const DT_SUNW_TLSSORTSZ: u32 = 1610612759;
+    pub const DT_SUNW_CAPINFO: u32 = 1610612760;
+    pub const DT_SUNW_STRPAD: u32 = 1610612761;
+    pub const DT_SUNW_CAPCHAIN: u32 = 1610612762;
+    pub const DT_SUNW_LDMACH: u32 = 1610612763;
+    pub const DT_SUNW_CAPCHAINENT: u32 = 1610612765;
+    pub const DT_SUNW_CAPCHAINSZ: u32 = 1610612767;
+    pub const DT_SUNW_PARENT: u32 = 1610612769;
+    pub const DT_SUNW_ASLR: u32 = 1610612771;
+    pub const DT_SUNW_RELAX: u32 = 1610612773;
+    pub const DT_SUNW_NXHEAP: u32 = 1610612777;
+    pub const DT_SUNW_NXSTACK: u32 = 1610612779;
 }
+
@@ -0,0 +1,436 @@
+// the DW_* constants keep the names of the standard, lowercase and all
+#![allow(non_upper_case_globals)]
+
+pub mod abbrev;
+pub mod aranges;
+pub mod constants;
+pub mod expr;
+pub mod frame;
+pub mod info;
+pub mod line;
+pub mod lists;
+pub mod reader;
+pub mod registers;
+
+use crate::elf::class::ElfClass;
+use crate::elf::def::elfconsts::ET_REL;
+use crate::elf::dwarf::abbrev::{AbbrevTable, parse_abbrev_table};
+use crate::elf::dwarf::constants::*;
+use crate::elf::dwarf::info::{
+    AttributeValue, Die, Unit, UnitHeader, is_location_attribute, parse_dies, parse_unit_header,
+};
+use crate::elf::dwarf::reader::{DwarfFormat, DwarfReader, Encoding, string_at};
+use crate::elf::error::ElfError;
+use crate::elf::file::ElfFile;
+use crate::elf::relocation::Elf64Relocations;
+
+// the debug sections that the dumps read, with the call frames of the
+// unwinder (.eh_frame and its header) and the ones of the split DWARF (.dwo)
+// files. A .zdebug_* section is loaded with the name of the .debug_* one
+// that it holds
+pub const DEBUG_SECTIONS: &[&str] = &[
+    ".debug_abbrev",
+    ".debug_addr",
+    ".debug_aranges",
+    ".debug_frame",
+    ".debug_info",
+    ".debug_line",
+    ".debug_line_str",
+    ".debug_loc",
+    ".debug_loclists",
+    ".debug_ranges",
+    ".debug_rnglists",
+    ".debug_str",
+    ".debug_str_offsets",
+    ".eh_frame",
+    ".eh_frame_hdr",
+    ".debug_abbrev.dwo",
+    ".debug_info.dwo",
+    ".debug_line.dwo",
+    ".debug_loc.dwo",
+    ".debug_loclists.dwo",
+    ".debug_rnglists.dwo",
+    ".debug_str.dwo",
+    ".debug_str_offsets.dwo",
+];
+
+// the name in DEBUG_SECTIONS of a section of the file, if it is one
+pub fn canonical_name(name: &str) -> Option<&'static str> {
+    let name = match name.strip_prefix(".zdebug_") {
+        Some(rest) => format!(".debug_{rest}"),
+        None => name.to_string(),
+    };
+
+    DEBUG_SECTIONS.iter().copied().find(|&known| known == name)
+}
+
+// the name of the .dwo variant of a section, the split units read their
+// strings and lists from those
+pub fn dwo_name(name: &'static str) -> &'static str {
+    DEBUG_SECTIONS
+        .iter()
+        .copied()
+        .find(|known| known.strip_suffix(".dwo") == Some(name))
+        .unwrap_or(name)
+}
+
+#[derive(Debug, Clone)]
+pub struct DwarfSection {
+    pub name: &'static str,
+    // the name in the file, the titles of the dumps show .zdebug_info for
+    // the compressed .debug_info
+    pub file_name: String,
+    pub index: usize,
+    pub address: u64,
+    // decompressed, and with the relocations of an ET_REL file applied
+    pub data: Vec<u8>,
+    // where those relocations were applied, sorted. In an object file a
+    // (0, 0) list entry is only the end of its list when it has none
+    pub relocated: Vec<u64>,
+}
+
+// the debug sections of a file, loaded once for all the dumps
+#[derive(Debug)]
+pub struct Dwarf {
+    pub sections: Vec<DwarfSection>,
+    pub is_little_endian: bool,
+    pub e_machine: u16,
+    // the size of the addresses of the class, .eh_frame has none of its own
+    pub address_size: u8,
+    // the relocations that could not be applied, with the name of the
+    // section they were for
+    pub skipped: Vec<(&'static str, ElfError)>,
+}
+
+// what the indexes and the offsets of a unit are relative to: the bases
+// from the attributes of its unit DIE (or the defaults of the split units,
+// that have no such attributes) and the base address of its lists
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UnitBases {
+    pub low_pc: u64,
+    pub addr_base: u64,
+    pub str_offsets_base: u64,
+    pub loclists_base: u64,
+    pub rnglists_base: u64,
+}
+
+// a list that a DIE points at, in .debug_loc(lists) or in .debug_ranges
+// (or .debug_rnglists). The lists are only in their section, what they are
+// read with is in the unit that points at them
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListReference {
+    pub offset: u64,
+    // the location views of the list (DW_AT_GNU_locviews), in its section
+    pub views: Option<u64>,
+    // the unit that refers to it, for the DIE references of the expressions
+    pub unit_offset: u64,
+    pub encoding: Encoding,
+    pub bases: UnitBases,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListKind {
+    Locations,
+    Ranges,
+}
+
+impl Dwarf {
+    pub fn load(file: &ElfFile) -> Result<Self, ElfError> {
+        let mut dwarf = Dwarf {
+            sections: Vec::new(),
+            is_little_endian: file.is_little_endian(),
+            e_machine: file.header().e_machine,
+            address_size: match file.class() {
+                ElfClass::Elf32 => 4,
+                ElfClass::Elf64 => 8,
+            },
+            skipped: Vec::new(),
+        };
+
+        for (index, section) in file.sections()?.iter().enumerate() {
+            let Some(name) = canonical_name(&section.name) else {
+                continue;
+            };
+            // the first one wins, like binutils
+            if dwarf.section(name).is_some() {
+                continue;
+            }
+
+            let (data, skipped) = file.relocated_data(index, true)?;
+            dwarf
+                .skipped
+                .extend(skipped.into_iter().map(|err| (name, err)));
+
+            let mut relocated = Vec::new();
+            if file.header().e_type as u32 == ET_REL {
+                for relocation_section in file.relocations_for(index)? {
+                    match file.relocations(relocation_section)? {
+                        Elf64Relocations::Rel(rels) => {
+                            relocated.extend(rels.iter().map(|rel| rel.r_offset))
+                        }
+                        Elf64Relocations::Rela(relas) => {
+                            relocated.extend(relas.iter().map(|rela| rela.r_offset))
+                        }
+                        Elf64Relocations::Relr(_) => {}
+                    }
+                }
+                relocated.sort_unstable();
+            }
+
+            dwarf.sections.push(DwarfSection {
+                name,
+                file_name: section.name.clone(),
+                index,
+                address: section.header.sh_addr,
+                data,
+                relocated,
+            });
+        }
+
+        Ok(dwarf)
+    }
+
+    pub fn section(&self, name: &str) -> Option<&DwarfSection> {
+        self.sections.iter().find(|section| section.name == name)
+    }
+
+    // the name of a section in the titles of the dumps, the one of the file
+    pub fn title<'a>(&'a self, name: &'a str) -> &'a str {
+        self.section(name)
+            .map_or(name, |section| section.file_name.as_str())
+    }
+
+    // the data of a section, empty when the file doesnt have it
+    pub fn data(&self, name: &str) -> &[u8] {
+        self.section(name).map_or(&[], |section| &section.data)
+    }
+
+    pub fn is_relocated(&self, name: &str, offset: u64) -> bool {
+        self.section(name)
+            .is_some_and(|section| section.relocated.binary_search(&offset).is_ok())
+    }
+
+    pub fn reader(&self, name: &'static str) -> DwarfReader<'_> {
+        DwarfReader::new(self.data(name), self.is_little_endian, name)
+    }
+
+    pub fn abbrev_table(&self, offset: u64, dwo: bool) -> Result<AbbrevTable, ElfError> {
+        let mut reader = self.reader(self.name(".debug_abbrev", dwo));
+        reader.seek(offset);
+        parse_abbrev_table(&mut reader)
+    }
+
+    // the section of the split units of a .dwo file when dwo is set
+    pub fn name(&self, name: &'static str, dwo: bool) -> &'static str {
+        if dwo { dwo_name(name) } else { name }
+    }
+
+    // the string at offset in .debug_str (or its .dwo variant)
+    pub fn string(&self, offset: u64, dwo: bool) -> Option<&[u8]> {
+        string_at(self.data(self.name(".debug_str", dwo)), offset)
+    }
+
+    pub fn line_string(&self, offset: u64) -> Option<&[u8]> {
+        string_at(self.data(".debug_line_str"), offset)
+    }
+
+    // the offset in .debug_str of the string at index in .debug_str_offsets
+    pub fn string_offset(
+        &self,
+        index: u64,
+        base: u64,
+        format: DwarfFormat,
+        dwo: bool,
+    ) -> Option<u64> {
+        let size = format.offset_size() as u64;
+        let mut reader = self.reader(self.name(".debug_str_offsets", dwo));
+        reader.seek(index.checked_mul(size)?.checked_add(base)?);
+        reader.offset_of(format, "offset").ok()
+    }
+
+    // the address at index in .debug_addr, that is only in the main file
+    pub fn address(&self, index: u64, base: u64, address_size: u8) -> Option<u64> {
+        let mut reader = self.reader(".debug_addr");
+        reader.seek(index.checked_mul(address_size as u64)?.checked_add(base)?);
+        reader.address(address_size).ok()
+    }
+
+    // the offset in its section of the list at index of the offsets array
+    // of a .debug_loclists or .debug_rnglists table, base is the start of
+    // the array
+    pub fn list_offset(
+        &self,
+        section: &'static str,
+        index: u64,
+        base: u64,
+        format: DwarfFormat,
+    ) -> Option<u64> {
+        let size = format.offset_size() as u64;
+        let mut reader = self.reader(section);
+        reader.seek(index.checked_mul(size)?.checked_add(base)?);
+        let offset = reader.offset_of(format, "offsets").ok()?;
+        base.checked_add(offset)
+    }
+
+    // the bases of a unit, from its unit DIE. The split units have none of
+    // the attributes, their indexes start after the headers of their
+    // sections (the DWARF 4 ones of gcc's -gsplit-dwarf have no headers)
+    pub fn unit_bases(&self, header: &UnitHeader, root: Option<&Die>, dwo: bool) -> UnitBases {
+        let is_split = dwo && header.version >= 5;
+        let list_header_size = if is_split {
+            header.format.length_size() as u64 + 8
+        } else {
+            0
+        };
+
+        let mut bases = UnitBases {
+            str_offsets_base: if is_split {
+                header.format.length_size() as u64 + 4
+            } else {
+                0
+            },
+            loclists_base: list_header_size,
+            rnglists_base: list_header_size,
+            ..UnitBases::default()
+        };
+        let Some(root) = root else {
+            return bases;
+        };
+
+        for attribute in &root.attributes {
+            let value = match attribute.value {
+                AttributeValue::SecOffset(value) => value,
+                ref value => value.constant().unwrap_or_default(),
+            };
+            match attribute.name {
+                DW_AT_addr_base | DW_AT_GNU_addr_base => bases.addr_base = value,
+                DW_AT_str_offsets_base => bases.str_offsets_base = value,
+                DW_AT_loclists_base => bases.loclists_base = value,
+                DW_AT_rnglists_base => bases.rnglists_base = value,
+                _ => {}
+            }
+        }
+
+        bases.low_pc = match root.attribute(DW_AT_low_pc) {
+            Some(&AttributeValue::Address(address)) => address,
+            Some(&AttributeValue::AddressIndex(index)) => self
+                .address(index, bases.addr_base, header.address_size)
+                .unwrap_or_default(),
+            _ => 0,
+        };
+        bases
+    }
+
+    // all the units of .debug_info (or .debug_info.dwo), with their DIEs
+    pub fn units(&self, dwo: bool) -> Result<Vec<Unit>, ElfError> {
+        let mut reader = self.reader(self.name(".debug_info", dwo));
+        let mut units = Vec::new();
+
+        while !reader.is_empty() {
+            let mut unit_reader = reader.clone();
+            let header = parse_unit_header(&mut unit_reader)?;
+            let dies_offset = unit_reader.offset();
+            let mut unit_reader = reader.split(header.end - header.offset);
+            unit_reader.seek(dies_offset);
+
+            let abbrevs = self.abbrev_table(header.abbrev_offset, dwo)?;
+            let dies = parse_dies(&mut unit_reader, &header, &abbrevs)?;
+            units.push(Unit { header, dies });
+        }
+
+        Ok(units)
+    }
+
+    // the lists of a kind that the DIEs point at, sorted by offset and
+    // without the duplicates (the first reference wins)
+    pub fn list_references(
+        &self,
+        kind: ListKind,
+        dwo: bool,
+    ) -> Result<Vec<ListReference>, ElfError> {
+        let mut references: Vec<ListReference> = Vec::new();
+
+        for unit in self.units(dwo)? {
+            let encoding = unit.header.encoding();
+            let bases = self.unit_bases(&unit.header, unit.root(), dwo);
+            let format = unit.header.format;
+
+            for die in &unit.dies {
+                let views = die.attribute(DW_AT_GNU_locviews).and_then(section_offset);
+
+                for attribute in &die.attributes {
+                    let offset = match (kind, attribute.name, &attribute.value) {
+                        (ListKind::Locations, name, value) if is_location_attribute(name) => {
+                            match *value {
+                                AttributeValue::SecOffset(offset) => Some(offset),
+                                AttributeValue::Data { value, size: 4 | 8 }
+                                    if encoding.version < 4 =>
+                                {
+                                    Some(value)
+                                }
+                                AttributeValue::LoclistIndex(index) => self.list_offset(
+                                    self.name(".debug_loclists", dwo),
+                                    index,
+                                    bases.loclists_base,
+                                    format,
+                                ),
+                                _ => None,
+                            }
+                        }
+                        (ListKind::Ranges, DW_AT_ranges, value) => match *value {
+                            AttributeValue::SecOffset(offset) => Some(offset),
+                            AttributeValue::Data { value, size: 4 | 8 } if encoding.version < 4 => {
+                                Some(value)
+                            }
+                            AttributeValue::RnglistIndex(index) => self.list_offset(
+                                self.name(".debug_rnglists", dwo),
+                                index,
+                                bases.rnglists_base,
+                                format,
+                            ),
+                            _ => None,
+                        },
+                        _ => None,
+                    };
+
+                    if let Some(offset) = offset {
+                        references.push(ListReference {
+                            offset,
+                            views: views.filter(|_| kind == ListKind::Locations),
+                            unit_offset: unit.header.offset,
+                            encoding,
+                            bases,
+                        });
+                    }
+                }
+            }
+        }
+
+        references.sort_by_key(|reference| reference.offset);
+        references.dedup_by_key(|reference| reference.offset);
+        Ok(references)
+    }
+}
+
+fn section_offset(value: &AttributeValue) -> Option<u64> {
+    match *value {
+        AttributeValue::SecOffset(offset) => Some(offset),
+        AttributeValue::Data { value, size: 4 | 8 } => Some(value),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{canonical_name, dwo_name};
+
+    #[test]
+    fn canonical_name_knows_the_zdebug_and_dwo_sections() {
+        assert_eq!(canonical_name(".zdebug_info"), Some(".debug_info"));
+        assert_eq!(canonical_name(".debug_str.dwo"), Some(".debug_str.dwo"));
+        assert_eq!(canonical_name(".zdebug_frame"), Some(".debug_frame"));
+        assert_eq!(canonical_name(".debug_macro"), None);
+        assert_eq!(dwo_name(".debug_abbrev"), ".debug_abbrev.dwo");
+        assert_eq!(dwo_name(".debug_addr"), ".debug_addr");
+    }
+}
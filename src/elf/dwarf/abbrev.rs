@@ -0,0 +1,124 @@
+use crate::elf::dwarf::constants::DW_FORM_implicit_const;
+use crate::elf::dwarf::reader::DwarfReader;
+use crate::elf::error::ElfError;
+use std::collections::HashMap;
+
+// one attribute of an abbreviation, DW_FORM_implicit_const keeps its value
+// here instead of in the DIEs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbbrevAttribute {
+    pub name: u64,
+    pub form: u64,
+    pub implicit_const: Option<i64>,
+}
+
+// the shape of the DIEs that use its code
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Abbrev {
+    pub code: u64,
+    pub tag: u64,
+    pub has_children: bool,
+    pub attributes: Vec<AbbrevAttribute>,
+}
+
+// the abbreviations of one table, the one that starts at offset in
+// .debug_abbrev (the units point to it with debug_abbrev_offset)
+#[derive(Debug, Clone, Default)]
+pub struct AbbrevTable {
+    pub offset: u64,
+    pub abbrevs: Vec<Abbrev>,
+    by_code: HashMap<u64, usize>,
+}
+
+impl AbbrevTable {
+    pub fn get(&self, code: u64) -> Option<&Abbrev> {
+        self.by_code.get(&code).map(|&index| &self.abbrevs[index])
+    }
+}
+
+// the table at the current offset of the reader, up to (and past) the code 0
+// that ends it
+pub fn parse_abbrev_table(reader: &mut DwarfReader) -> Result<AbbrevTable, ElfError> {
+    let mut table = AbbrevTable {
+        offset: reader.offset(),
+        ..AbbrevTable::default()
+    };
+
+    loop {
+        let code = reader.uleb128("abbrev_code")?;
+        if code == 0 {
+            return Ok(table);
+        }
+
+        let tag = reader.uleb128("tag")?;
+        let has_children = reader.u8("children")? != 0;
+
+        let mut attributes = Vec::new();
+        loop {
+            let name = reader.uleb128("attribute")?;
+            let form = reader.uleb128("form")?;
+            if name == 0 && form == 0 {
+                break;
+            }
+
+            let implicit_const = if form == DW_FORM_implicit_const {
+                Some(reader.sleb128("implicit_const")?)
+            } else {
+                None
+            };
+            attributes.push(AbbrevAttribute {
+                name,
+                form,
+                implicit_const,
+            });
+        }
+
+        // like binutils, the first one with a code wins
+        table.by_code.entry(code).or_insert(table.abbrevs.len());
+        table.abbrevs.push(Abbrev {
+            code,
+            tag,
+            has_children,
+            attributes,
+        });
+    }
+}
+
+// every table of .debug_abbrev, one after the other, for the dump. A table
+// that is cut short ends the list
+pub fn parse_abbrev_tables(reader: &mut DwarfReader) -> Result<Vec<AbbrevTable>, ElfError> {
+    let mut tables = Vec::new();
+
+    while !reader.is_empty() {
+        tables.push(parse_abbrev_table(reader)?);
+    }
+
+    Ok(tables)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_abbrev_tables;
+    use crate::elf::dwarf::reader::DwarfReader;
+
+    #[test]
+    fn parse_abbrev_tables_reads_the_attributes_and_the_implicit_consts() {
+        // 1: DW_TAG_base_type, no children, byte_size data1
+        // 2: DW_TAG_pointer_type, byte_size implicit_const 8
+        // and a second table with 1: DW_TAG_compile_unit, children
+        let data = [
+            1, 0x24, 0, 0x0b, 0x0b, 0, 0, 2, 0x0f, 0, 0x0b, 0x21, 8, 0, 0, 0, 1, 0x11, 1, 0, 0, 0,
+        ];
+        let mut reader = DwarfReader::new(&data, true, ".debug_abbrev");
+
+        let tables = parse_abbrev_tables(&mut reader).unwrap();
+
+        assert_eq!(tables.len(), 2);
+        assert_eq!(tables[1].offset, 16);
+        let pointer = tables[0].get(2).unwrap();
+        assert_eq!(pointer.tag, 0x0f);
+        assert_eq!(pointer.attributes[0].implicit_const, Some(8));
+        assert!(tables[1].get(1).unwrap().has_children);
+        assert!(tables[1].get(2).is_none());
+    }
+}
@@ -0,0 +1,93 @@
+use crate::elf::dwarf::reader::{DwarfFormat, DwarfReader};
+use crate::elf::error::ElfError;
+
+// a set of .debug_aranges, the address ranges of one unit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArangeSet {
+    pub offset: u64,
+    pub length: u64,
+    pub format: DwarfFormat,
+    pub version: u16,
+    pub debug_info_offset: u64,
+    pub address_size: u8,
+    pub segment_selector_size: u8,
+    // (address, length), with the (0, 0) that ends the set and anything
+    // after it
+    pub ranges: Vec<(u64, u64)>,
+    pub end: u64,
+}
+
+// the set at the current offset of the reader, that is left at the next one
+pub fn parse_arange_set(reader: &mut DwarfReader) -> Result<ArangeSet, ElfError> {
+    let offset = reader.offset();
+    let (length, format) = reader.initial_length()?;
+    let mut set = reader.split(length);
+    let end = reader.offset();
+
+    let version = set.u16("version")?;
+    let debug_info_offset = set.offset_of(format, "debug_info_offset")?;
+
+    let address_size_offset = set.offset();
+    let address_size = set.u8("address_size")?;
+    if !matches!(address_size, 1..=8) {
+        return Err(ElfError::invalid_value(
+            address_size_offset,
+            set.section(),
+            "address_size",
+            "an address size from 1 to 8",
+            address_size,
+        ));
+    }
+    let segment_selector_size = set.u8("segment_selector_size")?;
+
+    // the tuples are aligned to their size, from the start of the set
+    let tuple_size = 2 * address_size as u64 + segment_selector_size as u64;
+    let excess = (set.offset() - offset) % tuple_size;
+    if excess != 0 {
+        set.bytes(tuple_size - excess, "padding")?;
+    }
+
+    let mut ranges = Vec::new();
+    while set.remaining() as u64 >= tuple_size {
+        if segment_selector_size != 0 {
+            set.sized(segment_selector_size.min(8), "segment")?;
+        }
+        let address = set.address(address_size)?;
+        let length = set.address(address_size)?;
+        ranges.push((address, length));
+    }
+
+    Ok(ArangeSet {
+        offset,
+        length,
+        format,
+        version,
+        debug_info_offset,
+        address_size,
+        segment_selector_size,
+        ranges,
+        end,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_arange_set;
+    use crate::elf::dwarf::reader::DwarfReader;
+
+    #[test]
+    fn parse_arange_set_skips_the_padding_before_the_tuples() {
+        // a 32-bit set: 12 bytes of header, 4 of padding and two tuples
+        let mut data = vec![28, 0, 0, 0, 2, 0, 0x10, 0, 0, 0, 4, 0, 0, 0, 0, 0];
+        data.extend([0x00, 0x10, 0, 0, 0x20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+        let set = parse_arange_set(&mut DwarfReader::new(&data, true, ".debug_aranges")).unwrap();
+
+        assert_eq!(
+            (set.version, set.debug_info_offset, set.address_size),
+            (2, 0x10, 4)
+        );
+        assert_eq!(set.ranges, [(0x1000, 0x20), (0, 0)]);
+        assert_eq!(set.end, 32);
+    }
+}
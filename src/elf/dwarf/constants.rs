@@ -0,0 +1,678 @@
+// the DW_* values of the DWARF 5 standard (with the GNU extensions that gcc
+// still emits), each group with the function that gives back its name, for
+// the dumps. The names are the ones of dwarf2.h
+macro_rules! dwarf_constants {
+    ($(#[$meta:meta])* $name_of:ident: $ty:ty { $($constant:ident = $value:expr,)* }) => {
+        $(pub const $constant: $ty = $value;)*
+
+        $(#[$meta])*
+        pub fn $name_of(value: $ty) -> Option<&'static str> {
+            match value {
+                $($constant => Some(stringify!($constant)),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+dwarf_constants! {
+    // the type of a DWARF 5 unit, from its header
+    unit_type_name: u8 {
+        DW_UT_compile = 0x01,
+        DW_UT_type = 0x02,
+        DW_UT_partial = 0x03,
+        DW_UT_skeleton = 0x04,
+        DW_UT_split_compile = 0x05,
+        DW_UT_split_type = 0x06,
+    }
+}
+
+dwarf_constants! {
+    tag_name: u64 {
+        DW_TAG_array_type = 0x01,
+        DW_TAG_class_type = 0x02,
+        DW_TAG_entry_point = 0x03,
+        DW_TAG_enumeration_type = 0x04,
+        DW_TAG_formal_parameter = 0x05,
+        DW_TAG_imported_declaration = 0x08,
+        DW_TAG_label = 0x0a,
+        DW_TAG_lexical_block = 0x0b,
+        DW_TAG_member = 0x0d,
+        DW_TAG_pointer_type = 0x0f,
+        DW_TAG_reference_type = 0x10,
+        DW_TAG_compile_unit = 0x11,
+        DW_TAG_string_type = 0x12,
+        DW_TAG_structure_type = 0x13,
+        DW_TAG_subroutine_type = 0x15,
+        DW_TAG_typedef = 0x16,
+        DW_TAG_union_type = 0x17,
+        DW_TAG_unspecified_parameters = 0x18,
+        DW_TAG_variant = 0x19,
+        DW_TAG_common_block = 0x1a,
+        DW_TAG_common_inclusion = 0x1b,
+        DW_TAG_inheritance = 0x1c,
+        DW_TAG_inlined_subroutine = 0x1d,
+        DW_TAG_module = 0x1e,
+        DW_TAG_ptr_to_member_type = 0x1f,
+        DW_TAG_set_type = 0x20,
+        DW_TAG_subrange_type = 0x21,
+        DW_TAG_with_stmt = 0x22,
+        DW_TAG_access_declaration = 0x23,
+        DW_TAG_base_type = 0x24,
+        DW_TAG_catch_block = 0x25,
+        DW_TAG_const_type = 0x26,
+        DW_TAG_constant = 0x27,
+        DW_TAG_enumerator = 0x28,
+        DW_TAG_file_type = 0x29,
+        DW_TAG_friend = 0x2a,
+        DW_TAG_namelist = 0x2b,
+        DW_TAG_namelist_item = 0x2c,
+        DW_TAG_packed_type = 0x2d,
+        DW_TAG_subprogram = 0x2e,
+        DW_TAG_template_type_param = 0x2f,
+        DW_TAG_template_value_param = 0x30,
+        DW_TAG_thrown_type = 0x31,
+        DW_TAG_try_block = 0x32,
+        DW_TAG_variant_part = 0x33,
+        DW_TAG_variable = 0x34,
+        DW_TAG_volatile_type = 0x35,
+        DW_TAG_dwarf_procedure = 0x36,
+        DW_TAG_restrict_type = 0x37,
+        DW_TAG_interface_type = 0x38,
+        DW_TAG_namespace = 0x39,
+        DW_TAG_imported_module = 0x3a,
+        DW_TAG_unspecified_type = 0x3b,
+        DW_TAG_partial_unit = 0x3c,
+        DW_TAG_imported_unit = 0x3d,
+        DW_TAG_condition = 0x3f,
+        DW_TAG_shared_type = 0x40,
+        DW_TAG_type_unit = 0x41,
+        DW_TAG_rvalue_reference_type = 0x42,
+        DW_TAG_template_alias = 0x43,
+        DW_TAG_coarray_type = 0x44,
+        DW_TAG_generic_subrange = 0x45,
+        DW_TAG_dynamic_type = 0x46,
+        DW_TAG_atomic_type = 0x47,
+        DW_TAG_call_site = 0x48,
+        DW_TAG_call_site_parameter = 0x49,
+        DW_TAG_skeleton_unit = 0x4a,
+        DW_TAG_immutable_type = 0x4b,
+        DW_TAG_MIPS_loop = 0x4081,
+        DW_TAG_format_label = 0x4101,
+        DW_TAG_function_template = 0x4102,
+        DW_TAG_class_template = 0x4103,
+        DW_TAG_GNU_BINCL = 0x4104,
+        DW_TAG_GNU_EINCL = 0x4105,
+        DW_TAG_GNU_template_template_param = 0x4106,
+        DW_TAG_GNU_template_parameter_pack = 0x4107,
+        DW_TAG_GNU_formal_parameter_pack = 0x4108,
+        DW_TAG_GNU_call_site = 0x4109,
+        DW_TAG_GNU_call_site_parameter = 0x410a,
+    }
+}
+
+dwarf_constants! {
+    attribute_name: u64 {
+        DW_AT_sibling = 0x01,
+        DW_AT_location = 0x02,
+        DW_AT_name = 0x03,
+        DW_AT_ordering = 0x09,
+        DW_AT_byte_size = 0x0b,
+        DW_AT_bit_offset = 0x0c,
+        DW_AT_bit_size = 0x0d,
+        DW_AT_stmt_list = 0x10,
+        DW_AT_low_pc = 0x11,
+        DW_AT_high_pc = 0x12,
+        DW_AT_language = 0x13,
+        DW_AT_discr = 0x15,
+        DW_AT_discr_value = 0x16,
+        DW_AT_visibility = 0x17,
+        DW_AT_import = 0x18,
+        DW_AT_string_length = 0x19,
+        DW_AT_common_reference = 0x1a,
+        DW_AT_comp_dir = 0x1b,
+        DW_AT_const_value = 0x1c,
+        DW_AT_containing_type = 0x1d,
+        DW_AT_default_value = 0x1e,
+        DW_AT_inline = 0x20,
+        DW_AT_is_optional = 0x21,
+        DW_AT_lower_bound = 0x22,
+        DW_AT_producer = 0x25,
+        DW_AT_prototyped = 0x27,
+        DW_AT_return_addr = 0x2a,
+        DW_AT_start_scope = 0x2c,
+        DW_AT_bit_stride = 0x2e,
+        DW_AT_upper_bound = 0x2f,
+        DW_AT_abstract_origin = 0x31,
+        DW_AT_accessibility = 0x32,
+        DW_AT_address_class = 0x33,
+        DW_AT_artificial = 0x34,
+        DW_AT_base_types = 0x35,
+        DW_AT_calling_convention = 0x36,
+        DW_AT_count = 0x37,
+        DW_AT_data_member_location = 0x38,
+        DW_AT_decl_column = 0x39,
+        DW_AT_decl_file = 0x3a,
+        DW_AT_decl_line = 0x3b,
+        DW_AT_declaration = 0x3c,
+        DW_AT_discr_list = 0x3d,
+        DW_AT_encoding = 0x3e,
+        DW_AT_external = 0x3f,
+        DW_AT_frame_base = 0x40,
+        DW_AT_friend = 0x41,
+        DW_AT_identifier_case = 0x42,
+        DW_AT_macro_info = 0x43,
+        DW_AT_namelist_item = 0x44,
+        DW_AT_priority = 0x45,
+        DW_AT_segment = 0x46,
+        DW_AT_specification = 0x47,
+        DW_AT_static_link = 0x48,
+        DW_AT_type = 0x49,
+        DW_AT_use_location = 0x4a,
+        DW_AT_variable_parameter = 0x4b,
+        DW_AT_virtuality = 0x4c,
+        DW_AT_vtable_elem_location = 0x4d,
+        DW_AT_allocated = 0x4e,
+        DW_AT_associated = 0x4f,
+        DW_AT_data_location = 0x50,
+        DW_AT_byte_stride = 0x51,
+        DW_AT_entry_pc = 0x52,
+        DW_AT_use_UTF8 = 0x53,
+        DW_AT_extension = 0x54,
+        DW_AT_ranges = 0x55,
+        DW_AT_trampoline = 0x56,
+        DW_AT_call_column = 0x57,
+        DW_AT_call_file = 0x58,
+        DW_AT_call_line = 0x59,
+        DW_AT_description = 0x5a,
+        DW_AT_binary_scale = 0x5b,
+        DW_AT_decimal_scale = 0x5c,
+        DW_AT_small = 0x5d,
+        DW_AT_decimal_sign = 0x5e,
+        DW_AT_digit_count = 0x5f,
+        DW_AT_picture_string = 0x60,
+        DW_AT_mutable = 0x61,
+        DW_AT_threads_scaled = 0x62,
+        DW_AT_explicit = 0x63,
+        DW_AT_object_pointer = 0x64,
+        DW_AT_endianity = 0x65,
+        DW_AT_elemental = 0x66,
+        DW_AT_pure = 0x67,
+        DW_AT_recursive = 0x68,
+        DW_AT_signature = 0x69,
+        DW_AT_main_subprogram = 0x6a,
+        DW_AT_data_bit_offset = 0x6b,
+        DW_AT_const_expr = 0x6c,
+        DW_AT_enum_class = 0x6d,
+        DW_AT_linkage_name = 0x6e,
+        DW_AT_string_length_bit_size = 0x6f,
+        DW_AT_string_length_byte_size = 0x70,
+        DW_AT_rank = 0x71,
+        DW_AT_str_offsets_base = 0x72,
+        DW_AT_addr_base = 0x73,
+        DW_AT_rnglists_base = 0x74,
+        DW_AT_dwo_name = 0x76,
+        DW_AT_reference = 0x77,
+        DW_AT_rvalue_reference = 0x78,
+        DW_AT_macros = 0x79,
+        DW_AT_call_all_calls = 0x7a,
+        DW_AT_call_all_source_calls = 0x7b,
+        DW_AT_call_all_tail_calls = 0x7c,
+        DW_AT_call_return_pc = 0x7d,
+        DW_AT_call_value = 0x7e,
+        DW_AT_call_origin = 0x7f,
+        DW_AT_call_parameter = 0x80,
+        DW_AT_call_pc = 0x81,
+        DW_AT_call_tail_call = 0x82,
+        DW_AT_call_target = 0x83,
+        DW_AT_call_target_clobbered = 0x84,
+        DW_AT_call_data_location = 0x85,
+        DW_AT_call_data_value = 0x86,
+        DW_AT_noreturn = 0x87,
+        DW_AT_alignment = 0x88,
+        DW_AT_export_symbols = 0x89,
+        DW_AT_deleted = 0x8a,
+        DW_AT_defaulted = 0x8b,
+        DW_AT_loclists_base = 0x8c,
+        DW_AT_MIPS_fde = 0x2001,
+        DW_AT_MIPS_linkage_name = 0x2007,
+        DW_AT_sf_names = 0x2101,
+        DW_AT_src_info = 0x2102,
+        DW_AT_mac_info = 0x2103,
+        DW_AT_src_coords = 0x2104,
+        DW_AT_body_begin = 0x2105,
+        DW_AT_body_end = 0x2106,
+        DW_AT_GNU_vector = 0x2107,
+        DW_AT_GNU_guarded_by = 0x2108,
+        DW_AT_GNU_pt_guarded_by = 0x2109,
+        DW_AT_GNU_guarded = 0x210a,
+        DW_AT_GNU_pt_guarded = 0x210b,
+        DW_AT_GNU_locks_excluded = 0x210c,
+        DW_AT_GNU_exclusive_locks_required = 0x210d,
+        DW_AT_GNU_shared_locks_required = 0x210e,
+        DW_AT_GNU_odr_signature = 0x210f,
+        DW_AT_GNU_template_name = 0x2110,
+        DW_AT_GNU_call_site_value = 0x2111,
+        DW_AT_GNU_call_site_data_value = 0x2112,
+        DW_AT_GNU_call_site_target = 0x2113,
+        DW_AT_GNU_call_site_target_clobbered = 0x2114,
+        DW_AT_GNU_tail_call = 0x2115,
+        DW_AT_GNU_all_tail_call_sites = 0x2116,
+        DW_AT_GNU_all_call_sites = 0x2117,
+        DW_AT_GNU_all_source_call_sites = 0x2118,
+        DW_AT_GNU_macros = 0x2119,
+        DW_AT_GNU_deleted = 0x211a,
+        DW_AT_GNU_dwo_name = 0x2130,
+        DW_AT_GNU_dwo_id = 0x2131,
+        DW_AT_GNU_ranges_base = 0x2132,
+        DW_AT_GNU_addr_base = 0x2133,
+        DW_AT_GNU_pubnames = 0x2134,
+        DW_AT_GNU_pubtypes = 0x2135,
+        DW_AT_GNU_discriminator = 0x2136,
+        DW_AT_GNU_locviews = 0x2137,
+        DW_AT_GNU_entry_view = 0x2138,
+    }
+}
+
+dwarf_constants! {
+    form_name: u64 {
+        DW_FORM_addr = 0x01,
+        DW_FORM_block2 = 0x03,
+        DW_FORM_block4 = 0x04,
+        DW_FORM_data2 = 0x05,
+        DW_FORM_data4 = 0x06,
+        DW_FORM_data8 = 0x07,
+        DW_FORM_string = 0x08,
+        DW_FORM_block = 0x09,
+        DW_FORM_block1 = 0x0a,
+        DW_FORM_data1 = 0x0b,
+        DW_FORM_flag = 0x0c,
+        DW_FORM_sdata = 0x0d,
+        DW_FORM_strp = 0x0e,
+        DW_FORM_udata = 0x0f,
+        DW_FORM_ref_addr = 0x10,
+        DW_FORM_ref1 = 0x11,
+        DW_FORM_ref2 = 0x12,
+        DW_FORM_ref4 = 0x13,
+        DW_FORM_ref8 = 0x14,
+        DW_FORM_ref_udata = 0x15,
+        DW_FORM_indirect = 0x16,
+        DW_FORM_sec_offset = 0x17,
+        DW_FORM_exprloc = 0x18,
+        DW_FORM_flag_present = 0x19,
+        DW_FORM_strx = 0x1a,
+        DW_FORM_addrx = 0x1b,
+        DW_FORM_ref_sup4 = 0x1c,
+        DW_FORM_strp_sup = 0x1d,
+        DW_FORM_data16 = 0x1e,
+        DW_FORM_line_strp = 0x1f,
+        DW_FORM_ref_sig8 = 0x20,
+        DW_FORM_implicit_const = 0x21,
+        DW_FORM_loclistx = 0x22,
+        DW_FORM_rnglistx = 0x23,
+        DW_FORM_ref_sup8 = 0x24,
+        DW_FORM_strx1 = 0x25,
+        DW_FORM_strx2 = 0x26,
+        DW_FORM_strx3 = 0x27,
+        DW_FORM_strx4 = 0x28,
+        DW_FORM_addrx1 = 0x29,
+        DW_FORM_addrx2 = 0x2a,
+        DW_FORM_addrx3 = 0x2b,
+        DW_FORM_addrx4 = 0x2c,
+        DW_FORM_GNU_addr_index = 0x1f01,
+        DW_FORM_GNU_str_index = 0x1f02,
+        DW_FORM_GNU_ref_alt = 0x1f20,
+        DW_FORM_GNU_strp_alt = 0x1f21,
+    }
+}
+
+// DW_OP_lit*, DW_OP_reg* and DW_OP_breg* are ranges of 32 values, their
+// names are made from the number (see expr.rs)
+dwarf_constants! {
+    operation_name: u8 {
+        DW_OP_addr = 0x03,
+        DW_OP_deref = 0x06,
+        DW_OP_const1u = 0x08,
+        DW_OP_const1s = 0x09,
+        DW_OP_const2u = 0x0a,
+        DW_OP_const2s = 0x0b,
+        DW_OP_const4u = 0x0c,
+        DW_OP_const4s = 0x0d,
+        DW_OP_const8u = 0x0e,
+        DW_OP_const8s = 0x0f,
+        DW_OP_constu = 0x10,
+        DW_OP_consts = 0x11,
+        DW_OP_dup = 0x12,
+        DW_OP_drop = 0x13,
+        DW_OP_over = 0x14,
+        DW_OP_pick = 0x15,
+        DW_OP_swap = 0x16,
+        DW_OP_rot = 0x17,
+        DW_OP_xderef = 0x18,
+        DW_OP_abs = 0x19,
+        DW_OP_and = 0x1a,
+        DW_OP_div = 0x1b,
+        DW_OP_minus = 0x1c,
+        DW_OP_mod = 0x1d,
+        DW_OP_mul = 0x1e,
+        DW_OP_neg = 0x1f,
+        DW_OP_not = 0x20,
+        DW_OP_or = 0x21,
+        DW_OP_plus = 0x22,
+        DW_OP_plus_uconst = 0x23,
+        DW_OP_shl = 0x24,
+        DW_OP_shr = 0x25,
+        DW_OP_shra = 0x26,
+        DW_OP_xor = 0x27,
+        DW_OP_bra = 0x28,
+        DW_OP_eq = 0x29,
+        DW_OP_ge = 0x2a,
+        DW_OP_gt = 0x2b,
+        DW_OP_le = 0x2c,
+        DW_OP_lt = 0x2d,
+        DW_OP_ne = 0x2e,
+        DW_OP_skip = 0x2f,
+        DW_OP_lit0 = 0x30,
+        DW_OP_lit31 = 0x4f,
+        DW_OP_reg0 = 0x50,
+        DW_OP_reg31 = 0x6f,
+        DW_OP_breg0 = 0x70,
+        DW_OP_breg31 = 0x8f,
+        DW_OP_regx = 0x90,
+        DW_OP_fbreg = 0x91,
+        DW_OP_bregx = 0x92,
+        DW_OP_piece = 0x93,
+        DW_OP_deref_size = 0x94,
+        DW_OP_xderef_size = 0x95,
+        DW_OP_nop = 0x96,
+        DW_OP_push_object_address = 0x97,
+        DW_OP_call2 = 0x98,
+        DW_OP_call4 = 0x99,
+        DW_OP_call_ref = 0x9a,
+        DW_OP_form_tls_address = 0x9b,
+        DW_OP_call_frame_cfa = 0x9c,
+        DW_OP_bit_piece = 0x9d,
+        DW_OP_implicit_value = 0x9e,
+        DW_OP_stack_value = 0x9f,
+        DW_OP_implicit_pointer = 0xa0,
+        DW_OP_addrx = 0xa1,
+        DW_OP_constx = 0xa2,
+        DW_OP_entry_value = 0xa3,
+        DW_OP_const_type = 0xa4,
+        DW_OP_regval_type = 0xa5,
+        DW_OP_deref_type = 0xa6,
+        DW_OP_xderef_type = 0xa7,
+        DW_OP_convert = 0xa8,
+        DW_OP_reinterpret = 0xa9,
+        DW_OP_GNU_push_tls_address = 0xe0,
+        DW_OP_GNU_uninit = 0xf0,
+        DW_OP_GNU_encoded_addr = 0xf1,
+        DW_OP_GNU_implicit_pointer = 0xf2,
+        DW_OP_GNU_entry_value = 0xf3,
+        DW_OP_GNU_const_type = 0xf4,
+        DW_OP_GNU_regval_type = 0xf5,
+        DW_OP_GNU_deref_type = 0xf6,
+        DW_OP_GNU_convert = 0xf7,
+        DW_OP_GNU_reinterpret = 0xf9,
+        DW_OP_GNU_parameter_ref = 0xfa,
+        DW_OP_GNU_addr_index = 0xfb,
+        DW_OP_GNU_const_index = 0xfc,
+        DW_OP_GNU_variable_value = 0xfd,
+    }
+}
+
+// the DW_OP_* from here are the ones of the vendors
+pub const DW_OP_LO_USER: u8 = 0xe0;
+
+// the standard opcodes of the line number programs
+pub const DW_LNS_copy: u8 = 0x01;
+pub const DW_LNS_advance_pc: u8 = 0x02;
+pub const DW_LNS_advance_line: u8 = 0x03;
+pub const DW_LNS_set_file: u8 = 0x04;
+pub const DW_LNS_set_column: u8 = 0x05;
+pub const DW_LNS_negate_stmt: u8 = 0x06;
+pub const DW_LNS_set_basic_block: u8 = 0x07;
+pub const DW_LNS_const_add_pc: u8 = 0x08;
+pub const DW_LNS_fixed_advance_pc: u8 = 0x09;
+pub const DW_LNS_set_prologue_end: u8 = 0x0a;
+pub const DW_LNS_set_epilogue_begin: u8 = 0x0b;
+pub const DW_LNS_set_isa: u8 = 0x0c;
+
+// and the extended ones, that start with a 0
+pub const DW_LNE_end_sequence: u8 = 0x01;
+pub const DW_LNE_set_address: u8 = 0x02;
+pub const DW_LNE_define_file: u8 = 0x03;
+pub const DW_LNE_set_discriminator: u8 = 0x04;
+pub const DW_LNE_lo_user: u8 = 0x80;
+
+// what each column of the DWARF 5 directory and file name tables has
+pub const DW_LNCT_path: u64 = 0x1;
+pub const DW_LNCT_directory_index: u64 = 0x2;
+pub const DW_LNCT_timestamp: u64 = 0x3;
+pub const DW_LNCT_size: u64 = 0x4;
+pub const DW_LNCT_MD5: u64 = 0x5;
+
+// the entries of the DWARF 5 range lists
+pub const DW_RLE_end_of_list: u8 = 0x00;
+pub const DW_RLE_base_addressx: u8 = 0x01;
+pub const DW_RLE_startx_endx: u8 = 0x02;
+pub const DW_RLE_startx_length: u8 = 0x03;
+pub const DW_RLE_offset_pair: u8 = 0x04;
+pub const DW_RLE_base_address: u8 = 0x05;
+pub const DW_RLE_start_end: u8 = 0x06;
+pub const DW_RLE_start_length: u8 = 0x07;
+
+// and of the location lists, DW_LLE_GNU_view_pair is the one of gcc's
+// -gvariable-location-views=incompat5
+pub const DW_LLE_end_of_list: u8 = 0x00;
+pub const DW_LLE_base_addressx: u8 = 0x01;
+pub const DW_LLE_startx_endx: u8 = 0x02;
+pub const DW_LLE_startx_length: u8 = 0x03;
+pub const DW_LLE_offset_pair: u8 = 0x04;
+pub const DW_LLE_default_location: u8 = 0x05;
+pub const DW_LLE_base_address: u8 = 0x06;
+pub const DW_LLE_start_end: u8 = 0x07;
+pub const DW_LLE_start_length: u8 = 0x08;
+pub const DW_LLE_GNU_view_pair: u8 = 0x09;
+
+dwarf_constants! {
+    // the call frame instructions of .debug_frame and .eh_frame. The first
+    // three have their operand in the low 6 bits of the opcode
+    call_frame_name: u8 {
+        DW_CFA_advance_loc = 0x40,
+        DW_CFA_offset = 0x80,
+        DW_CFA_restore = 0xc0,
+        DW_CFA_nop = 0x00,
+        DW_CFA_set_loc = 0x01,
+        DW_CFA_advance_loc1 = 0x02,
+        DW_CFA_advance_loc2 = 0x03,
+        DW_CFA_advance_loc4 = 0x04,
+        DW_CFA_offset_extended = 0x05,
+        DW_CFA_restore_extended = 0x06,
+        DW_CFA_undefined = 0x07,
+        DW_CFA_same_value = 0x08,
+        DW_CFA_register = 0x09,
+        DW_CFA_remember_state = 0x0a,
+        DW_CFA_restore_state = 0x0b,
+        DW_CFA_def_cfa = 0x0c,
+        DW_CFA_def_cfa_register = 0x0d,
+        DW_CFA_def_cfa_offset = 0x0e,
+        DW_CFA_def_cfa_expression = 0x0f,
+        DW_CFA_expression = 0x10,
+        DW_CFA_offset_extended_sf = 0x11,
+        DW_CFA_def_cfa_sf = 0x12,
+        DW_CFA_def_cfa_offset_sf = 0x13,
+        DW_CFA_val_offset = 0x14,
+        DW_CFA_val_offset_sf = 0x15,
+        DW_CFA_val_expression = 0x16,
+        DW_CFA_MIPS_advance_loc8 = 0x1d,
+        DW_CFA_GNU_window_save = 0x2d,
+        DW_CFA_GNU_args_size = 0x2e,
+        DW_CFA_GNU_negative_offset_extended = 0x2f,
+    }
+}
+
+// how the pointers of .eh_frame and .eh_frame_hdr are encoded: the low 4
+// bits are the format, the next 3 what it is relative to
+pub const DW_EH_PE_absptr: u8 = 0x00;
+pub const DW_EH_PE_uleb128: u8 = 0x01;
+pub const DW_EH_PE_udata2: u8 = 0x02;
+pub const DW_EH_PE_udata4: u8 = 0x03;
+pub const DW_EH_PE_udata8: u8 = 0x04;
+pub const DW_EH_PE_sleb128: u8 = 0x09;
+pub const DW_EH_PE_sdata2: u8 = 0x0a;
+pub const DW_EH_PE_sdata4: u8 = 0x0b;
+pub const DW_EH_PE_sdata8: u8 = 0x0c;
+pub const DW_EH_PE_pcrel: u8 = 0x10;
+pub const DW_EH_PE_textrel: u8 = 0x20;
+pub const DW_EH_PE_datarel: u8 = 0x30;
+pub const DW_EH_PE_funcrel: u8 = 0x40;
+pub const DW_EH_PE_aligned: u8 = 0x50;
+pub const DW_EH_PE_indirect: u8 = 0x80;
+pub const DW_EH_PE_omit: u8 = 0xff;
+
+// the meaning of some of the constant attributes, shown after their value
+// like binutils does it
+pub fn language_description(language: u64) -> String {
+    let description = match language {
+        0x0001 => "ANSI C",
+        0x0002 => "non-ANSI C",
+        0x0003 => "Ada",
+        0x0004 => "C++",
+        0x0005 => "Cobol 74",
+        0x0006 => "Cobol 85",
+        0x0007 => "FORTRAN 77",
+        0x0008 => "Fortran 90",
+        0x0009 => "ANSI Pascal",
+        0x000a => "Modula 2",
+        0x000b => "Java",
+        0x000c => "ANSI C99",
+        0x000d => "ADA 95",
+        0x000e => "Fortran 95",
+        0x000f => "PLI",
+        0x0010 => "Objective C",
+        0x0011 => "Objective C++",
+        0x0012 => "Unified Parallel C",
+        0x0013 => "D",
+        0x0014 => "Python",
+        0x0015 => "OpenCL",
+        0x0016 => "Go",
+        0x0017 => "Modula 3",
+        0x0018 => "Haskell",
+        0x0019 => "C++03",
+        0x001a => "C++11",
+        0x001b => "OCaml",
+        0x001c => "Rust",
+        0x001d => "C11",
+        0x001e => "Swift",
+        0x001f => "Julia",
+        0x0020 => "Dylan",
+        0x0021 => "C++14",
+        0x0022 => "Fortran 03",
+        0x0023 => "Fortran 08",
+        0x0024 => "RenderScript",
+        0x8001 => "MIPS assembler",
+        0x8765 => "Unified Parallel C",
+        0x8000..=0xffff => return format!("implementation defined: {language:x}"),
+        _ => return format!("Unknown: {language:x}"),
+    };
+    description.to_string()
+}
+
+pub fn encoding_description(encoding: u64) -> &'static str {
+    match encoding {
+        0x00 => "void",
+        0x01 => "machine address",
+        0x02 => "boolean",
+        0x03 => "complex float",
+        0x04 => "float",
+        0x05 => "signed",
+        0x06 => "signed char",
+        0x07 => "unsigned",
+        0x08 => "unsigned char",
+        0x09 => "imaginary float",
+        0x0a => "packed_decimal",
+        0x0b => "numeric_string",
+        0x0c => "edited",
+        0x0d => "signed_fixed",
+        0x0e => "unsigned_fixed",
+        0x0f => "decimal float",
+        0x10 => "unicode string",
+        0x11 => "UCS",
+        0x12 => "ASCII",
+        0x80..=0xff => "user defined type",
+        _ => "unknown type",
+    }
+}
+
+pub fn inline_description(inline: u64) -> String {
+    let description = match inline {
+        0 => "not inlined",
+        1 => "inlined",
+        2 => "declared as inline but ignored",
+        3 => "declared as inline and inlined",
+        _ => return format!("Unknown inline attribute value: {inline:x}"),
+    };
+    description.to_string()
+}
+
+pub fn accessibility_description(accessibility: u64) -> &'static str {
+    match accessibility {
+        1 => "public",
+        2 => "protected",
+        3 => "private",
+        _ => "unknown accessibility",
+    }
+}
+
+pub fn visibility_description(visibility: u64) -> &'static str {
+    match visibility {
+        1 => "local",
+        2 => "exported",
+        3 => "qualified",
+        _ => "unknown visibility",
+    }
+}
+
+pub fn virtuality_description(virtuality: u64) -> &'static str {
+    match virtuality {
+        0 => "none",
+        1 => "virtual",
+        2 => "pure_virtual",
+        _ => "unknown virtuality",
+    }
+}
+
+pub fn calling_convention_description(convention: u64) -> &'static str {
+    match convention {
+        1 => "normal",
+        2 => "program",
+        3 => "nocall",
+        4 => "pass by reference",
+        5 => "pass by value",
+        0x40..=0xff => "user defined",
+        _ => "unknown convention",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{attribute_name, form_name, language_description, operation_name, tag_name};
+
+    #[test]
+    fn the_names_are_the_ones_of_dwarf2_h() {
+        assert_eq!(tag_name(0x11), Some("DW_TAG_compile_unit"));
+        assert_eq!(tag_name(0x4109), Some("DW_TAG_GNU_call_site"));
+        assert_eq!(attribute_name(0x2137), Some("DW_AT_GNU_locviews"));
+        assert_eq!(form_name(0x21), Some("DW_FORM_implicit_const"));
+        assert_eq!(operation_name(0x9c), Some("DW_OP_call_frame_cfa"));
+        assert_eq!(tag_name(0x1234), None);
+    }
+
+    #[test]
+    fn language_description_handles_the_user_range() {
+        assert_eq!(language_description(0x1d), "C11");
+        assert_eq!(language_description(0x8123), "implementation defined: 8123");
+        assert_eq!(language_description(0x99), "Unknown: 99");
+    }
+}
@@ -0,0 +1,214 @@
+use crate::elf::dwarf::constants::*;
+use crate::elf::dwarf::reader::{DwarfReader, Encoding};
+use crate::elf::error::ElfError;
+
+// the operand of an operation. The references to DIEs are kept as they are
+// in the expression: DW_OP_call_ref and the implicit pointers have offsets
+// in .debug_info, the others offsets in their unit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Operand {
+    None,
+    Address(u64),
+    Unsigned(u64),
+    Signed(i64),
+    // DW_OP_reg* and DW_OP_regx
+    Register(u64),
+    // DW_OP_breg* and DW_OP_bregx
+    RegisterOffset(u64, i64),
+    // DW_OP_bit_piece
+    BitPiece { size: u64, offset: u64 },
+    // DW_OP_implicit_value
+    Block(Vec<u8>),
+    Reference(u64),
+    ImplicitPointer { die: u64, offset: i64 },
+    // DW_OP_entry_value, the expression that is evaluated at the entry
+    Expression(Vec<Operation>),
+    TypedConstant { die: u64, value: Vec<u8> },
+    TypedRegister { register: u64, die: u64 },
+    TypedDeref { size: u8, die: u64 },
+    // an opcode that we dont know, so the size of its operands is unknown
+    // too, and it ends the expression
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Operation {
+    pub opcode: u8,
+    pub operand: Operand,
+}
+
+// the operations of a DWARF expression (DW_FORM_exprloc, the blocks of the
+// location attributes and the entries of the location lists)
+pub fn parse_expression(
+    data: &[u8],
+    is_little_endian: bool,
+    encoding: &Encoding,
+) -> Result<Vec<Operation>, ElfError> {
+    let mut reader = DwarfReader::new(data, is_little_endian, "DWARF expression");
+    let mut operations = Vec::new();
+
+    while !reader.is_empty() {
+        let operation = parse_operation(&mut reader, encoding)?;
+        let is_unknown = operation.operand == Operand::Unknown;
+        operations.push(operation);
+
+        if is_unknown {
+            break;
+        }
+    }
+
+    Ok(operations)
+}
+
+fn parse_operation(reader: &mut DwarfReader, encoding: &Encoding) -> Result<Operation, ElfError> {
+    let opcode = reader.u8("opcode")?;
+
+    let operand = match opcode {
+        DW_OP_addr => Operand::Address(reader.address(encoding.address_size)?),
+        DW_OP_const1u => Operand::Unsigned(reader.u8("operand")? as u64),
+        DW_OP_const1s => Operand::Signed(reader.i8("operand")? as i64),
+        DW_OP_const2u => Operand::Unsigned(reader.u16("operand")? as u64),
+        DW_OP_const2s | DW_OP_bra | DW_OP_skip => {
+            Operand::Signed(reader.u16("operand")? as i16 as i64)
+        }
+        DW_OP_const4u => Operand::Unsigned(reader.u32("operand")? as u64),
+        DW_OP_const4s => Operand::Signed(reader.u32("operand")? as i32 as i64),
+        DW_OP_const8u => Operand::Unsigned(reader.u64("operand")?),
+        DW_OP_const8s => Operand::Signed(reader.u64("operand")? as i64),
+        DW_OP_constu
+        | DW_OP_plus_uconst
+        | DW_OP_piece
+        | DW_OP_addrx
+        | DW_OP_constx
+        | DW_OP_GNU_addr_index
+        | DW_OP_GNU_const_index => Operand::Unsigned(reader.uleb128("operand")?),
+        DW_OP_consts | DW_OP_fbreg => Operand::Signed(reader.sleb128("operand")?),
+        DW_OP_pick | DW_OP_deref_size | DW_OP_xderef_size => {
+            Operand::Unsigned(reader.u8("operand")? as u64)
+        }
+        DW_OP_reg0..=DW_OP_reg31 => Operand::Register((opcode - DW_OP_reg0) as u64),
+        DW_OP_breg0..=DW_OP_breg31 => {
+            Operand::RegisterOffset((opcode - DW_OP_breg0) as u64, reader.sleb128("offset")?)
+        }
+        DW_OP_regx => Operand::Register(reader.uleb128("register")?),
+        DW_OP_bregx => {
+            let register = reader.uleb128("register")?;
+            Operand::RegisterOffset(register, reader.sleb128("offset")?)
+        }
+        DW_OP_bit_piece => Operand::BitPiece {
+            size: reader.uleb128("size")?,
+            offset: reader.uleb128("offset")?,
+        },
+        DW_OP_implicit_value => {
+            let size = reader.uleb128("size")?;
+            Operand::Block(reader.bytes(size, "value")?.to_vec())
+        }
+        DW_OP_call2 => Operand::Reference(reader.u16("operand")? as u64),
+        DW_OP_call4 | DW_OP_GNU_parameter_ref => Operand::Reference(reader.u32("operand")? as u64),
+        DW_OP_call_ref | DW_OP_GNU_variable_value => {
+            Operand::Reference(reader.sized(encoding.reference_size(), "operand")?)
+        }
+        DW_OP_implicit_pointer | DW_OP_GNU_implicit_pointer => Operand::ImplicitPointer {
+            die: reader.sized(encoding.reference_size(), "operand")?,
+            offset: reader.sleb128("offset")?,
+        },
+        DW_OP_entry_value | DW_OP_GNU_entry_value => {
+            let size = reader.uleb128("size")?;
+            let block = reader.bytes(size, "expression")?;
+            Operand::Expression(parse_expression(
+                block,
+                reader.is_little_endian(),
+                encoding,
+            )?)
+        }
+        DW_OP_const_type | DW_OP_GNU_const_type => {
+            let die = reader.uleb128("type")?;
+            let size = reader.u8("size")?;
+            Operand::TypedConstant {
+                die,
+                value: reader.bytes(size as u64, "value")?.to_vec(),
+            }
+        }
+        DW_OP_regval_type | DW_OP_GNU_regval_type => Operand::TypedRegister {
+            register: reader.uleb128("register")?,
+            die: reader.uleb128("type")?,
+        },
+        DW_OP_deref_type | DW_OP_xderef_type | DW_OP_GNU_deref_type => Operand::TypedDeref {
+            size: reader.u8("size")?,
+            die: reader.uleb128("type")?,
+        },
+        DW_OP_convert | DW_OP_reinterpret | DW_OP_GNU_convert | DW_OP_GNU_reinterpret => {
+            Operand::Reference(reader.uleb128("type")?)
+        }
+        DW_OP_GNU_encoded_addr => Operand::Unknown,
+        opcode
+            if operation_name(opcode).is_some() || (DW_OP_lit0..=DW_OP_lit31).contains(&opcode) =>
+        {
+            Operand::None
+        }
+        _ => Operand::Unknown,
+    };
+
+    Ok(Operation { opcode, operand })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Operand, Operation, parse_expression};
+    use crate::elf::dwarf::reader::{DwarfFormat, Encoding};
+
+    const ENCODING: Encoding = Encoding {
+        address_size: 8,
+        format: DwarfFormat::Dwarf32,
+        version: 5,
+    };
+
+    #[test]
+    fn parse_expression_reads_the_operands() {
+        // DW_OP_breg3 0, DW_OP_fbreg -80, DW_OP_plus_uconst 4, DW_OP_stack_value
+        let data = [0x73, 0, 0x91, 0xb0, 0x7f, 0x23, 4, 0x9f];
+
+        let operations = parse_expression(&data, true, &ENCODING).unwrap();
+
+        assert_eq!(
+            operations,
+            [
+                Operation {
+                    opcode: 0x73,
+                    operand: Operand::RegisterOffset(3, 0)
+                },
+                Operation {
+                    opcode: 0x91,
+                    operand: Operand::Signed(-80)
+                },
+                Operation {
+                    opcode: 0x23,
+                    operand: Operand::Unsigned(4)
+                },
+                Operation {
+                    opcode: 0x9f,
+                    operand: Operand::None
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_expression_nests_the_entry_values_and_stops_at_unknown_opcodes() {
+        // DW_OP_entry_value(DW_OP_reg5), then 0x01 (not an opcode) and garbage
+        let data = [0xa3, 1, 0x55, 0x01, 0xff, 0xff];
+
+        let operations = parse_expression(&data, true, &ENCODING).unwrap();
+
+        assert_eq!(operations.len(), 2);
+        assert_eq!(
+            operations[0].operand,
+            Operand::Expression(vec![Operation {
+                opcode: 0x55,
+                operand: Operand::Register(5)
+            }])
+        );
+        assert_eq!(operations[1].operand, Operand::Unknown);
+        assert!(parse_expression(&[0x03, 0, 0], true, &ENCODING).is_err());
+    }
+}
@@ -0,0 +1,1043 @@
+use std::collections::BTreeMap;
+
+use crate::elf::dwarf::constants::*;
+use crate::elf::dwarf::reader::{DwarfFormat, DwarfReader};
+use crate::elf::error::ElfError;
+
+// what the relative DW_EH_PE_* encodings add to the value. A pcrel one is
+// relative to the field itself, that is at its offset in a section loaded
+// at section
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PointerBases {
+    pub section: u64,
+    pub text: u64,
+    pub data: u64,
+    pub function: u64,
+}
+
+// a pointer of .eh_frame or .eh_frame_hdr, read with its encoding. An
+// indirect one is the address of the pointer, that only the process has
+pub fn read_encoded_pointer(
+    reader: &mut DwarfReader,
+    encoding: u8,
+    address_size: u8,
+    bases: &PointerBases,
+) -> Result<u64, ElfError> {
+    let offset = reader.offset();
+    let name = reader.section();
+    let invalid = || {
+        ElfError::invalid_value(
+            offset,
+            name,
+            "pointer encoding",
+            "a DW_EH_PE_* encoding",
+            encoding,
+        )
+    };
+
+    let base = match encoding & 0x70 {
+        DW_EH_PE_absptr => 0,
+        DW_EH_PE_pcrel => bases.section.wrapping_add(offset),
+        DW_EH_PE_textrel => bases.text,
+        DW_EH_PE_datarel => bases.data,
+        DW_EH_PE_funcrel => bases.function,
+        DW_EH_PE_aligned => {
+            let excess = offset % address_size as u64;
+            if excess != 0 {
+                reader.bytes(address_size as u64 - excess, "padding")?;
+            }
+            0
+        }
+        _ => return Err(invalid()),
+    };
+
+    let value = match encoding & 0x0f {
+        DW_EH_PE_absptr => reader.address(address_size)?,
+        DW_EH_PE_uleb128 => reader.uleb128("pointer")?,
+        DW_EH_PE_udata2 => reader.u16("pointer")? as u64,
+        DW_EH_PE_udata4 => reader.u32("pointer")? as u64,
+        DW_EH_PE_udata8 => reader.u64("pointer")?,
+        DW_EH_PE_sleb128 => reader.sleb128("pointer")? as u64,
+        DW_EH_PE_sdata2 => reader.u16("pointer")? as i16 as u64,
+        DW_EH_PE_sdata4 => reader.u32("pointer")? as i32 as u64,
+        DW_EH_PE_sdata8 => reader.u64("pointer")?,
+        _ => return Err(invalid()),
+    };
+
+    let mask = match address_size {
+        8.. => u64::MAX,
+        size => (1 << (8 * size as u32)) - 1,
+    };
+    Ok(base.wrapping_add(value) & mask)
+}
+
+// what the entries of a section are read with: .eh_frame has the CIE
+// pointers relative to the FDEs and the encoded pointers of the
+// augmentations, .debug_frame plain offsets and addresses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameSection {
+    pub is_eh_frame: bool,
+    pub address: u64,
+    // the one of the class, the CIEs of version 4 have their own
+    pub address_size: u8,
+}
+
+impl FrameSection {
+    fn is_cie_id(&self, id: u64, format: DwarfFormat) -> bool {
+        match (self.is_eh_frame, format) {
+            (true, _) => id == 0,
+            (false, DwarfFormat::Dwarf32) => id == 0xffff_ffff,
+            (false, DwarfFormat::Dwarf64) => id == u64::MAX,
+        }
+    }
+
+    fn bases(&self) -> PointerBases {
+        PointerBases {
+            section: self.address,
+            ..PointerBases::default()
+        }
+    }
+}
+
+// a Common Information Entry, what the FDEs that point at it share
+#[derive(Debug, Clone)]
+pub struct Cie<'data> {
+    pub offset: u64,
+    pub length: u64,
+    pub format: DwarfFormat,
+    pub id: u64,
+    pub version: u8,
+    pub augmentation: &'data [u8],
+    pub address_size: u8,
+    pub segment_selector_size: u8,
+    pub code_alignment_factor: u64,
+    pub data_alignment_factor: i64,
+    pub return_address_register: u64,
+    // the bytes after the 'z' of the augmentation, that the letters read
+    pub augmentation_data: &'data [u8],
+    // how the addresses of the FDEs (the 'R') and their LSDA pointers (the
+    // 'L') are encoded, with the personality routine of the 'P'
+    pub fde_encoding: u8,
+    pub lsda_encoding: u8,
+    pub personality: Option<u64>,
+    // the 'S', the frames of the signal handlers
+    pub is_signal_frame: bool,
+    // the initial instructions, that every FDE runs first
+    pub instructions: DwarfReader<'data>,
+    pub end: u64,
+}
+
+// a Frame Description Entry, the instructions of the addresses from
+// pc_begin to pc_begin + pc_range
+#[derive(Debug, Clone)]
+pub struct Fde<'data> {
+    pub offset: u64,
+    pub length: u64,
+    pub format: DwarfFormat,
+    // as it is in the entry, relative to itself in .eh_frame
+    pub cie_pointer: u64,
+    pub pc_begin: u64,
+    pub pc_range: u64,
+    pub augmentation_data: &'data [u8],
+    pub lsda: Option<u64>,
+    pub instructions: DwarfReader<'data>,
+    pub end: u64,
+    pub cie: Cie<'data>,
+}
+
+#[derive(Debug, Clone)]
+pub enum FrameEntry<'data> {
+    Cie(Cie<'data>),
+    Fde(Fde<'data>),
+    // the zero length that ends .eh_frame, at its offset
+    Terminator(u64),
+}
+
+// the entry at the current offset of the reader, that is left at the next
+// one. The reader has to be the one of the whole section, the CIE of an FDE
+// is read where it points at. Nothing after a length that cant be read can
+// be found, then the reader is left at the end
+pub fn parse_frame_entry<'data>(
+    reader: &mut DwarfReader<'data>,
+    section: &FrameSection,
+) -> Result<FrameEntry<'data>, ElfError> {
+    let whole = reader.clone();
+    let offset = reader.offset();
+    let (length, format) = reader
+        .initial_length()
+        .inspect_err(|_| reader.seek(u64::MAX))?;
+    if length == 0 {
+        return Ok(FrameEntry::Terminator(offset));
+    }
+    let mut entry = reader.split(length);
+    let end = reader.offset();
+
+    let id_offset = entry.offset();
+    let id = entry.offset_of(format, "CIE_id")?;
+    if section.is_cie_id(id, format) {
+        let cie = parse_cie_body(entry, offset, length, format, id, end, section)?;
+        return Ok(FrameEntry::Cie(cie));
+    }
+
+    let name = reader.section();
+    let not_a_cie =
+        || ElfError::invalid_value(id_offset, name, "CIE_pointer", "the offset of a CIE", id);
+    let cie_offset = if section.is_eh_frame {
+        id_offset.checked_sub(id).ok_or_else(not_a_cie)?
+    } else {
+        id
+    };
+    let mut cie_reader = whole;
+    cie_reader.seek(cie_offset);
+    let cie = parse_cie(&mut cie_reader, section)?.ok_or_else(not_a_cie)?;
+
+    if cie.segment_selector_size != 0 {
+        entry.sized(cie.segment_selector_size.min(8), "segment")?;
+    }
+    let bases = section.bases();
+    let (pc_begin, pc_range) = if section.is_eh_frame {
+        let pc_begin =
+            read_encoded_pointer(&mut entry, cie.fde_encoding, cie.address_size, &bases)?;
+        // a size, with the format of the encoding but relative to nothing
+        let pc_range = read_encoded_pointer(
+            &mut entry,
+            cie.fde_encoding & 0x0f,
+            cie.address_size,
+            &bases,
+        )?;
+        (pc_begin, pc_range)
+    } else {
+        (
+            entry.address(cie.address_size)?,
+            entry.address(cie.address_size)?,
+        )
+    };
+
+    let mut augmentation_data: &[u8] = &[];
+    let mut lsda = None;
+    if cie.augmentation.starts_with(b"z") {
+        let augmentation_length = entry.uleb128("augmentation_length")?;
+        augmentation_data = entry
+            .clone()
+            .bytes(augmentation_length, "augmentation_data")?;
+        let mut data = entry.split(augmentation_length);
+        if cie.lsda_encoding != DW_EH_PE_omit && !data.is_empty() {
+            let bases = PointerBases {
+                function: pc_begin,
+                ..bases
+            };
+            lsda = Some(read_encoded_pointer(
+                &mut data,
+                cie.lsda_encoding,
+                cie.address_size,
+                &bases,
+            )?);
+        }
+    }
+
+    Ok(FrameEntry::Fde(Fde {
+        offset,
+        length,
+        format,
+        cie_pointer: id,
+        pc_begin,
+        pc_range,
+        augmentation_data,
+        lsda,
+        instructions: entry,
+        end,
+        cie,
+    }))
+}
+
+// the CIE at the offset of the reader, none when the entry there is an FDE
+fn parse_cie<'data>(
+    reader: &mut DwarfReader<'data>,
+    section: &FrameSection,
+) -> Result<Option<Cie<'data>>, ElfError> {
+    let offset = reader.offset();
+    let (length, format) = reader.initial_length()?;
+    let mut entry = reader.split(length);
+    let end = reader.offset();
+
+    let id = entry.offset_of(format, "CIE_id")?;
+    if !section.is_cie_id(id, format) {
+        return Ok(None);
+    }
+    parse_cie_body(entry, offset, length, format, id, end, section).map(Some)
+}
+
+fn parse_cie_body<'data>(
+    mut entry: DwarfReader<'data>,
+    offset: u64,
+    length: u64,
+    format: DwarfFormat,
+    id: u64,
+    end: u64,
+    section: &FrameSection,
+) -> Result<Cie<'data>, ElfError> {
+    let version_offset = entry.offset();
+    let version = entry.u8("version")?;
+    if !matches!(version, 1 | 3 | 4) {
+        return Err(ElfError::invalid_value(
+            version_offset,
+            entry.section(),
+            "version",
+            "1, 3 or 4",
+            version,
+        ));
+    }
+
+    let augmentation_offset = entry.offset();
+    let augmentation = entry.cstr("augmentation")?;
+    // the eh_ptr of the old gcc augmentation, the address of the exception
+    // table
+    if augmentation == b"eh" {
+        entry.address(section.address_size)?;
+    }
+
+    let (address_size, segment_selector_size) = if version >= 4 {
+        let address_size_offset = entry.offset();
+        let address_size = entry.u8("address_size")?;
+        if !matches!(address_size, 1..=8) {
+            return Err(ElfError::invalid_value(
+                address_size_offset,
+                entry.section(),
+                "address_size",
+                "an address size from 1 to 8",
+                address_size,
+            ));
+        }
+        (address_size, entry.u8("segment_selector_size")?)
+    } else {
+        (section.address_size, 0)
+    };
+
+    let code_alignment_factor = entry.uleb128("code_alignment_factor")?;
+    let data_alignment_factor = entry.sleb128("data_alignment_factor")?;
+    let return_address_register = if version == 1 {
+        entry.u8("return_address_register")? as u64
+    } else {
+        entry.uleb128("return_address_register")?
+    };
+
+    let mut cie = Cie {
+        offset,
+        length,
+        format,
+        id,
+        version,
+        augmentation,
+        address_size,
+        segment_selector_size,
+        code_alignment_factor,
+        data_alignment_factor,
+        return_address_register,
+        augmentation_data: &[],
+        fde_encoding: DW_EH_PE_absptr,
+        lsda_encoding: DW_EH_PE_omit,
+        personality: None,
+        is_signal_frame: false,
+        instructions: entry.clone(),
+        end,
+    };
+
+    if let Some(letters) = augmentation.strip_prefix(b"z") {
+        let augmentation_length = entry.uleb128("augmentation_length")?;
+        cie.augmentation_data = entry
+            .clone()
+            .bytes(augmentation_length, "augmentation_data")?;
+        let mut data = entry.split(augmentation_length);
+        for &letter in letters {
+            match letter {
+                b'L' => cie.lsda_encoding = data.u8("lsda_encoding")?,
+                b'P' => {
+                    let encoding = data.u8("personality_encoding")?;
+                    cie.personality = Some(read_encoded_pointer(
+                        &mut data,
+                        encoding,
+                        address_size,
+                        &section.bases(),
+                    )?);
+                }
+                b'R' => cie.fde_encoding = data.u8("fde_encoding")?,
+                b'S' => cie.is_signal_frame = true,
+                // the branch target identification and the memory tagging
+                // of aarch64, that have no data
+                b'B' | b'G' => {}
+                // the length of the data is enough to skip the rest
+                _ => break,
+            }
+        }
+    } else if !augmentation.is_empty() && augmentation != b"eh" {
+        return Err(ElfError::invalid_value(
+            augmentation_offset,
+            entry.section(),
+            "augmentation",
+            "an augmentation that starts with z",
+            augmentation[0],
+        ));
+    }
+
+    cie.instructions = entry;
+    Ok(cie)
+}
+
+// a call frame instruction, with the opcode of the ones that have several
+// (for the names of the dumps). The locations and the offsets are already
+// multiplied by the alignment factors of the CIE
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CallFrameInstruction<'data> {
+    AdvanceLoc {
+        opcode: u8,
+        delta: u64,
+    },
+    SetLoc(u64),
+    DefCfa {
+        opcode: u8,
+        register: u64,
+        offset: i64,
+    },
+    DefCfaRegister(u64),
+    DefCfaOffset {
+        opcode: u8,
+        offset: i64,
+    },
+    DefCfaExpression(&'data [u8]),
+    Undefined(u64),
+    SameValue(u64),
+    Offset {
+        opcode: u8,
+        register: u64,
+        offset: i64,
+    },
+    ValOffset {
+        opcode: u8,
+        register: u64,
+        offset: i64,
+    },
+    Register {
+        register: u64,
+        target: u64,
+    },
+    Expression {
+        register: u64,
+        expression: &'data [u8],
+    },
+    ValExpression {
+        register: u64,
+        expression: &'data [u8],
+    },
+    Restore {
+        opcode: u8,
+        register: u64,
+    },
+    RememberState,
+    RestoreState,
+    ArgsSize(u64),
+    WindowSave,
+    Nop,
+}
+
+impl CallFrameInstruction<'_> {
+    // the register whose rule it sets, that makes it a column of the table
+    pub fn register(&self) -> Option<u64> {
+        match *self {
+            CallFrameInstruction::Undefined(register)
+            | CallFrameInstruction::SameValue(register)
+            | CallFrameInstruction::Offset { register, .. }
+            | CallFrameInstruction::ValOffset { register, .. }
+            | CallFrameInstruction::Register { register, .. }
+            | CallFrameInstruction::Expression { register, .. }
+            | CallFrameInstruction::ValExpression { register, .. }
+            | CallFrameInstruction::Restore { register, .. } => Some(register),
+            _ => None,
+        }
+    }
+}
+
+// the instruction at the current offset of the reader, one of the
+// instructions of the CIE or of one of its FDEs
+pub fn parse_call_frame_instruction<'data>(
+    reader: &mut DwarfReader<'data>,
+    cie: &Cie,
+    section: &FrameSection,
+) -> Result<CallFrameInstruction<'data>, ElfError> {
+    let opcode_offset = reader.offset();
+    let opcode = reader.u8("opcode")?;
+    let code = |delta: u64| delta.wrapping_mul(cie.code_alignment_factor);
+    let data = |offset: i64| offset.wrapping_mul(cie.data_alignment_factor);
+
+    let instruction = match opcode & 0xc0 {
+        DW_CFA_advance_loc => CallFrameInstruction::AdvanceLoc {
+            opcode: DW_CFA_advance_loc,
+            delta: code((opcode & 0x3f) as u64),
+        },
+        DW_CFA_offset => CallFrameInstruction::Offset {
+            opcode: DW_CFA_offset,
+            register: (opcode & 0x3f) as u64,
+            offset: data(reader.uleb128("offset")? as i64),
+        },
+        DW_CFA_restore => CallFrameInstruction::Restore {
+            opcode: DW_CFA_restore,
+            register: (opcode & 0x3f) as u64,
+        },
+        _ => match opcode {
+            DW_CFA_nop => CallFrameInstruction::Nop,
+            DW_CFA_set_loc if section.is_eh_frame => CallFrameInstruction::SetLoc(
+                read_encoded_pointer(reader, cie.fde_encoding, cie.address_size, &section.bases())?,
+            ),
+            DW_CFA_set_loc => CallFrameInstruction::SetLoc(reader.address(cie.address_size)?),
+            DW_CFA_advance_loc1 => CallFrameInstruction::AdvanceLoc {
+                opcode,
+                delta: code(reader.u8("delta")? as u64),
+            },
+            DW_CFA_advance_loc2 => CallFrameInstruction::AdvanceLoc {
+                opcode,
+                delta: code(reader.u16("delta")? as u64),
+            },
+            DW_CFA_advance_loc4 => CallFrameInstruction::AdvanceLoc {
+                opcode,
+                delta: code(reader.u32("delta")? as u64),
+            },
+            DW_CFA_MIPS_advance_loc8 => CallFrameInstruction::AdvanceLoc {
+                opcode,
+                delta: code(reader.u64("delta")?),
+            },
+            DW_CFA_offset_extended | DW_CFA_val_offset => {
+                let register = reader.uleb128("register")?;
+                let offset = data(reader.uleb128("offset")? as i64);
+                if opcode == DW_CFA_offset_extended {
+                    CallFrameInstruction::Offset {
+                        opcode,
+                        register,
+                        offset,
+                    }
+                } else {
+                    CallFrameInstruction::ValOffset {
+                        opcode,
+                        register,
+                        offset,
+                    }
+                }
+            }
+            DW_CFA_offset_extended_sf | DW_CFA_val_offset_sf => {
+                let register = reader.uleb128("register")?;
+                let offset = data(reader.sleb128("offset")?);
+                if opcode == DW_CFA_offset_extended_sf {
+                    CallFrameInstruction::Offset {
+                        opcode,
+                        register,
+                        offset,
+                    }
+                } else {
+                    CallFrameInstruction::ValOffset {
+                        opcode,
+                        register,
+                        offset,
+                    }
+                }
+            }
+            DW_CFA_GNU_negative_offset_extended => CallFrameInstruction::Offset {
+                opcode,
+                register: reader.uleb128("register")?,
+                offset: data((reader.uleb128("offset")? as i64).wrapping_neg()),
+            },
+            DW_CFA_restore_extended => CallFrameInstruction::Restore {
+                opcode,
+                register: reader.uleb128("register")?,
+            },
+            DW_CFA_undefined => CallFrameInstruction::Undefined(reader.uleb128("register")?),
+            DW_CFA_same_value => CallFrameInstruction::SameValue(reader.uleb128("register")?),
+            DW_CFA_register => CallFrameInstruction::Register {
+                register: reader.uleb128("register")?,
+                target: reader.uleb128("register")?,
+            },
+            DW_CFA_remember_state => CallFrameInstruction::RememberState,
+            DW_CFA_restore_state => CallFrameInstruction::RestoreState,
+            DW_CFA_def_cfa => CallFrameInstruction::DefCfa {
+                opcode,
+                register: reader.uleb128("register")?,
+                offset: reader.uleb128("offset")? as i64,
+            },
+            DW_CFA_def_cfa_sf => CallFrameInstruction::DefCfa {
+                opcode,
+                register: reader.uleb128("register")?,
+                offset: data(reader.sleb128("offset")?),
+            },
+            DW_CFA_def_cfa_register => {
+                CallFrameInstruction::DefCfaRegister(reader.uleb128("register")?)
+            }
+            DW_CFA_def_cfa_offset => CallFrameInstruction::DefCfaOffset {
+                opcode,
+                offset: reader.uleb128("offset")? as i64,
+            },
+            DW_CFA_def_cfa_offset_sf => CallFrameInstruction::DefCfaOffset {
+                opcode,
+                offset: data(reader.sleb128("offset")?),
+            },
+            DW_CFA_def_cfa_expression => {
+                let length = reader.uleb128("length")?;
+                CallFrameInstruction::DefCfaExpression(reader.bytes(length, "expression")?)
+            }
+            DW_CFA_expression | DW_CFA_val_expression => {
+                let register = reader.uleb128("register")?;
+                let length = reader.uleb128("length")?;
+                let expression = reader.bytes(length, "expression")?;
+                if opcode == DW_CFA_expression {
+                    CallFrameInstruction::Expression {
+                        register,
+                        expression,
+                    }
+                } else {
+                    CallFrameInstruction::ValExpression {
+                        register,
+                        expression,
+                    }
+                }
+            }
+            DW_CFA_GNU_args_size => CallFrameInstruction::ArgsSize(reader.uleb128("size")?),
+            DW_CFA_GNU_window_save => CallFrameInstruction::WindowSave,
+            _ => {
+                return Err(ElfError::invalid_value(
+                    opcode_offset,
+                    reader.section(),
+                    "opcode",
+                    "a DW_CFA_* instruction",
+                    opcode,
+                ));
+            }
+        },
+    };
+
+    Ok(instruction)
+}
+
+// all the instructions of a CIE or an FDE
+pub fn parse_call_frame_instructions<'data>(
+    mut reader: DwarfReader<'data>,
+    cie: &Cie,
+    section: &FrameSection,
+) -> Result<Vec<CallFrameInstruction<'data>>, ElfError> {
+    let mut instructions = Vec::new();
+    while !reader.is_empty() {
+        instructions.push(parse_call_frame_instruction(&mut reader, cie, section)?);
+    }
+    Ok(instructions)
+}
+
+// how the caller's value of a register is found, at the rows where it has
+// the rule
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegisterRule<'data> {
+    Undefined,
+    SameValue,
+    // saved at the CFA plus the offset
+    Offset(i64),
+    // the CFA plus the offset is the value
+    ValOffset(i64),
+    Register(u64),
+    Expression(&'data [u8]),
+    ValExpression(&'data [u8]),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CfaRule<'data> {
+    RegisterOffset { register: u64, offset: i64 },
+    Expression(&'data [u8]),
+}
+
+// a row of the unwind table, the rules from address to the next row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnwindRow<'data> {
+    pub address: u64,
+    pub cfa: CfaRule<'data>,
+    pub registers: BTreeMap<u64, RegisterRule<'data>>,
+}
+
+impl<'data> Cie<'data> {
+    // the rows of the initial instructions, from address 0
+    pub fn unwind_table(&self, section: &FrameSection) -> Result<Vec<UnwindRow<'data>>, ElfError> {
+        let instructions = parse_call_frame_instructions(self.instructions.clone(), self, section)?;
+        let mut row = UnwindRow {
+            address: 0,
+            cfa: CfaRule::RegisterOffset {
+                register: 0,
+                offset: 0,
+            },
+            registers: BTreeMap::new(),
+        };
+        Ok(execute(&instructions, &mut row, &BTreeMap::new()))
+    }
+}
+
+impl<'data> Fde<'data> {
+    // the rows from pc_begin, that start with the rules of the initial
+    // instructions of the CIE
+    pub fn unwind_table(&self, section: &FrameSection) -> Result<Vec<UnwindRow<'data>>, ElfError> {
+        let cie_instructions =
+            parse_call_frame_instructions(self.cie.instructions.clone(), &self.cie, section)?;
+        let instructions =
+            parse_call_frame_instructions(self.instructions.clone(), &self.cie, section)?;
+
+        let mut row = UnwindRow {
+            address: 0,
+            cfa: CfaRule::RegisterOffset {
+                register: 0,
+                offset: 0,
+            },
+            registers: BTreeMap::new(),
+        };
+        execute(&cie_instructions, &mut row, &BTreeMap::new());
+        let initial = row.registers.clone();
+        row.address = self.pc_begin;
+        Ok(execute(&instructions, &mut row, &initial))
+    }
+
+    // the row of the table that has the rules at pc, if the FDE covers it
+    pub fn unwind_row(
+        &self,
+        pc: u64,
+        section: &FrameSection,
+    ) -> Result<Option<UnwindRow<'data>>, ElfError> {
+        if pc.wrapping_sub(self.pc_begin) >= self.pc_range {
+            return Ok(None);
+        }
+        let rows = self.unwind_table(section)?;
+        Ok(rows.into_iter().rev().find(|row| row.address <= pc))
+    }
+}
+
+// runs the instructions from row, initial has the rules that the restores
+// go back to. Like binutils, every register that they name is in every row
+// (undefined until it has a rule), and the rows are the ones before each
+// change of the location and the one at the end, none when the
+// instructions are all nops
+fn execute<'data>(
+    instructions: &[CallFrameInstruction<'data>],
+    row: &mut UnwindRow<'data>,
+    initial: &BTreeMap<u64, RegisterRule<'data>>,
+) -> Vec<UnwindRow<'data>> {
+    for register in instructions.iter().filter_map(|i| i.register()) {
+        row.registers
+            .entry(register)
+            .or_insert(RegisterRule::Undefined);
+    }
+
+    let mut rows = Vec::new();
+    let mut stack = Vec::new();
+    for instruction in instructions {
+        match *instruction {
+            CallFrameInstruction::AdvanceLoc { delta, .. } => {
+                rows.push(row.clone());
+                row.address = row.address.wrapping_add(delta);
+            }
+            CallFrameInstruction::SetLoc(address) => {
+                rows.push(row.clone());
+                row.address = address;
+            }
+            CallFrameInstruction::DefCfa {
+                register, offset, ..
+            } => row.cfa = CfaRule::RegisterOffset { register, offset },
+            CallFrameInstruction::DefCfaRegister(register) => {
+                let offset = match row.cfa {
+                    CfaRule::RegisterOffset { offset, .. } => offset,
+                    CfaRule::Expression(_) => 0,
+                };
+                row.cfa = CfaRule::RegisterOffset { register, offset };
+            }
+            CallFrameInstruction::DefCfaOffset { offset, .. } => {
+                if let CfaRule::RegisterOffset {
+                    offset: current, ..
+                } = &mut row.cfa
+                {
+                    *current = offset;
+                }
+            }
+            CallFrameInstruction::DefCfaExpression(expression) => {
+                row.cfa = CfaRule::Expression(expression)
+            }
+            CallFrameInstruction::Undefined(register) => {
+                row.registers.insert(register, RegisterRule::Undefined);
+            }
+            CallFrameInstruction::SameValue(register) => {
+                row.registers.insert(register, RegisterRule::SameValue);
+            }
+            CallFrameInstruction::Offset {
+                register, offset, ..
+            } => {
+                row.registers.insert(register, RegisterRule::Offset(offset));
+            }
+            CallFrameInstruction::ValOffset {
+                register, offset, ..
+            } => {
+                row.registers
+                    .insert(register, RegisterRule::ValOffset(offset));
+            }
+            CallFrameInstruction::Register { register, target } => {
+                row.registers
+                    .insert(register, RegisterRule::Register(target));
+            }
+            CallFrameInstruction::Expression {
+                register,
+                expression,
+            } => {
+                row.registers
+                    .insert(register, RegisterRule::Expression(expression));
+            }
+            CallFrameInstruction::ValExpression {
+                register,
+                expression,
+            } => {
+                row.registers
+                    .insert(register, RegisterRule::ValExpression(expression));
+            }
+            CallFrameInstruction::Restore { register, .. } => {
+                let rule = initial
+                    .get(&register)
+                    .cloned()
+                    .unwrap_or(RegisterRule::Undefined);
+                row.registers.insert(register, rule);
+            }
+            CallFrameInstruction::RememberState => {
+                stack.push((row.cfa.clone(), row.registers.clone()));
+            }
+            CallFrameInstruction::RestoreState => {
+                if let Some((cfa, registers)) = stack.pop() {
+                    row.cfa = cfa;
+                    row.registers = registers;
+                }
+            }
+            CallFrameInstruction::ArgsSize(_)
+            | CallFrameInstruction::WindowSave
+            | CallFrameInstruction::Nop => {}
+        }
+    }
+
+    if instructions
+        .iter()
+        .any(|instruction| *instruction != CallFrameInstruction::Nop)
+    {
+        rows.push(row.clone());
+    }
+    rows
+}
+
+// the .eh_frame_hdr of the PT_GNU_EH_FRAME segment: where .eh_frame is, and
+// the initial locations of its FDEs sorted for a binary search
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EhFrameHdr {
+    pub version: u8,
+    pub eh_frame_ptr_encoding: u8,
+    pub fde_count_encoding: u8,
+    pub table_encoding: u8,
+    pub eh_frame_ptr: u64,
+    // none when it is omitted, then so is the table
+    pub fde_count: Option<u64>,
+    // (initial location, address of the FDE)
+    pub table: Vec<(u64, u64)>,
+}
+
+impl EhFrameHdr {
+    // the address of the FDE that starts at or before pc the closest, the
+    // one that covers it if any does
+    pub fn lookup(&self, pc: u64) -> Option<u64> {
+        let index = self.table.partition_point(|&(location, _)| location <= pc);
+        index.checked_sub(1).map(|index| self.table[index].1)
+    }
+}
+
+// the header in the reader, of a section loaded at address. The datarel
+// pointers are relative to its start
+pub fn parse_eh_frame_hdr(
+    reader: &mut DwarfReader,
+    address: u64,
+    address_size: u8,
+) -> Result<EhFrameHdr, ElfError> {
+    let version_offset = reader.offset();
+    let version = reader.u8("version")?;
+    if version != 1 {
+        return Err(ElfError::invalid_value(
+            version_offset,
+            reader.section(),
+            "version",
+            "1",
+            version,
+        ));
+    }
+    let eh_frame_ptr_encoding = reader.u8("eh_frame_ptr_enc")?;
+    let fde_count_encoding = reader.u8("fde_count_enc")?;
+    let table_encoding = reader.u8("table_enc")?;
+
+    let bases = PointerBases {
+        section: address,
+        data: address,
+        ..PointerBases::default()
+    };
+    let eh_frame_ptr = read_encoded_pointer(reader, eh_frame_ptr_encoding, address_size, &bases)?;
+    let fde_count = match fde_count_encoding {
+        DW_EH_PE_omit => None,
+        encoding => Some(read_encoded_pointer(
+            reader,
+            encoding,
+            address_size,
+            &bases,
+        )?),
+    };
+
+    let mut table = Vec::new();
+    if let Some(count) = fde_count
+        && table_encoding != DW_EH_PE_omit
+    {
+        for _ in 0..count.min(reader.remaining() as u64) {
+            let location = read_encoded_pointer(reader, table_encoding, address_size, &bases)?;
+            let fde = read_encoded_pointer(reader, table_encoding, address_size, &bases)?;
+            table.push((location, fde));
+        }
+    }
+
+    Ok(EhFrameHdr {
+        version,
+        eh_frame_ptr_encoding,
+        fde_count_encoding,
+        table_encoding,
+        eh_frame_ptr,
+        fde_count,
+        table,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CfaRule, FrameEntry, FrameSection, PointerBases, RegisterRule, parse_eh_frame_hdr,
+        parse_frame_entry, read_encoded_pointer,
+    };
+    use crate::elf::dwarf::reader::DwarfReader;
+    use std::collections::BTreeMap;
+
+    // a "zR" CIE with the usual x86-64 rules and an FDE of 0x1000..0x1010
+    // (a pcrel sdata4 pointer) that pushes rbp, then the terminator
+    fn create_eh_frame() -> Vec<u8> {
+        let mut data = vec![
+            0x14, 0, 0, 0, 0, 0, 0, 0, 1, b'z', b'R', 0, 1, 0x78, 0x10, 1, 0x1b,
+        ];
+        data.extend([0x0c, 7, 8, 0x90, 1, 0, 0]);
+        data.extend([0x14, 0, 0, 0, 0x1c, 0, 0, 0]);
+        data.extend((0x1000u32 - 0x20).to_le_bytes());
+        data.extend(0x10u32.to_le_bytes());
+        data.extend([0, 0x41, 0x0e, 0x10, 0x86, 2, 0, 0, 0, 0, 0, 0]);
+        data
+    }
+
+    const EH_FRAME: FrameSection = FrameSection {
+        is_eh_frame: true,
+        address: 0,
+        address_size: 8,
+    };
+
+    #[test]
+    fn read_encoded_pointer_adds_the_base_and_masks_to_the_address_size() {
+        let data = 0xfffffff0u32.to_le_bytes();
+        let bases = PointerBases {
+            section: 0x2000,
+            ..PointerBases::default()
+        };
+
+        let mut reader = DwarfReader::new(&data, true, ".eh_frame");
+        assert_eq!(
+            read_encoded_pointer(&mut reader, 0x1b, 8, &bases).unwrap(),
+            0x1ff0
+        );
+        let mut reader = DwarfReader::new(&data, true, ".eh_frame");
+        assert_eq!(
+            read_encoded_pointer(&mut reader, 0x03, 4, &bases).unwrap(),
+            0xfffffff0
+        );
+        let mut reader = DwarfReader::new(&data, true, ".eh_frame");
+        assert!(read_encoded_pointer(&mut reader, 0x07, 8, &bases).is_err());
+    }
+
+    #[test]
+    fn parse_frame_entry_reads_the_cie_of_each_fde() {
+        let data = create_eh_frame();
+        let mut reader = DwarfReader::new(&data, true, ".eh_frame");
+
+        let Ok(FrameEntry::Cie(cie)) = parse_frame_entry(&mut reader, &EH_FRAME) else {
+            panic!("not a CIE");
+        };
+        assert_eq!(cie.augmentation, b"zR");
+        assert_eq!((cie.fde_encoding, cie.data_alignment_factor), (0x1b, -8));
+
+        let Ok(FrameEntry::Fde(fde)) = parse_frame_entry(&mut reader, &EH_FRAME) else {
+            panic!("not an FDE");
+        };
+        assert_eq!((fde.pc_begin, fde.pc_range), (0x1000, 0x10));
+        assert_eq!((fde.cie_pointer, fde.cie.offset), (0x1c, 0));
+
+        assert!(matches!(
+            parse_frame_entry(&mut reader, &EH_FRAME),
+            Ok(FrameEntry::Terminator(0x30))
+        ));
+    }
+
+    #[test]
+    fn unwind_table_starts_the_fde_with_the_rules_of_its_cie() {
+        let data = create_eh_frame();
+        let mut reader = DwarfReader::new(&data, true, ".eh_frame");
+        parse_frame_entry(&mut reader, &EH_FRAME).unwrap();
+        let Ok(FrameEntry::Fde(fde)) = parse_frame_entry(&mut reader, &EH_FRAME) else {
+            panic!("not an FDE");
+        };
+
+        let rows = fde.unwind_table(&EH_FRAME).unwrap();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(
+            (rows[0].address, &rows[0].cfa),
+            (
+                0x1000,
+                &CfaRule::RegisterOffset {
+                    register: 7,
+                    offset: 8
+                }
+            )
+        );
+        assert_eq!(
+            rows[0].registers,
+            BTreeMap::from([(6, RegisterRule::Undefined), (16, RegisterRule::Offset(-8))])
+        );
+        assert_eq!(rows[1].address, 0x1001);
+        assert_eq!(rows[1].registers[&6], RegisterRule::Offset(-16));
+
+        assert_eq!(
+            fde.unwind_row(0x1008, &EH_FRAME).unwrap(),
+            Some(rows[1].clone())
+        );
+        assert_eq!(fde.unwind_row(0x1010, &EH_FRAME).unwrap(), None);
+    }
+
+    #[test]
+    fn eh_frame_hdr_lookup_finds_the_fde_before_the_address() {
+        // at 0x3000, with .eh_frame at 0x2000 and two FDEs
+        let mut data = vec![1, 0x1b, 0x03, 0x3b];
+        data.extend((0x2000u32.wrapping_sub(0x3004)).to_le_bytes());
+        data.extend(2u32.to_le_bytes());
+        for value in [-0x2000i32, -0xfe8, -0x1000, -0xfd0] {
+            data.extend(value.to_le_bytes());
+        }
+
+        let header = parse_eh_frame_hdr(
+            &mut DwarfReader::new(&data, true, ".eh_frame_hdr"),
+            0x3000,
+            8,
+        )
+        .unwrap();
+
+        assert_eq!((header.eh_frame_ptr, header.fde_count), (0x2000, Some(2)));
+        assert_eq!(header.table, [(0x1000, 0x2018), (0x2000, 0x2030)]);
+        assert_eq!(header.lookup(0xfff), None);
+        assert_eq!(header.lookup(0x1fff), Some(0x2018));
+        assert_eq!(header.lookup(0x2000), Some(0x2030));
+    }
+}
@@ -0,0 +1,479 @@
+use crate::elf::dwarf::abbrev::AbbrevTable;
+use crate::elf::dwarf::constants::*;
+use crate::elf::dwarf::reader::{DwarfFormat, DwarfReader, Encoding};
+use crate::elf::error::ElfError;
+
+// the header of a unit of .debug_info (or .debug_info.dwo). The offsets are
+// the ones in the section, end is where the next unit starts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnitHeader {
+    pub offset: u64,
+    pub length: u64,
+    pub format: DwarfFormat,
+    pub version: u16,
+    // DWARF 5 only, the older units are all compile (or partial) units
+    pub unit_type: Option<u8>,
+    pub abbrev_offset: u64,
+    pub address_size: u8,
+    // the skeleton and split units, to match them
+    pub dwo_id: Option<u64>,
+    // the type units
+    pub type_signature: Option<u64>,
+    pub type_offset: Option<u64>,
+    pub end: u64,
+}
+
+impl UnitHeader {
+    pub fn encoding(&self) -> Encoding {
+        Encoding {
+            address_size: self.address_size,
+            format: self.format,
+            version: self.version,
+        }
+    }
+}
+
+// the value of an attribute, as it is in the DIE: the strings, addresses and
+// lists that are in other sections are left as offsets (or indexes), the
+// ones that are in other units or in the .dwo file cant always be resolved
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AttributeValue {
+    Address(u64),
+    // DW_FORM_addrx*, an index in .debug_addr
+    AddressIndex(u64),
+    // DW_FORM_block* and DW_FORM_exprloc
+    Block(Vec<u8>),
+    // DW_FORM_data1 to DW_FORM_data8, that can be a constant or (before
+    // DWARF 4) an offset in another section
+    Data { value: u64, size: u8 },
+    Data16([u8; 16]),
+    Sdata(i64),
+    Udata(u64),
+    ImplicitConst(i64),
+    Flag(u8),
+    FlagPresent,
+    String(Vec<u8>),
+    // offsets in .debug_str and .debug_line_str
+    Strp(u64),
+    LineStrp(u64),
+    // DW_FORM_strx*, an index in .debug_str_offsets
+    StringIndex(u64),
+    // offsets in the alternate (DW_FORM_GNU_*_alt) or supplementary files
+    AltStrp(u64),
+    AltRef(u64),
+    // the other DIEs of the unit, the offset is relative to the unit
+    UnitRef(u64),
+    // and of .debug_info
+    InfoRef(u64),
+    TypeSignature(u64),
+    SecOffset(u64),
+    LoclistIndex(u64),
+    RnglistIndex(u64),
+}
+
+impl AttributeValue {
+    // the value of the constant forms
+    pub fn constant(&self) -> Option<u64> {
+        match *self {
+            AttributeValue::Data { value, .. } | AttributeValue::Udata(value) => Some(value),
+            AttributeValue::Sdata(value) | AttributeValue::ImplicitConst(value) => {
+                Some(value as u64)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attribute {
+    // where its value starts, in the section
+    pub offset: u64,
+    pub name: u64,
+    // the form of the value, after DW_FORM_indirect
+    pub form: u64,
+    pub value: AttributeValue,
+}
+
+// a debugging information entry. The null entries that end the lists of
+// children are kept (with the code and the tag 0), the dump shows them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Die {
+    pub offset: u64,
+    pub depth: usize,
+    pub code: u64,
+    pub tag: u64,
+    pub attributes: Vec<Attribute>,
+}
+
+impl Die {
+    pub fn attribute(&self, name: u64) -> Option<&AttributeValue> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.name == name)
+            .map(|attribute| &attribute.value)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Unit {
+    pub header: UnitHeader,
+    pub dies: Vec<Die>,
+}
+
+impl Unit {
+    // the unit DIE, with the bases of the indexes and the name of the unit
+    pub fn root(&self) -> Option<&Die> {
+        self.dies.first().filter(|die| die.code != 0)
+    }
+}
+
+// the header of the unit at the current offset of the reader, that is left
+// at its first DIE
+pub fn parse_unit_header(reader: &mut DwarfReader) -> Result<UnitHeader, ElfError> {
+    let offset = reader.offset();
+    let (length, format) = reader.initial_length()?;
+    let end = reader.offset().saturating_add(length);
+
+    let version_offset = reader.offset();
+    let version = reader.u16("version")?;
+    if !(2..=5).contains(&version) {
+        return Err(ElfError::invalid_value(
+            version_offset,
+            reader.section(),
+            "version",
+            "a DWARF version from 2 to 5",
+            version,
+        ));
+    }
+
+    let mut header = UnitHeader {
+        offset,
+        length,
+        format,
+        version,
+        unit_type: None,
+        abbrev_offset: 0,
+        address_size: 0,
+        dwo_id: None,
+        type_signature: None,
+        type_offset: None,
+        end,
+    };
+
+    if version >= 5 {
+        let unit_type = reader.u8("unit_type")?;
+        header.unit_type = Some(unit_type);
+        header.address_size = reader.u8("address_size")?;
+        header.abbrev_offset = reader.offset_of(format, "debug_abbrev_offset")?;
+
+        match unit_type {
+            DW_UT_skeleton | DW_UT_split_compile => header.dwo_id = Some(reader.u64("dwo_id")?),
+            DW_UT_type | DW_UT_split_type => {
+                header.type_signature = Some(reader.u64("type_signature")?);
+                header.type_offset = Some(reader.offset_of(format, "type_offset")?);
+            }
+            _ => {}
+        }
+    } else {
+        header.abbrev_offset = reader.offset_of(format, "debug_abbrev_offset")?;
+        header.address_size = reader.u8("address_size")?;
+    }
+
+    if !matches!(header.address_size, 1..=8) {
+        return Err(ElfError::invalid_value(
+            offset,
+            reader.section(),
+            "address_size",
+            "an address size from 1 to 8",
+            header.address_size,
+        ));
+    }
+
+    Ok(header)
+}
+
+// the DIEs of the unit, reader is the one of the whole unit (see
+// DwarfReader::split) left after the header
+pub fn parse_dies(
+    reader: &mut DwarfReader,
+    header: &UnitHeader,
+    abbrevs: &AbbrevTable,
+) -> Result<Vec<Die>, ElfError> {
+    let mut dies = Vec::new();
+    let mut depth = 0;
+
+    while !reader.is_empty() {
+        dies.push(parse_die(reader, header, abbrevs, &mut depth)?);
+    }
+
+    Ok(dies)
+}
+
+// the DIE at the current offset of the reader, depth is the one of the DIE
+// and is updated for the next one (it goes down after the DIEs with children
+// and up after the null entries)
+pub fn parse_die(
+    reader: &mut DwarfReader,
+    header: &UnitHeader,
+    abbrevs: &AbbrevTable,
+    depth: &mut usize,
+) -> Result<Die, ElfError> {
+    let offset = reader.offset();
+    let code = reader.uleb128("abbrev_code")?;
+
+    if code == 0 {
+        let die = Die {
+            offset,
+            depth: *depth,
+            code,
+            tag: 0,
+            attributes: Vec::new(),
+        };
+        *depth = depth.saturating_sub(1);
+        return Ok(die);
+    }
+
+    let abbrev = abbrevs.get(code).ok_or_else(|| {
+        ElfError::invalid_value(
+            offset,
+            reader.section(),
+            "abbrev_code",
+            format!("a code of the table at {:#x}", abbrevs.offset),
+            code,
+        )
+    })?;
+
+    let encoding = header.encoding();
+    let mut attributes = Vec::with_capacity(abbrev.attributes.len());
+    for spec in &abbrev.attributes {
+        let offset = reader.offset();
+        let (form, value) = parse_value(reader, &encoding, spec.form, spec.implicit_const)?;
+        attributes.push(Attribute {
+            offset,
+            name: spec.name,
+            form,
+            value,
+        });
+    }
+
+    let die = Die {
+        offset,
+        depth: *depth,
+        code,
+        tag: abbrev.tag,
+        attributes,
+    };
+    if abbrev.has_children {
+        *depth += 1;
+    }
+    Ok(die)
+}
+
+// the attributes whose value is a location description, an expression or
+// (with a section offset) a location list
+pub fn is_location_attribute(name: u64) -> bool {
+    matches!(
+        name,
+        DW_AT_location
+            | DW_AT_string_length
+            | DW_AT_return_addr
+            | DW_AT_data_member_location
+            | DW_AT_frame_base
+            | DW_AT_segment
+            | DW_AT_static_link
+            | DW_AT_use_location
+            | DW_AT_vtable_elem_location
+            | DW_AT_call_value
+            | DW_AT_call_data_value
+            | DW_AT_call_target
+            | DW_AT_call_target_clobbered
+            | DW_AT_GNU_call_site_value
+            | DW_AT_GNU_call_site_target
+            | DW_AT_GNU_call_site_target_clobbered
+    )
+}
+
+// the value of a form, and the form itself (the one after the indirection
+// of DW_FORM_indirect)
+pub fn parse_value(
+    reader: &mut DwarfReader,
+    encoding: &Encoding,
+    form: u64,
+    implicit_const: Option<i64>,
+) -> Result<(u64, AttributeValue), ElfError> {
+    let format = encoding.format;
+
+    let value = match form {
+        DW_FORM_addr => AttributeValue::Address(reader.address(encoding.address_size)?),
+        DW_FORM_addrx | DW_FORM_GNU_addr_index => {
+            AttributeValue::AddressIndex(reader.uleb128("value")?)
+        }
+        DW_FORM_addrx1 => AttributeValue::AddressIndex(reader.sized(1, "value")?),
+        DW_FORM_addrx2 => AttributeValue::AddressIndex(reader.sized(2, "value")?),
+        DW_FORM_addrx3 => AttributeValue::AddressIndex(reader.sized(3, "value")?),
+        DW_FORM_addrx4 => AttributeValue::AddressIndex(reader.sized(4, "value")?),
+        DW_FORM_block1 => {
+            let size = reader.u8("size")? as u64;
+            AttributeValue::Block(reader.bytes(size, "value")?.to_vec())
+        }
+        DW_FORM_block2 => {
+            let size = reader.u16("size")? as u64;
+            AttributeValue::Block(reader.bytes(size, "value")?.to_vec())
+        }
+        DW_FORM_block4 => {
+            let size = reader.u32("size")? as u64;
+            AttributeValue::Block(reader.bytes(size, "value")?.to_vec())
+        }
+        DW_FORM_block | DW_FORM_exprloc => {
+            let size = reader.uleb128("size")?;
+            AttributeValue::Block(reader.bytes(size, "value")?.to_vec())
+        }
+        DW_FORM_data1 => AttributeValue::Data {
+            value: reader.sized(1, "value")?,
+            size: 1,
+        },
+        DW_FORM_data2 => AttributeValue::Data {
+            value: reader.sized(2, "value")?,
+            size: 2,
+        },
+        DW_FORM_data4 => AttributeValue::Data {
+            value: reader.sized(4, "value")?,
+            size: 4,
+        },
+        DW_FORM_data8 => AttributeValue::Data {
+            value: reader.sized(8, "value")?,
+            size: 8,
+        },
+        DW_FORM_data16 => {
+            let bytes = reader.bytes(16, "value")?;
+            AttributeValue::Data16(bytes.try_into().expect("16 bytes were read"))
+        }
+        DW_FORM_sdata => AttributeValue::Sdata(reader.sleb128("value")?),
+        DW_FORM_udata => AttributeValue::Udata(reader.uleb128("value")?),
+        DW_FORM_implicit_const => AttributeValue::ImplicitConst(implicit_const.unwrap_or(0)),
+        DW_FORM_flag => AttributeValue::Flag(reader.u8("value")?),
+        DW_FORM_flag_present => AttributeValue::FlagPresent,
+        DW_FORM_string => AttributeValue::String(reader.cstr("value")?.to_vec()),
+        DW_FORM_strp => AttributeValue::Strp(reader.offset_of(format, "value")?),
+        DW_FORM_line_strp => AttributeValue::LineStrp(reader.offset_of(format, "value")?),
+        DW_FORM_strp_sup | DW_FORM_GNU_strp_alt => {
+            AttributeValue::AltStrp(reader.offset_of(format, "value")?)
+        }
+        DW_FORM_strx | DW_FORM_GNU_str_index => {
+            AttributeValue::StringIndex(reader.uleb128("value")?)
+        }
+        DW_FORM_strx1 => AttributeValue::StringIndex(reader.sized(1, "value")?),
+        DW_FORM_strx2 => AttributeValue::StringIndex(reader.sized(2, "value")?),
+        DW_FORM_strx3 => AttributeValue::StringIndex(reader.sized(3, "value")?),
+        DW_FORM_strx4 => AttributeValue::StringIndex(reader.sized(4, "value")?),
+        DW_FORM_ref1 => AttributeValue::UnitRef(reader.sized(1, "value")?),
+        DW_FORM_ref2 => AttributeValue::UnitRef(reader.sized(2, "value")?),
+        DW_FORM_ref4 => AttributeValue::UnitRef(reader.sized(4, "value")?),
+        DW_FORM_ref8 => AttributeValue::UnitRef(reader.sized(8, "value")?),
+        DW_FORM_ref_udata => AttributeValue::UnitRef(reader.uleb128("value")?),
+        DW_FORM_ref_addr => {
+            AttributeValue::InfoRef(reader.sized(encoding.reference_size(), "value")?)
+        }
+        DW_FORM_ref_sup4 => AttributeValue::AltRef(reader.sized(4, "value")?),
+        DW_FORM_ref_sup8 => AttributeValue::AltRef(reader.sized(8, "value")?),
+        DW_FORM_GNU_ref_alt => AttributeValue::AltRef(reader.offset_of(format, "value")?),
+        DW_FORM_ref_sig8 => AttributeValue::TypeSignature(reader.u64("value")?),
+        DW_FORM_sec_offset => AttributeValue::SecOffset(reader.offset_of(format, "value")?),
+        DW_FORM_loclistx => AttributeValue::LoclistIndex(reader.uleb128("value")?),
+        DW_FORM_rnglistx => AttributeValue::RnglistIndex(reader.uleb128("value")?),
+        DW_FORM_indirect => {
+            let offset = reader.offset();
+            let form = reader.uleb128("form")?;
+            // an indirection to another indirection would never end
+            if form == DW_FORM_indirect {
+                return Err(ElfError::invalid_value(
+                    offset,
+                    reader.section(),
+                    "form",
+                    "a form other than DW_FORM_indirect",
+                    form,
+                ));
+            }
+            return parse_value(reader, encoding, form, implicit_const);
+        }
+        _ => {
+            // without its size the rest of the unit cannot be read
+            return Err(ElfError::invalid_value(
+                reader.offset(),
+                reader.section(),
+                "form",
+                "a known DW_FORM",
+                form,
+            ));
+        }
+    };
+
+    Ok((form, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AttributeValue, parse_dies, parse_unit_header};
+    use crate::elf::dwarf::abbrev::parse_abbrev_table;
+    use crate::elf::dwarf::reader::{DwarfFormat, DwarfReader};
+
+    // a DWARF 5 compile unit with a DW_TAG_compile_unit (name "a.c",
+    // language C11) that has one DW_TAG_base_type child
+    fn create_unit() -> (Vec<u8>, Vec<u8>) {
+        let abbrev = vec![
+            1, 0x11, 1, 0x03, 0x08, 0x13, 0x0b, 0, 0, 2, 0x24, 0, 0x0b, 0x0b, 0x49, 0x13, 0, 0, 0,
+        ];
+        let mut info = vec![0, 0, 0, 0, 5, 0, 1, 8, 0, 0, 0, 0];
+        info.extend([1, b'a', b'.', b'c', 0, 0x1d]);
+        info.extend([2, 4, 0x0c, 0, 0, 0]);
+        info.push(0);
+        let length = (info.len() - 4) as u32;
+        info[0..4].copy_from_slice(&length.to_le_bytes());
+        (info, abbrev)
+    }
+
+    #[test]
+    fn parse_unit_header_reads_the_dwarf_5_layout() {
+        let (info, _) = create_unit();
+        let mut reader = DwarfReader::new(&info, true, ".debug_info");
+
+        let header = parse_unit_header(&mut reader).unwrap();
+
+        assert_eq!(header.format, DwarfFormat::Dwarf32);
+        assert_eq!((header.version, header.unit_type), (5, Some(1)));
+        assert_eq!((header.address_size, header.abbrev_offset), (8, 0));
+        assert_eq!(header.end, info.len() as u64);
+        assert_eq!(reader.offset(), 12);
+    }
+
+    #[test]
+    fn parse_dies_builds_the_tree_with_the_null_entries() {
+        let (info, abbrev) = create_unit();
+        let mut reader = DwarfReader::new(&info, true, ".debug_info");
+        let header = parse_unit_header(&mut reader).unwrap();
+        let abbrevs =
+            parse_abbrev_table(&mut DwarfReader::new(&abbrev, true, ".debug_abbrev")).unwrap();
+
+        let dies = parse_dies(&mut reader, &header, &abbrevs).unwrap();
+
+        assert_eq!(dies.len(), 3);
+        assert_eq!((dies[0].offset, dies[0].depth, dies[0].tag), (12, 0, 0x11));
+        assert_eq!(
+            dies[0].attributes[0].value,
+            AttributeValue::String(b"a.c".to_vec())
+        );
+        assert_eq!(dies[0].attribute(0x13).unwrap().constant(), Some(0x1d));
+        assert_eq!((dies[1].depth, dies[1].tag), (1, 0x24));
+        assert_eq!(dies[1].attributes[1].value, AttributeValue::UnitRef(0x0c));
+        assert_eq!((dies[2].depth, dies[2].code), (1, 0));
+    }
+
+    #[test]
+    fn parse_unit_header_rejects_the_unknown_versions() {
+        let data = [8, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 8];
+
+        let err = parse_unit_header(&mut DwarfReader::new(&data, true, ".debug_info")).unwrap_err();
+
+        assert_eq!((err.offset, err.field), (4, "version"));
+    }
+}
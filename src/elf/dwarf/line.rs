@@ -0,0 +1,541 @@
+use crate::elf::dwarf::constants::*;
+use crate::elf::dwarf::info::{AttributeValue, parse_value};
+use crate::elf::dwarf::reader::{DwarfFormat, DwarfReader, Encoding};
+use crate::elf::error::ElfError;
+
+// an entry of the directory or of the file name table. DWARF 5 describes
+// the columns of each table (DW_LNCT_* with a DW_FORM_*), the older versions
+// have a fixed layout that is read into the same columns
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineEntry {
+    pub values: Vec<(u64, AttributeValue)>,
+}
+
+impl LineEntry {
+    pub fn value(&self, content_type: u64) -> Option<&AttributeValue> {
+        self.values
+            .iter()
+            .find(|(content, _)| *content == content_type)
+            .map(|(_, value)| value)
+    }
+
+    pub fn path(&self) -> Option<&AttributeValue> {
+        self.value(DW_LNCT_path)
+    }
+
+    pub fn directory_index(&self) -> u64 {
+        self.value(DW_LNCT_directory_index)
+            .and_then(AttributeValue::constant)
+            .unwrap_or(0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineProgramHeader {
+    pub offset: u64,
+    pub length: u64,
+    pub format: DwarfFormat,
+    pub version: u16,
+    // DWARF 5 only
+    pub address_size: Option<u8>,
+    pub segment_selector_size: Option<u8>,
+    pub header_length: u64,
+    pub minimum_instruction_length: u8,
+    // DWARF 4 and later, it is 1 for the older ones
+    pub maximum_operations_per_instruction: u8,
+    pub default_is_stmt: bool,
+    pub line_base: i8,
+    pub line_range: u8,
+    pub opcode_base: u8,
+    // the number of operands of the standard opcodes, from 1
+    pub standard_opcode_lengths: Vec<u8>,
+    // the columns of the tables (DW_LNCT_*, DW_FORM_*), DWARF 5 only
+    pub directory_format: Vec<(u64, u64)>,
+    pub file_format: Vec<(u64, u64)>,
+    // where the entries of each table start
+    pub directories_offset: u64,
+    pub directories: Vec<LineEntry>,
+    pub files_offset: u64,
+    pub files: Vec<LineEntry>,
+    // the first instruction, and the end of the program
+    pub program_offset: u64,
+    pub end: u64,
+}
+
+impl LineProgramHeader {
+    // the advances of a special opcode (the whole opcode, not the one after
+    // opcode_base)
+    pub fn special_address_advance(&self, opcode: u8) -> u64 {
+        let adjusted = opcode.wrapping_sub(self.opcode_base);
+        (adjusted / self.line_range) as u64 * self.minimum_instruction_length as u64
+    }
+
+    pub fn special_line_advance(&self, opcode: u8) -> i64 {
+        let adjusted = opcode.wrapping_sub(self.opcode_base);
+        self.line_base as i64 + (adjusted % self.line_range) as i64
+    }
+
+    // DW_LNS_const_add_pc, the address advance of the special opcode 255
+    pub fn const_add_pc_advance(&self) -> u64 {
+        self.special_address_advance(255)
+    }
+
+    // the entry of the file register. DWARF 5 counts the files from 0, the
+    // older versions from 1 (the 0 was the primary source file, that is not
+    // in the table)
+    pub fn file(&self, file: u64) -> Option<&LineEntry> {
+        let index = if self.version >= 5 {
+            file
+        } else {
+            file.checked_sub(1)?
+        };
+        self.files.get(usize::try_from(index).ok()?)
+    }
+
+    // and the one of a directory index of a file entry, with the same
+    // difference between the versions
+    pub fn directory(&self, directory: u64) -> Option<&LineEntry> {
+        let index = if self.version >= 5 {
+            directory
+        } else {
+            directory.checked_sub(1)?
+        };
+        self.directories.get(usize::try_from(index).ok()?)
+    }
+}
+
+// the header of the line number program at the current offset of the
+// reader, that is left at the first instruction
+pub fn parse_line_program_header(reader: &mut DwarfReader) -> Result<LineProgramHeader, ElfError> {
+    let offset = reader.offset();
+    let (length, format) = reader.initial_length()?;
+    let end = reader.offset().saturating_add(length);
+
+    let version_offset = reader.offset();
+    let version = reader.u16("version")?;
+    if !(2..=5).contains(&version) {
+        return Err(ElfError::invalid_value(
+            version_offset,
+            reader.section(),
+            "version",
+            "a DWARF version from 2 to 5",
+            version,
+        ));
+    }
+
+    let (address_size, segment_selector_size) = if version >= 5 {
+        (
+            Some(reader.u8("address_size")?),
+            Some(reader.u8("segment_selector_size")?),
+        )
+    } else {
+        (None, None)
+    };
+
+    let header_length = reader.offset_of(format, "header_length")?;
+    let program_offset = reader.offset().saturating_add(header_length);
+    let minimum_instruction_length = reader.u8("minimum_instruction_length")?;
+    let maximum_operations_per_instruction = if version >= 4 {
+        reader.u8("maximum_operations_per_instruction")?
+    } else {
+        1
+    };
+    let default_is_stmt = reader.u8("default_is_stmt")? != 0;
+    let line_base = reader.i8("line_base")?;
+
+    let line_range_offset = reader.offset();
+    let line_range = reader.u8("line_range")?;
+    // the special opcodes are divided by it
+    if line_range == 0 {
+        return Err(ElfError::invalid_value(
+            line_range_offset,
+            reader.section(),
+            "line_range",
+            "a line range other than 0",
+            line_range,
+        ));
+    }
+
+    let opcode_base = reader.u8("opcode_base")?;
+    let standard_opcode_lengths = reader
+        .bytes(
+            opcode_base.saturating_sub(1) as u64,
+            "standard_opcode_lengths",
+        )?
+        .to_vec();
+
+    let mut header = LineProgramHeader {
+        offset,
+        length,
+        format,
+        version,
+        address_size,
+        segment_selector_size,
+        header_length,
+        minimum_instruction_length,
+        maximum_operations_per_instruction,
+        default_is_stmt,
+        line_base,
+        line_range,
+        opcode_base,
+        standard_opcode_lengths,
+        directory_format: Vec::new(),
+        file_format: Vec::new(),
+        directories_offset: 0,
+        directories: Vec::new(),
+        files_offset: 0,
+        files: Vec::new(),
+        program_offset,
+        end,
+    };
+
+    if version >= 5 {
+        let encoding = Encoding {
+            address_size: address_size.unwrap_or(0),
+            format,
+            version,
+        };
+
+        header.directory_format = parse_entry_format(reader)?;
+        let count = reader.uleb128("directories_count")?;
+        header.directories_offset = reader.offset();
+        header.directories = parse_entries(reader, &encoding, &header.directory_format, count)?;
+
+        header.file_format = parse_entry_format(reader)?;
+        let count = reader.uleb128("file_names_count")?;
+        header.files_offset = reader.offset();
+        header.files = parse_entries(reader, &encoding, &header.file_format, count)?;
+    } else {
+        header.directories_offset = reader.offset();
+        loop {
+            let directory = reader.cstr("include_directories")?;
+            if directory.is_empty() {
+                break;
+            }
+            header.directories.push(LineEntry {
+                values: vec![(DW_LNCT_path, AttributeValue::String(directory.to_vec()))],
+            });
+        }
+
+        header.files_offset = reader.offset();
+        loop {
+            let name = reader.cstr("file_names")?;
+            if name.is_empty() {
+                break;
+            }
+            header.files.push(parse_file_entry(reader, name)?);
+        }
+    }
+
+    Ok(header)
+}
+
+fn parse_entry_format(reader: &mut DwarfReader) -> Result<Vec<(u64, u64)>, ElfError> {
+    let count = reader.u8("entry_format_count")?;
+    (0..count)
+        .map(|_| Ok((reader.uleb128("content_type")?, reader.uleb128("form")?)))
+        .collect()
+}
+
+fn parse_entries(
+    reader: &mut DwarfReader,
+    encoding: &Encoding,
+    format: &[(u64, u64)],
+    count: u64,
+) -> Result<Vec<LineEntry>, ElfError> {
+    let mut entries = Vec::new();
+
+    // every entry takes at least a byte, a count past the end of the section
+    // is a broken one and shouldnt make us spin
+    for _ in 0..count.min(reader.remaining() as u64) {
+        let mut values = Vec::with_capacity(format.len());
+        for &(content_type, form) in format {
+            let (_, value) = parse_value(reader, encoding, form, None)?;
+            values.push((content_type, value));
+        }
+        entries.push(LineEntry { values });
+    }
+
+    Ok(entries)
+}
+
+// the entry of a file before DWARF 5 (and of DW_LNE_define_file), after its
+// name
+fn parse_file_entry(reader: &mut DwarfReader, name: &[u8]) -> Result<LineEntry, ElfError> {
+    Ok(LineEntry {
+        values: vec![
+            (DW_LNCT_path, AttributeValue::String(name.to_vec())),
+            (
+                DW_LNCT_directory_index,
+                AttributeValue::Udata(reader.uleb128("directory_index")?),
+            ),
+            (
+                DW_LNCT_timestamp,
+                AttributeValue::Udata(reader.uleb128("timestamp")?),
+            ),
+            (DW_LNCT_size, AttributeValue::Udata(reader.uleb128("size")?)),
+        ],
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LineInstruction {
+    // the whole opcode, from opcode_base
+    Special(u8),
+    Copy,
+    AdvancePc(u64),
+    AdvanceLine(i64),
+    SetFile(u64),
+    SetColumn(u64),
+    NegateStmt,
+    SetBasicBlock,
+    ConstAddPc,
+    FixedAdvancePc(u16),
+    SetPrologueEnd,
+    SetEpilogueBegin,
+    SetIsa(u64),
+    // a standard opcode of a later version (or of a vendor), its operands
+    // are all ULEB128
+    UnknownStandard { opcode: u8, operands: Vec<u64> },
+    EndSequence,
+    SetAddress(u64),
+    DefineFile(LineEntry),
+    SetDiscriminator(u64),
+    UnknownExtended { opcode: u8, data: Vec<u8> },
+}
+
+// the instruction at the current offset of the reader
+pub fn parse_line_instruction(
+    reader: &mut DwarfReader,
+    header: &LineProgramHeader,
+) -> Result<LineInstruction, ElfError> {
+    let opcode = reader.u8("opcode")?;
+
+    if opcode >= header.opcode_base {
+        return Ok(LineInstruction::Special(opcode));
+    }
+
+    let instruction = match opcode {
+        0 => {
+            let length = reader.uleb128("length")?;
+            let mut operands = reader.split(length);
+            if length == 0 {
+                return Ok(LineInstruction::UnknownExtended {
+                    opcode: 0,
+                    data: Vec::new(),
+                });
+            }
+
+            match operands.u8("opcode")? {
+                DW_LNE_end_sequence => LineInstruction::EndSequence,
+                DW_LNE_set_address => {
+                    let size = operands.remaining().min(8) as u8;
+                    LineInstruction::SetAddress(operands.address(size)?)
+                }
+                DW_LNE_define_file => {
+                    let name = operands.cstr("name")?;
+                    LineInstruction::DefineFile(parse_file_entry(&mut operands, name)?)
+                }
+                DW_LNE_set_discriminator => {
+                    LineInstruction::SetDiscriminator(operands.uleb128("discriminator")?)
+                }
+                opcode => LineInstruction::UnknownExtended {
+                    opcode,
+                    data: operands
+                        .bytes(operands.remaining() as u64, "data")?
+                        .to_vec(),
+                },
+            }
+        }
+        DW_LNS_copy => LineInstruction::Copy,
+        DW_LNS_advance_pc => LineInstruction::AdvancePc(reader.uleb128("operation_advance")?),
+        DW_LNS_advance_line => LineInstruction::AdvanceLine(reader.sleb128("line")?),
+        DW_LNS_set_file => LineInstruction::SetFile(reader.uleb128("file")?),
+        DW_LNS_set_column => LineInstruction::SetColumn(reader.uleb128("column")?),
+        DW_LNS_negate_stmt => LineInstruction::NegateStmt,
+        DW_LNS_set_basic_block => LineInstruction::SetBasicBlock,
+        DW_LNS_const_add_pc => LineInstruction::ConstAddPc,
+        DW_LNS_fixed_advance_pc => LineInstruction::FixedAdvancePc(reader.u16("operand")?),
+        DW_LNS_set_prologue_end => LineInstruction::SetPrologueEnd,
+        DW_LNS_set_epilogue_begin => LineInstruction::SetEpilogueBegin,
+        DW_LNS_set_isa => LineInstruction::SetIsa(reader.uleb128("isa")?),
+        _ => {
+            let count = header.standard_opcode_lengths[opcode as usize - 1];
+            let operands = (0..count)
+                .map(|_| reader.uleb128("operand"))
+                .collect::<Result<_, _>>()?;
+            LineInstruction::UnknownStandard { opcode, operands }
+        }
+    };
+
+    Ok(instruction)
+}
+
+// the registers of the line number state machine. The view is the one of
+// the location views (the number of rows before this one at the same
+// address), like binutils counts it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineState {
+    pub address: u64,
+    pub file: u64,
+    pub line: i64,
+    pub column: u64,
+    pub is_stmt: bool,
+    pub discriminator: u64,
+    pub end_sequence: bool,
+    pub view: u64,
+}
+
+impl LineState {
+    pub fn new(header: &LineProgramHeader) -> Self {
+        LineState {
+            address: 0,
+            file: 1,
+            line: 1,
+            column: 0,
+            is_stmt: header.default_is_stmt,
+            discriminator: 0,
+            end_sequence: false,
+            view: 0,
+        }
+    }
+
+    // runs the instruction, and returns the row that it adds to the line
+    // table (the special opcodes, DW_LNS_copy and DW_LNE_end_sequence do)
+    pub fn execute(
+        &mut self,
+        instruction: &LineInstruction,
+        header: &LineProgramHeader,
+    ) -> Option<LineState> {
+        match *instruction {
+            LineInstruction::Special(opcode) => {
+                self.advance(header.special_address_advance(opcode));
+                self.line = self.line.wrapping_add(header.special_line_advance(opcode));
+                return Some(self.emit_row());
+            }
+            LineInstruction::Copy => return Some(self.emit_row()),
+            LineInstruction::AdvancePc(advance) => {
+                self.advance(advance.wrapping_mul(header.minimum_instruction_length as u64))
+            }
+            LineInstruction::AdvanceLine(advance) => self.line = self.line.wrapping_add(advance),
+            LineInstruction::SetFile(file) => self.file = file,
+            LineInstruction::SetColumn(column) => self.column = column,
+            LineInstruction::NegateStmt => self.is_stmt = !self.is_stmt,
+            LineInstruction::ConstAddPc => self.advance(header.const_add_pc_advance()),
+            LineInstruction::FixedAdvancePc(advance) => {
+                self.address = self.address.wrapping_add(advance as u64);
+                self.view = 0;
+            }
+            LineInstruction::EndSequence => {
+                self.end_sequence = true;
+                let row = self.clone();
+                *self = LineState::new(header);
+                return Some(row);
+            }
+            LineInstruction::SetAddress(address) => {
+                self.address = address;
+                self.view = 0;
+            }
+            LineInstruction::SetDiscriminator(discriminator) => self.discriminator = discriminator,
+            _ => {}
+        }
+
+        None
+    }
+
+    // the view only goes back to 0 when the address really moves
+    fn advance(&mut self, advance: u64) {
+        self.address = self.address.wrapping_add(advance);
+        if advance != 0 {
+            self.view = 0;
+        }
+    }
+
+    fn emit_row(&mut self) -> LineState {
+        let row = self.clone();
+        self.view += 1;
+        self.discriminator = 0;
+        row
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LineInstruction, LineState, parse_line_instruction, parse_line_program_header};
+    use crate::elf::dwarf::info::AttributeValue;
+    use crate::elf::dwarf::reader::DwarfReader;
+
+    // a DWARF 3 program for "inc/sq.h" and "u.c" with a few instructions
+    fn create_program() -> Vec<u8> {
+        let mut data = vec![0, 0, 0, 0, 3, 0, 0, 0, 0, 0];
+        data.extend([1, 1, 0xfb, 14, 13]);
+        data.extend([0, 1, 1, 1, 1, 0, 0, 0, 1, 0, 0, 1]);
+        data.extend(b"inc\0\0");
+        data.extend(b"u.c\0\0\0\0sq.h\0\x01\0\0\0");
+        let header_length = (data.len() - 10) as u32;
+        data[6..10].copy_from_slice(&header_length.to_le_bytes());
+
+        // set_address 0x10, special (address +1, line +1), copy, advance_pc 2,
+        // special (+0, +1), end_sequence
+        data.extend([0, 9, 2, 0x10, 0, 0, 0, 0, 0, 0, 0]);
+        data.extend([13 + 6 + 14, 1, 2, 2, 13 + 6, 0, 1, 1]);
+        let length = (data.len() - 4) as u32;
+        data[0..4].copy_from_slice(&length.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn parse_line_program_header_reads_the_tables_of_dwarf_3() {
+        let data = create_program();
+        let mut reader = DwarfReader::new(&data, true, ".debug_line");
+
+        let header = parse_line_program_header(&mut reader).unwrap();
+
+        assert_eq!(
+            (header.version, header.maximum_operations_per_instruction),
+            (3, 1)
+        );
+        assert_eq!(
+            (header.line_base, header.line_range, header.opcode_base),
+            (-5, 14, 13)
+        );
+        assert_eq!(header.directories_offset, 0x1b);
+        assert_eq!(header.files.len(), 2);
+        assert_eq!(
+            header.file(2).unwrap().path(),
+            Some(&AttributeValue::String(b"sq.h".to_vec()))
+        );
+        assert_eq!(header.file(2).unwrap().directory_index(), 1);
+        assert!(header.file(0).is_none());
+        assert_eq!(reader.offset(), header.program_offset);
+    }
+
+    #[test]
+    fn line_state_counts_the_views_of_the_rows_at_the_same_address() {
+        let data = create_program();
+        let mut reader = DwarfReader::new(&data, true, ".debug_line");
+        let header = parse_line_program_header(&mut reader).unwrap();
+        let mut state = LineState::new(&header);
+
+        let mut rows = Vec::new();
+        while reader.offset() < header.end {
+            let instruction = parse_line_instruction(&mut reader, &header).unwrap();
+            if let Some(row) = state.execute(&instruction, &header) {
+                rows.push((row.address, row.line, row.view, row.end_sequence));
+            }
+            if instruction == LineInstruction::EndSequence {
+                assert_eq!(state, LineState::new(&header));
+            }
+        }
+
+        assert_eq!(
+            rows,
+            [
+                (0x11, 2, 0, false),
+                (0x11, 2, 1, false),
+                (0x13, 3, 0, false),
+                (0x13, 3, 1, true)
+            ]
+        );
+    }
+}
@@ -0,0 +1,303 @@
+use crate::elf::dwarf::constants::*;
+use crate::elf::dwarf::reader::{DwarfFormat, DwarfReader};
+use crate::elf::error::ElfError;
+
+// the header of a table of .debug_rnglists or .debug_loclists, the DWARF 5
+// sections of the range and location lists. The lists of a unit are in one
+// table, the offsets are relative to the end of the header (where the
+// offsets array starts, that DW_AT_rnglists_base and DW_AT_loclists_base
+// point at)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListTableHeader {
+    pub offset: u64,
+    pub length: u64,
+    pub format: DwarfFormat,
+    pub version: u16,
+    pub address_size: u8,
+    pub segment_selector_size: u8,
+    pub offset_entry_count: u32,
+    pub offsets_offset: u64,
+    pub offsets: Vec<u64>,
+    // where the lists start, after the offsets
+    pub lists_offset: u64,
+    pub end: u64,
+}
+
+// the header at the current offset of the reader, that is left at the first
+// list of the table
+pub fn parse_list_table_header(reader: &mut DwarfReader) -> Result<ListTableHeader, ElfError> {
+    let offset = reader.offset();
+    let (length, format) = reader.initial_length()?;
+    let end = reader.offset().saturating_add(length);
+
+    let version_offset = reader.offset();
+    let version = reader.u16("version")?;
+    if version != 5 {
+        return Err(ElfError::invalid_value(
+            version_offset,
+            reader.section(),
+            "version",
+            "5",
+            version,
+        ));
+    }
+
+    let address_size_offset = reader.offset();
+    let address_size = reader.u8("address_size")?;
+    if !matches!(address_size, 1..=8) {
+        return Err(ElfError::invalid_value(
+            address_size_offset,
+            reader.section(),
+            "address_size",
+            "an address size from 1 to 8",
+            address_size,
+        ));
+    }
+    let segment_selector_size = reader.u8("segment_selector_size")?;
+    let offset_entry_count = reader.u32("offset_entry_count")?;
+
+    let offsets_offset = reader.offset();
+    let mut offsets = Vec::new();
+    for _ in 0..offset_entry_count.min(reader.remaining() as u32) {
+        offsets.push(reader.offset_of(format, "offsets")?);
+    }
+
+    Ok(ListTableHeader {
+        offset,
+        length,
+        format,
+        version,
+        address_size,
+        segment_selector_size,
+        offset_entry_count,
+        offsets_offset,
+        offsets,
+        lists_offset: reader.offset(),
+        end,
+    })
+}
+
+// an entry of a range or location list, with its operands as they are: the
+// *x ones are indexes in .debug_addr, the offset pairs are relative to the
+// base address and the *_length ones have a length as their end
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ListEntry {
+    EndOfList,
+    BaseAddress(u64),
+    BaseAddressx(u64),
+    StartxEndx(u64, u64),
+    StartxLength(u64, u64),
+    OffsetPair(u64, u64),
+    StartEnd(u64, u64),
+    StartLength(u64, u64),
+    DefaultLocation,
+    ViewPair(u64, u64),
+}
+
+// the entry of a location list also has the expression of the location
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocationListEntry {
+    pub entry: ListEntry,
+    pub expression: Option<Vec<u8>>,
+}
+
+// an entry of .debug_rnglists
+pub fn parse_rnglist_entry(
+    reader: &mut DwarfReader,
+    address_size: u8,
+) -> Result<ListEntry, ElfError> {
+    let kind_offset = reader.offset();
+
+    Ok(match reader.u8("kind")? {
+        DW_RLE_end_of_list => ListEntry::EndOfList,
+        DW_RLE_base_addressx => ListEntry::BaseAddressx(reader.uleb128("index")?),
+        DW_RLE_startx_endx => {
+            ListEntry::StartxEndx(reader.uleb128("start")?, reader.uleb128("end")?)
+        }
+        DW_RLE_startx_length => {
+            ListEntry::StartxLength(reader.uleb128("start")?, reader.uleb128("length")?)
+        }
+        DW_RLE_offset_pair => {
+            ListEntry::OffsetPair(reader.uleb128("start")?, reader.uleb128("end")?)
+        }
+        DW_RLE_base_address => ListEntry::BaseAddress(reader.address(address_size)?),
+        DW_RLE_start_end => {
+            ListEntry::StartEnd(reader.address(address_size)?, reader.address(address_size)?)
+        }
+        DW_RLE_start_length => {
+            ListEntry::StartLength(reader.address(address_size)?, reader.uleb128("length")?)
+        }
+        kind => return Err(unknown_kind(reader, kind_offset, kind)),
+    })
+}
+
+// an entry of .debug_loclists, the expression of the ones that have one is
+// a counted block after the operands
+pub fn parse_loclist_entry(
+    reader: &mut DwarfReader,
+    address_size: u8,
+) -> Result<LocationListEntry, ElfError> {
+    let kind_offset = reader.offset();
+
+    let entry = match reader.u8("kind")? {
+        DW_LLE_end_of_list => ListEntry::EndOfList,
+        DW_LLE_base_addressx => ListEntry::BaseAddressx(reader.uleb128("index")?),
+        DW_LLE_startx_endx => {
+            ListEntry::StartxEndx(reader.uleb128("start")?, reader.uleb128("end")?)
+        }
+        DW_LLE_startx_length => {
+            ListEntry::StartxLength(reader.uleb128("start")?, reader.uleb128("length")?)
+        }
+        DW_LLE_offset_pair => {
+            ListEntry::OffsetPair(reader.uleb128("start")?, reader.uleb128("end")?)
+        }
+        DW_LLE_default_location => ListEntry::DefaultLocation,
+        DW_LLE_base_address => ListEntry::BaseAddress(reader.address(address_size)?),
+        DW_LLE_start_end => {
+            ListEntry::StartEnd(reader.address(address_size)?, reader.address(address_size)?)
+        }
+        DW_LLE_start_length => {
+            ListEntry::StartLength(reader.address(address_size)?, reader.uleb128("length")?)
+        }
+        DW_LLE_GNU_view_pair => {
+            ListEntry::ViewPair(reader.uleb128("begin")?, reader.uleb128("end")?)
+        }
+        kind => return Err(unknown_kind(reader, kind_offset, kind)),
+    };
+
+    let expression = match entry {
+        ListEntry::EndOfList | ListEntry::BaseAddress(_) | ListEntry::BaseAddressx(_) => None,
+        ListEntry::ViewPair(..) => None,
+        _ => {
+            let size = reader.uleb128("expression_length")?;
+            Some(reader.bytes(size, "expression")?.to_vec())
+        }
+    };
+
+    Ok(LocationListEntry { entry, expression })
+}
+
+// an entry of .debug_ranges, the lists before DWARF 5: pairs of addresses,
+// with (0, 0) at the end and -1 as the start of a new base address. In an
+// object file the addresses of a pair can be relocated to (0, 0), relocated
+// tells that it is one of those
+pub fn parse_range_entry(
+    reader: &mut DwarfReader,
+    address_size: u8,
+    relocated: bool,
+) -> Result<ListEntry, ElfError> {
+    let begin = reader.address(address_size)?;
+    let end = reader.address(address_size)?;
+
+    Ok(if begin == 0 && end == 0 && !relocated {
+        ListEntry::EndOfList
+    } else if begin == max_address(address_size) {
+        ListEntry::BaseAddress(end)
+    } else {
+        ListEntry::OffsetPair(begin, end)
+    })
+}
+
+// an entry of .debug_loc, like the ones of .debug_ranges but with a 2 byte
+// long expression after the addresses
+pub fn parse_location_entry(
+    reader: &mut DwarfReader,
+    address_size: u8,
+    relocated: bool,
+) -> Result<LocationListEntry, ElfError> {
+    let entry = parse_range_entry(reader, address_size, relocated)?;
+
+    let expression = match entry {
+        ListEntry::OffsetPair(..) => {
+            let size = reader.u16("expression_length")?;
+            Some(reader.bytes(size as u64, "expression")?.to_vec())
+        }
+        _ => None,
+    };
+
+    Ok(LocationListEntry { entry, expression })
+}
+
+fn max_address(address_size: u8) -> u64 {
+    u64::MAX >> (64 - 8 * address_size.clamp(1, 8) as u32)
+}
+
+fn unknown_kind(reader: &DwarfReader, offset: u64, kind: u8) -> ElfError {
+    ElfError::invalid_value(
+        offset,
+        reader.section(),
+        "kind",
+        "a known list entry kind",
+        kind,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ListEntry, parse_list_table_header, parse_loclist_entry, parse_range_entry,
+        parse_rnglist_entry,
+    };
+    use crate::elf::dwarf::reader::DwarfReader;
+
+    #[test]
+    fn parse_list_table_header_reads_the_offsets() {
+        let data = [16, 0, 0, 0, 5, 0, 8, 0, 1, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0];
+        let mut reader = DwarfReader::new(&data, true, ".debug_rnglists");
+
+        let header = parse_list_table_header(&mut reader).unwrap();
+
+        assert_eq!(
+            (header.address_size, header.offsets.as_slice()),
+            (8, &[4][..])
+        );
+        assert_eq!(
+            (header.offsets_offset, header.lists_offset, header.end),
+            (12, 16, 20)
+        );
+    }
+
+    #[test]
+    fn parse_rnglist_entry_and_parse_loclist_entry_read_their_operands() {
+        // DW_RLE_offset_pair 0x10 0x20, DW_RLE_end_of_list
+        let mut reader = DwarfReader::new(&[4, 0x10, 0x20, 0], true, ".debug_rnglists");
+        assert_eq!(
+            parse_rnglist_entry(&mut reader, 8).unwrap(),
+            ListEntry::OffsetPair(0x10, 0x20)
+        );
+        assert_eq!(
+            parse_rnglist_entry(&mut reader, 8).unwrap(),
+            ListEntry::EndOfList
+        );
+
+        // DW_LLE_offset_pair 0 4 with DW_OP_reg5, then an unknown kind
+        let mut reader = DwarfReader::new(&[4, 0, 4, 1, 0x55, 0x7f], true, ".debug_loclists");
+        let entry = parse_loclist_entry(&mut reader, 8).unwrap();
+        assert_eq!(
+            (entry.entry, entry.expression),
+            (ListEntry::OffsetPair(0, 4), Some(vec![0x55]))
+        );
+        assert!(parse_loclist_entry(&mut reader, 8).is_err());
+    }
+
+    #[test]
+    fn parse_range_entry_tells_the_base_addresses_apart() {
+        let data = [
+            0xff, 0xff, 0xff, 0xff, 0, 0x10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        let mut reader = DwarfReader::new(&data, true, ".debug_ranges");
+
+        assert_eq!(
+            parse_range_entry(&mut reader, 4, false).unwrap(),
+            ListEntry::BaseAddress(0x1000)
+        );
+        assert_eq!(
+            parse_range_entry(&mut reader.clone(), 4, false).unwrap(),
+            ListEntry::EndOfList
+        );
+        assert_eq!(
+            parse_range_entry(&mut reader, 4, true).unwrap(),
+            ListEntry::OffsetPair(0, 0)
+        );
+    }
+}
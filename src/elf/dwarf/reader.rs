@@ -0,0 +1,351 @@
+use crate::elf::error::ElfError;
+use crate::utils::endianess::EndianRead;
+
+// the 32-bit DWARF uses 4 byte offsets and lengths, the 64-bit one (that
+// starts its units with 0xffffffff) 8 byte ones. It has nothing to do with
+// the class of the file, a 32-bit file can have 64-bit DWARF
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DwarfFormat {
+    Dwarf32,
+    Dwarf64,
+}
+
+impl DwarfFormat {
+    pub fn offset_size(self) -> usize {
+        match self {
+            DwarfFormat::Dwarf32 => 4,
+            DwarfFormat::Dwarf64 => 8,
+        }
+    }
+
+    // the size of the initial length field itself
+    pub fn length_size(self) -> usize {
+        match self {
+            DwarfFormat::Dwarf32 => 4,
+            DwarfFormat::Dwarf64 => 12,
+        }
+    }
+}
+
+// what the values of a unit (or of a table of another section) need to be
+// read: the size of the addresses, the format and the version, DWARF 2 used
+// the size of the addresses for the references to other units
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Encoding {
+    pub address_size: u8,
+    pub format: DwarfFormat,
+    pub version: u16,
+}
+
+impl Encoding {
+    // DW_FORM_ref_addr, DW_OP_call_ref and the implicit pointers
+    pub fn reference_size(&self) -> u8 {
+        if self.version < 3 {
+            self.address_size
+        } else {
+            self.format.offset_size() as u8
+        }
+    }
+}
+
+// reads the values of a debug section one after the other, with the same
+// EndianRead as the rest of the crate. The offsets (and the ones of the
+// errors) are relative to the start of the section, and section is its name,
+// used as the structure of the errors
+#[derive(Debug, Clone)]
+pub struct DwarfReader<'data> {
+    data: &'data [u8],
+    offset: usize,
+    end: usize,
+    is_little_endian: bool,
+    section: &'static str,
+}
+
+impl<'data> DwarfReader<'data> {
+    pub fn new(data: &'data [u8], is_little_endian: bool, section: &'static str) -> Self {
+        DwarfReader {
+            data,
+            offset: 0,
+            end: data.len(),
+            is_little_endian,
+            section,
+        }
+    }
+
+    pub fn offset(&self) -> u64 {
+        self.offset as u64
+    }
+
+    pub fn section(&self) -> &'static str {
+        self.section
+    }
+
+    pub fn is_little_endian(&self) -> bool {
+        self.is_little_endian
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.offset >= self.end
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.end.saturating_sub(self.offset)
+    }
+
+    // moves to offset, that can be past the end (then nothing is left)
+    pub fn seek(&mut self, offset: u64) {
+        self.offset = usize::try_from(offset).unwrap_or(usize::MAX);
+    }
+
+    // a reader of the next size bytes, that are skipped in this one. A size
+    // that goes past the end is cut to what is left, the unit headers say
+    // how long the units are and a bad one shouldnt stop the rest
+    pub fn split(&mut self, size: u64) -> DwarfReader<'data> {
+        let size = usize::try_from(size)
+            .unwrap_or(usize::MAX)
+            .min(self.remaining());
+        let reader = DwarfReader {
+            end: self.offset + size,
+            ..self.clone()
+        };
+        self.offset += size;
+        reader
+    }
+
+    pub fn bytes(&mut self, size: u64, field: &'static str) -> Result<&'data [u8], ElfError> {
+        let left = self.remaining();
+        match usize::try_from(size) {
+            Ok(size) if size <= left => {
+                let bytes = &self.data[self.offset..self.offset + size];
+                self.offset += size;
+                Ok(bytes)
+            }
+            _ => Err(self.truncated(field, size, left)),
+        }
+    }
+
+    pub fn u8(&mut self, field: &'static str) -> Result<u8, ElfError> {
+        Ok(self.bytes(1, field)?[0])
+    }
+
+    pub fn i8(&mut self, field: &'static str) -> Result<i8, ElfError> {
+        Ok(self.u8(field)? as i8)
+    }
+
+    pub fn u16(&mut self, field: &'static str) -> Result<u16, ElfError> {
+        let bytes = self.bytes(2, field)?;
+        Ok(u16::read_from(bytes, self.is_little_endian))
+    }
+
+    pub fn u32(&mut self, field: &'static str) -> Result<u32, ElfError> {
+        let bytes = self.bytes(4, field)?;
+        Ok(u32::read_from(bytes, self.is_little_endian))
+    }
+
+    pub fn u64(&mut self, field: &'static str) -> Result<u64, ElfError> {
+        let bytes = self.bytes(8, field)?;
+        Ok(u64::read_from(bytes, self.is_little_endian))
+    }
+
+    // an unsigned value of 1 to 8 bytes, like DW_FORM_strx3 or an address
+    pub fn sized(&mut self, size: u8, field: &'static str) -> Result<u64, ElfError> {
+        let bytes = self.bytes(size as u64, field)?;
+        let mut word = [0; 8];
+        if self.is_little_endian {
+            word[..bytes.len()].copy_from_slice(bytes);
+        } else {
+            word[8 - bytes.len()..].copy_from_slice(bytes);
+        }
+        Ok(u64::read_from(&word, self.is_little_endian))
+    }
+
+    pub fn address(&mut self, size: u8) -> Result<u64, ElfError> {
+        self.sized(size, "address")
+    }
+
+    pub fn uleb128(&mut self, field: &'static str) -> Result<u64, ElfError> {
+        let start = self.offset;
+        let mut value = 0u64;
+        let mut shift = 0;
+
+        loop {
+            let Some(&byte) = self.data[..self.end].get(self.offset) else {
+                self.offset = start;
+                return Err(self.truncated(field, 1, 0));
+            };
+            self.offset += 1;
+
+            // the bits past the 64th are dropped, like binutils does
+            if shift < 64 {
+                value |= ((byte & 0x7f) as u64) << shift;
+            }
+            shift += 7;
+
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+    }
+
+    pub fn sleb128(&mut self, field: &'static str) -> Result<i64, ElfError> {
+        let start = self.offset;
+        let mut value = 0i64;
+        let mut shift = 0;
+
+        loop {
+            let Some(&byte) = self.data[..self.end].get(self.offset) else {
+                self.offset = start;
+                return Err(self.truncated(field, 1, 0));
+            };
+            self.offset += 1;
+
+            if shift < 64 {
+                value |= ((byte & 0x7f) as i64) << shift;
+            }
+            shift += 7;
+
+            if byte & 0x80 == 0 {
+                if shift < 64 && byte & 0x40 != 0 {
+                    value |= -1 << shift;
+                }
+                return Ok(value);
+            }
+        }
+    }
+
+    // the unit_length that starts every unit (and most of the tables), it
+    // also tells the format of what follows
+    pub fn initial_length(&mut self) -> Result<(u64, DwarfFormat), ElfError> {
+        match self.u32("unit_length")? {
+            0xffff_ffff => Ok((self.u64("unit_length")?, DwarfFormat::Dwarf64)),
+            // the values from 0xfffffff0 are reserved
+            length if length >= 0xffff_fff0 => Err(ElfError::invalid_value(
+                self.offset() - 4,
+                self.section,
+                "unit_length",
+                "a length or 0xffffffff",
+                length,
+            )),
+            length => Ok((length as u64, DwarfFormat::Dwarf32)),
+        }
+    }
+
+    // a section offset (like DW_FORM_sec_offset or debug_abbrev_offset)
+    pub fn offset_of(&mut self, format: DwarfFormat, field: &'static str) -> Result<u64, ElfError> {
+        match format {
+            DwarfFormat::Dwarf32 => Ok(self.u32(field)? as u64),
+            DwarfFormat::Dwarf64 => self.u64(field),
+        }
+    }
+
+    // a NUL terminated string, without the NUL (that can be missing at the
+    // end of the section)
+    pub fn cstr(&mut self, field: &'static str) -> Result<&'data [u8], ElfError> {
+        let rest = &self.data[self.offset.min(self.end)..self.end];
+        match rest.iter().position(|&byte| byte == 0) {
+            Some(len) => {
+                self.offset += len + 1;
+                Ok(&rest[..len])
+            }
+            None => Err(self.truncated(field, rest.len() as u64 + 1, rest.len())),
+        }
+    }
+
+    fn truncated(&self, field: &'static str, expected: u64, actual: usize) -> ElfError {
+        let mut err = ElfError::truncated(self.offset(), self.section, expected as usize, actual);
+        err.field = field;
+        err
+    }
+}
+
+// the string that starts at offset in a string section (.debug_str and
+// friends), None when the offset is past its end
+pub fn string_at(data: &[u8], offset: u64) -> Option<&[u8]> {
+    let rest = data.get(usize::try_from(offset).ok()?..)?;
+    let len = rest
+        .iter()
+        .position(|&byte| byte == 0)
+        .unwrap_or(rest.len());
+    Some(&rest[..len])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DwarfFormat, DwarfReader, string_at};
+
+    #[test]
+    fn uleb128_and_sleb128_read_the_examples_of_the_standard() {
+        let data = [
+            0x02, 0x7f, 0x80, 0x01, 0xe5, 0x8e, 0x26, 0x7f, 0x80, 0x7f, 0x3f,
+        ];
+        let mut reader = DwarfReader::new(&data[..7], true, ".debug_info");
+
+        assert_eq!(reader.uleb128("value").unwrap(), 2);
+        assert_eq!(reader.uleb128("value").unwrap(), 127);
+        assert_eq!(reader.uleb128("value").unwrap(), 128);
+        assert_eq!(reader.uleb128("value").unwrap(), 624485);
+        assert!(reader.is_empty());
+
+        let mut reader = DwarfReader::new(&data[7..], true, ".debug_info");
+        assert_eq!(reader.sleb128("value").unwrap(), -1);
+        assert_eq!(reader.sleb128("value").unwrap(), -128);
+        assert_eq!(reader.sleb128("value").unwrap(), 63);
+    }
+
+    #[test]
+    fn uleb128_that_runs_past_the_end_is_truncated() {
+        let mut reader = DwarfReader::new(&[0x80, 0x80], true, ".debug_line");
+
+        let err = reader.uleb128("file").unwrap_err();
+
+        assert_eq!(
+            (err.structure, err.field, err.offset),
+            (".debug_line", "file", 0)
+        );
+    }
+
+    #[test]
+    fn initial_length_tells_the_format_apart() {
+        let mut data = vec![0x10, 0, 0, 0];
+        data.extend([0xff, 0xff, 0xff, 0xff, 0x20, 0, 0, 0, 0, 0, 0, 0]);
+        let mut reader = DwarfReader::new(&data, true, ".debug_info");
+
+        assert_eq!(
+            reader.initial_length().unwrap(),
+            (0x10, DwarfFormat::Dwarf32)
+        );
+        assert_eq!(
+            reader.initial_length().unwrap(),
+            (0x20, DwarfFormat::Dwarf64)
+        );
+    }
+
+    #[test]
+    fn sized_reads_the_odd_sizes_in_both_endiannesses() {
+        let data = [1, 2, 3];
+
+        assert_eq!(
+            DwarfReader::new(&data, true, "").sized(3, "").unwrap(),
+            0x030201
+        );
+        assert_eq!(
+            DwarfReader::new(&data, false, "").sized(3, "").unwrap(),
+            0x010203
+        );
+    }
+
+    #[test]
+    fn split_keeps_the_offsets_of_the_section() {
+        let data = [1, 2, 3, 4, 5];
+        let mut reader = DwarfReader::new(&data, true, ".debug_info");
+        reader.u8("").unwrap();
+
+        let mut unit = reader.split(2);
+
+        assert_eq!(unit.offset(), 1);
+        assert_eq!(unit.u16("").unwrap(), 0x0302);
+        assert!(unit.is_empty() && unit.u8("").is_err());
+        assert_eq!(reader.u8("").unwrap(), 4);
+        assert_eq!(string_at(b"ab\0cd", 3), Some(&b"cd"[..]));
+    }
+}
@@ -0,0 +1,98 @@
+// the names of the DWARF register numbers of each machine, the ones of their
+// psABIs (and of binutils, that the location dumps show after the numbers)
+
+const X86_64_REGISTERS: [&str; 67] = [
+    "rax", "rdx", "rcx", "rbx", "rsi", "rdi", "rbp", "rsp", "r8", "r9", "r10", "r11", "r12", "r13",
+    "r14", "r15", "rip", "xmm0", "xmm1", "xmm2", "xmm3", "xmm4", "xmm5", "xmm6", "xmm7", "xmm8",
+    "xmm9", "xmm10", "xmm11", "xmm12", "xmm13", "xmm14", "xmm15", "st0", "st1", "st2", "st3",
+    "st4", "st5", "st6", "st7", "mm0", "mm1", "mm2", "mm3", "mm4", "mm5", "mm6", "mm7", "rflags",
+    "es", "cs", "ss", "ds", "fs", "gs", "", "", "fs.base", "gs.base", "", "", "tr", "ldtr",
+    "mxcsr", "fcw", "fsw",
+];
+
+const I386_REGISTERS: [&str; 50] = [
+    "eax", "ecx", "edx", "ebx", "esp", "ebp", "esi", "edi", "eip", "eflags", "", "st0", "st1",
+    "st2", "st3", "st4", "st5", "st6", "st7", "", "", "xmm0", "xmm1", "xmm2", "xmm3", "xmm4",
+    "xmm5", "xmm6", "xmm7", "mm0", "mm1", "mm2", "mm3", "mm4", "mm5", "mm6", "mm7", "fcw", "fsw",
+    "mxcsr", "es", "cs", "ss", "ds", "fs", "gs", "", "", "tr", "ldtr",
+];
+
+const RISCV_REGISTERS: [&str; 32] = [
+    "zero", "ra", "sp", "gp", "tp", "t0", "t1", "t2", "s0", "s1", "a0", "a1", "a2", "a3", "a4",
+    "a5", "a6", "a7", "s2", "s3", "s4", "s5", "s6", "s7", "s8", "s9", "s10", "s11", "t3", "t4",
+    "t5", "t6",
+];
+
+const RISCV_FLOAT_REGISTERS: [&str; 32] = [
+    "ft0", "ft1", "ft2", "ft3", "ft4", "ft5", "ft6", "ft7", "fs0", "fs1", "fa0", "fa1", "fa2",
+    "fa3", "fa4", "fa5", "fa6", "fa7", "fs2", "fs3", "fs4", "fs5", "fs6", "fs7", "fs8", "fs9",
+    "fs10", "fs11", "ft8", "ft9", "ft10", "ft11",
+];
+
+// the ones that are numbered in a row, like xmm16 to xmm31
+fn numbered(register: u64, first: u64, names: &[&'static str]) -> Option<&'static str> {
+    let index = usize::try_from(register.checked_sub(first)?).ok()?;
+    names.get(index).copied()
+}
+
+fn known(name: &'static str) -> Option<&'static str> {
+    Some(name).filter(|name| !name.is_empty())
+}
+
+pub fn x86_64_register_name(register: u64) -> Option<&'static str> {
+    const XMM16: [&str; 16] = [
+        "xmm16", "xmm17", "xmm18", "xmm19", "xmm20", "xmm21", "xmm22", "xmm23", "xmm24", "xmm25",
+        "xmm26", "xmm27", "xmm28", "xmm29", "xmm30", "xmm31",
+    ];
+    const K: [&str; 8] = ["k0", "k1", "k2", "k3", "k4", "k5", "k6", "k7"];
+
+    numbered(register, 0, &X86_64_REGISTERS)
+        .or_else(|| numbered(register, 67, &XMM16))
+        .or_else(|| numbered(register, 118, &K))
+        .and_then(known)
+}
+
+pub fn i386_register_name(register: u64) -> Option<&'static str> {
+    numbered(register, 0, &I386_REGISTERS).and_then(known)
+}
+
+pub fn aarch64_register_name(register: u64) -> Option<&'static str> {
+    const X: [&str; 32] = [
+        "x0", "x1", "x2", "x3", "x4", "x5", "x6", "x7", "x8", "x9", "x10", "x11", "x12", "x13",
+        "x14", "x15", "x16", "x17", "x18", "x19", "x20", "x21", "x22", "x23", "x24", "x25", "x26",
+        "x27", "x28", "x29", "x30", "sp",
+    ];
+    const V: [&str; 32] = [
+        "v0", "v1", "v2", "v3", "v4", "v5", "v6", "v7", "v8", "v9", "v10", "v11", "v12", "v13",
+        "v14", "v15", "v16", "v17", "v18", "v19", "v20", "v21", "v22", "v23", "v24", "v25", "v26",
+        "v27", "v28", "v29", "v30", "v31",
+    ];
+
+    numbered(register, 0, &X).or_else(|| numbered(register, 64, &V))
+}
+
+pub fn riscv_register_name(register: u64) -> Option<&'static str> {
+    numbered(register, 0, &RISCV_REGISTERS)
+        .or_else(|| numbered(register, 32, &RISCV_FLOAT_REGISTERS))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        aarch64_register_name, i386_register_name, riscv_register_name, x86_64_register_name,
+    };
+
+    #[test]
+    fn register_names_follow_the_numbering_of_the_psabis() {
+        assert_eq!(x86_64_register_name(5), Some("rdi"));
+        assert_eq!(x86_64_register_name(18), Some("xmm1"));
+        assert_eq!(x86_64_register_name(56), None);
+        assert_eq!(x86_64_register_name(82), Some("xmm31"));
+        assert_eq!(x86_64_register_name(125), Some("k7"));
+        assert_eq!(i386_register_name(4), Some("esp"));
+        assert_eq!(aarch64_register_name(31), Some("sp"));
+        assert_eq!(aarch64_register_name(95), Some("v31"));
+        assert_eq!(riscv_register_name(10), Some("a0"));
+        assert_eq!(riscv_register_name(42), Some("fa0"));
+    }
+}
@@ -0,0 +1,316 @@
+use crate::elf::class::ClassParseable;
+use crate::elf::def::elf32strc::{Elf32_Dyn, Elf32_Dyn__bindgen_ty_1};
+use crate::elf::def::elf64strc::{Elf64_Dyn, Elf64_Dyn__bindgen_ty_1, Elf64_Ehdr, Elf64_Phdr};
+use crate::elf::def::elfconsts::*;
+use crate::elf::error::ElfError;
+use crate::elf::section::{Elf64Section, parse_sections};
+use crate::elf::segment::{parse_program_headers, vma_to_offset};
+use crate::elf::strtab::get_string;
+use crate::elf::types::DynamicTag;
+use crate::utils::endianess::EndianRead;
+use crate::utils::parser::EndianParseable;
+use std::borrow::Cow;
+
+impl EndianParseable for Elf64_Dyn {
+    fn parse_endian(bytes: &[u8], is_little_endian: bool) -> Result<Self, ElfError> {
+        // offset: 0x0 -> 0x10 (relative to the start of the entry)
+        if bytes.len() < 16 {
+            return Err(ElfError::truncated(0, "Elf64_Dyn", 16, bytes.len()));
+        }
+
+        Ok(Elf64_Dyn {
+            // offset: 0x0 -> 0x7, signed, but every known tag is positive
+            d_tag: i64::read_from(&bytes[0..8], is_little_endian),
+            // offset: 0x8 -> 0xF, a value or an address, depending on the tag
+            d_un: Elf64_Dyn__bindgen_ty_1 {
+                d_val: u64::read_from(&bytes[8..16], is_little_endian),
+            },
+        })
+    }
+}
+
+impl EndianParseable for Elf32_Dyn {
+    fn parse_endian(bytes: &[u8], is_little_endian: bool) -> Result<Self, ElfError> {
+        // offset: 0x0 -> 0x8 (relative to the start of the entry)
+        if bytes.len() < 8 {
+            return Err(ElfError::truncated(0, "Elf32_Dyn", 8, bytes.len()));
+        }
+
+        Ok(Elf32_Dyn {
+            // offset: 0x0 -> 0x3
+            d_tag: u32::read_from(&bytes[0..4], is_little_endian) as i32,
+            // offset: 0x4 -> 0x7
+            d_un: Elf32_Dyn__bindgen_ty_1 {
+                d_val: u32::read_from(&bytes[4..8], is_little_endian),
+            },
+        })
+    }
+}
+
+impl From<Elf32_Dyn> for Elf64_Dyn {
+    fn from(entry: Elf32_Dyn) -> Self {
+        Elf64_Dyn {
+            // the tag keeps its sign, the value is just an unsigned word
+            d_tag: entry.d_tag as i64,
+            d_un: Elf64_Dyn__bindgen_ty_1 {
+                d_val: unsafe { entry.d_un.d_val } as u64,
+            },
+        }
+    }
+}
+
+impl ClassParseable for Elf64_Dyn {
+    type Elf32 = Elf32_Dyn;
+
+    const ELF32_SIZE: usize = 8;
+    const ELF64_SIZE: usize = 16;
+    const ELF32_NAME: &'static str = "Elf32_Dyn";
+    const ELF64_NAME: &'static str = "Elf64_Dyn";
+}
+
+impl Elf64_Dyn {
+    // d_val and d_ptr are both 8 bytes wide, so reading any of them is the
+    // same thing, the name only tells how the value should be interpreted
+    pub fn d_val(&self) -> u64 {
+        unsafe { self.d_un.d_val }
+    }
+
+    // the tags that we know fit in 32 bits, anything else is unknown
+    pub fn tag(&self) -> u32 {
+        u32::try_from(self.d_tag).unwrap_or(u32::MAX)
+    }
+
+    pub fn dynamic_tag(&self) -> DynamicTag {
+        self.tag().into()
+    }
+}
+
+pub struct Elf64Dynamic {
+    // offset of the entries in the file
+    pub offset: u64,
+    // every entry until the first DT_NULL (included)
+    pub entries: Vec<Elf64_Dyn>,
+}
+
+// the loader only looks at the PT_DYNAMIC segment, but when there are section
+// headers the .dynamic section is trusted instead (just like binutils does).
+// The field that gave the offset is kept for the errors
+fn dynamic_location(
+    segments: &[Elf64_Phdr],
+    sections: &[Elf64Section],
+) -> Option<(u64, u64, &'static str)> {
+    let segment = segments.iter().find(|phdr| phdr.p_type == PT_DYNAMIC)?;
+
+    if sections.is_empty() {
+        return Some((segment.p_offset, segment.p_filesz, "p_offset"));
+    }
+
+    let section = sections
+        .iter()
+        .find(|section| section.name == ".dynamic" && section.header.sh_size != 0)?;
+
+    if section.header.sh_type == SHT_NOBITS {
+        return None;
+    }
+
+    Some((
+        section.header.sh_offset,
+        section.header.sh_size,
+        "sh_offset",
+    ))
+}
+
+pub fn parse_dynamic_section(
+    bytes: &[u8],
+    header: &Elf64_Ehdr,
+    segments: &[Elf64_Phdr],
+    sections: &[Elf64Section],
+) -> Result<Option<Elf64Dynamic>, ElfError> {
+    let Some((offset, size, field)) = dynamic_location(segments, sections) else {
+        return Ok(None);
+    };
+
+    let class = header.class();
+
+    let data = usize::try_from(offset)
+        .ok()
+        .zip(usize::try_from(size).ok())
+        .and_then(|(start, size)| bytes.get(start..start.checked_add(size)?))
+        .ok_or_else(|| {
+            ElfError::out_of_bounds(
+                offset,
+                Elf64_Dyn::name_of(class),
+                field,
+                (offset, size),
+                bytes.len(),
+            )
+        })?;
+
+    let is_little_endian = header.is_little_endian();
+    let mut entries = Vec::new();
+
+    // the section is usually bigger than what is used, the first DT_NULL
+    // marks its real end
+    for entry in data.chunks_exact(Elf64_Dyn::size_of(class)) {
+        let entry = Elf64_Dyn::parse_class(entry, class, is_little_endian)?;
+        entries.push(entry);
+
+        if entry.d_tag == DT_NULL as i64 {
+            break;
+        }
+    }
+
+    Ok(Some(Elf64Dynamic { offset, entries }))
+}
+
+// the names used by DT_NEEDED, DT_SONAME etc. are offsets in the table at
+// the address of DT_STRTAB, with DT_STRSZ bytes
+pub fn dynamic_strings<'a>(
+    bytes: &'a [u8],
+    segments: &[Elf64_Phdr],
+    entries: &[Elf64_Dyn],
+) -> Option<&'a [u8]> {
+    let find = |tag: u32| entries.iter().find(|entry| entry.tag() == tag);
+
+    let address = find(DT_STRTAB)?.d_val();
+    let size = usize::try_from(find(DT_STRSZ)?.d_val()).ok()?;
+    let start = usize::try_from(vma_to_offset(segments, address)?).ok()?;
+
+    bytes.get(start..start.checked_add(size)?)
+}
+
+pub fn dynamic_name<'a>(strings: Option<&'a [u8]>, offset: u64) -> Option<Cow<'a, str>> {
+    get_string(strings?, usize::try_from(offset).ok()?)
+}
+
+// only the first DT_FLAGS_1 counts, like in the loader
+pub fn has_pie_flag(entries: &[Elf64_Dyn]) -> bool {
+    entries
+        .iter()
+        .find(|entry| entry.tag() == DT_FLAGS_1)
+        .is_some_and(|entry| entry.d_val() & DF_1_PIE as u64 != 0)
+}
+
+// ET_DYN is used by shared libraries AND by position independent executables,
+// the only way to tell them apart is the DF_1_PIE flag. This is only used to
+// name the file type, so a broken file is just not a PIE
+pub fn is_pie(bytes: &[u8], header: &Elf64_Ehdr) -> bool {
+    if header.e_type as u32 != ET_DYN {
+        return false;
+    }
+
+    let Ok(segments) = parse_program_headers(bytes, header) else {
+        return false;
+    };
+    let sections = parse_sections(bytes, header).unwrap_or_default();
+
+    parse_dynamic_section(bytes, header, &segments, &sections)
+        .ok()
+        .flatten()
+        .is_some_and(|dynamic| has_pie_flag(&dynamic.entries))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{dynamic_strings, has_pie_flag, parse_dynamic_section};
+    use crate::elf::def::elf64strc::Elf64_Phdr;
+    use crate::elf::error::{ElfError, ElfErrorKind};
+    use crate::test_utils::create_header;
+
+    fn create_segment(p_type: u32, offset: u64, size: u64) -> Elf64_Phdr {
+        Elf64_Phdr {
+            p_type,
+            p_flags: 6,
+            p_offset: offset,
+            p_vaddr: offset + 0x1000,
+            p_paddr: offset + 0x1000,
+            p_filesz: size,
+            p_memsz: size,
+            p_align: 8,
+        }
+    }
+
+    // a string table with "libc.so.6" followed by the dynamic section, that
+    // has some padding after its DT_NULL
+    fn create_file() -> (Vec<u8>, Vec<Elf64_Phdr>) {
+        let mut bytes = b"\0libc.so.6\0".to_vec();
+        bytes.resize(16, 0);
+
+        let entries: [(u64, u64); 6] = [
+            (1, 1),                  // DT_NEEDED
+            (5, 0x1000),             // DT_STRTAB
+            (10, 11),                // DT_STRSZ
+            (0x6ffffffb, 0x8000001), // DT_FLAGS_1 (NOW PIE)
+            (0, 0),                  // DT_NULL
+            (0, 0),
+        ];
+        for (tag, val) in entries {
+            bytes.extend_from_slice(&tag.to_le_bytes());
+            bytes.extend_from_slice(&val.to_le_bytes());
+        }
+
+        let segments = vec![
+            create_segment(1, 0, bytes.len() as u64),
+            create_segment(2, 16, 96),
+        ];
+
+        (bytes, segments)
+    }
+
+    #[test]
+    fn parse_dynamic_section_stops_at_the_first_null_entry() {
+        let (bytes, segments) = create_file();
+
+        let dynamic = parse_dynamic_section(&bytes, &create_header(3, 62), &segments, &[])
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(dynamic.offset, 16);
+        assert_eq!(dynamic.entries.len(), 5);
+        assert_eq!(dynamic.entries[0].d_tag, 1);
+        assert_eq!(dynamic.entries[0].d_val(), 1);
+        assert!(has_pie_flag(&dynamic.entries));
+    }
+
+    #[test]
+    fn parse_dynamic_section_returns_none_without_dynamic_segment() {
+        let (bytes, mut segments) = create_file();
+        segments.pop();
+
+        let dynamic = parse_dynamic_section(&bytes, &create_header(3, 62), &segments, &[]).unwrap();
+
+        assert!(dynamic.is_none());
+    }
+
+    #[test]
+    fn parse_dynamic_section_returns_out_of_bounds_err_when_it_is_past_the_file() {
+        let (bytes, mut segments) = create_file();
+        segments[1].p_filesz = 0x1000;
+
+        let err = parse_dynamic_section(&bytes, &create_header(3, 62), &segments, &[])
+            .err()
+            .unwrap();
+
+        assert!(matches!(
+            err,
+            ElfError {
+                offset: 16,
+                field: "p_offset",
+                kind: ElfErrorKind::OutOfBounds { size: 0x1000, .. },
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn dynamic_strings_follows_the_strtab_address() {
+        let (bytes, segments) = create_file();
+        let dynamic = parse_dynamic_section(&bytes, &create_header(3, 62), &segments, &[])
+            .unwrap()
+            .unwrap();
+
+        let strings = dynamic_strings(&bytes, &segments, &dynamic.entries).unwrap();
+
+        assert_eq!(strings, b"\0libc.so.6\0");
+    }
+}
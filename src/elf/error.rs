@@ -0,0 +1,194 @@
+use std::fmt;
+use std::io;
+
+// the only error of the parsers. Every one of them knows where in the file
+// the problem is (offset), what was being parsed (structure, like
+// "Elf64_Shdr") and the field with the bad value (like "e_shentsize"), so
+// the callers can both match on it and print something like
+// "at offset 0x12 (e_machine): ..."
+//
+// the offset is the one of the bad field, but the section headers dont know
+// where they are in the file, so for their fields (sh_link, sh_entsize...)
+// it is the offset of the section itself. When the whole structure is the
+// problem (it is truncated) the field is the name of the structure
+#[derive(Debug)]
+pub struct ElfError {
+    pub offset: u64,
+    pub structure: &'static str,
+    pub field: &'static str,
+    pub kind: ElfErrorKind,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum ElfErrorKind {
+    #[error("expected {expected}, found {actual:#x}")]
+    InvalidValue { expected: String, actual: u64 },
+    #[error("expected {expected} bytes, but only {actual} are left")]
+    Truncated { expected: u64, actual: u64 },
+    #[error("{size:#x} bytes at {start:#x} run past the end of the file ({file_size:#x} bytes)")]
+    OutOfBounds {
+        start: u64,
+        size: u64,
+        file_size: u64,
+    },
+    #[error("{start:#x}..{end:#x} overlaps the {other} at {other_start:#x}..{other_end:#x}")]
+    Overlaps {
+        start: u64,
+        end: u64,
+        other: &'static str,
+        other_start: u64,
+        other_end: u64,
+    },
+    #[error(
+        "{start:#x}..{end:#x} is only partly inside of the segment {segment} at {segment_start:#x}..{segment_end:#x}"
+    )]
+    Straddles {
+        start: u64,
+        end: u64,
+        segment: usize,
+        segment_start: u64,
+        segment_end: u64,
+    },
+    #[error("the data cannot be decompressed: {0}")]
+    Decompression(String),
+    #[error(transparent)]
+    Io(io::Error),
+}
+
+impl ElfError {
+    pub fn invalid_value(
+        offset: u64,
+        structure: &'static str,
+        field: &'static str,
+        expected: impl Into<String>,
+        actual: impl Into<u64>,
+    ) -> Self {
+        ElfError {
+            offset,
+            structure,
+            field,
+            kind: ElfErrorKind::InvalidValue {
+                expected: expected.into(),
+                actual: actual.into(),
+            },
+        }
+    }
+
+    // the offset is where the structure starts, and the field is the
+    // structure itself
+    pub fn truncated(offset: u64, structure: &'static str, expected: usize, actual: usize) -> Self {
+        ElfError {
+            offset,
+            structure,
+            field: structure,
+            kind: ElfErrorKind::Truncated {
+                expected: expected as u64,
+                actual: actual as u64,
+            },
+        }
+    }
+
+    // a table (or any other range of the file) that doesnt fit in it. The
+    // offset and field are the ones of the value that points to the table
+    // (e_shoff, sh_offset...), start and size are the ones of the table
+    pub fn out_of_bounds(
+        offset: u64,
+        structure: &'static str,
+        field: &'static str,
+        (start, size): (u64, u64),
+        file_size: usize,
+    ) -> Self {
+        ElfError {
+            offset,
+            structure,
+            field,
+            kind: ElfErrorKind::OutOfBounds {
+                start,
+                size,
+                file_size: file_size as u64,
+            },
+        }
+    }
+
+    // the compressed data of a section (that starts at offset) is broken, or
+    // uses something that this build cannot decompress
+    pub fn decompression(offset: u64, structure: &'static str, reason: impl Into<String>) -> Self {
+        ElfError {
+            offset,
+            structure,
+            field: structure,
+            kind: ElfErrorKind::Decompression(reason.into()),
+        }
+    }
+
+    // the entries are parsed from their own bytes, so their offsets are
+    // relative to the start of the entry until the table moves them to
+    // where the entry really is
+    pub fn at(mut self, base: u64) -> Self {
+        self.offset = self.offset.wrapping_add(base);
+        self
+    }
+}
+
+impl fmt::Display for ElfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            // the file could not even be read, there is no offset to show
+            ElfErrorKind::Io(err) => write!(f, "{err}"),
+            kind => write!(f, "at offset {:#x} ({}): {kind}", self.offset, self.field),
+        }
+    }
+}
+
+impl std::error::Error for ElfError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            ElfErrorKind::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ElfError {
+    fn from(err: io::Error) -> Self {
+        ElfError {
+            offset: 0,
+            structure: "",
+            field: "",
+            kind: ElfErrorKind::Io(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ElfError, ElfErrorKind};
+
+    #[test]
+    fn display_shows_the_offset_and_the_field() {
+        let err = ElfError::invalid_value(0x12, "Elf64_Ehdr", "e_machine", "a machine", 0xbeefu16);
+
+        assert_eq!(
+            err.to_string(),
+            "at offset 0x12 (e_machine): expected a machine, found 0xbeef"
+        );
+    }
+
+    #[test]
+    fn at_moves_the_offset_of_an_entry_to_the_file() {
+        let err = ElfError::truncated(0, "Elf64_Sym", 24, 10).at(0x400);
+
+        assert_eq!(err.offset, 0x400);
+        assert!(matches!(
+            err.kind,
+            ElfErrorKind::Truncated {
+                expected: 24,
+                actual: 10
+            }
+        ));
+        assert_eq!(
+            err.to_string(),
+            "at offset 0x400 (Elf64_Sym): expected 24 bytes, but only 10 are left"
+        );
+    }
+}
@@ -0,0 +1,857 @@
+use crate::elf::class::{ClassParseable, ElfClass};
+use crate::elf::compress::{compression_header, decompress_section};
+use crate::elf::def::elf64fields::Elf64_Versym;
+use crate::elf::def::elf64strc::{Elf64_Chdr, Elf64_Ehdr, Elf64_Phdr, Elf64_Shdr};
+use crate::elf::def::elfconsts::*;
+use crate::elf::dynamic::{Elf64Dynamic, dynamic_strings, parse_dynamic_section};
+use crate::elf::error::ElfError;
+use crate::elf::hash::{Elf64GnuHash, Elf64SysvHash, parse_gnu_hash, parse_sysv_hash};
+use crate::elf::note::{
+    Elf64Note, Elf64NoteArea, note_areas, parse_notes, parse_notes_until_error,
+};
+use crate::elf::relocate::{RelocationTarget, apply_relocations};
+use crate::elf::relocation::{
+    Elf64Relocations, is_relocation_section, linked_symbols, parse_relocations,
+};
+use crate::elf::section::{
+    Elf64Section, first_section_header, parse_sections, section_count, section_data,
+    string_table_index,
+};
+use crate::elf::segment::{interpreter, parse_program_headers, segment_count};
+use crate::elf::symbol::{Elf64Symbol, parse_symbols, symbol_section_indices, symbol_strings};
+use crate::elf::version::{
+    Elf64SymbolVersions, Elf64VersionDefinition, Elf64VersionNeed, parse_version_definitions,
+    parse_version_needs, parse_version_symbols,
+};
+use crate::utils::parser::Parseable;
+use std::borrow::Cow;
+use std::cell::{OnceCell, RefCell};
+use std::path::Path;
+
+// the entry point of the library: an ELF file (of any class and endianness)
+// with its bytes. Only the header is parsed up front, the tables are parsed
+// the first time that they are asked for, and the ones that are used by the
+// others (sections, segments and the dynamic section) are kept around
+pub struct ElfFile<'data> {
+    bytes: Cow<'data, [u8]>,
+    header: Elf64_Ehdr,
+    mode: ParseMode,
+    // the problems that the lenient mode went past, see take_warnings
+    warnings: RefCell<Vec<ElfError>>,
+    sections: OnceCell<Vec<Elf64Section>>,
+    segments: OnceCell<Vec<Elf64_Phdr>>,
+    dynamic: OnceCell<Option<Elf64Dynamic>>,
+}
+
+// what to do with a broken file. Strict stops at the first problem, like
+// the rest of the parsers. Lenient (for corrupted and hand made files) only
+// gives up when the file is not ELF at all or its header cannot be read:
+// everything else becomes a warning, and the broken table is read up to
+// where it is still good (or left empty), like GNU readelf does
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParseMode {
+    #[default]
+    Strict,
+    Lenient,
+}
+
+// OnceCell::get_or_try_init is still unstable, so the value is only stored
+// when the parsing worked, and a broken table is an error every time
+fn get_or_try_init<T>(
+    cell: &OnceCell<T>,
+    init: impl FnOnce() -> Result<T, ElfError>,
+) -> Result<&T, ElfError> {
+    if let Some(value) = cell.get() {
+        return Ok(value);
+    }
+
+    let value = init()?;
+    Ok(cell.get_or_init(|| value))
+}
+
+impl<'data> ElfFile<'data> {
+    // borrows the bytes, that must live as long as the file
+    pub fn parse(bytes: &'data [u8]) -> Result<Self, ElfError> {
+        Self::parse_with(bytes, ParseMode::Strict)
+    }
+
+    pub fn parse_with(bytes: &'data [u8], mode: ParseMode) -> Result<Self, ElfError> {
+        Self::new(Cow::Borrowed(bytes), mode)
+    }
+
+    fn new(bytes: Cow<'data, [u8]>, mode: ParseMode) -> Result<Self, ElfError> {
+        let (header, warnings) = match mode {
+            ParseMode::Strict => (Elf64_Ehdr::parse(&bytes)?, Vec::new()),
+            ParseMode::Lenient => Elf64_Ehdr::parse_lenient(&bytes)?,
+        };
+
+        Ok(ElfFile {
+            bytes,
+            header,
+            mode,
+            warnings: RefCell::new(warnings),
+            sections: OnceCell::new(),
+            segments: OnceCell::new(),
+            dynamic: OnceCell::new(),
+        })
+    }
+
+    // the warnings found since the last call, in the order that they were
+    // found. The tables are parsed lazily, so new ones can show up after
+    // each table is asked for. Always empty in the strict mode
+    pub fn take_warnings(&self) -> Vec<ElfError> {
+        self.warnings.take()
+    }
+
+    // in the lenient mode the error is kept as a warning and the caller goes
+    // on with whatever it can, in the strict mode it is just returned
+    fn recover(&self, err: ElfError) -> Result<(), ElfError> {
+        match self.mode {
+            ParseMode::Strict => Err(err),
+            ParseMode::Lenient => {
+                self.warnings.borrow_mut().push(err);
+                Ok(())
+            }
+        }
+    }
+
+    // the number of entries of a table at offset that are in the file, as
+    // many as a count of the header can have
+    fn entries_in_file(&self, offset: u64, entsize: u16) -> usize {
+        let left = (self.bytes.len() as u64).saturating_sub(offset);
+        (left / entsize.max(1) as u64).min(u16::MAX as u64) as usize
+    }
+
+    // a copy of the section that stops at the end of the file
+    fn clamped(&self, section: &Elf64Section) -> Elf64Section {
+        let size = self.bytes.len() as u64;
+        let mut section = section.clone();
+
+        section.header.sh_offset = section.header.sh_offset.min(size);
+        section.header.sh_size = section.header.sh_size.min(size - section.header.sh_offset);
+        section
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn header(&self) -> &Elf64_Ehdr {
+        &self.header
+    }
+
+    pub fn class(&self) -> ElfClass {
+        self.header.class()
+    }
+
+    pub fn is_little_endian(&self) -> bool {
+        self.header.is_little_endian()
+    }
+
+    pub fn sections(&self) -> Result<&[Elf64Section], ElfError> {
+        get_or_try_init(&self.sections, || {
+            parse_sections(&self.bytes, &self.header).or_else(|err| {
+                self.recover(err)?;
+
+                // the entries before the end of the file are still read
+                let count = section_count(&self.bytes, &self.header).unwrap_or_default();
+                let header = Elf64_Ehdr {
+                    e_shnum: count
+                        .min(self.entries_in_file(self.header.e_shoff, self.header.e_shentsize))
+                        as u16,
+                    ..self.header
+                };
+                Ok(parse_sections(&self.bytes, &header).unwrap_or_default())
+            })
+        })
+        .map(Vec::as_slice)
+    }
+
+    // the real number of sections, segments and index of the section names,
+    // even when they do not fit in the header (see first_section_header)
+    pub fn section_count(&self) -> Result<usize, ElfError> {
+        section_count(&self.bytes, &self.header)
+    }
+
+    pub fn string_table_index(&self) -> Result<u32, ElfError> {
+        string_table_index(&self.bytes, &self.header)
+    }
+
+    pub fn segment_count(&self) -> Result<usize, ElfError> {
+        segment_count(&self.bytes, &self.header)
+    }
+
+    // the section 0, where those values are when they dont fit in the header
+    pub fn first_section_header(&self) -> Result<Option<Elf64_Shdr>, ElfError> {
+        first_section_header(&self.bytes, &self.header)
+    }
+
+    pub fn section_by_name(&self, name: &str) -> Result<Option<&Elf64Section>, ElfError> {
+        Ok(self.sections()?.iter().find(|section| section.name == name))
+    }
+
+    // None when the section runs past the end of the file
+    pub fn section_data(&self, section: &Elf64Section) -> Option<&[u8]> {
+        section_data(&self.bytes, &section.header)
+    }
+
+    // the same of section_data, but a section that runs past the end of the
+    // file is an error of its sh_offset
+    fn checked_section_data(&self, section: &Elf64Section) -> Result<&[u8], ElfError> {
+        self.section_data(section).ok_or_else(|| {
+            ElfError::out_of_bounds(
+                section.header.sh_offset,
+                Elf64_Shdr::name_of(self.class()),
+                "sh_offset",
+                (section.header.sh_offset, section.header.sh_size),
+                self.bytes.len(),
+            )
+        })
+    }
+
+    // the Elf*_Chdr at the start of a SHF_COMPRESSED section, None for the
+    // other sections
+    pub fn compression_header(
+        &self,
+        section: &Elf64Section,
+    ) -> Result<Option<Elf64_Chdr>, ElfError> {
+        let data = self.checked_section_data(section)?;
+        compression_header(data, section, self.class(), self.is_little_endian())
+    }
+
+    // the data of the section checked against the end of the file, and
+    // decompressed when decompress is set and it is compressed (with
+    // SHF_COMPRESSED, or a .zdebug_* section)
+    pub fn section_contents(
+        &self,
+        section: &Elf64Section,
+        decompress: bool,
+    ) -> Result<Cow<'_, [u8]>, ElfError> {
+        let data = self.checked_section_data(section)?;
+        if !decompress {
+            return Ok(Cow::Borrowed(data));
+        }
+
+        match decompress_section(data, section, self.class(), self.is_little_endian())? {
+            Some(decompressed) => Ok(Cow::Owned(decompressed)),
+            None => Ok(Cow::Borrowed(data)),
+        }
+    }
+
+    pub fn segments(&self) -> Result<&[Elf64_Phdr], ElfError> {
+        get_or_try_init(&self.segments, || {
+            parse_program_headers(&self.bytes, &self.header).or_else(|err| {
+                self.recover(err)?;
+
+                let count = segment_count(&self.bytes, &self.header).unwrap_or_default();
+                let header = Elf64_Ehdr {
+                    e_phnum: count
+                        .min(self.entries_in_file(self.header.e_phoff, self.header.e_phentsize))
+                        as u16,
+                    ..self.header
+                };
+                Ok(parse_program_headers(&self.bytes, &header).unwrap_or_default())
+            })
+        })
+        .map(Vec::as_slice)
+    }
+
+    // the path of the dynamic loader, from the PT_INTERP segment
+    pub fn interpreter(&self) -> Result<Option<Cow<'_, str>>, ElfError> {
+        Ok(self
+            .segments()?
+            .iter()
+            .find_map(|phdr| interpreter(&self.bytes, phdr)))
+    }
+
+    // the symbols of a SHT_SYMTAB or SHT_DYNSYM section
+    pub fn symbols(&self, table: &Elf64Section) -> Result<Vec<Elf64Symbol>, ElfError> {
+        let class = self.class();
+        let is_little_endian = self.is_little_endian();
+
+        // without the strings the symbols are still shown, with no names
+        let strtab = match symbol_strings(&self.bytes, self.sections()?, table, class) {
+            Ok(strtab) => strtab,
+            Err(err) => {
+                self.recover(err)?;
+                &[]
+            }
+        };
+
+        let indices = symbol_section_indices(&self.bytes, self.sections()?, table);
+
+        let mut symbols =
+            match parse_symbols(&self.bytes, table, strtab, indices, class, is_little_endian) {
+                Ok(symbols) => symbols,
+                Err(err) => {
+                    self.recover(err)?;
+
+                    let table = self.clamped(table);
+                    parse_symbols(&self.bytes, &table, strtab, indices, class, is_little_endian)
+                        .unwrap_or_default()
+                }
+            };
+
+        if table.header.sh_type == SHT_DYNSYM {
+            self.add_versions(&mut symbols)?;
+        }
+
+        Ok(symbols)
+    }
+
+    // only the dynamic symbols have versions, see Elf64SymbolVersions
+    fn add_versions(&self, symbols: &mut [Elf64Symbol]) -> Result<(), ElfError> {
+        let Some(versions) = self.symbol_versions()? else {
+            return Ok(());
+        };
+
+        for (index, symbol) in symbols.iter_mut().enumerate() {
+            symbol.version = versions.symbol_version(index, symbol);
+        }
+
+        Ok(())
+    }
+
+    // the symbols of .symtab, None when the file was stripped
+    pub fn static_symbols(&self) -> Result<Option<Vec<Elf64Symbol>>, ElfError> {
+        self.symbols_of_type(SHT_SYMTAB)
+    }
+
+    // the symbols of .dynsym, None for the files that are not dynamic
+    pub fn dynamic_symbols(&self) -> Result<Option<Vec<Elf64Symbol>>, ElfError> {
+        self.symbols_of_type(SHT_DYNSYM)
+    }
+
+    fn symbols_of_type(&self, sh_type: u32) -> Result<Option<Vec<Elf64Symbol>>, ElfError> {
+        let table = self.section_of_type(sh_type)?;
+
+        table.map(|table| self.symbols(table)).transpose()
+    }
+
+    // the version index of each symbol of .dynsym, from the SHT_GNU_versym
+    // section
+    pub fn version_symbols(&self, section: &Elf64Section) -> Result<Vec<Elf64_Versym>, ElfError> {
+        parse_version_symbols(&self.bytes, section, self.is_little_endian())
+            .or_else(|err| self.recover(err).map(|_| Vec::new()))
+    }
+
+    // the versions defined by a SHT_GNU_verdef section
+    pub fn version_definitions(
+        &self,
+        section: &Elf64Section,
+    ) -> Result<Vec<Elf64VersionDefinition>, ElfError> {
+        let definitions = parse_version_definitions(
+            &self.bytes,
+            self.sections()?,
+            section,
+            self.is_little_endian(),
+        );
+
+        definitions.or_else(|err| self.recover(err).map(|_| Vec::new()))
+    }
+
+    // the versions needed by a SHT_GNU_verneed section
+    pub fn version_needs(&self, section: &Elf64Section) -> Result<Vec<Elf64VersionNeed>, ElfError> {
+        let needs =
+            parse_version_needs(&self.bytes, self.sections()?, section, self.is_little_endian());
+
+        needs.or_else(|err| self.recover(err).map(|_| Vec::new()))
+    }
+
+    // the three version sections together, None for the files without
+    // .gnu.version (the ones without versions at all)
+    pub fn symbol_versions(&self) -> Result<Option<Elf64SymbolVersions>, ElfError> {
+        let sections = self.sections()?;
+        let find = |sh_type: u32| sections.iter().find(|section| section.header.sh_type == sh_type);
+
+        let Some(versym) = find(SHT_GNU_versym) else {
+            return Ok(None);
+        };
+
+        let mut versions = Elf64SymbolVersions {
+            versym: self.version_symbols(versym)?,
+            ..Default::default()
+        };
+        if let Some(verdef) = find(SHT_GNU_verdef) {
+            versions.definitions = self.version_definitions(verdef)?;
+        }
+        if let Some(verneed) = find(SHT_GNU_verneed) {
+            versions.needs = self.version_needs(verneed)?;
+        }
+
+        Ok(Some(versions))
+    }
+
+    // the SysV hash table (.hash), None for the files without one
+    pub fn sysv_hash(&self) -> Result<Option<Elf64SysvHash>, ElfError> {
+        let Some(section) = self.section_of_type(SHT_HASH)? else {
+            return Ok(None);
+        };
+
+        let hash = parse_sysv_hash(&self.bytes, section, self.is_little_endian());
+        hash.map(Some).or_else(|err| self.recover(err).map(|_| None))
+    }
+
+    // the GNU hash table (.gnu.hash), None for the files without one
+    pub fn gnu_hash(&self) -> Result<Option<Elf64GnuHash>, ElfError> {
+        let Some(section) = self.section_of_type(SHT_GNU_HASH)? else {
+            return Ok(None);
+        };
+
+        let hash = parse_gnu_hash(&self.bytes, section, self.class(), self.is_little_endian());
+        hash.map(Some).or_else(|err| self.recover(err).map(|_| None))
+    }
+
+    // the index in .dynsym of the symbol that ld.so finds for the name (and
+    // version, when one is asked for), through .gnu.hash when the file has
+    // it, like ld.so does, or else .hash. None when it is not found, so a
+    // symbol of .dynsym that its own name does not find means that the
+    // hash table and the symbols disagree
+    pub fn lookup_dynamic_symbol(
+        &self,
+        name: &str,
+        version: Option<&str>,
+    ) -> Result<Option<usize>, ElfError> {
+        let Some(symbols) = self.dynamic_symbols()? else {
+            return Ok(None);
+        };
+
+        if let Some(hash) = self.gnu_hash()? {
+            return Ok(hash.lookup(name, version, &symbols));
+        }
+
+        Ok(self
+            .sysv_hash()?
+            .and_then(|hash| hash.lookup(name, version, &symbols)))
+    }
+
+    fn section_of_type(&self, sh_type: u32) -> Result<Option<&Elf64Section>, ElfError> {
+        Ok(self
+            .sections()?
+            .iter()
+            .find(|section| section.header.sh_type == sh_type))
+    }
+
+    // every SHT_REL, SHT_RELA and SHT_RELR section
+    pub fn relocation_sections(&self) -> Result<Vec<&Elf64Section>, ElfError> {
+        Ok(self
+            .sections()?
+            .iter()
+            .filter(|section| is_relocation_section(section))
+            .collect())
+    }
+
+    pub fn relocations(&self, section: &Elf64Section) -> Result<Elf64Relocations, ElfError> {
+        parse_relocations(&self.bytes, section, self.class(), self.is_little_endian()).or_else(
+            |err| {
+                self.recover(err)?;
+
+                let section = self.clamped(section);
+                parse_relocations(&self.bytes, &section, self.class(), self.is_little_endian())
+            },
+        )
+    }
+
+    // the symbols that the relocations of the section point to (by r_sym),
+    // None for the sections that dont use any
+    pub fn relocation_symbols(
+        &self,
+        section: &Elf64Section,
+    ) -> Result<Option<Vec<Elf64Symbol>>, ElfError> {
+        let symbols = linked_symbols(
+            &self.bytes,
+            self.sections()?,
+            section,
+            self.class(),
+            self.is_little_endian(),
+        );
+
+        let mut symbols = symbols.or_else(|err| self.recover(err).map(|_| None))?;
+
+        // the relocations of a dynamic file use .dynsym, with its versions
+        let link = section.header.sh_link as usize;
+        if let Some(symbols) = &mut symbols
+            && self.sections()?[link].header.sh_type == SHT_DYNSYM
+        {
+            self.add_versions(symbols)?;
+        }
+
+        Ok(symbols)
+    }
+
+    // the SHT_REL and SHT_RELA sections with relocations for the section at
+    // index (in their sh_info)
+    pub fn relocations_for(&self, index: usize) -> Result<Vec<&Elf64Section>, ElfError> {
+        let sections = self.sections()?;
+
+        Ok(sections
+            .iter()
+            .filter(|section| matches!(section.header.sh_type, SHT_REL | SHT_RELA))
+            .filter(|section| section.header.sh_info as usize == index)
+            .filter(|section| section.header.sh_size != 0)
+            .filter(|section| (section.header.sh_link as usize) < sections.len())
+            .collect())
+    }
+
+    // the bytes of the section at index with its relocations applied, and
+    // the relocations that could not be. Only the relocatable files (ET_REL)
+    // have relocations to apply, the others are already where they will be
+    // loaded and their bytes are returned as they are. The relocations are
+    // for the decompressed data, so decompress should be set for the
+    // compressed sections. An index past the sections is an error of e_shnum
+    pub fn relocated_data(
+        &self,
+        index: usize,
+        decompress: bool,
+    ) -> Result<(Vec<u8>, Vec<ElfError>), ElfError> {
+        let sections = self.sections()?;
+        let section = sections.get(index).ok_or_else(|| {
+            ElfError::invalid_value(
+                self.header.field_offset("e_shnum"),
+                self.header.structure(),
+                "e_shnum",
+                format!("an index below {}", sections.len()),
+                index as u64,
+            )
+        })?;
+        let mut data = self.section_contents(section, decompress)?.into_owned();
+
+        let mut skipped = Vec::new();
+        if self.header.e_type as u32 != ET_REL {
+            return Ok((data, skipped));
+        }
+
+        let mut target = RelocationTarget {
+            data: &mut data,
+            e_machine: self.header.e_machine,
+            class: self.class(),
+            is_little_endian: self.is_little_endian(),
+        };
+
+        for relocation_section in self.relocations_for(index)? {
+            // the relocations without symbols are not for the dumps
+            let Some(symbols) = self.relocation_symbols(relocation_section)? else {
+                continue;
+            };
+            let relocations = self.relocations(relocation_section)?;
+
+            skipped.extend(apply_relocations(
+                &mut target,
+                relocation_section,
+                &relocations,
+                &symbols,
+            ));
+        }
+
+        Ok((data, skipped))
+    }
+
+    // None for the files without a dynamic section
+    pub fn dynamic(&self) -> Result<Option<&Elf64Dynamic>, ElfError> {
+        get_or_try_init(&self.dynamic, || {
+            let dynamic = parse_dynamic_section(
+                &self.bytes,
+                &self.header,
+                self.segments()?,
+                self.sections()?,
+            );
+
+            dynamic.or_else(|err| self.recover(err).map(|_| None))
+        })
+        .map(Option::as_ref)
+    }
+
+    // the string table used by the names of the dynamic entries (DT_NEEDED,
+    // DT_SONAME...), see dynamic::dynamic_name
+    pub fn dynamic_strings(&self) -> Result<Option<&[u8]>, ElfError> {
+        let Some(dynamic) = self.dynamic()? else {
+            return Ok(None);
+        };
+
+        Ok(dynamic_strings(
+            &self.bytes,
+            self.segments()?,
+            &dynamic.entries,
+        ))
+    }
+
+    pub fn note_areas(&self) -> Result<Vec<Elf64NoteArea>, ElfError> {
+        Ok(note_areas(&self.header, self.sections()?, self.segments()?))
+    }
+
+    pub fn notes(&self, area: &Elf64NoteArea) -> Result<Vec<Elf64Note>, ElfError> {
+        if self.mode == ParseMode::Strict {
+            return parse_notes(&self.bytes, area, self.is_little_endian());
+        }
+
+        // the notes before a broken one are still shown
+        let (notes, err) = parse_notes_until_error(&self.bytes, area, self.is_little_endian());
+        if let Some(err) = err {
+            self.recover(err)?;
+        }
+
+        Ok(notes)
+    }
+}
+
+impl ElfFile<'static> {
+    // takes the bytes, for when there is nothing to borrow them from
+    pub fn from_vec(bytes: Vec<u8>) -> Result<Self, ElfError> {
+        Self::from_vec_with(bytes, ParseMode::Strict)
+    }
+
+    pub fn from_vec_with(bytes: Vec<u8>, mode: ParseMode) -> Result<Self, ElfError> {
+        Self::new(Cow::Owned(bytes), mode)
+    }
+
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, ElfError> {
+        Self::open_with(path, ParseMode::Strict)
+    }
+
+    pub fn open_with(path: impl AsRef<Path>, mode: ParseMode) -> Result<Self, ElfError> {
+        Self::from_vec_with(std::fs::read(path)?, mode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ElfFile, ParseMode};
+    use crate::elf::class::ElfClass;
+    use crate::elf::error::{ElfError, ElfErrorKind};
+    use crate::elf::relocation::Elf64Relocations;
+
+    // a little endian ET_REL with .text, .rela.text, .symtab, .strtab and
+    // .shstrtab, in the layout of the class
+    fn create_file(is_64bit: bool) -> Vec<u8> {
+        let (ehsize, shentsize, symsize, relasize) = if is_64bit {
+            (64, 64, 24, 24)
+        } else {
+            (52, 40, 16, 12)
+        };
+        let word = |value: u64, bytes: &mut Vec<u8>| {
+            if is_64bit {
+                bytes.extend_from_slice(&value.to_le_bytes());
+            } else {
+                bytes.extend_from_slice(&(value as u32).to_le_bytes());
+            }
+        };
+
+        let shstrtab = b"\0.text\0.rela.text\0.symtab\0.strtab\0.shstrtab\0";
+        let strtab = b"\0main\0";
+        let text = [0xc3u8; 16];
+
+        // the null symbol and main, a global function in .text
+        let mut symtab = vec![0u8; symsize];
+        symtab.extend_from_slice(&1u32.to_le_bytes());
+        if is_64bit {
+            symtab.extend_from_slice(&[0x12, 0]);
+            symtab.extend_from_slice(&1u16.to_le_bytes());
+            word(0x10, &mut symtab);
+            word(16, &mut symtab);
+        } else {
+            word(0x10, &mut symtab);
+            word(16, &mut symtab);
+            symtab.extend_from_slice(&[0x12, 0]);
+            symtab.extend_from_slice(&1u16.to_le_bytes());
+        }
+
+        // one relocation against main, with type 2 and addend -4
+        let mut rela = Vec::new();
+        word(4, &mut rela);
+        if is_64bit {
+            word((1 << 32) | 2, &mut rela);
+        } else {
+            word((1 << 8) | 2, &mut rela);
+        }
+        word(-4i64 as u64, &mut rela);
+
+        let mut data = vec![0u8; ehsize];
+        let mut offsets = Vec::new();
+        for part in [&text[..], &rela, &symtab, strtab, shstrtab] {
+            offsets.push(data.len() as u64);
+            data.extend_from_slice(part);
+        }
+        while data.len() % 8 != 0 {
+            data.push(0);
+        }
+        let shoff = data.len() as u64;
+
+        // name, type, flags, addr, offset, size, link, info, align, entsize
+        let sections = [
+            (0, 0, 0, 0, 0, 0, 0, 0),
+            (1, 1, 6, offsets[0], text.len(), 0, 0, 0),
+            (7, 4, 0x40, offsets[1], rela.len(), 3, 1, relasize),
+            (18, 2, 0, offsets[2], symtab.len(), 4, 1, symsize),
+            (26, 3, 0, offsets[3], strtab.len(), 0, 0, 0),
+            (34, 3, 0, offsets[4], shstrtab.len(), 0, 0, 0),
+        ];
+        for (name, sh_type, flags, offset, size, link, info, entsize) in sections {
+            data.extend_from_slice(&(name as u32).to_le_bytes());
+            data.extend_from_slice(&(sh_type as u32).to_le_bytes());
+            word(flags, &mut data);
+            word(0, &mut data);
+            word(offset, &mut data);
+            word(size as u64, &mut data);
+            data.extend_from_slice(&(link as u32).to_le_bytes());
+            data.extend_from_slice(&(info as u32).to_le_bytes());
+            word(1, &mut data);
+            word(entsize as u64, &mut data);
+        }
+
+        data[0..4].copy_from_slice(&[0x7f, 0x45, 0x4c, 0x46]);
+        data[4] = if is_64bit { 2 } else { 1 };
+        data[5] = 1;
+        data[6] = 1;
+        data[16..18].copy_from_slice(&1u16.to_le_bytes()); // ET_REL
+        data[18..20].copy_from_slice(&62u16.to_le_bytes());
+        data[20..24].copy_from_slice(&1u32.to_le_bytes());
+        let tail = if is_64bit {
+            data[40..48].copy_from_slice(&shoff.to_le_bytes());
+            52
+        } else {
+            data[32..36].copy_from_slice(&(shoff as u32).to_le_bytes());
+            40
+        };
+        data[tail..tail + 2].copy_from_slice(&(ehsize as u16).to_le_bytes());
+        data[tail + 6..tail + 8].copy_from_slice(&(shentsize as u16).to_le_bytes());
+        data[tail + 8..tail + 10].copy_from_slice(&6u16.to_le_bytes());
+        data[tail + 10..tail + 12].copy_from_slice(&5u16.to_le_bytes());
+
+        data
+    }
+
+    #[test]
+    fn parse_returns_the_same_tables_for_both_classes() {
+        for is_64bit in [true, false] {
+            let bytes = create_file(is_64bit);
+            let file = ElfFile::parse(&bytes).unwrap();
+
+            let expected = if is_64bit {
+                ElfClass::Elf64
+            } else {
+                ElfClass::Elf32
+            };
+            assert_eq!(file.class(), expected);
+
+            let names: Vec<&str> = file
+                .sections()
+                .unwrap()
+                .iter()
+                .map(|section| section.name.as_str())
+                .collect();
+            assert_eq!(
+                names,
+                ["", ".text", ".rela.text", ".symtab", ".strtab", ".shstrtab"]
+            );
+
+            let symbols = file.static_symbols().unwrap().unwrap();
+            assert_eq!(symbols[1].name.as_deref(), Some("main"));
+            assert_eq!(symbols[1].sym.st_value, 0x10);
+            assert!(file.dynamic_symbols().unwrap().is_none());
+        }
+    }
+
+    #[test]
+    fn relocations_are_read_with_their_symbols() {
+        for is_64bit in [true, false] {
+            let bytes = create_file(is_64bit);
+            let file = ElfFile::parse(&bytes).unwrap();
+
+            let sections = file.relocation_sections().unwrap();
+            assert_eq!(sections.len(), 1);
+
+            let Elf64Relocations::Rela(entries) = file.relocations(sections[0]).unwrap() else {
+                panic!("expected RELA relocations");
+            };
+            assert_eq!(entries[0].r_offset, 4);
+            assert_eq!(entries[0].r_info, (1 << 32) | 2);
+            assert_eq!(entries[0].r_addend, -4);
+
+            let symbols = file.relocation_symbols(sections[0]).unwrap().unwrap();
+            assert_eq!(symbols[1].name.as_deref(), Some("main"));
+        }
+    }
+
+    #[test]
+    fn relocated_data_returns_invalid_value_err_for_a_missing_section() {
+        let bytes = create_file(true);
+        let file = ElfFile::parse(&bytes).unwrap();
+        let count = file.sections().unwrap().len();
+
+        let err = file.relocated_data(count, false).unwrap_err();
+
+        assert!(matches!(
+            &err,
+            ElfError {
+                offset: 0x3c,
+                field: "e_shnum",
+                kind: ElfErrorKind::InvalidValue { actual, .. },
+                ..
+            } if *actual == count as u64
+        ));
+        assert!(err.to_string().contains(&format!("an index below {count}")));
+    }
+
+    #[test]
+    fn from_vec_owns_the_bytes() {
+        let file = ElfFile::from_vec(create_file(true)).unwrap();
+
+        assert!(file.segments().unwrap().is_empty());
+        assert!(file.dynamic().unwrap().is_none());
+        assert!(file.section_by_name(".text").unwrap().is_some());
+    }
+
+    #[test]
+    fn parse_returns_err_for_non_elf_files() {
+        assert!(ElfFile::parse(b"#!/bin/sh\n").is_err());
+    }
+
+    #[test]
+    fn lenient_mode_warns_about_the_problems_and_goes_on() {
+        let mut bytes = create_file(true);
+        bytes[9] = 0xff; // garbage in the padding
+        let shoff = u64::from_le_bytes(bytes[40..48].try_into().unwrap()) as usize;
+        bytes[shoff + 3 * 64 + 40] = 9; // .symtab links to a section that doesnt exist
+
+        assert!(ElfFile::parse(&bytes).is_err());
+
+        let file = ElfFile::parse_with(&bytes, ParseMode::Lenient).unwrap();
+        let symbols = file.static_symbols().unwrap().unwrap();
+
+        assert_eq!(symbols.len(), 2);
+        assert_eq!(symbols[1].name, None);
+        assert!(matches!(
+            file.take_warnings().as_slice(),
+            [
+                ElfError {
+                    offset: 9,
+                    field: "EI_PAD",
+                    ..
+                },
+                ElfError {
+                    field: "sh_link",
+                    ..
+                }
+            ]
+        ));
+        assert!(file.take_warnings().is_empty());
+    }
+
+    #[test]
+    fn lenient_mode_reads_the_sections_before_the_end_of_the_file() {
+        let mut bytes = create_file(true);
+        let shoff = u64::from_le_bytes(bytes[40..48].try_into().unwrap()) as usize;
+        bytes.truncate(shoff + 3 * 64 + 10);
+
+        let file = ElfFile::parse_with(&bytes, ParseMode::Lenient).unwrap();
+
+        assert_eq!(file.sections().unwrap().len(), 3);
+        assert!(matches!(
+            file.take_warnings().as_slice(),
+            [ElfError {
+                field: "e_shoff",
+                ..
+            }]
+        ));
+    }
+}
@@ -0,0 +1,334 @@
+use crate::elf::def::elfconsts::*;
+
+// the e_flags decoders of each machine. Every one of them returns the text
+// shown after the hex value in the file header, with the same names (and in
+// the same order) used by binutils, each part starting with ", "
+
+fn push(out: &mut String, text: &str) {
+    out.push_str(", ");
+    out.push_str(text);
+}
+
+// the EABI version is in the highest byte, and the meaning of the other bits
+// changes with it. Bits that we dont know are shown only once as <unknown>
+pub fn arm_flags(e_flags: u32) -> String {
+    let mut out = String::new();
+    let eabi = e_flags & EF_ARM_EABIMASK;
+    let mut flags = e_flags & !EF_ARM_EABIMASK;
+    let mut unknown = false;
+
+    // these two are the same for every version
+    if flags & EF_ARM_RELEXEC != 0 {
+        push(&mut out, "relocatable executable");
+        flags &= !EF_ARM_RELEXEC;
+    }
+
+    if flags & EF_ARM_PIC != 0 {
+        push(&mut out, "position independent");
+        flags &= !EF_ARM_PIC;
+    }
+
+    // the known names of each version, the bits are checked from the lowest
+    // to the highest one
+    let names: &[(u32, &str)] = match eabi {
+        EF_ARM_EABI_UNKNOWN => &[
+            (EF_ARM_INTERWORK, "interworking enabled"),
+            (EF_ARM_APCS_26, "uses APCS/26"),
+            (EF_ARM_APCS_FLOAT, "uses APCS/float"),
+            (EF_ARM_ALIGN8, "8 bit structure alignment"),
+            (EF_ARM_NEW_ABI, "uses new ABI"),
+            (EF_ARM_OLD_ABI, "uses old ABI"),
+            (EF_ARM_SOFT_FLOAT, "software FP"),
+            (EF_ARM_VFP_FLOAT, "VFP"),
+            (EF_ARM_MAVERICK_FLOAT, "Maverick FP"),
+        ],
+        EF_ARM_EABI_VER1 => &[(EF_ARM_SYMSARESORTED, "sorted symbol tables")],
+        EF_ARM_EABI_VER2 => &[
+            (EF_ARM_SYMSARESORTED, "sorted symbol tables"),
+            (EF_ARM_DYNSYMSUSESEGIDX, "dynamic symbols use segment index"),
+            (EF_ARM_MAPSYMSFIRST, "mapping symbols precede others"),
+        ],
+        // binutils does not look at the other bits of version 3
+        EF_ARM_EABI_VER3 => {
+            flags = 0;
+            &[]
+        }
+        EF_ARM_EABI_VER4 => &[(EF_ARM_LE8, "LE8"), (EF_ARM_BE8, "BE8")],
+        EF_ARM_EABI_VER5 => &[
+            (EF_ARM_ABI_FLOAT_SOFT, "soft-float ABI"),
+            (EF_ARM_ABI_FLOAT_HARD, "hard-float ABI"),
+            (EF_ARM_LE8, "LE8"),
+            (EF_ARM_BE8, "BE8"),
+        ],
+        _ => {
+            push(&mut out, "<unrecognized EABI>");
+            if flags != 0 {
+                push(&mut out, "<unknown>");
+            }
+            return out;
+        }
+    };
+
+    let version = match eabi {
+        EF_ARM_EABI_UNKNOWN => "GNU EABI".to_string(),
+        _ => format!("Version{} EABI", eabi >> 24),
+    };
+    push(&mut out, &version);
+
+    while flags != 0 {
+        let flag = flags & flags.wrapping_neg();
+        flags &= !flag;
+
+        match names.iter().find(|(bit, _)| *bit == flag) {
+            Some((_, name)) => push(&mut out, name),
+            None => unknown = true,
+        }
+    }
+
+    if unknown {
+        push(&mut out, "<unknown>");
+    }
+
+    out
+}
+
+// the processor (EF_MIPS_MACH) is a GNU extension, so it is only shown when
+// it is set, the ISA level is always shown
+pub fn mips_flags(e_flags: u32) -> String {
+    let mut out = String::new();
+
+    let bits = [
+        (EF_MIPS_NOREORDER, "noreorder"),
+        (EF_MIPS_PIC, "pic"),
+        (EF_MIPS_CPIC, "cpic"),
+        (EF_MIPS_UCODE, "ugen_reserved"),
+        (EF_MIPS_ABI2, "abi2"),
+        (EF_MIPS_OPTIONS_FIRST, "odk first"),
+        (EF_MIPS_32BITMODE, "32bitmode"),
+        (EF_MIPS_NAN2008, "nan2008"),
+        (EF_MIPS_FP64, "fp64"),
+    ];
+    for (bit, name) in bits {
+        if e_flags & bit != 0 {
+            push(&mut out, name);
+        }
+    }
+
+    // the E_MIPS_MACH_* values of binutils include/elf/mips.h
+    let mach = match (e_flags & EF_MIPS_MACH) >> 16 {
+        0x00 => None,
+        0x81 => Some("3900"),
+        0x82 => Some("4010"),
+        0x83 => Some("4100"),
+        0x85 => Some("4650"),
+        0x87 => Some("4120"),
+        0x88 => Some("4111"),
+        0x8a => Some("sb1"),
+        0x8b => Some("octeon"),
+        0x8c => Some("xlr"),
+        0x8d => Some("octeon2"),
+        0x8e => Some("octeon3"),
+        0x91 => Some("5400"),
+        0x92 => Some("5900"),
+        0x93 => Some("interaptiv-mr2"),
+        0x98 => Some("5500"),
+        0x99 => Some("9000"),
+        0xa0 => Some("loongson-2e"),
+        0xa1 => Some("loongson-2f"),
+        0xa2 => Some("gs464"),
+        0xa3 => Some("gs464e"),
+        0xa4 => Some("gs264e"),
+        _ => Some("unknown CPU"),
+    };
+    if let Some(mach) = mach {
+        push(&mut out, mach);
+    }
+
+    // the ABI is a GNU extension too, 0 is probably o32, but not for sure
+    let abi = match e_flags & EF_MIPS_ABI {
+        0 => None,
+        E_MIPS_ABI_O32 => Some("o32"),
+        E_MIPS_ABI_O64 => Some("o64"),
+        E_MIPS_ABI_EABI32 => Some("eabi32"),
+        E_MIPS_ABI_EABI64 => Some("eabi64"),
+        _ => Some("unknown ABI"),
+    };
+    if let Some(abi) = abi {
+        push(&mut out, abi);
+    }
+
+    let ases = [
+        (EF_MIPS_ARCH_ASE_MDMX, "mdmx"),
+        (EF_MIPS_ARCH_ASE_M16, "mips16"),
+        (EF_MIPS_ARCH_ASE_MICROMIPS, "micromips"),
+    ];
+    for (bit, name) in ases {
+        if e_flags & bit != 0 {
+            push(&mut out, name);
+        }
+    }
+
+    let isa = match e_flags & EF_MIPS_ARCH {
+        EF_MIPS_ARCH_1 => "mips1",
+        EF_MIPS_ARCH_2 => "mips2",
+        EF_MIPS_ARCH_3 => "mips3",
+        EF_MIPS_ARCH_4 => "mips4",
+        EF_MIPS_ARCH_5 => "mips5",
+        EF_MIPS_ARCH_32 => "mips32",
+        EF_MIPS_ARCH_32R2 => "mips32r2",
+        EF_MIPS_ARCH_32R6 => "mips32r6",
+        EF_MIPS_ARCH_64 => "mips64",
+        EF_MIPS_ARCH_64R2 => "mips64r2",
+        EF_MIPS_ARCH_64R6 => "mips64r6",
+        _ => "unknown ISA",
+    };
+    push(&mut out, isa);
+
+    out
+}
+
+// 1 is the old ELFv1 ABI (with function descriptors), 2 is ELFv2, and 0 means
+// that the object does not use anything specific of any of them
+pub fn ppc64_flags(e_flags: u32) -> String {
+    match e_flags & EF_PPC64_ABI {
+        0 => String::new(),
+        abi => format!(", abiv{abi}"),
+    }
+}
+
+pub fn riscv_flags(e_flags: u32) -> String {
+    let mut out = String::new();
+
+    if e_flags & EF_RISCV_RVC != 0 {
+        push(&mut out, "RVC");
+    }
+
+    if e_flags & EF_RISCV_RVE != 0 {
+        push(&mut out, "RVE");
+    }
+
+    if e_flags & EF_RISCV_TSO != 0 {
+        push(&mut out, "TSO");
+    }
+
+    let float_abi = match e_flags & EF_RISCV_FLOAT_ABI {
+        EF_RISCV_FLOAT_ABI_SOFT => "soft-float ABI",
+        EF_RISCV_FLOAT_ABI_SINGLE => "single-float ABI",
+        EF_RISCV_FLOAT_ABI_DOUBLE => "double-float ABI",
+        _ => "quad-float ABI",
+    };
+    push(&mut out, float_abi);
+
+    out
+}
+
+pub fn loongarch_flags(e_flags: u32) -> String {
+    let mut out = String::new();
+
+    match e_flags & EF_LARCH_ABI_MODIFIER_MASK {
+        EF_LARCH_ABI_SOFT_FLOAT => push(&mut out, "SOFT-FLOAT"),
+        EF_LARCH_ABI_SINGLE_FLOAT => push(&mut out, "SINGLE-FLOAT"),
+        EF_LARCH_ABI_DOUBLE_FLOAT => push(&mut out, "DOUBLE-FLOAT"),
+        _ => {}
+    }
+
+    match e_flags & EF_LARCH_OBJABI_MASK {
+        EF_LARCH_OBJABI_V0 => push(&mut out, "OBJ-v0"),
+        EF_LARCH_OBJABI_V1 => push(&mut out, "OBJ-v1"),
+        _ => {}
+    }
+
+    out
+}
+
+// the extensions used by the object, followed by the memory model (3 is not
+// a valid model, so nothing is shown for it)
+pub fn sparcv9_flags(e_flags: u32) -> String {
+    let mut out = String::new();
+
+    let extensions = [
+        (EF_SPARC_32PLUS, "v8+"),
+        (EF_SPARC_SUN_US1, "ultrasparcI"),
+        (EF_SPARC_SUN_US3, "ultrasparcIII"),
+        (EF_SPARC_HAL_R1, "halr1"),
+        (EF_SPARC_LEDATA, "ledata"),
+    ];
+    for (bit, name) in extensions {
+        if e_flags & bit != 0 {
+            push(&mut out, name);
+        }
+    }
+
+    match e_flags & EF_SPARCV9_MM {
+        EF_SPARCV9_TSO => push(&mut out, "tso"),
+        EF_SPARCV9_PSO => push(&mut out, "pso"),
+        EF_SPARCV9_RMO => push(&mut out, "rmo"),
+        _ => {}
+    }
+
+    out
+}
+
+// the low 7 bits are the AVR architecture (avr:5 for the atmega ones, avr:6
+// for the ones with more than 128k of flash...), the E_AVR_MACH_* of binutils
+pub fn avr_flags(e_flags: u32) -> String {
+    let mut out = String::new();
+
+    match e_flags & EF_AVR_MACH {
+        mach @ (1..=6 | 25 | 31 | 35 | 51 | 100..=107) => push(&mut out, &format!("avr:{mach}")),
+        _ => push(&mut out, "avr:<unknown>"),
+    }
+
+    if e_flags & EF_AVR_LINKRELAX_PREPARED != 0 {
+        push(&mut out, "link-relax");
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{arm_flags, avr_flags, mips_flags, riscv_flags, sparcv9_flags};
+
+    #[test]
+    fn riscv_flags_returns_the_extensions_and_float_abi() {
+        assert_eq!(riscv_flags(0x5), ", RVC, double-float ABI");
+        assert_eq!(riscv_flags(0x1f), ", RVC, RVE, TSO, quad-float ABI");
+    }
+
+    #[test]
+    fn arm_flags_returns_the_eabi_version_and_its_bits() {
+        assert_eq!(arm_flags(0x5000400), ", Version5 EABI, hard-float ABI");
+        assert_eq!(
+            arm_flags(0x4c00023),
+            ", relocatable executable, position independent, Version4 EABI, LE8, BE8, <unknown>"
+        );
+        assert_eq!(
+            arm_flags(0x6000001),
+            ", relocatable executable, <unrecognized EABI>"
+        );
+        assert_eq!(arm_flags(0x1000), ", GNU EABI, <unknown>");
+    }
+
+    #[test]
+    fn mips_flags_returns_the_isa_level_even_when_it_is_zero() {
+        assert_eq!(
+            mips_flags(0x70001007),
+            ", noreorder, pic, cpic, o32, mips32r2"
+        );
+        assert_eq!(mips_flags(0x400), ", nan2008, mips1");
+        assert_eq!(mips_flags(0xb00f0000), ", unknown CPU, unknown ISA");
+    }
+
+    #[test]
+    fn sparcv9_flags_returns_the_memory_model() {
+        assert_eq!(sparcv9_flags(0x200), ", ultrasparcI, tso");
+        assert_eq!(sparcv9_flags(0x103), ", v8+");
+    }
+
+    #[test]
+    fn avr_flags_returns_unknown_for_unnamed_architectures() {
+        assert_eq!(avr_flags(0x85), ", avr:5, link-relax");
+        assert_eq!(avr_flags(0x7), ", avr:<unknown>");
+    }
+}
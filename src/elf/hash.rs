@@ -0,0 +1,588 @@
+use crate::elf::class::ElfClass;
+use crate::elf::def::elfconsts::*;
+use crate::elf::error::ElfError;
+use crate::elf::section::{Elf64Section, section_data};
+use crate::elf::symbol::Elf64Symbol;
+use crate::elf::version::VersionKind;
+use crate::utils::endianess::EndianRead;
+
+// the dynamic loader finds the symbols of .dynsym by name through a hash
+// table, the SysV one (.hash, SHT_HASH) or the GNU one (.gnu.hash,
+// SHT_GNU_HASH), that is faster and the only one of most files today. Both
+// split the symbols in buckets by the hash of their names, and each bucket
+// is a chain of symbols that is walked until the name is found
+
+// the SysV table is nbucket and nchain, then the buckets with the first
+// symbol of each one and the chains with the next symbol of each symbol
+// (STN_UNDEF at the end). nchain is the number of symbols of .dynsym
+#[derive(Debug, Clone)]
+pub struct Elf64SysvHash {
+    // where the table is in the file, for the errors
+    pub offset: u64,
+    // the entries have 4 bytes, but 8 on the 64-bit Alpha and s390
+    pub entry_size: u64,
+    pub buckets: Vec<u32>,
+    pub chains: Vec<u32>,
+}
+
+// the GNU table has the symbols sorted by bucket, from symoffset on (the ones
+// before it cant be found), so the chains are just the hashes of those
+// symbols, with the lowest bit set on the last one of each bucket. A bloom
+// filter of words of the class says quickly that most names are not there
+#[derive(Debug, Clone)]
+pub struct Elf64GnuHash {
+    pub offset: u64,
+    pub symoffset: u32,
+    pub bloom_shift: u32,
+    // the bits of a bloom word, 32 or 64 like the class
+    pub bloom_bits: u32,
+    pub bloom: Vec<u64>,
+    pub buckets: Vec<u32>,
+    pub chains: Vec<u32>,
+}
+
+// the hash function of the SysV tables, from the System V ABI
+pub fn elf_hash(name: &[u8]) -> u32 {
+    let mut hash = 0u32;
+
+    for &c in name {
+        hash = (hash << 4).wrapping_add(c as u32);
+        let high = hash & 0xf0000000;
+        if high != 0 {
+            hash ^= high >> 24;
+        }
+        hash &= !high;
+    }
+
+    hash
+}
+
+// the one of the GNU tables, Bernstein's djb2 (h * 33 + c)
+pub fn gnu_hash(name: &[u8]) -> u32 {
+    name.iter().fold(5381u32, |hash, &c| {
+        hash.wrapping_mul(33).wrapping_add(c as u32)
+    })
+}
+
+impl Elf64SysvHash {
+    // the index of the symbol that ld.so finds for the name, see is_match
+    pub fn lookup(
+        &self,
+        name: &str,
+        version: Option<&str>,
+        symbols: &[Elf64Symbol],
+    ) -> Option<usize> {
+        if self.buckets.is_empty() {
+            return None;
+        }
+
+        let hash = elf_hash(name.as_bytes());
+        let mut index = self.buckets[(hash % self.buckets.len() as u32) as usize];
+
+        // a chain can not be longer than the table, the ones that are
+        // only have a loop
+        for _ in 0..self.chains.len() {
+            if index == STN_UNDEF {
+                break;
+            }
+
+            if symbols
+                .get(index as usize)
+                .is_some_and(|symbol| is_match(symbol, name, version))
+            {
+                return Some(index as usize);
+            }
+
+            index = *self.chains.get(index as usize)?;
+        }
+
+        None
+    }
+
+    // the number of symbols of each bucket. A chain that goes out of the
+    // table or back to a symbol that was already seen is cut there, and an
+    // error about it is returned with the lengths
+    pub fn chain_lengths(&self) -> (Vec<usize>, Option<ElfError>) {
+        let mut lengths = vec![0; self.buckets.len()];
+        let mut visited = vec![false; self.chains.len()];
+        let mut err = None;
+
+        // the offset of the entry of each index, the bucket or the chain of
+        // the symbol before it
+        let chains = 2 + self.buckets.len() as u64;
+        let entry_offset = |entry: u64| self.offset + self.entry_size * entry;
+
+        for (bucket, &first) in self.buckets.iter().enumerate() {
+            let mut index = first;
+            let mut offset = entry_offset(2 + bucket as u64);
+
+            while index != STN_UNDEF {
+                lengths[bucket] += 1;
+
+                if visited.get(index as usize) != Some(&false) {
+                    err.get_or_insert_with(|| {
+                        ElfError::invalid_value(
+                            offset,
+                            "SHT_HASH",
+                            "chain",
+                            format!(
+                                "the index of a symbol below {} not seen before",
+                                self.chains.len()
+                            ),
+                            index,
+                        )
+                    });
+                    break;
+                }
+
+                visited[index as usize] = true;
+                offset = entry_offset(chains + index as u64);
+                index = self.chains[index as usize];
+            }
+        }
+
+        (lengths, err)
+    }
+}
+
+impl Elf64GnuHash {
+    // the same walk of ld.so (do_lookup_x in dl-lookup.c): the bloom filter,
+    // then the bucket, and the names are only compared when the hashes
+    // (without their lowest bit) are equal
+    pub fn lookup(
+        &self,
+        name: &str,
+        version: Option<&str>,
+        symbols: &[Elf64Symbol],
+    ) -> Option<usize> {
+        let hash = gnu_hash(name.as_bytes());
+
+        // the filter has 2 bits of each name, the size is a power of 2
+        let bits = self.bloom_bits;
+        let word = (hash / bits) & (self.bloom.len() as u32).wrapping_sub(1);
+        let word = *self.bloom.get(word as usize)?;
+        let first = (word >> (hash % bits)) & 1;
+        let second = (word >> ((hash >> self.bloom_shift) % bits)) & 1;
+        if first & second == 0 {
+            return None;
+        }
+
+        if self.buckets.is_empty() {
+            return None;
+        }
+        let bucket = self.buckets[(hash % self.buckets.len() as u32) as usize];
+        if bucket == 0 {
+            return None;
+        }
+
+        let start = bucket.checked_sub(self.symoffset)? as usize;
+        for (i, &chain) in self.chains.iter().enumerate().skip(start) {
+            let index = i + self.symoffset as usize;
+
+            if (chain ^ hash) >> 1 == 0
+                && symbols
+                    .get(index)
+                    .is_some_and(|symbol| is_match(symbol, name, version))
+            {
+                return Some(index);
+            }
+
+            if chain & 1 != 0 {
+                break;
+            }
+        }
+
+        None
+    }
+
+    // the number of symbols of each bucket, a chain without its end bit
+    // stops at the end of the table
+    pub fn chain_lengths(&self) -> Vec<usize> {
+        self.buckets
+            .iter()
+            .map(|&bucket| {
+                if bucket == 0 {
+                    return 0;
+                }
+
+                // like binutils, a bucket out of the chains still has 1
+                let start = bucket.wrapping_sub(self.symoffset) as usize;
+                let rest = self.chains.get(start..).unwrap_or_default();
+                match rest.iter().position(|chain| chain & 1 != 0) {
+                    Some(last) => last + 1,
+                    None => rest.len() + 1,
+                }
+            })
+            .collect()
+    }
+
+    // a table of a file without exported symbols has all its buckets at 0
+    pub fn is_empty(&self) -> bool {
+        self.buckets.iter().all(|&bucket| bucket == 0)
+    }
+}
+
+// the checks of ld.so (check_match in dl-lookup.c) for a symbol in the chain:
+// a symbol without a value is not a definition (unless it is ABS or TLS, with
+// an offset), only the types of code and data are found, and the hidden
+// versions (foo@VERS_1) are only for who asks for them. The undefined
+// symbols with a value are the PLT entries that an executable gives to its
+// functions, and are still found (for the pointers to them)
+fn is_match(symbol: &Elf64Symbol, name: &str, version: Option<&str>) -> bool {
+    let sym = &symbol.sym;
+
+    if sym.st_value == 0 && sym.st_shndx as u32 != SHN_ABS && sym.st_type() != STT_TLS {
+        return false;
+    }
+
+    if !matches!(
+        sym.st_type(),
+        STT_NOTYPE | STT_OBJECT | STT_FUNC | STT_COMMON | STT_TLS | STT_GNU_IFUNC
+    ) {
+        return false;
+    }
+
+    if symbol.name.as_deref() != Some(name) {
+        return false;
+    }
+
+    match (version, &symbol.version) {
+        (Some(version), Some(found)) => found.name == version,
+        (None, Some(found)) => found.kind != VersionKind::Hidden,
+        // the files without versions have any version that is asked for
+        (_, None) => true,
+    }
+}
+
+pub fn parse_sysv_hash(
+    bytes: &[u8],
+    section: &Elf64Section,
+    is_little_endian: bool,
+) -> Result<Elf64SysvHash, ElfError> {
+    let shdr = &section.header;
+    let data = hash_data(bytes, section, "SHT_HASH")?;
+    let entry_size = if shdr.sh_entsize == 8 { 8 } else { 4 };
+
+    let entries: Vec<u32> = data
+        .chunks_exact(entry_size)
+        .map(|entry| match entry_size {
+            8 => u64::read_from(entry, is_little_endian) as u32,
+            _ => u32::read_from(entry, is_little_endian),
+        })
+        .collect();
+
+    let [nbucket, nchain, ..] = entries[..] else {
+        return Err(ElfError::truncated(
+            shdr.sh_offset,
+            "SHT_HASH",
+            2 * entry_size,
+            data.len(),
+        ));
+    };
+
+    let size = 2 + nbucket as usize + nchain as usize;
+    if entries.len() < size {
+        return Err(ElfError::truncated(
+            shdr.sh_offset,
+            "SHT_HASH",
+            size * entry_size,
+            data.len(),
+        ));
+    }
+
+    let (buckets, chains) = entries[2..size].split_at(nbucket as usize);
+
+    Ok(Elf64SysvHash {
+        offset: shdr.sh_offset,
+        entry_size: entry_size as u64,
+        buckets: buckets.to_vec(),
+        chains: chains.to_vec(),
+    })
+}
+
+pub fn parse_gnu_hash(
+    bytes: &[u8],
+    section: &Elf64Section,
+    class: ElfClass,
+    is_little_endian: bool,
+) -> Result<Elf64GnuHash, ElfError> {
+    let shdr = &section.header;
+    let data = hash_data(bytes, section, "SHT_GNU_HASH")?;
+
+    // nbuckets, symoffset, bloom_size and bloom_shift
+    if data.len() < 16 {
+        return Err(ElfError::truncated(
+            shdr.sh_offset,
+            "SHT_GNU_HASH",
+            16,
+            data.len(),
+        ));
+    }
+    let word = |offset: usize| u32::read_from(&data[offset..offset + 4], is_little_endian);
+    let (nbuckets, symoffset, bloom_size, bloom_shift) = (word(0), word(4), word(8), word(12));
+
+    let bloom_bytes = if class.is_64bit() { 8 } else { 4 };
+    let buckets_start = 16 + bloom_size as usize * bloom_bytes;
+    let chains_start = buckets_start + nbuckets as usize * 4;
+    if data.len() < chains_start {
+        return Err(ElfError::truncated(
+            shdr.sh_offset,
+            "SHT_GNU_HASH",
+            chains_start,
+            data.len(),
+        ));
+    }
+
+    let bloom = data[16..buckets_start]
+        .chunks_exact(bloom_bytes)
+        .map(|entry| match bloom_bytes {
+            8 => u64::read_from(entry, is_little_endian),
+            _ => u32::read_from(entry, is_little_endian) as u64,
+        })
+        .collect();
+    let words = |data: &[u8]| -> Vec<u32> {
+        data.chunks_exact(4)
+            .map(|entry| u32::read_from(entry, is_little_endian))
+            .collect()
+    };
+
+    Ok(Elf64GnuHash {
+        offset: shdr.sh_offset,
+        symoffset,
+        bloom_shift,
+        bloom_bits: bloom_bytes as u32 * 8,
+        bloom,
+        buckets: words(&data[buckets_start..chains_start]),
+        chains: words(&data[chains_start..]),
+    })
+}
+
+fn hash_data<'a>(
+    bytes: &'a [u8],
+    section: &Elf64Section,
+    structure: &'static str,
+) -> Result<&'a [u8], ElfError> {
+    let shdr = &section.header;
+
+    section_data(bytes, shdr).ok_or_else(|| {
+        ElfError::out_of_bounds(
+            shdr.sh_offset,
+            structure,
+            "sh_offset",
+            (shdr.sh_offset, shdr.sh_size),
+            bytes.len(),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{elf_hash, gnu_hash, parse_gnu_hash, parse_sysv_hash};
+    use crate::elf::class::ElfClass;
+    use crate::elf::def::elf64strc::{Elf64_Shdr, Elf64_Sym};
+    use crate::elf::error::{ElfError, ElfErrorKind};
+    use crate::elf::symbol::Elf64Symbol;
+    use crate::test_utils::create_section;
+
+    // the null symbol and 3 global functions
+    fn create_symbols() -> Vec<Elf64Symbol> {
+        ["", "foo", "bar", "baz"]
+            .iter()
+            .enumerate()
+            .map(|(i, name)| Elf64Symbol {
+                sym: Elf64_Sym {
+                    st_name: 0,
+                    st_info: if i == 0 { 0 } else { 0x12 },
+                    st_other: 0,
+                    st_shndx: if i == 0 { 0 } else { 11 },
+                    st_value: i as u64 * 0x10,
+                    st_size: 0,
+                },
+                name: Some(name.to_string()),
+                xindex: None,
+                version: None,
+            })
+            .collect()
+    }
+
+    fn words(values: &[u32]) -> Vec<u8> {
+        values
+            .iter()
+            .flat_map(|value| value.to_le_bytes())
+            .collect()
+    }
+
+    // 2 buckets, with the symbols in the bucket of their hash
+    fn create_sysv_hash(symbols: &[Elf64Symbol]) -> Vec<u8> {
+        let mut buckets = [0u32; 2];
+        let mut chains = vec![0u32; symbols.len()];
+
+        for (index, symbol) in symbols.iter().enumerate().skip(1) {
+            let bucket = (elf_hash(symbol.name.as_deref().unwrap().as_bytes()) % 2) as usize;
+            chains[index] = buckets[bucket];
+            buckets[bucket] = index as u32;
+        }
+
+        let mut table = vec![2, symbols.len() as u32];
+        table.extend_from_slice(&buckets);
+        table.extend_from_slice(&chains);
+        words(&table)
+    }
+
+    // 1 bucket (so the symbols are already sorted) and 1 bloom word with
+    // the bits of every symbol
+    fn create_gnu_hash(symbols: &[Elf64Symbol]) -> Vec<u8> {
+        let hashes: Vec<u32> = symbols[1..]
+            .iter()
+            .map(|symbol| gnu_hash(symbol.name.as_deref().unwrap().as_bytes()))
+            .collect();
+
+        let bloom = hashes.iter().fold(0u64, |bloom, hash| {
+            bloom | 1 << (hash % 64) | 1 << ((hash >> 6) % 64)
+        });
+        let mut chains: Vec<u32> = hashes.iter().map(|hash| hash & !1).collect();
+        *chains.last_mut().unwrap() |= 1;
+
+        let mut bytes = words(&[1, 1, 1, 6]);
+        bytes.extend_from_slice(&bloom.to_le_bytes());
+        bytes.extend_from_slice(&words(&[1]));
+        bytes.extend_from_slice(&words(&chains));
+        bytes
+    }
+
+    #[test]
+    fn hash_functions_return_the_values_of_the_abi() {
+        assert_eq!(elf_hash(b""), 0);
+        assert_eq!(elf_hash(b"printf"), 0x077905a6);
+        assert_eq!(gnu_hash(b""), 0x00001505);
+        assert_eq!(gnu_hash(b"printf"), 0x156b2bb8);
+    }
+
+    #[test]
+    fn lookup_finds_every_symbol_through_the_sysv_table() {
+        let symbols = create_symbols();
+        let bytes = create_sysv_hash(&symbols);
+        let mut section = create_section(".hash", 5);
+        section.header = Elf64_Shdr {
+            sh_size: bytes.len() as u64,
+            sh_entsize: 4,
+            ..section.header
+        };
+
+        let hash = parse_sysv_hash(&bytes, &section, true).unwrap();
+
+        assert_eq!(hash.lookup("foo", None, &symbols), Some(1));
+        assert_eq!(hash.lookup("bar", None, &symbols), Some(2));
+        assert_eq!(hash.lookup("baz", None, &symbols), Some(3));
+        assert_eq!(hash.lookup("qux", None, &symbols), None);
+
+        let (lengths, err) = hash.chain_lengths();
+        assert_eq!(lengths.iter().sum::<usize>(), 3);
+        assert!(err.is_none());
+    }
+
+    #[test]
+    fn lookup_finds_every_symbol_through_the_gnu_table() {
+        let symbols = create_symbols();
+        let bytes = create_gnu_hash(&symbols);
+        let mut section = create_section(".gnu.hash", 0x6ffffff6);
+        section.header = Elf64_Shdr {
+            sh_size: bytes.len() as u64,
+            ..section.header
+        };
+
+        let hash = parse_gnu_hash(&bytes, &section, ElfClass::Elf64, true).unwrap();
+
+        assert_eq!(hash.lookup("foo", None, &symbols), Some(1));
+        assert_eq!(hash.lookup("bar", None, &symbols), Some(2));
+        assert_eq!(hash.lookup("baz", None, &symbols), Some(3));
+        assert_eq!(hash.lookup("qux", None, &symbols), None);
+        assert_eq!(hash.chain_lengths(), [3]);
+    }
+
+    #[test]
+    fn is_empty_is_true_when_every_gnu_bucket_is_0() {
+        let mut bytes = words(&[1, 1, 1, 6]);
+        bytes.extend_from_slice(&0u64.to_le_bytes());
+        bytes.extend_from_slice(&words(&[0]));
+        let mut section = create_section(".gnu.hash", 0x6ffffff6);
+        section.header = Elf64_Shdr {
+            sh_size: bytes.len() as u64,
+            ..section.header
+        };
+
+        let hash = parse_gnu_hash(&bytes, &section, ElfClass::Elf64, true).unwrap();
+
+        assert!(hash.is_empty());
+        assert_eq!(hash.chain_lengths(), [0]);
+        assert_eq!(hash.lookup("foo", None, &create_symbols()), None);
+    }
+
+    #[test]
+    fn lookup_skips_the_undefined_symbols() {
+        let mut symbols = create_symbols();
+        symbols[2].sym.st_shndx = 0;
+        symbols[2].sym.st_value = 0;
+        let bytes = create_gnu_hash(&symbols);
+        let mut section = create_section(".gnu.hash", 0x6ffffff6);
+        section.header = Elf64_Shdr {
+            sh_size: bytes.len() as u64,
+            ..section.header
+        };
+
+        let hash = parse_gnu_hash(&bytes, &section, ElfClass::Elf64, true).unwrap();
+
+        assert_eq!(hash.lookup("bar", None, &symbols), None);
+    }
+
+    #[test]
+    fn chain_lengths_returns_an_err_for_a_loop_in_the_sysv_chains() {
+        let symbols = create_symbols();
+        // a single bucket with 1 -> 2 -> 1
+        let bytes = words(&[1, 4, 1, 0, 2, 1, 0]);
+        let mut section = create_section(".hash", 5);
+        section.header = Elf64_Shdr {
+            sh_size: bytes.len() as u64,
+            sh_entsize: 4,
+            ..section.header
+        };
+
+        let hash = parse_sysv_hash(&bytes, &section, true).unwrap();
+        let (lengths, err) = hash.chain_lengths();
+
+        assert_eq!(lengths, [3]);
+        assert!(matches!(
+            err,
+            Some(ElfError {
+                field: "chain",
+                kind: ElfErrorKind::InvalidValue { actual: 1, .. },
+                ..
+            })
+        ));
+        assert_eq!(hash.lookup("baz", None, &symbols), None);
+    }
+
+    #[test]
+    fn parse_gnu_hash_returns_truncated_err_when_the_buckets_dont_fit() {
+        let bytes = words(&[4, 1, 1, 6, 0, 0]);
+        let mut section = create_section(".gnu.hash", 0x6ffffff6);
+        section.header = Elf64_Shdr {
+            sh_size: bytes.len() as u64,
+            ..section.header
+        };
+
+        let err = parse_gnu_hash(&bytes, &section, ElfClass::Elf64, true).unwrap_err();
+
+        assert!(matches!(
+            err,
+            ElfError {
+                structure: "SHT_GNU_HASH",
+                kind: ElfErrorKind::Truncated {
+                    expected: 40,
+                    actual: 24
+                },
+                ..
+            }
+        ));
+    }
+}
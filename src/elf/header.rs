@@ -1,45 +1,407 @@
+use crate::elf::class::ElfClass;
+use crate::elf::def::elf32strc::Elf32_Ehdr;
 use crate::elf::def::elf64strc::Elf64_Ehdr;
-use crate::elf::validator::arch::arch64::{Elf64BitValidator, elf64bitvalidationerrors::*};
+use crate::elf::error::ElfError;
+use crate::elf::types::{FileType, Machine, OsAbi};
+use crate::elf::validator::header::ElfHeaderValidator;
+use crate::utils::endianess::EndianRead;
 use crate::utils::parser::Parseable;
-use std::error::Error;
 
-impl Parseable for Elf64_Ehdr {
-    fn parse(bytes: &[u8]) -> Result<Self, Box<dyn Error>> {
-        // offset: 0x0 -> 0x34
-
-        // the objective of this method is to validate AND
-        // parse the bytes in an Elf64_Ehdr struct
-
-        // first step is verify if is really an 64 bit elf file.
-        // by default for 64 bits binary elf files, we have
-        // the minimum size of 52 bytes in the bin file. This 52
-        // bytes are provenient from the elf header struct, so we can
-        // use it as a ruge and ugly validation for the elf file
-        if bytes.len() < 52 {
-            return Err(Elf64BitEIdentValidationErrors::NonELFFileError.into());
+impl Elf64_Ehdr {
+    // EI_DATA was already validated at parse time, so anything that is not
+    // ELFDATA2LSB (1) is big endian
+    pub fn is_little_endian(&self) -> bool {
+        self.e_ident[5] == 1
+    }
+
+    // the same goes for EI_CLASS, that can only be ELFCLASS32 or ELFCLASS64
+    pub fn class(&self) -> ElfClass {
+        ElfClass::from_ident(self.e_ident[4])
+    }
+
+    pub fn file_type(&self) -> FileType {
+        (self.e_type as u32).into()
+    }
+
+    pub fn machine(&self) -> Machine {
+        (self.e_machine as u32).into()
+    }
+
+    pub fn os_abi(&self) -> OsAbi {
+        (self.e_ident[7] as u32).into()
+    }
+
+    // the name of the header in the errors about it
+    pub fn structure(&self) -> &'static str {
+        match self.class() {
+            ElfClass::Elf32 => "Elf32_Ehdr",
+            ElfClass::Elf64 => "Elf64_Ehdr",
+        }
+    }
+
+    // where a field of the header is in the file, for the errors about its
+    // value. In 32-bit files the addresses and offsets have 4 bytes, so
+    // everything after e_entry is closer to the start
+    pub fn field_offset(&self, field: &str) -> u64 {
+        let (elf32, elf64) = match field {
+            "e_type" => (0x10, 0x10),
+            "e_machine" => (0x12, 0x12),
+            "e_version" => (0x14, 0x14),
+            "e_entry" => (0x18, 0x18),
+            "e_phoff" => (0x1c, 0x20),
+            "e_shoff" => (0x20, 0x28),
+            "e_flags" => (0x24, 0x30),
+            "e_ehsize" => (0x28, 0x34),
+            "e_phentsize" => (0x2a, 0x36),
+            "e_phnum" => (0x2c, 0x38),
+            "e_shentsize" => (0x2e, 0x3a),
+            "e_shnum" => (0x30, 0x3c),
+            "e_shstrndx" => (0x32, 0x3e),
+            _ => (0, 0),
+        };
+
+        match self.class() {
+            ElfClass::Elf32 => elf32,
+            ElfClass::Elf64 => elf64,
+        }
+    }
+}
+
+// the 32-bit header has the same fields, but the addresses and offsets
+// have 4 bytes, so the fields after e_entry are all moved
+impl From<Elf32_Ehdr> for Elf64_Ehdr {
+    fn from(header: Elf32_Ehdr) -> Self {
+        Elf64_Ehdr {
+            e_ident: header.e_ident,
+            e_type: header.e_type,
+            e_machine: header.e_machine,
+            e_version: header.e_version,
+            e_entry: header.e_entry as u64,
+            e_phoff: header.e_phoff as u64,
+            e_shoff: header.e_shoff as u64,
+            e_flags: header.e_flags,
+            e_ehsize: header.e_ehsize,
+            e_phentsize: header.e_phentsize,
+            e_phnum: header.e_phnum,
+            e_shentsize: header.e_shentsize,
+            e_shnum: header.e_shnum,
+            e_shstrndx: header.e_shstrndx,
         }
+    }
+}
+
+impl Parseable for Elf64_Ehdr {
+    fn parse(bytes: &[u8]) -> Result<Self, ElfError> {
+        parse_header(bytes, &mut ElfHeaderValidator::new(bytes))
+    }
+}
+
+impl Elf64_Ehdr {
+    // like parse, but the values that dont stop the rest of the header from
+    // being read (an unknown EI_VERSION or e_type, garbage in the padding)
+    // are returned as warnings
+    pub fn parse_lenient(bytes: &[u8]) -> Result<(Self, Vec<ElfError>), ElfError> {
+        let mut validator = ElfHeaderValidator::lenient(bytes);
+        let header = parse_header(bytes, &mut validator)?;
 
-        // the first part of the elf header are a 16 bytes array that is called
-        // e_ident. It contains important data about the bin file, like endiannes,
-        // important offsets, if is 32 bit or not etc.
-        let mut elf64bitvalidator = Elf64BitValidator::new(bytes);
+        Ok((header, validator.into_warnings()))
+    }
+}
+
+fn parse_header(
+    bytes: &[u8],
+    validator: &mut ElfHeaderValidator,
+) -> Result<Elf64_Ehdr, ElfError> {
+    // offset: 0x0 -> 0x40
+
+    // the objective of this method is to validate AND
+    // parse the bytes in an Elf64_Ehdr struct
+
+    // the first part of the elf header are a 16 bytes array that is called
+    // e_ident. It contains important data about the bin file, like endiannes,
+    // important offsets, if is 32 bit or not etc.
+    let e_ident_bytes = validator.validate_e_ident()?;
+
+    let mut e_ident = [0u8; 16];
+    e_ident.copy_from_slice(e_ident_bytes);
+
+    // now that we know that it is really an elf file, and of witch class,
+    // the whole header have to be there. The 32-bit one has 52 bytes, and
+    // the 64-bit one has 64 bytes
+    let class = ElfClass::from_ident(e_ident[4]);
+    let (structure, size) = match class {
+        ElfClass::Elf32 => ("Elf32_Ehdr", 52),
+        ElfClass::Elf64 => ("Elf64_Ehdr", 64),
+    };
+    if bytes.len() < size {
+        return Err(ElfError::truncated(0, structure, size, bytes.len()));
+    }
 
-        _ = elf64bitvalidator.validate_e_ident();
+    // e_ident was validated, so EI_DATA is either 1 (LE) or 2 (BE) here
+    let is_little_endian = e_ident[5] == 1;
 
-        // now, we run out from elf e_ident, we can validate the others
-        // field from elf file. The next bytes, uses other types than char,
-        // that in this case, is definned to 1 byte each char (ASCII). But
-        // usually, the other parts cannot be defined with char, cause it
-        // uses more than one byte per field.
+    // now, we run out from elf e_ident, we can validate the others
+    // field from elf file. The next bytes, uses other types than char,
+    // that in this case, is definned to 1 byte each char (ASCII). But
+    // usually, the other parts cannot be defined with char, cause it
+    // uses more than one byte per field.
 
-        _ = elf64bitvalidator.validate_e_type();
+    // We have some types (witch are only symbols to raw unsigned values)
+    // like Elf64_Half type, is u16 (in 64-bit object), and occupes the next
+    // 2 bytes of mem
+    let e_type = u16::read_from(validator.validate_e_type()?, is_little_endian);
+    let e_machine = u16::read_from(validator.validate_e_machine()?, is_little_endian);
+
+    // up to here both classes are the same, the rest of a 32-bit header
+    // has 4 bytes addresses and offsets
+    if class == ElfClass::Elf32 {
+        return Ok(parse_elf32_header(bytes, e_ident, e_type, e_machine, is_little_endian).into());
+    }
+
+    // the rest of the fields have no validation by themselves (they only make
+    // sense when compared with the rest of the file), so we just decode them
+    // with the file endiannes
+
+    // offset: 0x14 -> 0x17
+    let e_version = u32::read_from(&bytes[20..24], is_little_endian);
+    // offset: 0x18 -> 0x1F
+    let e_entry = u64::read_from(&bytes[24..32], is_little_endian);
+    // offset: 0x20 -> 0x27
+    let e_phoff = u64::read_from(&bytes[32..40], is_little_endian);
+    // offset: 0x28 -> 0x2F
+    let e_shoff = u64::read_from(&bytes[40..48], is_little_endian);
+    // offset: 0x30 -> 0x33
+    let e_flags = u32::read_from(&bytes[48..52], is_little_endian);
+    // offset: 0x34 -> 0x35
+    let e_ehsize = u16::read_from(&bytes[52..54], is_little_endian);
+    // offset: 0x36 -> 0x37
+    let e_phentsize = u16::read_from(&bytes[54..56], is_little_endian);
+    // offset: 0x38 -> 0x39
+    let e_phnum = u16::read_from(&bytes[56..58], is_little_endian);
+    // offset: 0x3A -> 0x3B
+    let e_shentsize = u16::read_from(&bytes[58..60], is_little_endian);
+    // offset: 0x3C -> 0x3D
+    let e_shnum = u16::read_from(&bytes[60..62], is_little_endian);
+    // offset: 0x3E -> 0x3F
+    let e_shstrndx = u16::read_from(&bytes[62..64], is_little_endian);
+
+    Ok(Elf64_Ehdr {
+        e_ident,
+        e_type,
+        e_machine,
+        e_version,
+        e_entry,
+        e_phoff,
+        e_shoff,
+        e_flags,
+        e_ehsize,
+        e_phentsize,
+        e_phnum,
+        e_shentsize,
+        e_shnum,
+        e_shstrndx,
+    })
+}
+
+// offset: 0x0 -> 0x34, e_ident, e_type and e_machine were already read
+fn parse_elf32_header(
+    bytes: &[u8],
+    e_ident: [u8; 16],
+    e_type: u16,
+    e_machine: u16,
+    is_little_endian: bool,
+) -> Elf32_Ehdr {
+    Elf32_Ehdr {
+        e_ident,
+        e_type,
+        e_machine,
+        // offset: 0x14 -> 0x17
+        e_version: u32::read_from(&bytes[20..24], is_little_endian),
+        // offset: 0x18 -> 0x1B
+        e_entry: u32::read_from(&bytes[24..28], is_little_endian),
+        // offset: 0x1C -> 0x1F
+        e_phoff: u32::read_from(&bytes[28..32], is_little_endian),
+        // offset: 0x20 -> 0x23
+        e_shoff: u32::read_from(&bytes[32..36], is_little_endian),
+        // offset: 0x24 -> 0x27
+        e_flags: u32::read_from(&bytes[36..40], is_little_endian),
+        // offset: 0x28 -> 0x29
+        e_ehsize: u16::read_from(&bytes[40..42], is_little_endian),
+        // offset: 0x2A -> 0x2B
+        e_phentsize: u16::read_from(&bytes[42..44], is_little_endian),
+        // offset: 0x2C -> 0x2D
+        e_phnum: u16::read_from(&bytes[44..46], is_little_endian),
+        // offset: 0x2E -> 0x2F
+        e_shentsize: u16::read_from(&bytes[46..48], is_little_endian),
+        // offset: 0x30 -> 0x31
+        e_shnum: u16::read_from(&bytes[48..50], is_little_endian),
+        // offset: 0x32 -> 0x33
+        e_shstrndx: u16::read_from(&bytes[50..52], is_little_endian),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::elf::class::ElfClass;
+    use crate::elf::def::elf64strc::Elf64_Ehdr;
+    use crate::elf::error::{ElfError, ElfErrorKind};
+    use crate::utils::parser::Parseable;
+
+    fn create_valid_header(is_little_endian: bool) -> [u8; 64] {
+        let mut file = [0u8; 64];
+        file[0..4].copy_from_slice(&[0x7f, 0x45, 0x4c, 0x46]);
+        file[4] = 2; // 64-bit
+        file[5] = if is_little_endian { 1 } else { 2 };
+        file[6] = 1; // version
+
+        let put16 = |file: &mut [u8; 64], at: usize, val: u16| {
+            let bytes = if is_little_endian {
+                val.to_le_bytes()
+            } else {
+                val.to_be_bytes()
+            };
+            file[at..at + 2].copy_from_slice(&bytes);
+        };
+        let put32 = |file: &mut [u8; 64], at: usize, val: u32| {
+            let bytes = if is_little_endian {
+                val.to_le_bytes()
+            } else {
+                val.to_be_bytes()
+            };
+            file[at..at + 4].copy_from_slice(&bytes);
+        };
+        let put64 = |file: &mut [u8; 64], at: usize, val: u64| {
+            let bytes = if is_little_endian {
+                val.to_le_bytes()
+            } else {
+                val.to_be_bytes()
+            };
+            file[at..at + 8].copy_from_slice(&bytes);
+        };
+
+        put16(&mut file, 16, 2); // ET_EXEC
+        put16(&mut file, 18, 0x3e); // x86-64
+        put32(&mut file, 20, 1);
+        put64(&mut file, 24, 0x401000);
+        put64(&mut file, 32, 64);
+        put64(&mut file, 40, 0x3a58);
+        put32(&mut file, 48, 0);
+        put16(&mut file, 52, 64);
+        put16(&mut file, 54, 56);
+        put16(&mut file, 56, 13);
+        put16(&mut file, 58, 64);
+        put16(&mut file, 60, 31);
+        put16(&mut file, 62, 30);
+
+        file
+    }
+
+    fn assert_header_fields(header: &Elf64_Ehdr) {
+        assert_eq!(header.e_type, 2);
+        assert_eq!(header.e_machine, 0x3e);
+        assert_eq!(header.e_version, 1);
+        assert_eq!(header.e_entry, 0x401000);
+        assert_eq!(header.e_phoff, 64);
+        assert_eq!(header.e_shoff, 0x3a58);
+        assert_eq!(header.e_flags, 0);
+        assert_eq!(header.e_ehsize, 64);
+        assert_eq!(header.e_phentsize, 56);
+        assert_eq!(header.e_phnum, 13);
+        assert_eq!(header.e_shentsize, 64);
+        assert_eq!(header.e_shnum, 31);
+        assert_eq!(header.e_shstrndx, 30);
+    }
+
+    #[test]
+    fn parse_returns_every_field_of_a_le_header() {
+        let file = create_valid_header(true);
+
+        let header = Elf64_Ehdr::parse(&file).unwrap();
+
+        assert_eq!(header.e_ident, file[..16]);
+        assert_header_fields(&header);
+    }
+
+    #[test]
+    fn parse_returns_every_field_of_a_be_header() {
+        let file = create_valid_header(false);
+
+        let header = Elf64_Ehdr::parse(&file).unwrap();
+
+        assert_eq!(header.e_ident, file[..16]);
+        assert_header_fields(&header);
+    }
+
+    #[test]
+    fn parse_returns_truncated_err_when_file_is_smaller_than_the_header() {
+        let file = create_valid_header(true);
+
+        let err = Elf64_Ehdr::parse(&file[..63]).unwrap_err();
+
+        assert!(matches!(
+            err,
+            ElfError {
+                offset: 0,
+                structure: "Elf64_Ehdr",
+                kind: ElfErrorKind::Truncated {
+                    expected: 64,
+                    actual: 63
+                },
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn parse_propagates_the_validator_errors() {
+        let mut file = create_valid_header(true);
+        file[16..18].copy_from_slice(&[0x05, 0x00]);
+
+        let err = Elf64_Ehdr::parse(&file).unwrap_err();
+
+        assert!(matches!(
+            err,
+            ElfError {
+                offset: 0x10,
+                field: "e_type",
+                kind: ElfErrorKind::InvalidValue { actual: 5, .. },
+                ..
+            }
+        ));
+        assert!(err.to_string().starts_with("at offset 0x10 (e_type): "));
+    }
 
-        // We have some types (witch are only symbols to raw unsigned values)
-        // like Elf64_Half type, is u16 (in 64-bit object), and occupes the next
-        // 2 bytes of mem
+    #[test]
+    fn parse_widens_the_fields_of_a_32_bit_header() {
+        let mut file = [0u8; 52];
+        file[0..4].copy_from_slice(&[0x7f, 0x45, 0x4c, 0x46]);
+        file[4] = 1; // 32-bit
+        file[5] = 1;
+        file[6] = 1;
+        file[16..18].copy_from_slice(&2u16.to_le_bytes()); // ET_EXEC
+        file[18..20].copy_from_slice(&3u16.to_le_bytes()); // i386
+        file[24..28].copy_from_slice(&0x8049000u32.to_le_bytes());
+        file[28..32].copy_from_slice(&52u32.to_le_bytes());
+        file[32..36].copy_from_slice(&0x3210u32.to_le_bytes());
+        file[40..42].copy_from_slice(&52u16.to_le_bytes());
+        file[42..44].copy_from_slice(&32u16.to_le_bytes());
+        file[44..46].copy_from_slice(&9u16.to_le_bytes());
+        file[46..48].copy_from_slice(&40u16.to_le_bytes());
+        file[48..50].copy_from_slice(&27u16.to_le_bytes());
+        file[50..52].copy_from_slice(&26u16.to_le_bytes());
 
-        // let e_type = &bytes[16..0];
+        let header = Elf64_Ehdr::parse(&file).unwrap();
 
-        todo!()
+        assert_eq!(header.class(), ElfClass::Elf32);
+        assert_eq!(header.e_machine, 3);
+        assert_eq!(header.e_entry, 0x8049000);
+        assert_eq!(header.e_phoff, 52);
+        assert_eq!(header.e_shoff, 0x3210);
+        assert_eq!(header.e_ehsize, 52);
+        assert_eq!(header.e_phentsize, 32);
+        assert_eq!(header.e_phnum, 9);
+        assert_eq!(header.e_shentsize, 40);
+        assert_eq!(header.e_shnum, 27);
+        assert_eq!(header.e_shstrndx, 26);
     }
 }
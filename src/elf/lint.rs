@@ -0,0 +1,590 @@
+use crate::elf::class::{ClassParseable, ElfClass};
+use crate::elf::def::elf64strc::{Elf64_Ehdr, Elf64_Phdr, Elf64_Shdr};
+use crate::elf::def::elfconsts::*;
+use crate::elf::error::{ElfError, ElfErrorKind};
+use crate::elf::file::ElfFile;
+use crate::elf::section::Elf64Section;
+
+// the parsers only stop at the values that they cannot get past, but a file
+// can be read just fine and still be wrong: tables on top of each other, an
+// entry point that is not in any code... This pass looks at the header and
+// its tables together and returns every problem that it finds (nothing when
+// the file is fine), in the order of the checks below
+pub fn lint(file: &ElfFile) -> Vec<ElfError> {
+    let header = file.header();
+    let mut problems = Vec::new();
+
+    // a table that cannot be parsed is a problem by itself, the checks that
+    // need it just have nothing to look at
+    let sections = file.sections().unwrap_or_else(|err| {
+        problems.push(err);
+        &[]
+    });
+    let segments = file.segments().unwrap_or_else(|err| {
+        problems.push(err);
+        &[]
+    });
+
+    // the real counts, that can be in the section 0 (when it cannot be read
+    // the tables above already failed, so the raw ones are good enough)
+    let counts = Counts {
+        phnum: file.segment_count().unwrap_or(header.e_phnum as usize),
+        shnum: file.section_count().unwrap_or(header.e_shnum as usize),
+        shstrndx: file
+            .string_table_index()
+            .unwrap_or(header.e_shstrndx as u32),
+    };
+
+    check_sizes(header, &counts, &mut problems);
+    check_tables(header, &counts, file.bytes().len(), &mut problems);
+    check_shstrndx(header, &counts, &mut problems);
+    check_section_bounds(header, sections, file.bytes().len(), &mut problems);
+    check_entry(header, segments, &mut problems);
+    check_load_order(header, segments, &mut problems);
+    check_straddling(header, sections, segments, &mut problems);
+
+    problems
+}
+
+struct Counts {
+    phnum: usize,
+    shnum: usize,
+    shstrndx: u32,
+}
+
+// the sizes in the header must be the ones of the structures of the class.
+// The parsers accept bigger entries (as padding), but no one writes them
+fn check_sizes(header: &Elf64_Ehdr, counts: &Counts, problems: &mut Vec<ElfError>) {
+    let class = header.class();
+    let ehdr_size = match class {
+        ElfClass::Elf32 => 52,
+        ElfClass::Elf64 => 64,
+    };
+    let ehdr = (header.structure(), ehdr_size);
+    let phdr = (Elf64_Phdr::name_of(class), Elf64_Phdr::size_of(class));
+    let shdr = (Elf64_Shdr::name_of(class), Elf64_Shdr::size_of(class));
+
+    // the size of the entries of an empty table doesnt matter
+    let checks = [
+        ("e_ehsize", header.e_ehsize, ehdr, true),
+        ("e_phentsize", header.e_phentsize, phdr, counts.phnum != 0),
+        ("e_shentsize", header.e_shentsize, shdr, counts.shnum != 0),
+    ];
+
+    for (field, value, (structure, size), used) in checks {
+        if used && value as usize != size {
+            problems.push(ElfError::invalid_value(
+                header.field_offset(field),
+                structure,
+                field,
+                format!("{size} (the size of an {structure})"),
+                value,
+            ));
+        }
+    }
+}
+
+// the header, the program header table and the section header table must
+// be inside of the file, and none of them can be on top of another
+fn check_tables(
+    header: &Elf64_Ehdr,
+    counts: &Counts,
+    file_size: usize,
+    problems: &mut Vec<ElfError>,
+) {
+    let class = header.class();
+    let table = |start: u64, count: usize, entsize: u16| {
+        (
+            start,
+            start.saturating_add((count as u64).saturating_mul(entsize as u64)),
+        )
+    };
+
+    // (what the table is, the field that points to it, its structure, where it is)
+    let tables = [
+        (
+            "ELF header",
+            "e_ehsize",
+            header.structure(),
+            (0, header.e_ehsize as u64),
+        ),
+        (
+            "program header table",
+            "e_phoff",
+            Elf64_Phdr::name_of(class),
+            table(header.e_phoff, counts.phnum, header.e_phentsize),
+        ),
+        (
+            "section header table",
+            "e_shoff",
+            Elf64_Shdr::name_of(class),
+            table(header.e_shoff, counts.shnum, header.e_shentsize),
+        ),
+    ];
+    // an empty table is not anywhere
+    let tables: Vec<_> = tables
+        .into_iter()
+        .filter(|(.., (start, end))| start < end)
+        .collect();
+
+    for (i, &(_, field, structure, (start, end))) in tables.iter().enumerate() {
+        if end > file_size as u64 {
+            problems.push(ElfError::out_of_bounds(
+                header.field_offset(field),
+                structure,
+                field,
+                (start, end - start),
+                file_size,
+            ));
+        }
+
+        for &(other, _, _, (other_start, other_end)) in &tables[..i] {
+            if start < other_end && other_start < end {
+                problems.push(ElfError {
+                    offset: header.field_offset(field),
+                    structure,
+                    field,
+                    kind: ElfErrorKind::Overlaps {
+                        start,
+                        end,
+                        other,
+                        other_start,
+                        other_end,
+                    },
+                });
+            }
+        }
+    }
+}
+
+// the section with the names must be one of the sections, 0 (SHN_UNDEF) is
+// a file without names
+fn check_shstrndx(header: &Elf64_Ehdr, counts: &Counts, problems: &mut Vec<ElfError>) {
+    let index = counts.shstrndx;
+
+    if counts.shnum != 0 && index != SHN_UNDEF && index as usize >= counts.shnum {
+        problems.push(ElfError::invalid_value(
+            header.field_offset("e_shstrndx"),
+            header.structure(),
+            "e_shstrndx",
+            format!("an index below the number of sections ({})", counts.shnum),
+            index,
+        ));
+    }
+}
+
+// the bytes of every section (but the NOBITS ones, that have none) must be
+// inside of the file
+fn check_section_bounds(
+    header: &Elf64_Ehdr,
+    sections: &[Elf64Section],
+    file_size: usize,
+    problems: &mut Vec<ElfError>,
+) {
+    for (i, section) in sections.iter().enumerate() {
+        let shdr = &section.header;
+        if shdr.sh_type == SHT_NOBITS || shdr.sh_type == SHT_NULL {
+            continue;
+        }
+
+        if shdr.sh_offset.saturating_add(shdr.sh_size) > file_size as u64 {
+            problems.push(ElfError::out_of_bounds(
+                shdr_field(header, i, "sh_offset"),
+                Elf64_Shdr::name_of(header.class()),
+                "sh_offset",
+                (shdr.sh_offset, shdr.sh_size),
+                file_size,
+            ));
+        }
+    }
+}
+
+// the program starts at e_entry, so it must be in a segment that is loaded
+// and can be executed. Files without PT_LOAD segments (or without an entry
+// point, like most shared objects) have nothing to check
+fn check_entry(header: &Elf64_Ehdr, segments: &[Elf64_Phdr], problems: &mut Vec<ElfError>) {
+    let e_type = header.e_type as u32;
+    let mut loads = segments
+        .iter()
+        .filter(|phdr| phdr.p_type == PT_LOAD)
+        .peekable();
+
+    if !(e_type == ET_EXEC || e_type == ET_DYN) || header.e_entry == 0 || loads.peek().is_none() {
+        return;
+    }
+
+    let executed = loads.any(|phdr| {
+        phdr.p_flags & PF_X != 0
+            && header.e_entry >= phdr.p_vaddr
+            && header.e_entry - phdr.p_vaddr < phdr.p_memsz
+    });
+
+    if !executed {
+        problems.push(ElfError::invalid_value(
+            header.field_offset("e_entry"),
+            header.structure(),
+            "e_entry",
+            "an address inside of an executable PT_LOAD segment",
+            header.e_entry,
+        ));
+    }
+}
+
+// the PT_LOAD segments must be sorted by their virtual address
+fn check_load_order(header: &Elf64_Ehdr, segments: &[Elf64_Phdr], problems: &mut Vec<ElfError>) {
+    let mut previous: Option<u64> = None;
+
+    for (i, phdr) in segments.iter().enumerate() {
+        if phdr.p_type != PT_LOAD {
+            continue;
+        }
+
+        if let Some(previous) = previous
+            && phdr.p_vaddr < previous
+        {
+            problems.push(ElfError::invalid_value(
+                phdr_field(header, i, "p_vaddr"),
+                Elf64_Phdr::name_of(header.class()),
+                "p_vaddr",
+                format!("at least {previous:#x} (the p_vaddr of the PT_LOAD before it)"),
+                phdr.p_vaddr,
+            ));
+        }
+
+        previous = Some(phdr.p_vaddr);
+    }
+}
+
+// a section is either inside of a PT_LOAD segment or out of it, never half
+// in, both in memory (for SHF_ALLOC sections) and in the file (for the ones
+// with bytes). .tbss is skipped, it takes no space outside of PT_TLS
+fn check_straddling(
+    header: &Elf64_Ehdr,
+    sections: &[Elf64Section],
+    segments: &[Elf64_Phdr],
+    problems: &mut Vec<ElfError>,
+) {
+    let structure = Elf64_Shdr::name_of(header.class());
+
+    for (i, section) in sections.iter().enumerate() {
+        let shdr = &section.header;
+        let is_alloc = shdr.sh_flags & SHF_ALLOC as u64 != 0;
+        let is_nobits = shdr.sh_type == SHT_NOBITS;
+        let is_tbss = is_nobits && shdr.sh_flags & SHF_TLS as u64 != 0;
+
+        if !is_alloc || is_tbss || shdr.sh_size == 0 {
+            continue;
+        }
+
+        for (j, phdr) in segments.iter().enumerate() {
+            if phdr.p_type != PT_LOAD {
+                continue;
+            }
+
+            let in_memory = (shdr.sh_addr, phdr.p_vaddr, phdr.p_memsz);
+            let in_file = (shdr.sh_offset, phdr.p_offset, phdr.p_filesz);
+            let ranges = [
+                ("sh_addr", in_memory, true),
+                ("sh_offset", in_file, !is_nobits),
+            ];
+
+            for (field, (start, segment_start, segment_size), checked) in ranges {
+                let end = start.saturating_add(shdr.sh_size);
+                let segment_end = segment_start.saturating_add(segment_size);
+                let overlaps = start < segment_end && segment_start < end;
+                let inside = start >= segment_start && end <= segment_end;
+
+                if checked && overlaps && !inside {
+                    problems.push(ElfError {
+                        offset: shdr_field(header, i, field),
+                        structure,
+                        field,
+                        kind: ElfErrorKind::Straddles {
+                            start,
+                            end,
+                            segment: j,
+                            segment_start,
+                            segment_end,
+                        },
+                    });
+                }
+            }
+        }
+    }
+}
+
+// where the field of the entry index of the section header table is in the
+// file, unlike the parsers this pass knows where every entry is
+fn shdr_field(header: &Elf64_Ehdr, index: usize, field: &str) -> u64 {
+    let (elf32, elf64) = match field {
+        "sh_addr" => (0x0c, 0x10),
+        "sh_offset" => (0x10, 0x18),
+        _ => (0, 0),
+    };
+
+    entry_field(
+        header,
+        header.e_shoff,
+        header.e_shentsize,
+        index,
+        (elf32, elf64),
+    )
+}
+
+fn phdr_field(header: &Elf64_Ehdr, index: usize, field: &str) -> u64 {
+    let (elf32, elf64) = match field {
+        "p_vaddr" => (0x08, 0x10),
+        _ => (0, 0),
+    };
+
+    entry_field(
+        header,
+        header.e_phoff,
+        header.e_phentsize,
+        index,
+        (elf32, elf64),
+    )
+}
+
+fn entry_field(
+    header: &Elf64_Ehdr,
+    table: u64,
+    entsize: u16,
+    index: usize,
+    (elf32, elf64): (u64, u64),
+) -> u64 {
+    let field = match header.class() {
+        ElfClass::Elf32 => elf32,
+        ElfClass::Elf64 => elf64,
+    };
+
+    table
+        .wrapping_add(index as u64 * entsize as u64)
+        .wrapping_add(field)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::lint;
+    use crate::elf::def::elf64strc::{Elf64_Phdr, Elf64_Shdr};
+    use crate::elf::error::{ElfError, ElfErrorKind};
+    use crate::elf::file::{ElfFile, ParseMode};
+
+    fn create_phdr(
+        p_flags: u32,
+        p_offset: u64,
+        p_vaddr: u64,
+        p_filesz: u64,
+        p_memsz: u64,
+    ) -> Elf64_Phdr {
+        Elf64_Phdr {
+            p_type: 1,
+            p_flags,
+            p_offset,
+            p_vaddr,
+            p_paddr: p_vaddr,
+            p_filesz,
+            p_memsz,
+            p_align: 0x1000,
+        }
+    }
+
+    fn create_shdr(
+        sh_name: u32,
+        sh_type: u32,
+        sh_flags: u64,
+        sh_addr: u64,
+        sh_offset: u64,
+        sh_size: u64,
+    ) -> Elf64_Shdr {
+        Elf64_Shdr {
+            sh_name,
+            sh_type,
+            sh_flags,
+            sh_addr,
+            sh_offset,
+            sh_size,
+            sh_link: 0,
+            sh_info: 0,
+            sh_addralign: 1,
+            sh_entsize: 0,
+        }
+    }
+
+    // a little endian ET_EXEC with a code segment (.text) and a data one
+    // (.bss), that the tests break in the ways that the lint looks for
+    fn create_file(patch: impl FnOnce(&mut [Elf64_Phdr; 2], &mut [Elf64_Shdr; 4])) -> Vec<u8> {
+        let mut phdrs = [
+            create_phdr(5, 0, 0x400000, 0x1000, 0x1000),
+            create_phdr(6, 0x1000, 0x401000, 0x100, 0x200),
+        ];
+        let mut shdrs = [
+            create_shdr(0, 0, 0, 0, 0, 0),
+            create_shdr(1, 1, 6, 0x400800, 0x800, 0x100),
+            create_shdr(7, 8, 3, 0x401100, 0x1100, 0x100),
+            create_shdr(12, 3, 0, 0, 0x1100, 0x20),
+        ];
+        patch(&mut phdrs, &mut shdrs);
+
+        let mut data = vec![0u8; 0x1300];
+        data[..4].copy_from_slice(b"\x7fELF");
+        data[4..7].copy_from_slice(&[2, 1, 1]);
+        data[0x10..0x12].copy_from_slice(&2u16.to_le_bytes());
+        data[0x12..0x14].copy_from_slice(&62u16.to_le_bytes());
+        data[0x14..0x18].copy_from_slice(&1u32.to_le_bytes());
+        data[0x18..0x20].copy_from_slice(&0x400800u64.to_le_bytes());
+        data[0x20..0x28].copy_from_slice(&64u64.to_le_bytes());
+        data[0x28..0x30].copy_from_slice(&0x1200u64.to_le_bytes());
+        // e_ehsize, e_phentsize, e_phnum, e_shentsize, e_shnum, e_shstrndx
+        for (i, value) in [64u16, 56, 2, 64, 4, 3].into_iter().enumerate() {
+            data[0x34 + i * 2..0x36 + i * 2].copy_from_slice(&value.to_le_bytes());
+        }
+
+        for (i, phdr) in phdrs.iter().enumerate() {
+            let entry = &mut data[64 + i * 56..];
+            entry[0..4].copy_from_slice(&phdr.p_type.to_le_bytes());
+            entry[4..8].copy_from_slice(&phdr.p_flags.to_le_bytes());
+            let words = [
+                phdr.p_offset,
+                phdr.p_vaddr,
+                phdr.p_paddr,
+                phdr.p_filesz,
+                phdr.p_memsz,
+                phdr.p_align,
+            ];
+            for (j, word) in words.into_iter().enumerate() {
+                entry[8 + j * 8..16 + j * 8].copy_from_slice(&word.to_le_bytes());
+            }
+        }
+
+        data[0x1100..0x1100 + 22].copy_from_slice(b"\0.text\0.bss\0.shstrtab\0");
+
+        for (i, shdr) in shdrs.iter().enumerate() {
+            let entry = &mut data[0x1200 + i * 64..];
+            entry[0..4].copy_from_slice(&shdr.sh_name.to_le_bytes());
+            entry[4..8].copy_from_slice(&shdr.sh_type.to_le_bytes());
+            let words = [shdr.sh_flags, shdr.sh_addr, shdr.sh_offset, shdr.sh_size];
+            for (j, word) in words.into_iter().enumerate() {
+                entry[8 + j * 8..16 + j * 8].copy_from_slice(&word.to_le_bytes());
+            }
+            entry[48..56].copy_from_slice(&shdr.sh_addralign.to_le_bytes());
+        }
+
+        data
+    }
+
+    fn fields(problems: &[ElfError]) -> Vec<(u64, &str)> {
+        problems
+            .iter()
+            .map(|problem| (problem.offset, problem.field))
+            .collect()
+    }
+
+    #[test]
+    fn lint_returns_nothing_for_a_consistent_file() {
+        let data = create_file(|_, _| {});
+        let file = ElfFile::parse(&data).unwrap();
+
+        assert!(lint(&file).is_empty());
+    }
+
+    #[test]
+    fn lint_returns_the_header_sizes_that_are_not_the_ones_of_the_class() {
+        let mut data = create_file(|_, _| {});
+        data[0x34..0x36].copy_from_slice(&52u16.to_le_bytes());
+        data[0x36..0x38].copy_from_slice(&64u16.to_le_bytes());
+        let file = ElfFile::parse(&data).unwrap();
+
+        assert_eq!(
+            fields(&lint(&file)),
+            [(0x34, "e_ehsize"), (0x36, "e_phentsize")]
+        );
+    }
+
+    #[test]
+    fn lint_returns_tables_that_overlap_or_run_past_the_end_of_the_file() {
+        let mut data = create_file(|_, _| {});
+        // the program header table now starts inside of the ELF header
+        data[0x20..0x28].copy_from_slice(&0x30u64.to_le_bytes());
+        // and the section header table ends after the file
+        data[0x28..0x30].copy_from_slice(&0x1280u64.to_le_bytes());
+        let file = ElfFile::parse_with(&data, ParseMode::Lenient).unwrap();
+
+        let problems = lint(&file);
+
+        assert!(problems.iter().any(|problem| matches!(
+            problem.kind,
+            ElfErrorKind::Overlaps {
+                start: 0x30,
+                end: 0xa0,
+                other: "ELF header",
+                ..
+            }
+        )));
+        assert!(problems.iter().any(|problem| problem.field == "e_shoff"
+            && matches!(
+                problem.kind,
+                ElfErrorKind::OutOfBounds { start: 0x1280, .. }
+            )));
+    }
+
+    #[test]
+    fn lint_returns_an_e_shstrndx_that_is_not_a_section() {
+        let mut data = create_file(|_, _| {});
+        data[0x3e..0x40].copy_from_slice(&4u16.to_le_bytes());
+        let file = ElfFile::parse_with(&data, ParseMode::Lenient).unwrap();
+
+        assert!(fields(&lint(&file)).contains(&(0x3e, "e_shstrndx")));
+    }
+
+    #[test]
+    fn lint_returns_an_entry_point_that_is_not_in_the_code() {
+        let mut data = create_file(|_, _| {});
+        data[0x18..0x20].copy_from_slice(&0x401000u64.to_le_bytes());
+        let file = ElfFile::parse(&data).unwrap();
+
+        assert_eq!(fields(&lint(&file)), [(0x18, "e_entry")]);
+    }
+
+    #[test]
+    fn lint_returns_load_segments_out_of_order() {
+        let data = create_file(|phdrs, _| phdrs.swap(0, 1));
+        let file = ElfFile::parse(&data).unwrap();
+
+        // the p_vaddr of the second entry of the table
+        assert_eq!(fields(&lint(&file)), [(64 + 56 + 0x10, "p_vaddr")]);
+    }
+
+    #[test]
+    fn lint_returns_sections_that_are_half_inside_of_a_segment() {
+        // .text now goes past the end of the code segment into the start of
+        // the data one, in memory and in the file
+        let data = create_file(|_, shdrs| shdrs[1].sh_size = 0x900);
+        let file = ElfFile::parse(&data).unwrap();
+
+        let problems = lint(&file);
+
+        assert_eq!(
+            fields(&problems),
+            [
+                (0x1240 + 0x10, "sh_addr"),
+                (0x1240 + 0x18, "sh_offset"),
+                (0x1240 + 0x10, "sh_addr"),
+                (0x1240 + 0x18, "sh_offset")
+            ]
+        );
+        assert!(matches!(
+            problems[0].kind,
+            ElfErrorKind::Straddles {
+                start: 0x400800,
+                end: 0x401100,
+                segment: 0,
+                ..
+            }
+        ));
+        assert!(matches!(
+            problems[2].kind,
+            ElfErrorKind::Straddles { segment: 1, .. }
+        ));
+    }
+}
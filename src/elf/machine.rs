@@ -0,0 +1,416 @@
+use crate::elf::class::ElfClass;
+use crate::elf::def::elfconsts::*;
+use crate::elf::dwarf::registers::*;
+use crate::elf::flags::*;
+use crate::elf::relocate::*;
+use crate::elf::relocation_types::*;
+
+// everything that readelf knows about each e_machine. The name is the one
+// shown by the file header, and the decoders are the same ones used by every
+// other output, so the header and the relocations never disagree
+#[derive(Debug, Clone, Copy)]
+pub struct MachineInfo {
+    pub name: &'static str,
+    // None when the machine has files of both classes (like x32 for
+    // EM_X86_64, or ILP32 for EM_AARCH64) or when we just dont know
+    pub class: Option<ElfClass>,
+    // the usual endianness, for the machines that can use both it is the
+    // one of their first (or most common) ABI
+    pub little_endian: Option<bool>,
+    pub relocation_name: Option<fn(u32) -> Option<&'static str>>,
+    // what the relocations that can be applied to a dump (-R) do
+    pub relocation_kind: Option<fn(u32) -> Option<RelocationKind>>,
+    // the text shown after the hex value of e_flags, starting with ", "
+    pub flags: Option<fn(u32) -> String>,
+    // the names of the DWARF register numbers, for the location dumps
+    pub dwarf_register: Option<fn(u64) -> Option<&'static str>>,
+}
+
+impl MachineInfo {
+    fn new(name: &'static str) -> Self {
+        MachineInfo {
+            name,
+            class: None,
+            little_endian: None,
+            relocation_name: None,
+            relocation_kind: None,
+            flags: None,
+            dwarf_register: None,
+        }
+    }
+
+    fn elf32(self) -> Self {
+        MachineInfo {
+            class: Some(ElfClass::Elf32),
+            ..self
+        }
+    }
+
+    fn elf64(self) -> Self {
+        MachineInfo {
+            class: Some(ElfClass::Elf64),
+            ..self
+        }
+    }
+
+    fn little(self) -> Self {
+        MachineInfo {
+            little_endian: Some(true),
+            ..self
+        }
+    }
+
+    fn big(self) -> Self {
+        MachineInfo {
+            little_endian: Some(false),
+            ..self
+        }
+    }
+
+    fn relocations(self, decoder: fn(u32) -> Option<&'static str>) -> Self {
+        MachineInfo {
+            relocation_name: Some(decoder),
+            ..self
+        }
+    }
+
+    fn applied(self, decoder: fn(u32) -> Option<RelocationKind>) -> Self {
+        MachineInfo {
+            relocation_kind: Some(decoder),
+            ..self
+        }
+    }
+
+    fn flags(self, decoder: fn(u32) -> String) -> Self {
+        MachineInfo {
+            flags: Some(decoder),
+            ..self
+        }
+    }
+
+    fn registers(self, decoder: fn(u64) -> Option<&'static str>) -> Self {
+        MachineInfo {
+            dwarf_register: Some(decoder),
+            ..self
+        }
+    }
+}
+
+// every machine known by binutils, the big values at the end are the old
+// ones that were used before the machine got an official number
+pub fn machine_info(e_machine: u16) -> Option<MachineInfo> {
+    let info = match e_machine as u32 {
+        EM_NONE => MachineInfo::new("None"),
+        EM_M32 => MachineInfo::new("WE32100"),
+        EM_SPARC => MachineInfo::new("Sparc").elf32().big(),
+        EM_386 => MachineInfo::new("Intel 80386")
+            .elf32()
+            .little()
+            .relocations(i386_relocation_name)
+            .applied(i386_relocation_kind)
+            .registers(i386_register_name),
+        EM_68K => MachineInfo::new("MC68000").elf32().big(),
+        EM_88K => MachineInfo::new("MC88000"),
+        EM_IAMCU => MachineInfo::new("Intel MCU")
+            .elf32()
+            .little()
+            .relocations(i386_relocation_name)
+            .applied(i386_relocation_kind)
+            .registers(i386_register_name),
+        EM_860 => MachineInfo::new("Intel 80860"),
+        EM_MIPS => MachineInfo::new("MIPS R3000").big().flags(mips_flags),
+        EM_S370 => MachineInfo::new("IBM System/370").elf32().big(),
+        EM_MIPS_RS3_LE => MachineInfo::new("MIPS R4000 big-endian")
+            .little()
+            .flags(mips_flags),
+        EM_OLD_SPARCV9 => MachineInfo::new("Sparc v9 (old)"),
+        EM_PARISC => MachineInfo::new("HPPA").big(),
+        EM_VPP500 => MachineInfo::new("Fujitsu VPP500"),
+        EM_SPARC32PLUS => MachineInfo::new("Sparc v8+").elf32().big(),
+        EM_960 => MachineInfo::new("Intel 80960"),
+        EM_PPC => MachineInfo::new("PowerPC").elf32().big(),
+        EM_PPC64 => MachineInfo::new("PowerPC64")
+            .elf64()
+            .big()
+            .relocations(ppc64_relocation_name)
+            .applied(ppc64_relocation_kind)
+            .flags(ppc64_flags),
+        EM_S390 => MachineInfo::new("IBM S/390")
+            .big()
+            .relocations(s390_relocation_name)
+            .applied(s390_relocation_kind),
+        EM_SPU => MachineInfo::new("SPU"),
+        EM_V800 => MachineInfo::new("Renesas V850 (using RH850 ABI)"),
+        EM_FR20 => MachineInfo::new("Fujitsu FR20"),
+        EM_RH32 => MachineInfo::new("TRW RH32"),
+        EM_RCE => MachineInfo::new("MCORE"),
+        EM_ARM => MachineInfo::new("ARM")
+            .elf32()
+            .little()
+            .relocations(arm_relocation_name)
+            .applied(arm_relocation_kind)
+            .flags(arm_flags),
+        EM_FAKE_ALPHA => MachineInfo::new("Digital Alpha (old)"),
+        EM_SH => MachineInfo::new("Renesas / SuperH SH").elf32().little(),
+        EM_SPARCV9 => MachineInfo::new("Sparc v9")
+            .elf64()
+            .big()
+            .flags(sparcv9_flags),
+        EM_TRICORE => MachineInfo::new("Siemens Tricore"),
+        EM_ARC => MachineInfo::new("ARC"),
+        EM_H8_300 => MachineInfo::new("Renesas H8/300"),
+        EM_H8_300H => MachineInfo::new("Renesas H8/300H"),
+        EM_H8S => MachineInfo::new("Renesas H8S"),
+        EM_H8_500 => MachineInfo::new("Renesas H8/500"),
+        EM_IA_64 => MachineInfo::new("Intel IA-64").elf64().little(),
+        EM_MIPS_X => MachineInfo::new("Stanford MIPS-X"),
+        EM_COLDFIRE => MachineInfo::new("Motorola Coldfire"),
+        EM_68HC12 => MachineInfo::new("Motorola MC68HC12 Microcontroller"),
+        EM_MMA => MachineInfo::new("Fujitsu Multimedia Accelerator"),
+        EM_PCP => MachineInfo::new("Siemens PCP"),
+        EM_NCPU => MachineInfo::new("Sony nCPU embedded RISC processor"),
+        EM_NDR1 => MachineInfo::new("Denso NDR1 microprocesspr"),
+        EM_STARCORE => MachineInfo::new("Motorola Star*Core processor"),
+        EM_ME16 => MachineInfo::new("Toyota ME16 processor"),
+        EM_ST100 => MachineInfo::new("STMicroelectronics ST100 processor"),
+        EM_TINYJ => MachineInfo::new("Advanced Logic Corp. TinyJ embedded processor"),
+        EM_X86_64 => MachineInfo::new("Advanced Micro Devices X86-64")
+            .little()
+            .relocations(x86_64_relocation_name)
+            .applied(x86_64_relocation_kind)
+            .registers(x86_64_register_name),
+        EM_PDSP => MachineInfo::new("Sony DSP processor"),
+        EM_PDP10 => MachineInfo::new("Digital Equipment Corp. PDP-10"),
+        EM_PDP11 => MachineInfo::new("Digital Equipment Corp. PDP-11"),
+        EM_FX66 => MachineInfo::new("Siemens FX66 microcontroller"),
+        EM_ST9PLUS => MachineInfo::new("STMicroelectronics ST9+ 8/16 bit microcontroller"),
+        EM_ST7 => MachineInfo::new("STMicroelectronics ST7 8-bit microcontroller"),
+        EM_68HC16 => MachineInfo::new("Motorola MC68HC16 Microcontroller"),
+        EM_68HC11 => MachineInfo::new("Motorola MC68HC11 Microcontroller"),
+        EM_68HC08 => MachineInfo::new("Motorola MC68HC08 Microcontroller"),
+        EM_68HC05 => MachineInfo::new("Motorola MC68HC05 Microcontroller"),
+        EM_SVX => MachineInfo::new("Silicon Graphics SVx"),
+        EM_ST19 => MachineInfo::new("STMicroelectronics ST19 8-bit microcontroller"),
+        EM_VAX => MachineInfo::new("Digital VAX").elf32().little(),
+        EM_CRIS => MachineInfo::new("Axis Communications 32-bit embedded processor")
+            .elf32()
+            .little(),
+        EM_JAVELIN => MachineInfo::new("Infineon Technologies 32-bit embedded cpu"),
+        EM_FIREPATH => MachineInfo::new("Element 14 64-bit DSP processor"),
+        EM_ZSP => MachineInfo::new("LSI Logic's 16-bit DSP processor"),
+        EM_MMIX => MachineInfo::new("Donald Knuth's educational 64-bit processor"),
+        EM_HUANY => MachineInfo::new("Harvard Universitys's machine-independent object format"),
+        EM_PRISM => MachineInfo::new("Vitesse Prism"),
+        EM_AVR => MachineInfo::new("Atmel AVR 8-bit microcontroller")
+            .elf32()
+            .little()
+            .flags(avr_flags),
+        EM_FR30 => MachineInfo::new("Fujitsu FR30"),
+        EM_D10V => MachineInfo::new("d10v"),
+        EM_D30V => MachineInfo::new("d30v"),
+        EM_V850 => MachineInfo::new("Renesas V850"),
+        EM_M32R => MachineInfo::new("Renesas M32R (formerly Mitsubishi M32r)"),
+        EM_MN10300 => MachineInfo::new("mn10300"),
+        EM_MN10200 => MachineInfo::new("mn10200"),
+        EM_PJ => MachineInfo::new("picoJava"),
+        EM_OPENRISC => MachineInfo::new("OpenRISC 1000").elf32().big(),
+        EM_ARC_COMPACT => MachineInfo::new("ARCompact").elf32().little(),
+        EM_XTENSA => MachineInfo::new("Tensilica Xtensa Processor")
+            .elf32()
+            .little(),
+        EM_VIDEOCORE => MachineInfo::new("Alphamosaic VideoCore processor"),
+        EM_TMM_GPP => MachineInfo::new("Thompson Multimedia General Purpose Processor"),
+        EM_NS32K => MachineInfo::new("National Semiconductor 32000 series"),
+        EM_TPC => MachineInfo::new("Tenor Network TPC processor"),
+        EM_SNP1K => MachineInfo::new("Trebia SNP 1000 processor"),
+        EM_ST200 => MachineInfo::new("STMicroelectronics ST200 microcontroller"),
+        EM_IP2K => MachineInfo::new("Ubicom IP2xxx 8-bit microcontrollers"),
+        EM_MAX => MachineInfo::new("MAX Processor"),
+        EM_CR => MachineInfo::new("National Semiconductor CompactRISC"),
+        EM_F2MC16 => MachineInfo::new("Fujitsu F2MC16"),
+        EM_MSP430 => MachineInfo::new("Texas Instruments msp430 microcontroller")
+            .elf32()
+            .little(),
+        EM_BLACKFIN => MachineInfo::new("Analog Devices Blackfin"),
+        EM_SE_C33 => MachineInfo::new("S1C33 Family of Seiko Epson processors"),
+        EM_SEP => MachineInfo::new("Sharp embedded microprocessor"),
+        EM_ARCA => MachineInfo::new("Arca RISC microprocessor"),
+        EM_UNICORE => MachineInfo::new("Unicore"),
+        EM_EXCESS => MachineInfo::new("eXcess 16/32/64-bit configurable embedded CPU"),
+        EM_DXP => MachineInfo::new("Icera Semiconductor Inc. Deep Execution Processor"),
+        EM_ALTERA_NIOS2 => MachineInfo::new("Altera Nios II").elf32().little(),
+        EM_CRX => MachineInfo::new("National Semiconductor CRX microprocessor"),
+        EM_XGATE => MachineInfo::new("Motorola XGATE embedded processor"),
+        EM_C166 => MachineInfo::new("Infineon Technologies xc16x"),
+        EM_M16C => MachineInfo::new("Renesas M16C series microprocessors"),
+        EM_DSPIC30F => MachineInfo::new("Microchip Technology dsPIC30F Digital Signal Controller"),
+        EM_CE => MachineInfo::new("Freescale Communication Engine RISC core"),
+        EM_M32C => MachineInfo::new("Renesas M32c"),
+        EM_TSK3000 => MachineInfo::new("Altium TSK3000 core"),
+        EM_RS08 => MachineInfo::new("Freescale RS08 embedded processor"),
+        EM_ECOG2 => MachineInfo::new("Cyan Technology eCOG2 microprocessor"),
+        EM_SCORE7 => MachineInfo::new("SUNPLUS S+Core"),
+        EM_DSP24 => MachineInfo::new("New Japan Radio (NJR) 24-bit DSP Processor"),
+        EM_VIDEOCORE3 => MachineInfo::new("Broadcom VideoCore III processor"),
+        EM_LATTICEMICO32 => MachineInfo::new("Lattice Mico32"),
+        EM_SE_C17 => MachineInfo::new("Seiko Epson C17 family"),
+        EM_TI_C6000 => MachineInfo::new("Texas Instruments TMS320C6000 DSP family"),
+        EM_TI_C2000 => MachineInfo::new("Texas Instruments TMS320C2000 DSP family"),
+        EM_TI_C5500 => MachineInfo::new("Texas Instruments TMS320C55x DSP family"),
+        EM_TI_PRU => MachineInfo::new("TI PRU I/O processor"),
+        EM_MMDSP_PLUS => MachineInfo::new("STMicroelectronics 64bit VLIW Data Signal Processor"),
+        EM_CYPRESS_M8C => MachineInfo::new("Cypress M8C microprocessor"),
+        EM_R32C => MachineInfo::new("Renesas R32C series microprocessors"),
+        EM_TRIMEDIA => MachineInfo::new("NXP Semiconductors TriMedia architecture family"),
+        EM_QDSP6 => MachineInfo::new("QUALCOMM DSP6 Processor").elf32().little(),
+        EM_8051 => MachineInfo::new("Intel 8051 and variants"),
+        EM_STXP7X => MachineInfo::new("STMicroelectronics STxP7x family"),
+        EM_NDS32 => {
+            MachineInfo::new("Andes Technology compact code size embedded RISC processor family")
+        }
+        EM_ECOG1X => MachineInfo::new("Cyan Technology eCOG1X family"),
+        EM_MAXQ30 => MachineInfo::new("Dallas Semiconductor MAXQ30 Core microcontrollers"),
+        EM_XIMO16 => MachineInfo::new("New Japan Radio (NJR) 16-bit DSP Processor"),
+        EM_MANIK => MachineInfo::new("M2000 Reconfigurable RISC Microprocessor"),
+        EM_CRAYNV2 => MachineInfo::new("Cray Inc. NV2 vector architecture"),
+        EM_RX => MachineInfo::new("Renesas RX"),
+        EM_METAG => MachineInfo::new("Imagination Technologies Meta processor architecture"),
+        EM_MCST_ELBRUS => MachineInfo::new("MCST Elbrus general purpose hardware architecture"),
+        EM_ECOG16 => MachineInfo::new("Cyan Technology eCOG16 family"),
+        EM_CR16 => MachineInfo::new("Xilinx MicroBlaze"),
+        EM_ETPU => MachineInfo::new("Freescale Extended Time Processing Unit"),
+        EM_SLE9X => MachineInfo::new("Infineon Technologies SLE9X core"),
+        EM_L10M => MachineInfo::new("Intel L1OM"),
+        EM_K10M => MachineInfo::new("Intel K1OM"),
+        EM_INTEL182 => MachineInfo::new("Intel (reserved)"),
+        EM_AARCH64 => MachineInfo::new("AArch64")
+            .little()
+            .relocations(aarch64_relocation_name)
+            .applied(aarch64_relocation_kind)
+            .registers(aarch64_register_name),
+        EM_ARM184 => MachineInfo::new("ARM (reserved)"),
+        EM_AVR32 => MachineInfo::new("Atmel Corporation 32-bit microprocessor"),
+        EM_STM8 => MachineInfo::new("STMicroeletronics STM8 8-bit microcontroller"),
+        EM_TILE64 => MachineInfo::new("Tilera TILE64 multicore architecture family"),
+        EM_TILEPRO => MachineInfo::new("Tilera TILEPro multicore architecture family"),
+        EM_MICROBLAZE => MachineInfo::new("Xilinx MicroBlaze").elf32().big(),
+        EM_CUDA => MachineInfo::new("NVIDIA CUDA architecture"),
+        EM_TILEGX => MachineInfo::new("Tilera TILE-Gx multicore architecture family"),
+        EM_CLOUDSHIELD => MachineInfo::new("CloudShield architecture family"),
+        EM_COREA_1ST => MachineInfo::new("KIPO-KAIST Core-A 1st generation processor family"),
+        EM_COREA_2ND => MachineInfo::new("KIPO-KAIST Core-A 2nd generation processor family"),
+        EM_ARCV2 => MachineInfo::new("ARCv2").elf32().little(),
+        EM_OPEN8 => MachineInfo::new("Open8 8-bit RISC soft processor core"),
+        EM_RL78 => MachineInfo::new("Renesas RL78"),
+        EM_VIDEOCORE5 => MachineInfo::new("Broadcom VideoCore V processor"),
+        EM_78KOR => MachineInfo::new("Renesas 78K0R"),
+        EM_56800EX => MachineInfo::new("Freescale 56800EX Digital Signal Controller (DSC)"),
+        EM_BA1 => MachineInfo::new("Beyond BA1 CPU architecture"),
+        EM_BA2 => MachineInfo::new("Beyond BA2 CPU architecture"),
+        EM_XCORE => MachineInfo::new("XMOS xCORE processor family"),
+        EM_MCHP_PIC => MachineInfo::new("Microchip 8-bit PIC(r) family"),
+        EM_INTELGT => MachineInfo::new("Intel Graphics Technology"),
+        EM_KM32 => MachineInfo::new("KM211 KM32 32-bit processor"),
+        EM_KMX32 => MachineInfo::new("KM211 KMX32 32-bit processor"),
+        EM_EMX16 => MachineInfo::new("KM211 KMX16 16-bit processor"),
+        EM_EMX8 => MachineInfo::new("KM211 KMX8 8-bit processor"),
+        EM_KVARC => MachineInfo::new("KM211 KVARC processor"),
+        EM_CDP => MachineInfo::new("Paneve CDP architecture family"),
+        EM_COGE => MachineInfo::new("Cognitive Smart Memory Processor"),
+        EM_COOL => MachineInfo::new("Bluechip Systems CoolEngine"),
+        EM_NORC => MachineInfo::new("Nanoradio Optimized RISC"),
+        EM_CSR_KALIMBA => MachineInfo::new("CSR Kalimba architecture family"),
+        EM_Z80 => MachineInfo::new("Zilog Z80"),
+        EM_VISIUM => MachineInfo::new("CDS VISIUMcore processor"),
+        EM_FT32 => MachineInfo::new("FTDI Chip FT32"),
+        EM_MOXIE => MachineInfo::new("Moxie"),
+        EM_AMDGPU => MachineInfo::new("AMD GPU").elf64().little(),
+        EM_RISCV => MachineInfo::new("RISC-V")
+            .little()
+            .relocations(riscv_relocation_name)
+            .applied(riscv_relocation_kind)
+            .flags(riscv_flags)
+            .registers(riscv_register_name),
+        EM_LANAI => MachineInfo::new("Lanai 32-bit processor"),
+        EM_CEVA => MachineInfo::new("CEVA Processor Architecture Family"),
+        EM_CEVA_X2 => MachineInfo::new("CEVA X2 Processor Family"),
+        EM_BPF => MachineInfo::new("Linux BPF").elf64().little(),
+        EM_GRAPHCORE => MachineInfo::new("Graphcore Intelligent Processing Unit"),
+        EM_IMG1 => MachineInfo::new("Imagination Technologies"),
+        EM_NFP => MachineInfo::new("Netronome Flow Processor"),
+        EM_VE => MachineInfo::new("NEC Vector Engine"),
+        EM_CSKY => MachineInfo::new("C-SKY").elf32().little(),
+        EM_ARC_COMPACT3_64 => MachineInfo::new("Synopsys ARCv2.3 64-bit"),
+        EM_MCS6502 => MachineInfo::new("MOS Technology MCS 6502 processor"),
+        EM_ARC_COMPACT3 => MachineInfo::new("Synopsys ARCv2.3 32-bit"),
+        EM_KVX => MachineInfo::new("Kalray VLIW core of the MPPA processor family"),
+        EM_65816 => MachineInfo::new("WDC 65816/65C816"),
+        EM_LOONGARCH => MachineInfo::new("LoongArch")
+            .little()
+            .flags(loongarch_flags),
+        0x103 => MachineInfo::new("ChipON KungFu32"),
+        EM_AVR_OLD => MachineInfo::new("Atmel AVR 8-bit microcontroller"),
+        EM_ADAPTEVA_EPIPHANY => MachineInfo::new("Adapteva EPIPHANY"),
+        EM_MT => MachineInfo::new("Morpho Techologies MT processor"),
+        EM_CYGNUS_FR30 => MachineInfo::new("Fujitsu FR30"),
+        EM_WEBASSEMBLY => MachineInfo::new("Web Assembly"),
+        EM_XC16X => MachineInfo::new("Infineon Technologies xc16x"),
+        EM_S12Z => MachineInfo::new("Freescale S12Z"),
+        EM_CYGNUS_FRV => MachineInfo::new("Fujitsu FR-V"),
+        EM_DLX => MachineInfo::new("OpenDLX"),
+        EM_CYGNUS_D10V => MachineInfo::new("d10v"),
+        EM_CYGNUS_D30V => MachineInfo::new("d30v"),
+        EM_IP2K_OLD => MachineInfo::new("Ubicom IP2xxx 8-bit microcontrollers"),
+        EM_ALPHA => MachineInfo::new("Alpha").elf64().little(),
+        EM_CYGNUS_M32R => MachineInfo::new("Renesas M32R (formerly Mitsubishi M32r)"),
+        EM_CYGNUS_V850 => MachineInfo::new("Renesas V850"),
+        EM_S390_OLD => MachineInfo::new("IBM S/390"),
+        EM_XTENSA_OLD => MachineInfo::new("Tensilica Xtensa Processor"),
+        EM_XSTORMY16 => MachineInfo::new("Sanyo XStormy16 CPU core"),
+        EM_MICROBLAZE_OLD => MachineInfo::new("Xilinx MicroBlaze"),
+        EM_CYGNUS_MN10300 => MachineInfo::new("mn10300"),
+        EM_CYGNUS_MN10200 => MachineInfo::new("mn10200"),
+        EM_CYGNUS_MEP => MachineInfo::new("Toshiba MeP Media Engine"),
+        0xfeb0 => MachineInfo::new("Altera Nios"),
+        EM_IQ2000 => MachineInfo::new("Vitesse IQ2000"),
+        EM_NIOS32 => MachineInfo::new("Altera Nios"),
+        _ => return None,
+    };
+
+    Some(info)
+}
+
+pub fn machine_name(e_machine: u16) -> String {
+    match machine_info(e_machine) {
+        Some(info) => info.name.to_string(),
+        None => format!("<unknown>: 0x{e_machine:x}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{machine_info, machine_name};
+    use crate::elf::class::ElfClass;
+
+    #[test]
+    fn machine_name_returns_the_binutils_names() {
+        assert_eq!(machine_name(62), "Advanced Micro Devices X86-64");
+        assert_eq!(machine_name(258), "LoongArch");
+        assert_eq!(machine_name(0x9026), "Alpha");
+        assert_eq!(machine_name(0x1234), "<unknown>: 0x1234");
+    }
+
+    #[test]
+    fn machine_info_has_the_metadata_of_the_machine() {
+        let arm = machine_info(40).unwrap();
+        assert_eq!(arm.class, Some(ElfClass::Elf32));
+        assert_eq!(arm.little_endian, Some(true));
+
+        let decoder = arm.relocation_name.unwrap();
+        assert_eq!(decoder(10), Some("R_ARM_THM_CALL"));
+
+        // x32 uses EM_X86_64 too
+        assert_eq!(machine_info(62).unwrap().class, None);
+        assert!(machine_info(8).unwrap().relocation_name.is_none());
+    }
+}
@@ -1,3 +1,25 @@
+pub mod archive;
 pub mod header;
-mod def;
+pub mod class;
+pub mod compress;
+pub mod def;
+pub mod dwarf;
+pub mod dynamic;
+pub mod error;
+pub mod file;
+pub mod flags;
+pub mod hash;
+pub mod lint;
+pub mod machine;
+pub mod note;
+pub mod osabi;
+pub mod relocate;
+pub mod relocation;
+pub mod relocation_types;
+pub mod section;
+pub mod segment;
+pub mod strtab;
+pub mod symbol;
+pub mod types;
+pub mod version;
 mod validator;
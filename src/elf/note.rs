@@ -0,0 +1,316 @@
+use crate::elf::def::elf64strc::{Elf64_Ehdr, Elf64_Nhdr, Elf64_Phdr};
+use crate::elf::def::elfconsts::*;
+use crate::elf::error::ElfError;
+use crate::elf::section::Elf64Section;
+use crate::utils::endianess::EndianRead;
+use crate::utils::parser::EndianParseable;
+
+impl EndianParseable for Elf64_Nhdr {
+    fn parse_endian(bytes: &[u8], is_little_endian: bool) -> Result<Self, ElfError> {
+        // offset: 0x0 -> 0xC (relative to the start of the note), the note
+        // header has the same 3 words in 32 and 64-bit files
+        if bytes.len() < 12 {
+            return Err(ElfError::truncated(0, "Elf64_Nhdr", 12, bytes.len()));
+        }
+
+        Ok(Elf64_Nhdr {
+            // offset: 0x0 -> 0x3, size of the owner name (with its NUL)
+            n_namesz: u32::read_from(&bytes[0..4], is_little_endian),
+            // offset: 0x4 -> 0x7, size of the descriptor, without the padding
+            n_descsz: u32::read_from(&bytes[4..8], is_little_endian),
+            // offset: 0x8 -> 0xB, the meaning of the type depends of the owner
+            n_type: u32::read_from(&bytes[8..12], is_little_endian),
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Elf64Note {
+    pub nhdr: Elf64_Nhdr,
+    // the owner of the note ("GNU", "Go", "FreeBSD"...), without its NUL
+    pub name: String,
+    // the name exactly as it is in the file, the GNU build attributes keep
+    // binary values in there
+    pub raw_name: Vec<u8>,
+    pub desc: Vec<u8>,
+}
+
+// the notes can come from a SHT_NOTE section or from a PT_NOTE segment,
+// both are just a list of notes, one after the other
+#[derive(Debug)]
+pub struct Elf64NoteArea {
+    // the name of the section, None for the segments
+    pub section: Option<String>,
+    pub offset: u64,
+    pub size: u64,
+    pub align: u64,
+}
+
+// core files only have segments, the other ones use the sections when they
+// exist and only look at the segments when there is no SHT_NOTE at all
+pub fn note_areas(
+    header: &Elf64_Ehdr,
+    sections: &[Elf64Section],
+    segments: &[Elf64_Phdr],
+) -> Vec<Elf64NoteArea> {
+    let from_segments = || {
+        segments
+            .iter()
+            .filter(|phdr| phdr.p_type == PT_NOTE)
+            .map(|phdr| Elf64NoteArea {
+                section: None,
+                offset: phdr.p_offset,
+                size: phdr.p_filesz,
+                align: phdr.p_align,
+            })
+            .collect()
+    };
+
+    if header.e_type as u32 == ET_CORE {
+        return from_segments();
+    }
+
+    let areas: Vec<Elf64NoteArea> = sections
+        .iter()
+        .filter(|section| section.header.sh_type == SHT_NOTE)
+        .map(|section| Elf64NoteArea {
+            section: Some(section.name.clone()),
+            offset: section.header.sh_offset,
+            size: section.header.sh_size,
+            align: section.header.sh_addralign,
+        })
+        .collect();
+
+    if areas.is_empty() {
+        from_segments()
+    } else {
+        areas
+    }
+}
+
+pub fn parse_notes(
+    bytes: &[u8],
+    area: &Elf64NoteArea,
+    is_little_endian: bool,
+) -> Result<Vec<Elf64Note>, ElfError> {
+    let mut notes = Vec::new();
+    read_notes(bytes, area, is_little_endian, &mut notes)?;
+
+    Ok(notes)
+}
+
+// for the lenient mode, the notes before the first broken one are still
+// good, so they are returned together with the error that stopped us
+pub fn parse_notes_until_error(
+    bytes: &[u8],
+    area: &Elf64NoteArea,
+    is_little_endian: bool,
+) -> (Vec<Elf64Note>, Option<ElfError>) {
+    let mut notes = Vec::new();
+    let err = read_notes(bytes, area, is_little_endian, &mut notes).err();
+
+    (notes, err)
+}
+
+fn read_notes(
+    bytes: &[u8],
+    area: &Elf64NoteArea,
+    is_little_endian: bool,
+    notes: &mut Vec<Elf64Note>,
+) -> Result<(), ElfError> {
+    // the fields of the section (or of the segment) that tell where the notes
+    // are, for the errors
+    let (offset_field, align_field) = match area.section {
+        Some(_) => ("sh_offset", "sh_addralign"),
+        None => ("p_offset", "p_align"),
+    };
+
+    let data = usize::try_from(area.offset)
+        .ok()
+        .zip(usize::try_from(area.size).ok())
+        .and_then(|(start, size)| bytes.get(start..start.checked_add(size)?))
+        .ok_or_else(|| {
+            ElfError::out_of_bounds(
+                area.offset,
+                "Elf64_Nhdr",
+                offset_field,
+                (area.offset, area.size),
+                bytes.len(),
+            )
+        })?;
+
+    // the gABI says that the notes are aligned to 8 bytes in 64-bit files,
+    // but Linux uses 4 bytes almost everywhere. Sections with an alignment
+    // of 0 or 1 are treated as 4 too
+    let align = match area.align {
+        0..=4 => 4,
+        8 => 8,
+        align => {
+            return Err(ElfError::invalid_value(
+                area.offset,
+                "Elf64_Nhdr",
+                align_field,
+                "an alignment of 4 or 8",
+                align,
+            ));
+        }
+    };
+    let align_up = |value: u64| value.div_ceil(align) * align;
+
+    let mut position = 0;
+
+    while position < data.len() {
+        let note_offset = area.offset + position as u64;
+        let nhdr = Elf64_Nhdr::parse_endian(&data[position..], is_little_endian)
+            .map_err(|err| err.at(note_offset))?;
+
+        // both the name and the descriptor are padded to the alignment, and
+        // the padding of the last note must be in the area too
+        let desc_offset = align_up(12 + nhdr.n_namesz as u64);
+        let next_offset = align_up(desc_offset + nhdr.n_descsz as u64);
+        let left = (data.len() - position) as u64;
+        if next_offset > left {
+            // the name is the first one that can be too big
+            let (at, field, size) = if desc_offset > left {
+                (0, "n_namesz", nhdr.n_namesz)
+            } else {
+                (4, "n_descsz", nhdr.n_descsz)
+            };
+
+            return Err(ElfError::invalid_value(
+                note_offset + at,
+                "Elf64_Nhdr",
+                field,
+                format!("a size that fits in the {left} bytes left"),
+                size,
+            ));
+        }
+
+        let note = &data[position..position + next_offset as usize];
+        let name = &note[12..12 + nhdr.n_namesz as usize];
+        let desc = &note[desc_offset as usize..desc_offset as usize + nhdr.n_descsz as usize];
+
+        // the NUL is part of n_namesz, but some old cores dont have it
+        let owner = name.split(|&byte| byte == 0).next().unwrap_or_default();
+
+        notes.push(Elf64Note {
+            nhdr,
+            name: String::from_utf8_lossy(owner).into_owned(),
+            raw_name: name.to_vec(),
+            desc: desc.to_vec(),
+        });
+
+        position += next_offset as usize;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Elf64NoteArea, parse_notes, parse_notes_until_error};
+    use crate::elf::error::{ElfError, ElfErrorKind};
+
+    fn create_area(size: usize, align: u64) -> Elf64NoteArea {
+        Elf64NoteArea {
+            section: Some(".note.test".to_string()),
+            offset: 0,
+            size: size as u64,
+            align,
+        }
+    }
+
+    fn push_note(bytes: &mut Vec<u8>, name: &[u8], n_type: u32, desc: &[u8], align: usize) {
+        bytes.extend_from_slice(&(name.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&(desc.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&n_type.to_le_bytes());
+        bytes.extend_from_slice(name);
+        bytes.resize(bytes.len().next_multiple_of(align), 0);
+        bytes.extend_from_slice(desc);
+        bytes.resize(bytes.len().next_multiple_of(align), 0);
+    }
+
+    #[test]
+    fn parse_notes_reads_notes_with_4_bytes_alignment() {
+        let mut bytes = Vec::new();
+        push_note(&mut bytes, b"GNU\0", 3, &[0xde, 0xad, 0xbe, 0xef, 0x01], 4);
+        push_note(&mut bytes, b"Go\0", 4, b"abc", 4);
+
+        let notes = parse_notes(&bytes, &create_area(bytes.len(), 4), true).unwrap();
+
+        assert_eq!(notes.len(), 2);
+        assert_eq!(notes[0].name, "GNU");
+        assert_eq!(notes[0].nhdr.n_type, 3);
+        assert_eq!(notes[0].desc, [0xde, 0xad, 0xbe, 0xef, 0x01]);
+        assert_eq!(notes[1].name, "Go");
+        assert_eq!(notes[1].desc, b"abc");
+    }
+
+    #[test]
+    fn parse_notes_pads_the_name_to_8_bytes_when_aligned_to_8() {
+        let mut bytes = Vec::new();
+        push_note(&mut bytes, b"FreeBSD\0", 1, &[1, 2, 3, 4, 5, 6, 7, 8], 8);
+
+        let notes = parse_notes(&bytes, &create_area(bytes.len(), 8), true).unwrap();
+
+        // with 4 bytes, the descriptor would start at 0x14 instead of 0x18
+        assert_eq!(bytes.len(), 32);
+        assert_eq!(notes[0].desc, [1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn parse_notes_returns_invalid_note_size_err_when_the_descriptor_doesnt_fit() {
+        let mut bytes = Vec::new();
+        push_note(&mut bytes, b"GNU\0", 3, &[1, 2, 3, 4], 4);
+        bytes[4] = 0x40;
+
+        let err = parse_notes(&bytes, &create_area(bytes.len(), 4), true)
+            .err()
+            .unwrap();
+
+        assert!(matches!(
+            err,
+            ElfError {
+                offset: 4,
+                field: "n_descsz",
+                kind: ElfErrorKind::InvalidValue { actual: 0x40, .. },
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn parse_notes_until_error_keeps_the_notes_before_the_broken_one() {
+        let mut bytes = Vec::new();
+        push_note(&mut bytes, b"GNU\0", 3, &[1, 2, 3, 4], 4);
+        push_note(&mut bytes, b"Go\0", 4, b"abc", 4);
+        bytes[20] = 0x40; // n_namesz of the second note
+
+        let (notes, err) = parse_notes_until_error(&bytes, &create_area(bytes.len(), 4), true);
+
+        assert_eq!(notes.len(), 1);
+        assert_eq!(notes[0].name, "GNU");
+        assert!(matches!(
+            err,
+            Some(ElfError {
+                offset: 20,
+                field: "n_namesz",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn parse_notes_returns_invalid_alignment_err_for_odd_alignments() {
+        let err = parse_notes(&[], &create_area(0, 16), true).err().unwrap();
+
+        assert!(matches!(
+            err,
+            ElfError {
+                field: "sh_addralign",
+                kind: ElfErrorKind::InvalidValue { actual: 16, .. },
+                ..
+            }
+        ));
+    }
+}
@@ -0,0 +1,80 @@
+use crate::elf::def::elf64strc::Elf64_Ehdr;
+use crate::elf::def::elfconsts::*;
+
+// the names that binutils gives to EI_OSABI. The values from 64 onwards are
+// machine specific, so the same value can be a different ABI (or nothing at
+// all) depending on e_machine
+pub fn osabi_name(osabi: u8, e_machine: u16) -> Option<&'static str> {
+    let name = match osabi as u32 {
+        ELFOSABI_NONE => "UNIX - System V",
+        ELFOSABI_HPUX => "UNIX - HP-UX",
+        ELFOSABI_NETBSD => "UNIX - NetBSD",
+        ELFOSABI_GNU => "UNIX - GNU",
+        ELFOSABI_SOLARIS => "UNIX - Solaris",
+        ELFOSABI_AIX => "UNIX - AIX",
+        ELFOSABI_IRIX => "UNIX - IRIX",
+        ELFOSABI_FREEBSD => "UNIX - FreeBSD",
+        ELFOSABI_TRU64 => "UNIX - TRU64",
+        ELFOSABI_MODESTO => "Novell - Modesto",
+        ELFOSABI_OPENBSD => "UNIX - OpenBSD",
+        ELFOSABI_OPENVMS => "VMS - OpenVMS",
+        ELFOSABI_NSK => "HP - Non-Stop Kernel",
+        ELFOSABI_AROS => "AROS",
+        ELFOSABI_FENIXOS => "FenixOS",
+        ELFOSABI_CLOUDABI => "Nuxi CloudABI",
+        ELFOSABI_OPENVOS => "Stratus Technologies OpenVOS",
+        osabi => return machine_osabi_name(e_machine as u32, osabi),
+    };
+
+    Some(name)
+}
+
+fn machine_osabi_name(e_machine: u32, osabi: u32) -> Option<&'static str> {
+    let name = match (e_machine, osabi) {
+        (EM_ARM, ELFOSABI_ARM) => "ARM",
+        (EM_ARM, ELFOSABI_ARM_FDPIC) => "ARM FDPIC",
+        (EM_TI_C6000, ELFOSABI_C6000_ELFABI) => "Bare-metal C6000",
+        (EM_TI_C6000, ELFOSABI_C6000_LINUX) => "Linux C6000",
+        (EM_AMDGPU, ELFOSABI_AMDGPU_HSA) => "AMD HSA",
+        (EM_AMDGPU, ELFOSABI_AMDGPU_PAL) => "AMD PAL",
+        (EM_AMDGPU, ELFOSABI_AMDGPU_MESA3D) => "AMD Mesa3D",
+        (EM_MSP430 | EM_MSP430_OLD | EM_VISIUM, ELFOSABI_STANDALONE) => "Standalone App",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+// the OS specific ranges (SHT_LOOS..SHT_HIOS, STB_LOOS..STB_HIOS...) only
+// have a meaning for the ABI that defined them. The GNU extensions are
+// shared with FreeBSD (and, for the sections, with every ABI that is not
+// Solaris), so each user of the ranges has to ask about the one it needs
+pub fn is_gnu_or_freebsd(header: &Elf64_Ehdr) -> bool {
+    matches!(header.e_ident[7] as u32, ELFOSABI_GNU | ELFOSABI_FREEBSD)
+}
+
+pub fn is_solaris(header: &Elf64_Ehdr) -> bool {
+    header.e_ident[7] as u32 == ELFOSABI_SOLARIS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::osabi_name;
+
+    #[test]
+    fn osabi_name_returns_the_binutils_names() {
+        assert_eq!(osabi_name(0, 62), Some("UNIX - System V"));
+        assert_eq!(osabi_name(3, 62), Some("UNIX - GNU"));
+        assert_eq!(osabi_name(18, 62), Some("Stratus Technologies OpenVOS"));
+        assert_eq!(osabi_name(4, 62), None);
+    }
+
+    #[test]
+    fn osabi_name_depends_on_the_machine_for_big_values() {
+        assert_eq!(osabi_name(97, 40), Some("ARM"));
+        assert_eq!(osabi_name(97, 62), None);
+        assert_eq!(osabi_name(64, 224), Some("AMD HSA"));
+        assert_eq!(osabi_name(255, 105), Some("Standalone App"));
+        assert_eq!(osabi_name(255, 62), None);
+    }
+}
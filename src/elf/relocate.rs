@@ -0,0 +1,371 @@
+use crate::elf::class::{ClassParseable, ElfClass};
+use crate::elf::def::elf64strc::{Elf64_Rel, Elf64_Rela};
+use crate::elf::error::ElfError;
+use crate::elf::machine::machine_info;
+use crate::elf::relocation::{Elf64Relocations, r_sym, r_type};
+use crate::elf::section::Elf64Section;
+use crate::elf::symbol::Elf64Symbol;
+
+// what a relocation does to the bytes that it points to. Only the simple
+// ones are applied (the same ones that binutils applies to its dumps, that
+// are the ones used by the debug sections): a word with the address of the
+// symbol, or with its distance from the relocated word
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelocationKind {
+    // does nothing (R_*_NONE)
+    None,
+    // S + A, in a word of this many bytes
+    Absolute(usize),
+    // S + A - P
+    PcRelative(usize),
+}
+
+pub fn relocation_kind(e_machine: u16, r_type: u32) -> Option<RelocationKind> {
+    machine_info(e_machine)?.relocation_kind?(r_type)
+}
+
+pub fn x86_64_relocation_kind(r_type: u32) -> Option<RelocationKind> {
+    match r_type {
+        0 => Some(RelocationKind::None),
+        1 => Some(RelocationKind::Absolute(8)),
+        2 => Some(RelocationKind::PcRelative(4)),
+        10 => Some(RelocationKind::Absolute(4)),
+        24 => Some(RelocationKind::PcRelative(8)),
+        _ => None,
+    }
+}
+
+pub fn i386_relocation_kind(r_type: u32) -> Option<RelocationKind> {
+    match r_type {
+        0 => Some(RelocationKind::None),
+        1 => Some(RelocationKind::Absolute(4)),
+        2 => Some(RelocationKind::PcRelative(4)),
+        _ => None,
+    }
+}
+
+pub fn aarch64_relocation_kind(r_type: u32) -> Option<RelocationKind> {
+    match r_type {
+        0 | 256 => Some(RelocationKind::None),
+        257 => Some(RelocationKind::Absolute(8)),
+        258 => Some(RelocationKind::Absolute(4)),
+        259 => Some(RelocationKind::Absolute(2)),
+        260 => Some(RelocationKind::PcRelative(8)),
+        261 => Some(RelocationKind::PcRelative(4)),
+        _ => None,
+    }
+}
+
+pub fn arm_relocation_kind(r_type: u32) -> Option<RelocationKind> {
+    match r_type {
+        0 => Some(RelocationKind::None),
+        2 => Some(RelocationKind::Absolute(4)),
+        3 => Some(RelocationKind::PcRelative(4)),
+        _ => None,
+    }
+}
+
+pub fn riscv_relocation_kind(r_type: u32) -> Option<RelocationKind> {
+    match r_type {
+        0 => Some(RelocationKind::None),
+        1 => Some(RelocationKind::Absolute(4)),
+        2 => Some(RelocationKind::Absolute(8)),
+        _ => None,
+    }
+}
+
+pub fn ppc64_relocation_kind(r_type: u32) -> Option<RelocationKind> {
+    match r_type {
+        0 => Some(RelocationKind::None),
+        1 => Some(RelocationKind::Absolute(4)),
+        26 => Some(RelocationKind::PcRelative(4)),
+        38 => Some(RelocationKind::Absolute(8)),
+        44 => Some(RelocationKind::PcRelative(8)),
+        _ => None,
+    }
+}
+
+pub fn s390_relocation_kind(r_type: u32) -> Option<RelocationKind> {
+    match r_type {
+        0 => Some(RelocationKind::None),
+        4 => Some(RelocationKind::Absolute(4)),
+        5 => Some(RelocationKind::PcRelative(4)),
+        22 => Some(RelocationKind::Absolute(8)),
+        23 => Some(RelocationKind::PcRelative(8)),
+        _ => None,
+    }
+}
+
+// the target of a relocation section (sh_info) with its data, for
+// apply_relocations
+pub struct RelocationTarget<'a> {
+    pub data: &'a mut [u8],
+    pub e_machine: u16,
+    pub class: ElfClass,
+    pub is_little_endian: bool,
+}
+
+// applies the relocations of one SHT_REL or SHT_RELA section (section) to the
+// data of the section that they are for. REL has the addend in the bytes that
+// are relocated, RELA in the entry. The relocations that cannot be applied
+// are skipped and returned as errors at their entry
+pub fn apply_relocations(
+    target: &mut RelocationTarget,
+    section: &Elf64Section,
+    relocations: &Elf64Relocations,
+    symbols: &[Elf64Symbol],
+) -> Vec<ElfError> {
+    let class = target.class;
+    let (structure, entsize, entries) = match relocations {
+        Elf64Relocations::Rel(entries) => (
+            Elf64_Rel::name_of(class),
+            Elf64_Rel::size_of(class),
+            entries
+                .iter()
+                .map(|rel| (rel.r_offset, rel.r_info, None))
+                .collect::<Vec<_>>(),
+        ),
+        Elf64Relocations::Rela(entries) => (
+            Elf64_Rela::name_of(class),
+            Elf64_Rela::size_of(class),
+            entries
+                .iter()
+                .map(|rela| (rela.r_offset, rela.r_info, Some(rela.r_addend)))
+                .collect(),
+        ),
+        // RELR is only used by the dynamic loader
+        Elf64Relocations::Relr(_) => return Vec::new(),
+    };
+
+    // r_info is the second word of the entry
+    let r_info_offset = class.address_size() as u64;
+    let mut skipped = Vec::new();
+
+    for (i, (r_offset, r_info, r_addend)) in entries.into_iter().enumerate() {
+        let entry = section.header.sh_offset + (i * entsize) as u64;
+        let r_type = r_type(r_info);
+
+        let (size, is_pc_relative) = match relocation_kind(target.e_machine, r_type) {
+            Some(RelocationKind::None) => continue,
+            Some(RelocationKind::Absolute(size)) => (size, false),
+            Some(RelocationKind::PcRelative(size)) => (size, true),
+            None => {
+                skipped.push(ElfError::invalid_value(
+                    entry + r_info_offset,
+                    structure,
+                    "r_info",
+                    "a relocation type that can be applied to a dump",
+                    r_type,
+                ));
+                continue;
+            }
+        };
+
+        let Some(word) = usize::try_from(r_offset)
+            .ok()
+            .and_then(|start| target.data.get_mut(start..start.checked_add(size)?))
+        else {
+            skipped.push(ElfError::invalid_value(
+                entry,
+                structure,
+                "r_offset",
+                "an offset inside of the relocated section",
+                r_offset,
+            ));
+            continue;
+        };
+
+        let Some(symbol) = symbols.get(r_sym(r_info) as usize) else {
+            skipped.push(ElfError::invalid_value(
+                entry + r_info_offset,
+                structure,
+                "r_info",
+                "the index of a symbol of the linked table",
+                r_sym(r_info),
+            ));
+            continue;
+        };
+
+        let addend = match r_addend {
+            Some(addend) => addend as u64,
+            None => read_word(word, target.is_little_endian),
+        };
+        let mut value = symbol.sym.st_value.wrapping_add(addend);
+        if is_pc_relative {
+            value = value.wrapping_sub(r_offset);
+        }
+
+        write_word(word, value, target.is_little_endian);
+    }
+
+    skipped
+}
+
+// the words have 2, 4 or 8 bytes, the bigger bytes of the value are dropped
+fn read_word(word: &[u8], is_little_endian: bool) -> u64 {
+    let mut bytes = [0u8; 8];
+    if is_little_endian {
+        bytes[..word.len()].copy_from_slice(word);
+        u64::from_le_bytes(bytes)
+    } else {
+        bytes[8 - word.len()..].copy_from_slice(word);
+        u64::from_be_bytes(bytes)
+    }
+}
+
+fn write_word(word: &mut [u8], value: u64, is_little_endian: bool) {
+    let size = word.len();
+    if is_little_endian {
+        word.copy_from_slice(&value.to_le_bytes()[..size]);
+    } else {
+        word.copy_from_slice(&value.to_be_bytes()[8 - size..]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RelocationTarget, apply_relocations};
+    use crate::elf::class::ElfClass;
+    use crate::elf::def::elf64strc::{Elf64_Rel, Elf64_Rela, Elf64_Shdr, Elf64_Sym};
+    use crate::elf::error::ElfError;
+    use crate::elf::relocation::Elf64Relocations;
+    use crate::elf::symbol::Elf64Symbol;
+    use crate::test_utils::create_section;
+
+    // the null symbol and one at 0x1000
+    fn create_symbols() -> Vec<Elf64Symbol> {
+        [0, 0x1000]
+            .into_iter()
+            .map(|st_value| Elf64Symbol {
+                sym: Elf64_Sym {
+                    st_name: 0,
+                    st_info: 0x03,
+                    st_other: 0,
+                    st_shndx: 1,
+                    st_value,
+                    st_size: 0,
+                },
+                name: None,
+                xindex: None,
+                version: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn apply_relocations_applies_the_absolute_and_pc_relative_rela() {
+        // the relocations of the section 1, at 0x200
+        let mut section = create_section(".rela.text", 4);
+        section.header = Elf64_Shdr {
+            sh_offset: 0x200,
+            sh_info: 1,
+            ..section.header
+        };
+
+        let mut data = [0u8; 16];
+        let mut target = RelocationTarget {
+            data: &mut data,
+            e_machine: 62,
+            class: ElfClass::Elf64,
+            is_little_endian: true,
+        };
+        // R_X86_64_64 at 0 and R_X86_64_PC32 at 8, both against the symbol 1
+        let relocations = Elf64Relocations::Rela(vec![
+            Elf64_Rela {
+                r_offset: 0,
+                r_info: (1 << 32) | 1,
+                r_addend: 0x10,
+            },
+            Elf64_Rela {
+                r_offset: 8,
+                r_info: (1 << 32) | 2,
+                r_addend: -4,
+            },
+        ]);
+
+        let skipped = apply_relocations(&mut target, &section, &relocations, &create_symbols());
+
+        assert!(skipped.is_empty());
+        assert_eq!(u64::from_le_bytes(data[0..8].try_into().unwrap()), 0x1010);
+        assert_eq!(
+            u32::from_le_bytes(data[8..12].try_into().unwrap()),
+            0x1000 - 4 - 8
+        );
+    }
+
+    #[test]
+    fn apply_relocations_reads_the_addend_of_rel_from_the_data() {
+        // the relocations of the section 1, at 0x200
+        let mut section = create_section(".rel.text", 9);
+        section.header = Elf64_Shdr {
+            sh_offset: 0x200,
+            sh_info: 1,
+            ..section.header
+        };
+
+        let mut data = [0, 0, 0, 0x20];
+        let mut target = RelocationTarget {
+            data: &mut data,
+            e_machine: 20,
+            class: ElfClass::Elf32,
+            is_little_endian: false,
+        };
+        let relocations = Elf64Relocations::Rel(vec![Elf64_Rel {
+            r_offset: 0,
+            r_info: (1 << 32) | 1,
+        }]);
+
+        // EM_PPC has no relocation that we apply
+        let skipped = apply_relocations(&mut target, &section, &relocations, &create_symbols());
+        assert!(matches!(
+            skipped[..],
+            [ElfError {
+                offset: 0x204,
+                field: "r_info",
+                ..
+            }]
+        ));
+
+        // but EM_386 (here in big endian) has R_386_32
+        target.e_machine = 3;
+        let skipped = apply_relocations(&mut target, &section, &relocations, &create_symbols());
+        assert!(skipped.is_empty());
+        assert_eq!(data, [0, 0, 0x10, 0x20]);
+    }
+
+    #[test]
+    fn apply_relocations_skips_the_offsets_past_the_end_of_the_section() {
+        // the relocations of the section 1, at 0x200
+        let mut section = create_section(".rela.text", 4);
+        section.header = Elf64_Shdr {
+            sh_offset: 0x200,
+            sh_info: 1,
+            ..section.header
+        };
+
+        let mut data = [0u8; 4];
+        let mut target = RelocationTarget {
+            data: &mut data,
+            e_machine: 62,
+            class: ElfClass::Elf64,
+            is_little_endian: true,
+        };
+        let relocations = Elf64Relocations::Rela(vec![Elf64_Rela {
+            r_offset: 2,
+            r_info: (1 << 32) | 10,
+            r_addend: 0,
+        }]);
+
+        let skipped = apply_relocations(&mut target, &section, &relocations, &create_symbols());
+
+        assert!(matches!(
+            skipped[..],
+            [ElfError {
+                offset: 0x200,
+                field: "r_offset",
+                ..
+            }]
+        ));
+        assert_eq!(data, [0; 4]);
+    }
+}
@@ -0,0 +1,349 @@
+use crate::elf::class::{ClassParseable, ElfClass};
+use crate::elf::def::elf32strc::{Elf32_Rel, Elf32_Rela};
+use crate::elf::def::elf32fields::Elf32_Relr;
+use crate::elf::def::elf64fields::Elf64_Relr;
+use crate::elf::def::elf64strc::{Elf64_Rel, Elf64_Rela};
+use crate::elf::def::elfconsts::*;
+use crate::elf::error::ElfError;
+use crate::elf::section::{Elf64Section, section_data};
+use crate::elf::symbol::{Elf64Symbol, parse_symbol_table};
+use crate::utils::endianess::EndianRead;
+use crate::utils::parser::EndianParseable;
+
+impl EndianParseable for Elf64_Rel {
+    fn parse_endian(bytes: &[u8], is_little_endian: bool) -> Result<Self, ElfError> {
+        // offset: 0x0 -> 0x10 (relative to the start of the entry)
+        if bytes.len() < 16 {
+            return Err(ElfError::truncated(0, "Elf64_Rel", 16, bytes.len()));
+        }
+
+        Ok(Elf64_Rel {
+            // offset: 0x0 -> 0x7, where the relocation is applied
+            r_offset: u64::read_from(&bytes[0..8], is_little_endian),
+            // offset: 0x8 -> 0xF, symbol index (high 32 bits) and type (low 32 bits)
+            r_info: u64::read_from(&bytes[8..16], is_little_endian),
+        })
+    }
+}
+
+impl EndianParseable for Elf64_Rela {
+    fn parse_endian(bytes: &[u8], is_little_endian: bool) -> Result<Self, ElfError> {
+        // offset: 0x0 -> 0x18 (relative to the start of the entry)
+        if bytes.len() < 24 {
+            return Err(ElfError::truncated(0, "Elf64_Rela", 24, bytes.len()));
+        }
+
+        Ok(Elf64_Rela {
+            // offset: 0x0 -> 0x7
+            r_offset: u64::read_from(&bytes[0..8], is_little_endian),
+            // offset: 0x8 -> 0xF
+            r_info: u64::read_from(&bytes[8..16], is_little_endian),
+            // offset: 0x10 -> 0x17, the same of Elf64_Rel, but with an explicit addend
+            r_addend: i64::read_from(&bytes[16..24], is_little_endian),
+        })
+    }
+}
+
+impl EndianParseable for Elf32_Rel {
+    fn parse_endian(bytes: &[u8], is_little_endian: bool) -> Result<Self, ElfError> {
+        // offset: 0x0 -> 0x8 (relative to the start of the entry)
+        if bytes.len() < 8 {
+            return Err(ElfError::truncated(0, "Elf32_Rel", 8, bytes.len()));
+        }
+
+        Ok(Elf32_Rel {
+            // offset: 0x0 -> 0x3
+            r_offset: u32::read_from(&bytes[0..4], is_little_endian),
+            // offset: 0x4 -> 0x7, symbol index (high 24 bits) and type (low 8 bits)
+            r_info: u32::read_from(&bytes[4..8], is_little_endian),
+        })
+    }
+}
+
+impl EndianParseable for Elf32_Rela {
+    fn parse_endian(bytes: &[u8], is_little_endian: bool) -> Result<Self, ElfError> {
+        // offset: 0x0 -> 0xC (relative to the start of the entry)
+        if bytes.len() < 12 {
+            return Err(ElfError::truncated(0, "Elf32_Rela", 12, bytes.len()));
+        }
+
+        Ok(Elf32_Rela {
+            // offset: 0x0 -> 0x3
+            r_offset: u32::read_from(&bytes[0..4], is_little_endian),
+            // offset: 0x4 -> 0x7
+            r_info: u32::read_from(&bytes[4..8], is_little_endian),
+            // offset: 0x8 -> 0xB
+            r_addend: u32::read_from(&bytes[8..12], is_little_endian) as i32,
+        })
+    }
+}
+
+// the 32-bit r_info is moved to the 64-bit layout, so r_sym and r_type work
+// the same way for both classes
+fn widen_r_info(r_info: u32) -> u64 {
+    ((r_info as u64 >> 8) << 32) | (r_info as u64 & 0xff)
+}
+
+impl From<Elf32_Rel> for Elf64_Rel {
+    fn from(rel: Elf32_Rel) -> Self {
+        Elf64_Rel {
+            r_offset: rel.r_offset as u64,
+            r_info: widen_r_info(rel.r_info),
+        }
+    }
+}
+
+impl From<Elf32_Rela> for Elf64_Rela {
+    fn from(rela: Elf32_Rela) -> Self {
+        Elf64_Rela {
+            r_offset: rela.r_offset as u64,
+            r_info: widen_r_info(rela.r_info),
+            r_addend: rela.r_addend as i64,
+        }
+    }
+}
+
+impl ClassParseable for Elf64_Rel {
+    type Elf32 = Elf32_Rel;
+
+    const ELF32_SIZE: usize = 8;
+    const ELF64_SIZE: usize = 16;
+    const ELF32_NAME: &'static str = "Elf32_Rel";
+    const ELF64_NAME: &'static str = "Elf64_Rel";
+}
+
+impl ClassParseable for Elf64_Rela {
+    type Elf32 = Elf32_Rela;
+
+    const ELF32_SIZE: usize = 12;
+    const ELF64_SIZE: usize = 24;
+    const ELF32_NAME: &'static str = "Elf32_Rela";
+    const ELF64_NAME: &'static str = "Elf64_Rela";
+}
+
+// the same as the ELF64_R_SYM and ELF64_R_TYPE macros of elf.h
+pub fn r_sym(r_info: u64) -> u32 {
+    (r_info >> 32) as u32
+}
+
+pub fn r_type(r_info: u64) -> u32 {
+    (r_info & 0xffffffff) as u32
+}
+
+// r_info as it is in the file, the 32-bit one is packed again (the same as
+// the ELF32_R_INFO macro)
+pub fn file_r_info(r_info: u64, class: ElfClass) -> u64 {
+    match class {
+        ElfClass::Elf32 => ((r_sym(r_info) as u64) << 8) | (r_type(r_info) as u64 & 0xff),
+        ElfClass::Elf64 => r_info,
+    }
+}
+
+pub enum Elf64Relocations {
+    Rel(Vec<Elf64_Rel>),
+    Rela(Vec<Elf64_Rela>),
+    // every entry is an address or a bitmap of the next addresses, use
+    // decode_relr to get the addresses that are relocated
+    Relr(Vec<Elf64_Relr>),
+}
+
+pub fn is_relocation_section(section: &Elf64Section) -> bool {
+    matches!(section.header.sh_type, SHT_REL | SHT_RELA | SHT_RELR)
+}
+
+// the entries are read with their real size, no matter what sh_entsize says
+// (binutils does the same thing)
+pub fn parse_relocations(
+    bytes: &[u8],
+    section: &Elf64Section,
+    class: ElfClass,
+    is_little_endian: bool,
+) -> Result<Elf64Relocations, ElfError> {
+    let shdr = &section.header;
+    let data = section_data(bytes, shdr).ok_or_else(|| {
+        ElfError::out_of_bounds(
+            shdr.sh_offset,
+            relocation_name(shdr.sh_type, class),
+            "sh_offset",
+            (shdr.sh_offset, shdr.sh_size),
+            bytes.len(),
+        )
+    })?;
+
+    let relocations = match shdr.sh_type {
+        SHT_REL => Elf64Relocations::Rel(
+            data.chunks_exact(Elf64_Rel::size_of(class))
+                .map(|entry| Elf64_Rel::parse_class(entry, class, is_little_endian))
+                .collect::<Result<_, _>>()?,
+        ),
+        SHT_RELA => Elf64Relocations::Rela(
+            data.chunks_exact(Elf64_Rela::size_of(class))
+                .map(|entry| Elf64_Rela::parse_class(entry, class, is_little_endian))
+                .collect::<Result<_, _>>()?,
+        ),
+        _ => Elf64Relocations::Relr(
+            data.chunks_exact(class.address_size())
+                .map(|entry| match class {
+                    ElfClass::Elf32 => Elf32_Relr::read_from(entry, is_little_endian) as u64,
+                    ElfClass::Elf64 => u64::read_from(entry, is_little_endian),
+                })
+                .collect(),
+        ),
+    };
+
+    Ok(relocations)
+}
+
+// the struct of the entries of a relocation section, for the errors
+fn relocation_name(sh_type: u32, class: ElfClass) -> &'static str {
+    match (sh_type, class) {
+        (SHT_REL, _) => Elf64_Rel::name_of(class),
+        (SHT_RELA, _) => Elf64_Rela::name_of(class),
+        (_, ElfClass::Elf32) => "Elf32_Relr",
+        (_, ElfClass::Elf64) => "Elf64_Relr",
+    }
+}
+
+// RELR packs the relative relocations: an even entry is the address of the
+// next relocation, and an odd one is a bitmap of the words after the last
+// address (63 in 64-bit files, 31 in 32-bit ones, the lowest bit only marks
+// it as a bitmap)
+pub fn decode_relr(entries: &[Elf64_Relr], class: ElfClass) -> Vec<u64> {
+    let word = class.address_size() as u64;
+    let bits = word * 8 - 1;
+
+    let mut addresses = Vec::new();
+    let mut base = 0u64;
+
+    for &entry in entries {
+        if entry & 1 == 0 {
+            addresses.push(entry);
+            base = entry.wrapping_add(word);
+        } else {
+            let mut bitmap = entry >> 1;
+            let mut address = base;
+
+            while bitmap != 0 {
+                if bitmap & 1 == 1 {
+                    addresses.push(address);
+                }
+                bitmap >>= 1;
+                address = address.wrapping_add(word);
+            }
+
+            base = base.wrapping_add(bits * word);
+        }
+    }
+
+    addresses
+}
+
+// the symbols used by the relocations are in the table at sh_link. RELR
+// (and some REL/RELA of the loader) dont use symbols and have sh_link = 0
+pub fn linked_symbols(
+    bytes: &[u8],
+    sections: &[Elf64Section],
+    section: &Elf64Section,
+    class: ElfClass,
+    is_little_endian: bool,
+) -> Result<Option<Vec<Elf64Symbol>>, ElfError> {
+    let link = section.header.sh_link;
+    if link == 0 {
+        return Ok(None);
+    }
+
+    let table = sections
+        .get(link as usize)
+        .filter(|table| matches!(table.header.sh_type, SHT_SYMTAB | SHT_DYNSYM))
+        .ok_or_else(|| {
+            ElfError::invalid_value(
+                section.header.sh_offset,
+                relocation_name(section.header.sh_type, class),
+                "sh_link",
+                "the index of a symbol table",
+                link,
+            )
+        })?;
+
+    parse_symbol_table(bytes, sections, table, class, is_little_endian).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Elf64Relocations, decode_relr, file_r_info, parse_relocations, r_sym, r_type};
+    use crate::elf::class::ElfClass;
+    use crate::elf::def::elf64strc::Elf64_Shdr;
+    use crate::test_utils::create_section;
+
+    #[test]
+    fn parse_relocations_reads_rela_entries_with_signed_addends() {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&0x3fc0u64.to_be_bytes());
+        bytes.extend_from_slice(&0x0000000100000006u64.to_be_bytes());
+        bytes.extend_from_slice(&(-4i64).to_be_bytes());
+
+        let mut section = create_section(".rela.text", 4);
+        section.header = Elf64_Shdr {
+            sh_size: 24,
+            sh_entsize: 24,
+            ..section.header
+        };
+
+        let relocations = parse_relocations(&bytes, &section, ElfClass::Elf64, false).unwrap();
+
+        let Elf64Relocations::Rela(relocations) = relocations else {
+            panic!("RELA sections should be parsed as Elf64_Rela");
+        };
+        assert_eq!(relocations.len(), 1);
+        assert_eq!(relocations[0].r_offset, 0x3fc0);
+        assert_eq!(r_sym(relocations[0].r_info), 1);
+        assert_eq!(r_type(relocations[0].r_info), 6);
+        assert_eq!(relocations[0].r_addend, -4);
+    }
+
+    #[test]
+    fn decode_relr_expands_addresses_and_bitmaps() {
+        // 0x1000, then a bitmap with the 1st and the 3rd words after it
+        let entries = [0x1000, 0b1011];
+
+        assert_eq!(
+            decode_relr(&entries, ElfClass::Elf64),
+            [0x1000, 0x1008, 0x1018]
+        );
+    }
+
+    #[test]
+    fn decode_relr_uses_4_bytes_words_in_32_bit_files() {
+        // the bitmap starts after the word of the address, 31 words of 4 bytes
+        let entries = [0x1000, 0b1011, 0b11];
+
+        assert_eq!(
+            decode_relr(&entries, ElfClass::Elf32),
+            [0x1000, 0x1004, 0x100c, 0x1080]
+        );
+    }
+
+    #[test]
+    fn parse_relocations_widens_the_32_bit_r_info() {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&0x804c00cu32.to_le_bytes());
+        bytes.extend_from_slice(&0x0000_0207u32.to_le_bytes());
+
+        let mut section = create_section(".rel.text", 9);
+        section.header = Elf64_Shdr {
+            sh_size: 8,
+            sh_entsize: 8,
+            ..section.header
+        };
+
+        let relocations = parse_relocations(&bytes, &section, ElfClass::Elf32, true).unwrap();
+
+        let Elf64Relocations::Rel(relocations) = relocations else {
+            panic!("REL sections should be parsed as Elf64_Rel");
+        };
+        assert_eq!(relocations[0].r_offset, 0x804c00c);
+        assert_eq!(r_sym(relocations[0].r_info), 2);
+        assert_eq!(r_type(relocations[0].r_info), 7);
+        assert_eq!(file_r_info(relocations[0].r_info, ElfClass::Elf32), 0x207);
+    }
+}
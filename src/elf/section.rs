@@ -0,0 +1,369 @@
+use crate::elf::class::ClassParseable;
+use crate::elf::def::elf32strc::Elf32_Shdr;
+use crate::elf::def::elf64strc::{Elf64_Ehdr, Elf64_Shdr};
+use crate::elf::def::elfconsts::*;
+use crate::elf::error::ElfError;
+use crate::elf::strtab::get_string;
+use crate::elf::types::SectionType;
+use crate::utils::endianess::EndianRead;
+use crate::utils::parser::EndianParseable;
+
+impl EndianParseable for Elf64_Shdr {
+    fn parse_endian(bytes: &[u8], is_little_endian: bool) -> Result<Self, ElfError> {
+        // offset: 0x0 -> 0x40 (relative to the start of the entry)
+
+        // every section header entry has the same 64 bytes in 64-bit files
+        // (40 in 32-bit ones), e_shentsize can be bigger than that (with some
+        // padding), but never smaller
+        if bytes.len() < 64 {
+            return Err(ElfError::truncated(0, "Elf64_Shdr", 64, bytes.len()));
+        }
+
+        Ok(Elf64_Shdr {
+            // offset: 0x0 -> 0x3, offset of the name inside of the e_shstrndx section
+            sh_name: u32::read_from(&bytes[0..4], is_little_endian),
+            // offset: 0x4 -> 0x7
+            sh_type: u32::read_from(&bytes[4..8], is_little_endian),
+            // offset: 0x8 -> 0xF
+            sh_flags: u64::read_from(&bytes[8..16], is_little_endian),
+            // offset: 0x10 -> 0x17, virtual address when loaded (0 if not)
+            sh_addr: u64::read_from(&bytes[16..24], is_little_endian),
+            // offset: 0x18 -> 0x1F, where the section bytes are in the file
+            sh_offset: u64::read_from(&bytes[24..32], is_little_endian),
+            // offset: 0x20 -> 0x27
+            sh_size: u64::read_from(&bytes[32..40], is_little_endian),
+            // offset: 0x28 -> 0x2B, the meaning of link and info depends of
+            // the section type
+            sh_link: u32::read_from(&bytes[40..44], is_little_endian),
+            // offset: 0x2C -> 0x2F
+            sh_info: u32::read_from(&bytes[44..48], is_little_endian),
+            // offset: 0x30 -> 0x37
+            sh_addralign: u64::read_from(&bytes[48..56], is_little_endian),
+            // offset: 0x38 -> 0x3F, size of each entry for tables (symtab, rela...)
+            sh_entsize: u64::read_from(&bytes[56..64], is_little_endian),
+        })
+    }
+}
+
+impl EndianParseable for Elf32_Shdr {
+    fn parse_endian(bytes: &[u8], is_little_endian: bool) -> Result<Self, ElfError> {
+        // offset: 0x0 -> 0x28 (relative to the start of the entry), the same
+        // fields of the 64-bit one, but every one of them has 4 bytes
+        if bytes.len() < 40 {
+            return Err(ElfError::truncated(0, "Elf32_Shdr", 40, bytes.len()));
+        }
+
+        let word = |at: usize| u32::read_from(&bytes[at..at + 4], is_little_endian);
+
+        Ok(Elf32_Shdr {
+            sh_name: word(0),
+            sh_type: word(4),
+            sh_flags: word(8),
+            sh_addr: word(12),
+            sh_offset: word(16),
+            sh_size: word(20),
+            sh_link: word(24),
+            sh_info: word(28),
+            sh_addralign: word(32),
+            sh_entsize: word(36),
+        })
+    }
+}
+
+impl From<Elf32_Shdr> for Elf64_Shdr {
+    fn from(shdr: Elf32_Shdr) -> Self {
+        Elf64_Shdr {
+            sh_name: shdr.sh_name,
+            sh_type: shdr.sh_type,
+            sh_flags: shdr.sh_flags as u64,
+            sh_addr: shdr.sh_addr as u64,
+            sh_offset: shdr.sh_offset as u64,
+            sh_size: shdr.sh_size as u64,
+            sh_link: shdr.sh_link,
+            sh_info: shdr.sh_info,
+            sh_addralign: shdr.sh_addralign as u64,
+            sh_entsize: shdr.sh_entsize as u64,
+        }
+    }
+}
+
+impl ClassParseable for Elf64_Shdr {
+    type Elf32 = Elf32_Shdr;
+
+    const ELF32_SIZE: usize = 40;
+    const ELF64_SIZE: usize = 64;
+    const ELF32_NAME: &'static str = "Elf32_Shdr";
+    const ELF64_NAME: &'static str = "Elf64_Shdr";
+}
+
+impl Elf64_Shdr {
+    pub fn section_type(&self) -> SectionType {
+        self.sh_type.into()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Elf64Section {
+    pub header: Elf64_Shdr,
+    pub name: String,
+}
+
+// the bytes of the section inside of the file. NOBITS sections (like .bss)
+// occupy no space in the file, so they have no data at all
+pub fn section_data<'a>(bytes: &'a [u8], shdr: &Elf64_Shdr) -> Option<&'a [u8]> {
+    if shdr.sh_type == SHT_NOBITS {
+        return Some(&[]);
+    }
+
+    let start = usize::try_from(shdr.sh_offset).ok()?;
+    let size = usize::try_from(shdr.sh_size).ok()?;
+
+    bytes.get(start..start.checked_add(size)?)
+}
+
+pub fn parse_section_headers(
+    bytes: &[u8],
+    header: &Elf64_Ehdr,
+) -> Result<Vec<Elf64_Shdr>, ElfError> {
+    // the section header table is at e_shoff, and have e_shnum entries of
+    // e_shentsize bytes each one. A file without sections have e_shoff = 0
+    let count = section_count(bytes, header)?;
+    if header.e_shoff == 0 || count == 0 {
+        return Ok(Vec::new());
+    }
+
+    read_section_headers(bytes, header, count)
+}
+
+// the first count entries of the section header table
+fn read_section_headers(
+    bytes: &[u8],
+    header: &Elf64_Ehdr,
+    count: usize,
+) -> Result<Vec<Elf64_Shdr>, ElfError> {
+    let class = header.class();
+    let structure = Elf64_Shdr::name_of(class);
+
+    let size = Elf64_Shdr::size_of(class);
+    if (header.e_shentsize as usize) < size {
+        return Err(ElfError::invalid_value(
+            header.field_offset("e_shentsize"),
+            structure,
+            "e_shentsize",
+            format!("at least {size} (the size of an {structure})"),
+            header.e_shentsize,
+        ));
+    }
+
+    let entsize = header.e_shentsize as usize;
+    let table_size = entsize.saturating_mul(count);
+
+    let table = usize::try_from(header.e_shoff)
+        .ok()
+        .and_then(|start| bytes.get(start..start.checked_add(table_size)?))
+        .ok_or_else(|| {
+            ElfError::out_of_bounds(
+                header.field_offset("e_shoff"),
+                structure,
+                "e_shoff",
+                (header.e_shoff, table_size as u64),
+                bytes.len(),
+            )
+        })?;
+
+    let is_little_endian = header.is_little_endian();
+
+    table
+        .chunks_exact(entsize)
+        .map(|entry| Elf64_Shdr::parse_class(entry, class, is_little_endian))
+        .collect()
+}
+
+// files with SHN_LORESERVE (0xff00) sections or more cannot have the count
+// in e_shnum, so it is 0 and the real count is in the sh_size of the section
+// 0 (that is never used for anything else). The same goes for the index of
+// the section names, that is SHN_XINDEX in e_shstrndx and the real one is in
+// its sh_link, and for the PN_XNUM in e_phnum (see segment_count). None when
+// the file has no sections at all
+pub fn first_section_header(
+    bytes: &[u8],
+    header: &Elf64_Ehdr,
+) -> Result<Option<Elf64_Shdr>, ElfError> {
+    if header.e_shoff == 0 {
+        return Ok(None);
+    }
+
+    Ok(read_section_headers(bytes, header, 1)?.pop())
+}
+
+// the number of entries of the section header table. The section 0 is only
+// read when e_shnum says that the count is there
+pub fn section_count(bytes: &[u8], header: &Elf64_Ehdr) -> Result<usize, ElfError> {
+    if header.e_shnum as u32 != SHN_UNDEF {
+        return Ok(header.e_shnum as usize);
+    }
+
+    let first = first_section_header(bytes, header)?;
+    Ok(first.map_or(0, |shdr| shdr.sh_size as usize))
+}
+
+// the index of the section with the names of the sections, SHN_UNDEF when
+// the file has none
+pub fn string_table_index(bytes: &[u8], header: &Elf64_Ehdr) -> Result<u32, ElfError> {
+    if header.e_shstrndx as u32 != SHN_XINDEX {
+        return Ok(header.e_shstrndx as u32);
+    }
+
+    let first = first_section_header(bytes, header)?;
+    Ok(first.map_or(SHN_UNDEF, |shdr| shdr.sh_link))
+}
+
+pub fn parse_sections(bytes: &[u8], header: &Elf64_Ehdr) -> Result<Vec<Elf64Section>, ElfError> {
+    let shdrs = parse_section_headers(bytes, header)?;
+
+    // the names are not inside of the section headers, they are all together
+    // in the section with index e_shstrndx (usually .shstrtab)
+    let shstrndx = string_table_index(bytes, header)?;
+    let shstrtab = shdrs
+        .get(shstrndx as usize)
+        .filter(|_| shstrndx != SHN_UNDEF)
+        .map(|shstrtab| section_data(bytes, shstrtab));
+
+    let sections = shdrs
+        .iter()
+        .map(|shdr| {
+            let name = match shstrtab {
+                None => "<no-strings>".to_string(),
+                Some(None) => "<corrupt>".to_string(),
+                Some(Some(table)) => get_string(table, shdr.sh_name as usize)
+                    .map(|name| name.into_owned())
+                    .unwrap_or_else(|| "<corrupt>".to_string()),
+            };
+
+            Elf64Section {
+                header: *shdr,
+                name,
+            }
+        })
+        .collect();
+
+    Ok(sections)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_sections, section_data};
+    use crate::elf::def::elf64strc::Elf64_Ehdr;
+    use crate::elf::error::{ElfError, ElfErrorKind};
+
+    // a tiny file with only the ELF header, a NULL section and the .shstrtab
+    fn create_file() -> (Vec<u8>, Elf64_Ehdr) {
+        let shstrtab = b"\0.shstrtab\0";
+        let mut bytes = vec![0u8; 64];
+        bytes.extend_from_slice(shstrtab);
+
+        let shoff = bytes.len() as u64;
+        bytes.extend_from_slice(&[0u8; 64]);
+
+        let mut entry = [0u8; 64];
+        entry[0..4].copy_from_slice(&1u32.to_le_bytes());
+        entry[4..8].copy_from_slice(&3u32.to_le_bytes());
+        entry[24..32].copy_from_slice(&64u64.to_le_bytes());
+        entry[32..40].copy_from_slice(&(shstrtab.len() as u64).to_le_bytes());
+        entry[48..56].copy_from_slice(&1u64.to_le_bytes());
+        bytes.extend_from_slice(&entry);
+
+        let header = Elf64_Ehdr {
+            e_ident: [0x7f, 0x45, 0x4c, 0x46, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            e_type: 1,
+            e_machine: 62,
+            e_version: 1,
+            e_entry: 0,
+            e_phoff: 0,
+            e_shoff: shoff,
+            e_flags: 0,
+            e_ehsize: 64,
+            e_phentsize: 0,
+            e_phnum: 0,
+            e_shentsize: 64,
+            e_shnum: 2,
+            e_shstrndx: 1,
+        };
+
+        (bytes, header)
+    }
+
+    #[test]
+    fn parse_sections_returns_every_section_with_its_name() {
+        let (bytes, header) = create_file();
+
+        let sections = parse_sections(&bytes, &header).unwrap();
+
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[0].name, "");
+        assert_eq!(sections[1].name, ".shstrtab");
+        assert_eq!(sections[1].header.sh_type, 3);
+        assert_eq!(section_data(&bytes, &sections[1].header).unwrap(), b"\0.shstrtab\0");
+    }
+
+    #[test]
+    fn parse_sections_reads_the_counts_that_dont_fit_in_the_header_from_the_section_0() {
+        let (mut bytes, mut header) = create_file();
+        header.e_shnum = 0;
+        header.e_shstrndx = 0xffff;
+        let shoff = header.e_shoff as usize;
+        bytes[shoff + 32..shoff + 40].copy_from_slice(&2u64.to_le_bytes());
+        bytes[shoff + 40..shoff + 44].copy_from_slice(&1u32.to_le_bytes());
+
+        let sections = parse_sections(&bytes, &header).unwrap();
+
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[1].name, ".shstrtab");
+    }
+
+    #[test]
+    fn parse_sections_returns_out_of_bounds_err_when_table_is_past_the_eof() {
+        let (bytes, mut header) = create_file();
+        header.e_shnum = 3;
+
+        let err = parse_sections(&bytes, &header).unwrap_err();
+
+        assert!(matches!(
+            err,
+            ElfError {
+                offset: 0x28,
+                field: "e_shoff",
+                kind: ElfErrorKind::OutOfBounds { size: 192, .. },
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn parse_sections_returns_invalid_shentsize_err_when_it_is_too_small() {
+        let (bytes, mut header) = create_file();
+        header.e_shentsize = 40;
+
+        let err = parse_sections(&bytes, &header).unwrap_err();
+
+        assert!(matches!(
+            err,
+            ElfError {
+                offset: 0x3a,
+                field: "e_shentsize",
+                kind: ElfErrorKind::InvalidValue { actual: 40, .. },
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn parse_sections_names_everything_no_strings_without_shstrndx() {
+        let (bytes, mut header) = create_file();
+        header.e_shstrndx = 0;
+
+        let sections = parse_sections(&bytes, &header).unwrap();
+
+        assert!(sections.iter().all(|s| s.name == "<no-strings>"));
+    }
+}
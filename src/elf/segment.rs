@@ -0,0 +1,368 @@
+use crate::elf::class::ClassParseable;
+use crate::elf::def::elf32strc::Elf32_Phdr;
+use crate::elf::def::elf64strc::{Elf64_Ehdr, Elf64_Phdr, Elf64_Shdr};
+use crate::elf::def::elfconsts::*;
+use crate::elf::error::ElfError;
+use crate::elf::section::first_section_header;
+use crate::elf::strtab::get_string;
+use crate::elf::types::SegmentType;
+use crate::utils::endianess::EndianRead;
+use crate::utils::parser::EndianParseable;
+
+impl EndianParseable for Elf64_Phdr {
+    fn parse_endian(bytes: &[u8], is_little_endian: bool) -> Result<Self, ElfError> {
+        // offset: 0x0 -> 0x38 (relative to the start of the entry)
+
+        // in 64-bit files p_flags comes right after p_type (in 32-bit files
+        // it is after p_memsz), so the struct keeps the 8 bytes fields aligned
+        if bytes.len() < 56 {
+            return Err(ElfError::truncated(0, "Elf64_Phdr", 56, bytes.len()));
+        }
+
+        Ok(Elf64_Phdr {
+            // offset: 0x0 -> 0x3
+            p_type: u32::read_from(&bytes[0..4], is_little_endian),
+            // offset: 0x4 -> 0x7
+            p_flags: u32::read_from(&bytes[4..8], is_little_endian),
+            // offset: 0x8 -> 0xF
+            p_offset: u64::read_from(&bytes[8..16], is_little_endian),
+            // offset: 0x10 -> 0x17
+            p_vaddr: u64::read_from(&bytes[16..24], is_little_endian),
+            // offset: 0x18 -> 0x1F, only meaningful where physical addressing is a thing
+            p_paddr: u64::read_from(&bytes[24..32], is_little_endian),
+            // offset: 0x20 -> 0x27
+            p_filesz: u64::read_from(&bytes[32..40], is_little_endian),
+            // offset: 0x28 -> 0x2F, can be bigger than p_filesz (the rest is zeroed, like .bss)
+            p_memsz: u64::read_from(&bytes[40..48], is_little_endian),
+            // offset: 0x30 -> 0x37
+            p_align: u64::read_from(&bytes[48..56], is_little_endian),
+        })
+    }
+}
+
+impl EndianParseable for Elf32_Phdr {
+    fn parse_endian(bytes: &[u8], is_little_endian: bool) -> Result<Self, ElfError> {
+        // offset: 0x0 -> 0x20 (relative to the start of the entry), here
+        // p_flags is the 7th field, after p_memsz
+        if bytes.len() < 32 {
+            return Err(ElfError::truncated(0, "Elf32_Phdr", 32, bytes.len()));
+        }
+
+        let word = |at: usize| u32::read_from(&bytes[at..at + 4], is_little_endian);
+
+        Ok(Elf32_Phdr {
+            p_type: word(0),
+            p_offset: word(4),
+            p_vaddr: word(8),
+            p_paddr: word(12),
+            p_filesz: word(16),
+            p_memsz: word(20),
+            p_flags: word(24),
+            p_align: word(28),
+        })
+    }
+}
+
+impl From<Elf32_Phdr> for Elf64_Phdr {
+    fn from(phdr: Elf32_Phdr) -> Self {
+        Elf64_Phdr {
+            p_type: phdr.p_type,
+            p_flags: phdr.p_flags,
+            p_offset: phdr.p_offset as u64,
+            p_vaddr: phdr.p_vaddr as u64,
+            p_paddr: phdr.p_paddr as u64,
+            p_filesz: phdr.p_filesz as u64,
+            p_memsz: phdr.p_memsz as u64,
+            p_align: phdr.p_align as u64,
+        }
+    }
+}
+
+impl ClassParseable for Elf64_Phdr {
+    type Elf32 = Elf32_Phdr;
+
+    const ELF32_SIZE: usize = 32;
+    const ELF64_SIZE: usize = 56;
+    const ELF32_NAME: &'static str = "Elf32_Phdr";
+    const ELF64_NAME: &'static str = "Elf64_Phdr";
+}
+
+impl Elf64_Phdr {
+    pub fn segment_type(&self) -> SegmentType {
+        self.p_type.into()
+    }
+}
+
+pub fn parse_program_headers(
+    bytes: &[u8],
+    header: &Elf64_Ehdr,
+) -> Result<Vec<Elf64_Phdr>, ElfError> {
+    // same idea of the section header table, but at e_phoff, with e_phnum
+    // entries of e_phentsize bytes. Relocatable files usually have none
+    let count = segment_count(bytes, header)?;
+    if header.e_phoff == 0 || count == 0 {
+        return Ok(Vec::new());
+    }
+
+    let class = header.class();
+    let structure = Elf64_Phdr::name_of(class);
+
+    let size = Elf64_Phdr::size_of(class);
+    if (header.e_phentsize as usize) < size {
+        return Err(ElfError::invalid_value(
+            header.field_offset("e_phentsize"),
+            structure,
+            "e_phentsize",
+            format!("at least {size} (the size of an {structure})"),
+            header.e_phentsize,
+        ));
+    }
+
+    let entsize = header.e_phentsize as usize;
+    let table_size = entsize.saturating_mul(count);
+
+    let table = usize::try_from(header.e_phoff)
+        .ok()
+        .and_then(|start| bytes.get(start..start.checked_add(table_size)?))
+        .ok_or_else(|| {
+            ElfError::out_of_bounds(
+                header.field_offset("e_phoff"),
+                structure,
+                "e_phoff",
+                (header.e_phoff, table_size as u64),
+                bytes.len(),
+            )
+        })?;
+
+    let is_little_endian = header.is_little_endian();
+
+    table
+        .chunks_exact(entsize)
+        .map(|entry| Elf64_Phdr::parse_class(entry, class, is_little_endian))
+        .collect()
+}
+
+// the number of entries of the program header table. When there are too
+// many for e_phnum it is PN_XNUM, and the real count is in the sh_info of the
+// section 0 (like the count of sections, see first_section_header). A file
+// without that section really has PN_XNUM segments
+pub fn segment_count(bytes: &[u8], header: &Elf64_Ehdr) -> Result<usize, ElfError> {
+    if header.e_phnum as u32 != PN_XNUM {
+        return Ok(header.e_phnum as usize);
+    }
+
+    let first = first_section_header(bytes, header)?;
+    Ok(first
+        .map(|shdr| shdr.sh_info)
+        .filter(|&count| count != 0)
+        .unwrap_or(PN_XNUM) as usize)
+}
+
+// the PT_INTERP segment only holds the path of the program interpreter
+// (the dynamic loader), as a NUL terminated string
+pub fn interpreter<'a>(bytes: &'a [u8], phdr: &Elf64_Phdr) -> Option<std::borrow::Cow<'a, str>> {
+    if phdr.p_type != PT_INTERP {
+        return None;
+    }
+
+    get_string(bytes, usize::try_from(phdr.p_offset).ok()?)
+}
+
+// the size that a section occupies inside of a segment. A .tbss section only
+// takes space in the PT_TLS segment, for the other ones its like if it was empty
+fn section_size(shdr: &Elf64_Shdr, phdr: &Elf64_Phdr) -> u64 {
+    if shdr.sh_flags & SHF_TLS as u64 == 0
+        || shdr.sh_type != SHT_NOBITS
+        || phdr.p_type == PT_TLS
+    {
+        shdr.sh_size
+    } else {
+        0
+    }
+}
+
+// this is the same (strict) check that binutils uses to build the "Section to
+// Segment mapping". All the subtractions are wrapping on purpose, to keep
+// the same unsigned arithmetic of the C macro (ELF_SECTION_IN_SEGMENT_STRICT)
+pub fn section_in_segment(shdr: &Elf64_Shdr, phdr: &Elf64_Phdr) -> bool {
+    let is_tls = shdr.sh_flags & SHF_TLS as u64 != 0;
+    let is_alloc = shdr.sh_flags & SHF_ALLOC as u64 != 0;
+    let is_nobits = shdr.sh_type == SHT_NOBITS;
+    let size = section_size(shdr, phdr);
+
+    // .tbss is special, it is only listed in the PT_TLS segment
+    if is_tls && is_nobits && phdr.p_type != PT_TLS {
+        return false;
+    }
+
+    // only PT_LOAD, PT_GNU_RELRO and PT_TLS segments can have SHF_TLS sections,
+    // PT_TLS only have SHF_TLS sections and PT_PHDR have no sections at all
+    let tls_ok = if is_tls {
+        matches!(phdr.p_type, PT_TLS | PT_GNU_RELRO | PT_LOAD)
+    } else {
+        phdr.p_type != PT_TLS && phdr.p_type != PT_PHDR
+    };
+
+    // the segments that are mapped in memory only have SHF_ALLOC sections
+    let alloc_ok = is_alloc
+        || !(matches!(
+            phdr.p_type,
+            PT_LOAD | PT_DYNAMIC | PT_GNU_EH_FRAME | PT_GNU_STACK | PT_GNU_RELRO | PT_GNU_SFRAME
+        ) || (PT_GNU_MBIND_LO..=PT_GNU_MBIND_HI).contains(&phdr.p_type));
+
+    // anything that is not NOBITS must be inside of the segment in the file
+    let offset_ok = is_nobits
+        || (shdr.sh_offset >= phdr.p_offset
+            && shdr.sh_offset - phdr.p_offset <= phdr.p_filesz.wrapping_sub(1)
+            && (shdr.sh_offset - phdr.p_offset).wrapping_add(size) <= phdr.p_filesz);
+
+    // and the SHF_ALLOC sections must be inside of the segment in memory
+    let vma_ok = !is_alloc
+        || (shdr.sh_addr >= phdr.p_vaddr
+            && shdr.sh_addr - phdr.p_vaddr <= phdr.p_memsz.wrapping_sub(1)
+            && (shdr.sh_addr - phdr.p_vaddr).wrapping_add(size) <= phdr.p_memsz);
+
+    // empty sections at the start or at the end of PT_DYNAMIC and PT_NOTE
+    // dont count as being inside of them
+    let empty_ok = (phdr.p_type != PT_DYNAMIC && phdr.p_type != PT_NOTE)
+        || shdr.sh_size != 0
+        || phdr.p_memsz == 0
+        || ((is_nobits
+            || (shdr.sh_offset > phdr.p_offset
+                && shdr.sh_offset - phdr.p_offset < phdr.p_filesz))
+            && (!is_alloc
+                || (shdr.sh_addr > phdr.p_vaddr
+                    && shdr.sh_addr - phdr.p_vaddr < phdr.p_memsz)));
+
+    tls_ok && alloc_ok && offset_ok && vma_ok && empty_ok
+}
+
+// the dynamic section talks in virtual addresses, so to find something in
+// the file we need the PT_LOAD segment that maps that address (same rule that
+// binutils uses, the start of the segment is rounded down to its alignment)
+pub fn vma_to_offset(segments: &[Elf64_Phdr], vma: u64) -> Option<u64> {
+    segments
+        .iter()
+        .filter(|phdr| phdr.p_type == PT_LOAD)
+        .find(|phdr| {
+            vma >= phdr.p_vaddr & phdr.p_align.wrapping_neg()
+                && vma <= phdr.p_vaddr.wrapping_add(phdr.p_filesz)
+        })
+        .map(|phdr| vma.wrapping_sub(phdr.p_vaddr).wrapping_add(phdr.p_offset))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{section_in_segment, segment_count, vma_to_offset};
+    use crate::elf::def::elf64strc::{Elf64_Ehdr, Elf64_Phdr, Elf64_Shdr};
+
+    fn create_segment(p_type: u32, offset: u64, size: u64) -> Elf64_Phdr {
+        Elf64_Phdr {
+            p_type,
+            p_flags: 4,
+            p_offset: offset,
+            p_vaddr: offset + 0x1000,
+            p_paddr: offset + 0x1000,
+            p_filesz: size,
+            p_memsz: size,
+            p_align: 0x1000,
+        }
+    }
+
+    fn create_section(sh_type: u32, sh_flags: u64, offset: u64, size: u64) -> Elf64_Shdr {
+        Elf64_Shdr {
+            sh_name: 0,
+            sh_type,
+            sh_flags,
+            sh_addr: offset + 0x1000,
+            sh_offset: offset,
+            sh_size: size,
+            sh_link: 0,
+            sh_info: 0,
+            sh_addralign: 1,
+            sh_entsize: 0,
+        }
+    }
+
+    #[test]
+    fn segment_count_reads_pn_xnum_from_the_section_0() {
+        // only the ELF header (that is not read here) and the section 0
+        let mut bytes = vec![0u8; 128];
+        bytes[64 + 44..64 + 48].copy_from_slice(&70000u32.to_le_bytes());
+        let mut header = Elf64_Ehdr {
+            e_ident: [0x7f, 0x45, 0x4c, 0x46, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            e_type: 4,
+            e_machine: 62,
+            e_version: 1,
+            e_entry: 0,
+            e_phoff: 128,
+            e_shoff: 64,
+            e_flags: 0,
+            e_ehsize: 64,
+            e_phentsize: 56,
+            e_phnum: 0xffff,
+            e_shentsize: 64,
+            e_shnum: 1,
+            e_shstrndx: 0,
+        };
+
+        assert_eq!(segment_count(&bytes, &header).unwrap(), 70000);
+
+        header.e_phnum = 3;
+        assert_eq!(segment_count(&bytes, &header).unwrap(), 3);
+    }
+
+    #[test]
+    fn section_in_segment_accepts_alloc_sections_inside_of_load() {
+        let load = create_segment(1, 0x0, 0x800);
+        let text = create_section(1, 0x6, 0x400, 0x100);
+
+        assert!(section_in_segment(&text, &load));
+    }
+
+    #[test]
+    fn section_in_segment_rejects_sections_that_cross_the_segment_end() {
+        let load = create_segment(1, 0x0, 0x800);
+        let text = create_section(1, 0x6, 0x780, 0x100);
+
+        assert!(!section_in_segment(&text, &load));
+    }
+
+    #[test]
+    fn section_in_segment_rejects_non_alloc_sections_in_load() {
+        let load = create_segment(1, 0x0, 0x800);
+        let comment = create_section(1, 0x30, 0x400, 0x10);
+
+        assert!(!section_in_segment(&comment, &load));
+    }
+
+    #[test]
+    fn section_in_segment_only_shows_tbss_in_the_tls_segment() {
+        let load = create_segment(1, 0x0, 0x800);
+        let tls = create_segment(7, 0x400, 0x10);
+        let tbss = create_section(8, 0x403, 0x400, 0x10);
+
+        assert!(section_in_segment(&tbss, &tls));
+        assert!(!section_in_segment(&tbss, &load));
+    }
+
+    #[test]
+    fn section_in_segment_rejects_everything_in_phdr() {
+        let phdr = create_segment(6, 0x40, 0x200);
+        let section = create_section(1, 0x2, 0x40, 0x10);
+
+        assert!(!section_in_segment(&section, &phdr));
+    }
+
+    #[test]
+    fn vma_to_offset_uses_the_load_segment_of_the_address() {
+        let segments = [
+            create_segment(1, 0x0, 0x800),
+            create_segment(1, 0x2000, 0x100),
+        ];
+
+        assert_eq!(vma_to_offset(&segments, 0x3010), Some(0x2010));
+        assert_eq!(vma_to_offset(&segments, 0x1400), Some(0x400));
+        assert_eq!(vma_to_offset(&segments, 0x9000), None);
+    }
+}
@@ -0,0 +1,32 @@
+use std::borrow::Cow;
+
+// string tables (.shstrtab, .strtab, .dynstr...) are just a bunch of NUL
+// terminated strings glued together, and every structure that has a "name"
+// only keeps the offset of it inside the table. Names are not required to be
+// valid utf-8, so the invalid bytes are replaced instead of failing
+pub fn get_string(table: &[u8], offset: usize) -> Option<Cow<'_, str>> {
+    let rest = table.get(offset..)?;
+    let end = rest.iter().position(|&b| b == 0)?;
+
+    Some(String::from_utf8_lossy(&rest[..end]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::get_string;
+
+    #[test]
+    fn get_string_returns_the_string_until_the_nul_byte() {
+        let table = b"\0.text\0.data\0";
+
+        assert_eq!(get_string(table, 1).unwrap(), ".text");
+        assert_eq!(get_string(table, 3).unwrap(), "ext");
+        assert_eq!(get_string(table, 0).unwrap(), "");
+    }
+
+    #[test]
+    fn get_string_returns_none_when_offset_is_out_of_the_table_or_not_terminated() {
+        assert!(get_string(b"\0.text\0", 7).is_none());
+        assert!(get_string(b"\0.text", 1).is_none());
+    }
+}
@@ -0,0 +1,382 @@
+use crate::elf::class::{ClassParseable, ElfClass};
+use crate::elf::def::elf32strc::Elf32_Sym;
+use crate::elf::def::elf64strc::Elf64_Sym;
+use crate::elf::def::elfconsts::*;
+use crate::elf::error::ElfError;
+use crate::elf::section::{Elf64Section, section_data};
+use crate::elf::strtab::get_string;
+use crate::elf::types::{SymbolBinding, SymbolType, SymbolVisibility};
+use crate::elf::version::SymbolVersion;
+use crate::utils::endianess::EndianRead;
+use crate::utils::parser::EndianParseable;
+
+impl EndianParseable for Elf64_Sym {
+    fn parse_endian(bytes: &[u8], is_little_endian: bool) -> Result<Self, ElfError> {
+        // offset: 0x0 -> 0x18 (relative to the start of the entry)
+        if bytes.len() < 24 {
+            return Err(ElfError::truncated(0, "Elf64_Sym", 24, bytes.len()));
+        }
+
+        Ok(Elf64_Sym {
+            // offset: 0x0 -> 0x3, offset of the name in the linked string table
+            st_name: u32::read_from(&bytes[0..4], is_little_endian),
+            // offset: 0x4, binding (high nibble) and type (low nibble)
+            st_info: bytes[4],
+            // offset: 0x5, visibility (2 lowest bits), the rest is machine specific
+            st_other: bytes[5],
+            // offset: 0x6 -> 0x7, section where the symbol is defined
+            st_shndx: u16::read_from(&bytes[6..8], is_little_endian),
+            // offset: 0x8 -> 0xF
+            st_value: u64::read_from(&bytes[8..16], is_little_endian),
+            // offset: 0x10 -> 0x17
+            st_size: u64::read_from(&bytes[16..24], is_little_endian),
+        })
+    }
+}
+
+impl EndianParseable for Elf32_Sym {
+    fn parse_endian(bytes: &[u8], is_little_endian: bool) -> Result<Self, ElfError> {
+        // offset: 0x0 -> 0x10 (relative to the start of the entry), the value
+        // and the size come before st_info in 32-bit files
+        if bytes.len() < 16 {
+            return Err(ElfError::truncated(0, "Elf32_Sym", 16, bytes.len()));
+        }
+
+        Ok(Elf32_Sym {
+            // offset: 0x0 -> 0x3
+            st_name: u32::read_from(&bytes[0..4], is_little_endian),
+            // offset: 0x4 -> 0x7
+            st_value: u32::read_from(&bytes[4..8], is_little_endian),
+            // offset: 0x8 -> 0xB
+            st_size: u32::read_from(&bytes[8..12], is_little_endian),
+            // offset: 0xC
+            st_info: bytes[12],
+            // offset: 0xD
+            st_other: bytes[13],
+            // offset: 0xE -> 0xF
+            st_shndx: u16::read_from(&bytes[14..16], is_little_endian),
+        })
+    }
+}
+
+impl From<Elf32_Sym> for Elf64_Sym {
+    fn from(sym: Elf32_Sym) -> Self {
+        Elf64_Sym {
+            st_name: sym.st_name,
+            st_info: sym.st_info,
+            st_other: sym.st_other,
+            st_shndx: sym.st_shndx,
+            st_value: sym.st_value as u64,
+            st_size: sym.st_size as u64,
+        }
+    }
+}
+
+impl ClassParseable for Elf64_Sym {
+    type Elf32 = Elf32_Sym;
+
+    const ELF32_SIZE: usize = 16;
+    const ELF64_SIZE: usize = 24;
+    const ELF32_NAME: &'static str = "Elf32_Sym";
+    const ELF64_NAME: &'static str = "Elf64_Sym";
+}
+
+// the same as the ELF64_ST_* macros of elf.h (the ELF32_ST_* ones are equal)
+impl Elf64_Sym {
+    pub fn st_bind(&self) -> u32 {
+        (self.st_info >> 4) as u32
+    }
+
+    pub fn st_type(&self) -> u32 {
+        (self.st_info & 0xf) as u32
+    }
+
+    pub fn st_visibility(&self) -> u32 {
+        (self.st_other & 0x3) as u32
+    }
+
+    pub fn binding(&self) -> SymbolBinding {
+        self.st_bind().into()
+    }
+
+    pub fn symbol_type(&self) -> SymbolType {
+        self.st_type().into()
+    }
+
+    pub fn visibility(&self) -> SymbolVisibility {
+        self.st_visibility().into()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Elf64Symbol {
+    pub sym: Elf64_Sym,
+    // None when st_name points outside of the string table
+    pub name: Option<String>,
+    // st_shndx only has 16 bits, so the symbols of the sections from
+    // SHN_LORESERVE on have SHN_XINDEX there, and the real index is the entry
+    // of the symbol in the SHT_SYMTAB_SHNDX section of the table. None for
+    // every other symbol (and when that section is missing)
+    pub xindex: Option<u32>,
+    // from .gnu.version, only for the symbols of .dynsym (see
+    // ElfFile::symbols). None for the ones without a version
+    pub version: Option<SymbolVersion>,
+}
+
+impl Elf64Symbol {
+    // the index of the section of the symbol, None for the special indices
+    // (like SHN_ABS) that are not a section. SHN_UNDEF is still 0
+    pub fn section_index(&self) -> Option<usize> {
+        match self.xindex {
+            Some(index) => Some(index as usize),
+            None if (self.sym.st_shndx as u32) < SHN_LORESERVE => Some(self.sym.st_shndx as usize),
+            None => None,
+        }
+    }
+}
+
+// symbol tables are the SHT_SYMTAB (.symtab) and SHT_DYNSYM (.dynsym)
+// sections, their names are in the string table pointed by sh_link
+pub fn parse_symbol_table(
+    bytes: &[u8],
+    sections: &[Elf64Section],
+    section: &Elf64Section,
+    class: ElfClass,
+    is_little_endian: bool,
+) -> Result<Vec<Elf64Symbol>, ElfError> {
+    let strtab = symbol_strings(bytes, sections, section, class)?;
+    let indices = symbol_section_indices(bytes, sections, section);
+
+    parse_symbols(bytes, section, strtab, indices, class, is_little_endian)
+}
+
+// the string table (sh_link) of a symbol table
+pub fn symbol_strings<'a>(
+    bytes: &'a [u8],
+    sections: &[Elf64Section],
+    section: &Elf64Section,
+    class: ElfClass,
+) -> Result<&'a [u8], ElfError> {
+    let shdr = &section.header;
+
+    sections
+        .get(shdr.sh_link as usize)
+        .and_then(|strtab| section_data(bytes, &strtab.header))
+        .ok_or_else(|| {
+            ElfError::invalid_value(
+                shdr.sh_offset,
+                Elf64_Sym::name_of(class),
+                "sh_link",
+                "the index of a string table in the file",
+                shdr.sh_link,
+            )
+        })
+}
+
+// the SHT_SYMTAB_SHNDX section of a symbol table (the one with the table in
+// its sh_link), with a 4 bytes section index for each symbol. Empty when the
+// table has none, like almost every file
+pub fn symbol_section_indices<'a>(
+    bytes: &'a [u8],
+    sections: &[Elf64Section],
+    section: &Elf64Section,
+) -> &'a [u8] {
+    // the table can be a copy (see ElfFile::symbols), so it is found by its
+    // header instead of its address
+    let is_table = |index: u32| {
+        sections.get(index as usize).is_some_and(|table| {
+            table.header.sh_type == section.header.sh_type
+                && table.header.sh_offset == section.header.sh_offset
+        })
+    };
+
+    sections
+        .iter()
+        .filter(|shndx| shndx.header.sh_type == SHT_SYMTAB_SHNDX && is_table(shndx.header.sh_link))
+        .find_map(|shndx| section_data(bytes, &shndx.header))
+        .unwrap_or_default()
+}
+
+// the symbols of a table with its names in strtab and the indices of its
+// SHT_SYMTAB_SHNDX section (empty when there is none). The lenient mode uses
+// it with no strings at all when sh_link is broken, so the symbols are still
+// shown (without names)
+pub fn parse_symbols(
+    bytes: &[u8],
+    section: &Elf64Section,
+    strtab: &[u8],
+    indices: &[u8],
+    class: ElfClass,
+    is_little_endian: bool,
+) -> Result<Vec<Elf64Symbol>, ElfError> {
+    let shdr = &section.header;
+
+    let structure = Elf64_Sym::name_of(class);
+
+    let size = Elf64_Sym::size_of(class);
+    if shdr.sh_entsize < size as u64 {
+        return Err(ElfError::invalid_value(
+            shdr.sh_offset,
+            structure,
+            "sh_entsize",
+            format!("at least {size} (the size of an {structure})"),
+            shdr.sh_entsize,
+        ));
+    }
+
+    let data = section_data(bytes, shdr).ok_or_else(|| {
+        ElfError::out_of_bounds(
+            shdr.sh_offset,
+            structure,
+            "sh_offset",
+            (shdr.sh_offset, shdr.sh_size),
+            bytes.len(),
+        )
+    })?;
+
+    data.chunks_exact(shdr.sh_entsize as usize)
+        .enumerate()
+        .map(|(i, entry)| {
+            let sym = Elf64_Sym::parse_class(entry, class, is_little_endian)?;
+            let name = get_string(strtab, sym.st_name as usize).map(|name| name.into_owned());
+
+            let xindex = indices
+                .get(i * 4..i * 4 + 4)
+                .filter(|_| sym.st_shndx as u32 == SHN_XINDEX)
+                .map(|index| u32::read_from(index, is_little_endian));
+
+            Ok(Elf64Symbol {
+                sym,
+                name,
+                xindex,
+                version: None,
+            })
+        })
+        .collect()
+}
+
+pub fn is_symbol_table(section: &Elf64Section) -> bool {
+    matches!(section.header.sh_type, SHT_SYMTAB | SHT_DYNSYM)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_symbol_table;
+    use crate::elf::class::ElfClass;
+    use crate::elf::def::elf64strc::Elf64_Shdr;
+    use crate::elf::error::{ElfError, ElfErrorKind};
+    use crate::elf::section::Elf64Section;
+    use crate::test_utils::create_section;
+
+    // a null symbol followed by a global function called "main", in BE
+    fn create_file() -> (Vec<u8>, Vec<Elf64Section>) {
+        let mut bytes = vec![0u8; 24];
+        let mut main = [0u8; 24];
+        main[0..4].copy_from_slice(&1u32.to_be_bytes());
+        main[4] = 0x12; // GLOBAL FUNC
+        main[6..8].copy_from_slice(&14u16.to_be_bytes());
+        main[8..16].copy_from_slice(&0x1139u64.to_be_bytes());
+        main[16..24].copy_from_slice(&45u64.to_be_bytes());
+        bytes.extend_from_slice(&main);
+        bytes.extend_from_slice(b"\0main\0");
+
+        let section = |sh_type, sh_offset, sh_size, sh_link, sh_entsize| {
+            let section = create_section("", sh_type);
+            Elf64Section {
+                header: Elf64_Shdr {
+                    sh_offset,
+                    sh_size,
+                    sh_link,
+                    sh_entsize,
+                    ..section.header
+                },
+                ..section
+            }
+        };
+        let sections = vec![
+            create_section("", 0),
+            section(2, 0, 48, 2, 24),
+            section(3, 48, 6, 0, 0),
+        ];
+
+        (bytes, sections)
+    }
+
+    #[test]
+    fn parse_symbol_table_returns_every_symbol_with_its_name() {
+        let (bytes, sections) = create_file();
+
+        let symbols = parse_symbol_table(&bytes, &sections, &sections[1], ElfClass::Elf64, false).unwrap();
+
+        assert_eq!(symbols.len(), 2);
+        assert_eq!(symbols[0].name.as_deref(), Some(""));
+        assert_eq!(symbols[1].name.as_deref(), Some("main"));
+        assert_eq!(symbols[1].sym.st_bind(), 1);
+        assert_eq!(symbols[1].sym.st_type(), 2);
+        assert_eq!(symbols[1].sym.st_shndx, 14);
+        assert_eq!(symbols[1].sym.st_value, 0x1139);
+        assert_eq!(symbols[1].sym.st_size, 45);
+    }
+
+    #[test]
+    fn parse_symbol_table_reads_the_xindex_symbols_from_the_symtab_shndx_section() {
+        let (mut bytes, mut sections) = create_file();
+        // main is now in the section 70000
+        bytes[30..32].copy_from_slice(&0xffffu16.to_be_bytes());
+        let shndx = bytes.len() as u64;
+        bytes.extend_from_slice(&0u32.to_be_bytes());
+        bytes.extend_from_slice(&70000u32.to_be_bytes());
+        let mut section = create_section("", 18);
+        section.header = Elf64_Shdr {
+            sh_offset: shndx,
+            sh_size: 8,
+            sh_link: 1,
+            sh_entsize: 4,
+            ..section.header
+        };
+        sections.push(section);
+
+        let symbols = parse_symbol_table(&bytes, &sections, &sections[1], ElfClass::Elf64, false).unwrap();
+
+        assert_eq!(symbols[0].xindex, None);
+        assert_eq!(symbols[0].section_index(), Some(0));
+        assert_eq!(symbols[1].sym.st_shndx, 0xffff);
+        assert_eq!(symbols[1].xindex, Some(70000));
+        assert_eq!(symbols[1].section_index(), Some(70000));
+    }
+
+    #[test]
+    fn parse_symbol_table_returns_invalid_strtab_link_err_when_link_is_out_of_range() {
+        let (bytes, mut sections) = create_file();
+        sections[1].header.sh_link = 7;
+
+        let err = parse_symbol_table(&bytes, &sections, &sections[1], ElfClass::Elf64, false).unwrap_err();
+
+        assert!(matches!(
+            err,
+            ElfError {
+                structure: "Elf64_Sym",
+                field: "sh_link",
+                kind: ElfErrorKind::InvalidValue { actual: 7, .. },
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn parse_symbol_table_returns_invalid_entsize_err_when_it_is_too_small() {
+        let (bytes, mut sections) = create_file();
+        sections[1].header.sh_entsize = 0;
+
+        let err = parse_symbol_table(&bytes, &sections, &sections[1], ElfClass::Elf64, false).unwrap_err();
+
+        assert!(matches!(
+            err,
+            ElfError {
+                field: "sh_entsize",
+                kind: ElfErrorKind::InvalidValue { actual: 0, .. },
+                ..
+            }
+        ));
+    }
+}
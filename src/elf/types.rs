@@ -0,0 +1,1175 @@
+use crate::elf::def::elfconsts::*;
+use crate::elf::relocation_types::{
+    aarch64_relocation_name, arm_relocation_name, i386_relocation_name, ppc64_relocation_name,
+    riscv_relocation_name, s390_relocation_name, x86_64_relocation_name,
+};
+
+// the raw values of elf::def are plain u32 consts, like in elf.h. These are
+// the same values as enums, for who wants to match on them without caring
+// about the ranges that each one of them can have. Only the names of the
+// generic ABI (and of the GNU extensions) are here, the values specific to
+// one processor live in the ranges of the Unknown variant. Aliases (like
+// ELFOSABI_LINUX) are the variant of the first name with that value
+macro_rules! elf_enum {
+    // without c_name, for the values whose names are not the ones of elf.h
+    (values $(#[$meta:meta])* $name:ident { $($variant:ident = $value:ident,)* }) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($variant,)*
+            // a value without a name here: reserved, in one of the OS or
+            // processor ranges, or just invalid
+            Unknown(u32),
+        }
+
+        // the names of elf.h are kept, even the lowercase ones
+        #[allow(non_upper_case_globals)]
+        impl From<u32> for $name {
+            fn from(value: u32) -> Self {
+                match value {
+                    $($value => $name::$variant,)*
+                    value => $name::Unknown(value),
+                }
+            }
+        }
+
+        #[allow(non_upper_case_globals)]
+        impl From<$name> for u32 {
+            fn from(value: $name) -> Self {
+                match value {
+                    $($name::$variant => $value,)*
+                    $name::Unknown(value) => value,
+                }
+            }
+        }
+    };
+    ($(#[$meta:meta])* $name:ident { $($variant:ident = $value:ident,)* }) => {
+        elf_enum!(values $(#[$meta])* $name { $($variant = $value,)* });
+
+        impl $name {
+            // the name of the constant in elf.h, like "SHT_PROGBITS"
+            pub fn c_name(self) -> Option<&'static str> {
+                match self {
+                    $($name::$variant => Some(stringify!($value)),)*
+                    $name::Unknown(_) => None,
+                }
+            }
+        }
+    };
+}
+
+elf_enum! {
+    // the type of the file, from e_type
+    FileType {
+        None = ET_NONE,
+        Rel = ET_REL,
+        Exec = ET_EXEC,
+        Dyn = ET_DYN,
+        Core = ET_CORE,
+    }
+}
+
+elf_enum! {
+    // the architecture of the file, from e_machine
+    Machine {
+        None = EM_NONE,
+        M32 = EM_M32,
+        Sparc = EM_SPARC,
+        Em386 = EM_386,
+        Em68k = EM_68K,
+        Em88k = EM_88K,
+        Iamcu = EM_IAMCU,
+        Em860 = EM_860,
+        Mips = EM_MIPS,
+        S370 = EM_S370,
+        MipsRs3Le = EM_MIPS_RS3_LE,
+        Parisc = EM_PARISC,
+        Vpp500 = EM_VPP500,
+        Sparc32plus = EM_SPARC32PLUS,
+        Em960 = EM_960,
+        Ppc = EM_PPC,
+        Ppc64 = EM_PPC64,
+        S390 = EM_S390,
+        Spu = EM_SPU,
+        V800 = EM_V800,
+        Fr20 = EM_FR20,
+        Rh32 = EM_RH32,
+        Rce = EM_RCE,
+        Arm = EM_ARM,
+        FakeAlpha = EM_FAKE_ALPHA,
+        Sh = EM_SH,
+        Sparcv9 = EM_SPARCV9,
+        Tricore = EM_TRICORE,
+        Arc = EM_ARC,
+        H8_300 = EM_H8_300,
+        H8_300h = EM_H8_300H,
+        H8s = EM_H8S,
+        H8_500 = EM_H8_500,
+        Ia64 = EM_IA_64,
+        MipsX = EM_MIPS_X,
+        Coldfire = EM_COLDFIRE,
+        Em68hc12 = EM_68HC12,
+        Mma = EM_MMA,
+        Pcp = EM_PCP,
+        Ncpu = EM_NCPU,
+        Ndr1 = EM_NDR1,
+        Starcore = EM_STARCORE,
+        Me16 = EM_ME16,
+        St100 = EM_ST100,
+        Tinyj = EM_TINYJ,
+        X86_64 = EM_X86_64,
+        Pdsp = EM_PDSP,
+        Pdp10 = EM_PDP10,
+        Pdp11 = EM_PDP11,
+        Fx66 = EM_FX66,
+        St9plus = EM_ST9PLUS,
+        St7 = EM_ST7,
+        Em68hc16 = EM_68HC16,
+        Em68hc11 = EM_68HC11,
+        Em68hc08 = EM_68HC08,
+        Em68hc05 = EM_68HC05,
+        Svx = EM_SVX,
+        St19 = EM_ST19,
+        Vax = EM_VAX,
+        Cris = EM_CRIS,
+        Javelin = EM_JAVELIN,
+        Firepath = EM_FIREPATH,
+        Zsp = EM_ZSP,
+        Mmix = EM_MMIX,
+        Huany = EM_HUANY,
+        Prism = EM_PRISM,
+        Avr = EM_AVR,
+        Fr30 = EM_FR30,
+        D10v = EM_D10V,
+        D30v = EM_D30V,
+        V850 = EM_V850,
+        M32r = EM_M32R,
+        Mn10300 = EM_MN10300,
+        Mn10200 = EM_MN10200,
+        Pj = EM_PJ,
+        Openrisc = EM_OPENRISC,
+        ArcCompact = EM_ARC_COMPACT,
+        Xtensa = EM_XTENSA,
+        Videocore = EM_VIDEOCORE,
+        TmmGpp = EM_TMM_GPP,
+        Ns32k = EM_NS32K,
+        Tpc = EM_TPC,
+        Snp1k = EM_SNP1K,
+        St200 = EM_ST200,
+        Ip2k = EM_IP2K,
+        Max = EM_MAX,
+        Cr = EM_CR,
+        F2mc16 = EM_F2MC16,
+        Msp430 = EM_MSP430,
+        Blackfin = EM_BLACKFIN,
+        SeC33 = EM_SE_C33,
+        Sep = EM_SEP,
+        Arca = EM_ARCA,
+        Unicore = EM_UNICORE,
+        Excess = EM_EXCESS,
+        Dxp = EM_DXP,
+        AlteraNios2 = EM_ALTERA_NIOS2,
+        Crx = EM_CRX,
+        Xgate = EM_XGATE,
+        C166 = EM_C166,
+        M16c = EM_M16C,
+        Dspic30f = EM_DSPIC30F,
+        Ce = EM_CE,
+        M32c = EM_M32C,
+        Tsk3000 = EM_TSK3000,
+        Rs08 = EM_RS08,
+        Sharc = EM_SHARC,
+        Ecog2 = EM_ECOG2,
+        Score7 = EM_SCORE7,
+        Dsp24 = EM_DSP24,
+        Videocore3 = EM_VIDEOCORE3,
+        Latticemico32 = EM_LATTICEMICO32,
+        SeC17 = EM_SE_C17,
+        TiC6000 = EM_TI_C6000,
+        TiC2000 = EM_TI_C2000,
+        TiC5500 = EM_TI_C5500,
+        TiArp32 = EM_TI_ARP32,
+        TiPru = EM_TI_PRU,
+        MmdspPlus = EM_MMDSP_PLUS,
+        CypressM8c = EM_CYPRESS_M8C,
+        R32c = EM_R32C,
+        Trimedia = EM_TRIMEDIA,
+        Qdsp6 = EM_QDSP6,
+        Em8051 = EM_8051,
+        Stxp7x = EM_STXP7X,
+        Nds32 = EM_NDS32,
+        Ecog1x = EM_ECOG1X,
+        Maxq30 = EM_MAXQ30,
+        Ximo16 = EM_XIMO16,
+        Manik = EM_MANIK,
+        Craynv2 = EM_CRAYNV2,
+        Rx = EM_RX,
+        Metag = EM_METAG,
+        McstElbrus = EM_MCST_ELBRUS,
+        Ecog16 = EM_ECOG16,
+        Cr16 = EM_CR16,
+        Etpu = EM_ETPU,
+        Sle9x = EM_SLE9X,
+        L10m = EM_L10M,
+        K10m = EM_K10M,
+        Aarch64 = EM_AARCH64,
+        Avr32 = EM_AVR32,
+        Stm8 = EM_STM8,
+        Tile64 = EM_TILE64,
+        Tilepro = EM_TILEPRO,
+        Microblaze = EM_MICROBLAZE,
+        Cuda = EM_CUDA,
+        Tilegx = EM_TILEGX,
+        Cloudshield = EM_CLOUDSHIELD,
+        Corea1st = EM_COREA_1ST,
+        Corea2nd = EM_COREA_2ND,
+        Arcv2 = EM_ARCV2,
+        Open8 = EM_OPEN8,
+        Rl78 = EM_RL78,
+        Videocore5 = EM_VIDEOCORE5,
+        Em78kor = EM_78KOR,
+        Em56800ex = EM_56800EX,
+        Ba1 = EM_BA1,
+        Ba2 = EM_BA2,
+        Xcore = EM_XCORE,
+        MchpPic = EM_MCHP_PIC,
+        Intelgt = EM_INTELGT,
+        Km32 = EM_KM32,
+        Kmx32 = EM_KMX32,
+        Emx16 = EM_EMX16,
+        Emx8 = EM_EMX8,
+        Kvarc = EM_KVARC,
+        Cdp = EM_CDP,
+        Coge = EM_COGE,
+        Cool = EM_COOL,
+        Norc = EM_NORC,
+        CsrKalimba = EM_CSR_KALIMBA,
+        Z80 = EM_Z80,
+        Visium = EM_VISIUM,
+        Ft32 = EM_FT32,
+        Moxie = EM_MOXIE,
+        Amdgpu = EM_AMDGPU,
+        Riscv = EM_RISCV,
+        Bpf = EM_BPF,
+        Csky = EM_CSKY,
+        Loongarch = EM_LOONGARCH,
+        Alpha = EM_ALPHA,
+        OldSparcv9 = EM_OLD_SPARCV9,
+        Intel182 = EM_INTEL182,
+        Arm184 = EM_ARM184,
+        Lanai = EM_LANAI,
+        Ceva = EM_CEVA,
+        CevaX2 = EM_CEVA_X2,
+        Graphcore = EM_GRAPHCORE,
+        Img1 = EM_IMG1,
+        Nfp = EM_NFP,
+        Ve = EM_VE,
+        ArcCompact3_64 = EM_ARC_COMPACT3_64,
+        Mcs6502 = EM_MCS6502,
+        ArcCompact3 = EM_ARC_COMPACT3,
+        Kvx = EM_KVX,
+        Em65816 = EM_65816,
+        AvrOld = EM_AVR_OLD,
+        AdaptevaEpiphany = EM_ADAPTEVA_EPIPHANY,
+        Mt = EM_MT,
+        CygnusFr30 = EM_CYGNUS_FR30,
+        Webassembly = EM_WEBASSEMBLY,
+        Xc16x = EM_XC16X,
+        S12z = EM_S12Z,
+        CygnusFrv = EM_CYGNUS_FRV,
+        Dlx = EM_DLX,
+        CygnusD10v = EM_CYGNUS_D10V,
+        CygnusD30v = EM_CYGNUS_D30V,
+        Ip2kOld = EM_IP2K_OLD,
+        CygnusM32r = EM_CYGNUS_M32R,
+        CygnusV850 = EM_CYGNUS_V850,
+        S390Old = EM_S390_OLD,
+        XtensaOld = EM_XTENSA_OLD,
+        Xstormy16 = EM_XSTORMY16,
+        MicroblazeOld = EM_MICROBLAZE_OLD,
+        CygnusMn10300 = EM_CYGNUS_MN10300,
+        CygnusMn10200 = EM_CYGNUS_MN10200,
+        CygnusMep = EM_CYGNUS_MEP,
+        Iq2000 = EM_IQ2000,
+        Nios32 = EM_NIOS32,
+    }
+}
+
+elf_enum! {
+    // the OS (or ABI) extensions used by the file, from EI_OSABI
+    OsAbi {
+        None = ELFOSABI_NONE,
+        Hpux = ELFOSABI_HPUX,
+        Netbsd = ELFOSABI_NETBSD,
+        Gnu = ELFOSABI_GNU,
+        Solaris = ELFOSABI_SOLARIS,
+        Aix = ELFOSABI_AIX,
+        Irix = ELFOSABI_IRIX,
+        Freebsd = ELFOSABI_FREEBSD,
+        Tru64 = ELFOSABI_TRU64,
+        Modesto = ELFOSABI_MODESTO,
+        Openbsd = ELFOSABI_OPENBSD,
+        Openvms = ELFOSABI_OPENVMS,
+        Nsk = ELFOSABI_NSK,
+        Aros = ELFOSABI_AROS,
+        Fenixos = ELFOSABI_FENIXOS,
+        Cloudabi = ELFOSABI_CLOUDABI,
+        Openvos = ELFOSABI_OPENVOS,
+        Arm = ELFOSABI_ARM,
+        Standalone = ELFOSABI_STANDALONE,
+    }
+}
+
+elf_enum! {
+    // the contents of a section, from sh_type
+    SectionType {
+        Null = SHT_NULL,
+        Progbits = SHT_PROGBITS,
+        Symtab = SHT_SYMTAB,
+        Strtab = SHT_STRTAB,
+        Rela = SHT_RELA,
+        Hash = SHT_HASH,
+        Dynamic = SHT_DYNAMIC,
+        Note = SHT_NOTE,
+        Nobits = SHT_NOBITS,
+        Rel = SHT_REL,
+        Shlib = SHT_SHLIB,
+        Dynsym = SHT_DYNSYM,
+        InitArray = SHT_INIT_ARRAY,
+        FiniArray = SHT_FINI_ARRAY,
+        PreinitArray = SHT_PREINIT_ARRAY,
+        Group = SHT_GROUP,
+        SymtabShndx = SHT_SYMTAB_SHNDX,
+        Relr = SHT_RELR,
+        GnuAttributes = SHT_GNU_ATTRIBUTES,
+        GnuHash = SHT_GNU_HASH,
+        GnuLiblist = SHT_GNU_LIBLIST,
+        GnuVerdef = SHT_GNU_verdef,
+        GnuVerneed = SHT_GNU_verneed,
+        GnuVersym = SHT_GNU_versym,
+    }
+}
+
+elf_enum! {
+    // the kind of a segment, from p_type
+    SegmentType {
+        Null = PT_NULL,
+        Load = PT_LOAD,
+        Dynamic = PT_DYNAMIC,
+        Interp = PT_INTERP,
+        Note = PT_NOTE,
+        Shlib = PT_SHLIB,
+        Phdr = PT_PHDR,
+        Tls = PT_TLS,
+        GnuEhFrame = PT_GNU_EH_FRAME,
+        GnuStack = PT_GNU_STACK,
+        GnuRelro = PT_GNU_RELRO,
+        GnuProperty = PT_GNU_PROPERTY,
+        GnuSframe = PT_GNU_SFRAME,
+        OpenbsdRandomize = PT_OPENBSD_RANDOMIZE,
+        OpenbsdWxneeded = PT_OPENBSD_WXNEEDED,
+        OpenbsdBootdata = PT_OPENBSD_BOOTDATA,
+    }
+}
+
+elf_enum! {
+    // the visibility of a symbol to the linker, from ELF64_ST_BIND
+    SymbolBinding {
+        Local = STB_LOCAL,
+        Global = STB_GLOBAL,
+        Weak = STB_WEAK,
+        GnuUnique = STB_GNU_UNIQUE,
+    }
+}
+
+elf_enum! {
+    // what a symbol is, from ELF64_ST_TYPE
+    SymbolType {
+        Notype = STT_NOTYPE,
+        Object = STT_OBJECT,
+        Func = STT_FUNC,
+        Section = STT_SECTION,
+        File = STT_FILE,
+        Common = STT_COMMON,
+        Tls = STT_TLS,
+        Relc = STT_RELC,
+        Srelc = STT_SRELC,
+        GnuIfunc = STT_GNU_IFUNC,
+    }
+}
+
+elf_enum! {
+    // the visibility of a symbol outside of its component, from ELF64_ST_VISIBILITY
+    SymbolVisibility {
+        Default = STV_DEFAULT,
+        Internal = STV_INTERNAL,
+        Hidden = STV_HIDDEN,
+        Protected = STV_PROTECTED,
+    }
+}
+
+elf_enum! {
+    // the meaning of a dynamic entry, from d_tag
+    DynamicTag {
+        Null = DT_NULL,
+        Needed = DT_NEEDED,
+        Pltrelsz = DT_PLTRELSZ,
+        Pltgot = DT_PLTGOT,
+        Hash = DT_HASH,
+        Strtab = DT_STRTAB,
+        Symtab = DT_SYMTAB,
+        Rela = DT_RELA,
+        Relasz = DT_RELASZ,
+        Relaent = DT_RELAENT,
+        Strsz = DT_STRSZ,
+        Syment = DT_SYMENT,
+        Init = DT_INIT,
+        Fini = DT_FINI,
+        Soname = DT_SONAME,
+        Rpath = DT_RPATH,
+        Symbolic = DT_SYMBOLIC,
+        Rel = DT_REL,
+        Relsz = DT_RELSZ,
+        Relent = DT_RELENT,
+        Pltrel = DT_PLTREL,
+        Debug = DT_DEBUG,
+        Textrel = DT_TEXTREL,
+        Jmprel = DT_JMPREL,
+        BindNow = DT_BIND_NOW,
+        InitArray = DT_INIT_ARRAY,
+        FiniArray = DT_FINI_ARRAY,
+        InitArraysz = DT_INIT_ARRAYSZ,
+        FiniArraysz = DT_FINI_ARRAYSZ,
+        Runpath = DT_RUNPATH,
+        Flags = DT_FLAGS,
+        PreinitArray = DT_PREINIT_ARRAY,
+        PreinitArraysz = DT_PREINIT_ARRAYSZ,
+        SymtabShndx = DT_SYMTAB_SHNDX,
+        Relrsz = DT_RELRSZ,
+        Relr = DT_RELR,
+        Relrent = DT_RELRENT,
+        GnuPrelinked = DT_GNU_PRELINKED,
+        GnuConflictsz = DT_GNU_CONFLICTSZ,
+        GnuLiblistsz = DT_GNU_LIBLISTSZ,
+        Checksum = DT_CHECKSUM,
+        Pltpadsz = DT_PLTPADSZ,
+        Moveent = DT_MOVEENT,
+        Movesz = DT_MOVESZ,
+        Feature1 = DT_FEATURE_1,
+        Posflag1 = DT_POSFLAG_1,
+        Syminsz = DT_SYMINSZ,
+        Syminent = DT_SYMINENT,
+        GnuHash = DT_GNU_HASH,
+        TlsdescPlt = DT_TLSDESC_PLT,
+        TlsdescGot = DT_TLSDESC_GOT,
+        GnuConflict = DT_GNU_CONFLICT,
+        GnuLiblist = DT_GNU_LIBLIST,
+        Config = DT_CONFIG,
+        Depaudit = DT_DEPAUDIT,
+        Audit = DT_AUDIT,
+        Pltpad = DT_PLTPAD,
+        Movetab = DT_MOVETAB,
+        Syminfo = DT_SYMINFO,
+        Versym = DT_VERSYM,
+        Relacount = DT_RELACOUNT,
+        Relcount = DT_RELCOUNT,
+        Flags1 = DT_FLAGS_1,
+        Verdef = DT_VERDEF,
+        Verdefnum = DT_VERDEFNUM,
+        Verneed = DT_VERNEED,
+        Verneednum = DT_VERNEEDNUM,
+        Auxiliary = DT_AUXILIARY,
+        Filter = DT_FILTER,
+    }
+}
+
+elf_enum! {
+    values
+    // the relocation types of EM_X86_64
+    X86_64Relocation {
+        None = R_X86_64_NONE,
+        R64 = R_X86_64_64,
+        Pc32 = R_X86_64_PC32,
+        Got32 = R_X86_64_GOT32,
+        Plt32 = R_X86_64_PLT32,
+        Copy = R_X86_64_COPY,
+        GlobDat = R_X86_64_GLOB_DAT,
+        JumpSlot = R_X86_64_JUMP_SLOT,
+        Relative = R_X86_64_RELATIVE,
+        Gotpcrel = R_X86_64_GOTPCREL,
+        R32 = R_X86_64_32,
+        R32s = R_X86_64_32S,
+        R16 = R_X86_64_16,
+        Pc16 = R_X86_64_PC16,
+        R8 = R_X86_64_8,
+        Pc8 = R_X86_64_PC8,
+        Dtpmod64 = R_X86_64_DTPMOD64,
+        Dtpoff64 = R_X86_64_DTPOFF64,
+        Tpoff64 = R_X86_64_TPOFF64,
+        Tlsgd = R_X86_64_TLSGD,
+        Tlsld = R_X86_64_TLSLD,
+        Dtpoff32 = R_X86_64_DTPOFF32,
+        Gottpoff = R_X86_64_GOTTPOFF,
+        Tpoff32 = R_X86_64_TPOFF32,
+        Pc64 = R_X86_64_PC64,
+        Gotoff64 = R_X86_64_GOTOFF64,
+        Gotpc32 = R_X86_64_GOTPC32,
+        Got64 = R_X86_64_GOT64,
+        Gotpcrel64 = R_X86_64_GOTPCREL64,
+        Gotpc64 = R_X86_64_GOTPC64,
+        Gotplt64 = R_X86_64_GOTPLT64,
+        Pltoff64 = R_X86_64_PLTOFF64,
+        Size32 = R_X86_64_SIZE32,
+        Size64 = R_X86_64_SIZE64,
+        Gotpc32Tlsdesc = R_X86_64_GOTPC32_TLSDESC,
+        TlsdescCall = R_X86_64_TLSDESC_CALL,
+        Tlsdesc = R_X86_64_TLSDESC,
+        Irelative = R_X86_64_IRELATIVE,
+        Relative64 = R_X86_64_RELATIVE64,
+        Gotpcrelx = R_X86_64_GOTPCRELX,
+        RexGotpcrelx = R_X86_64_REX_GOTPCRELX,
+    }
+}
+
+elf_enum! {
+    values
+    // the relocation types of EM_386 (and EM_IAMCU)
+    I386Relocation {
+        None = R_386_NONE,
+        R32 = R_386_32,
+        Pc32 = R_386_PC32,
+        Got32 = R_386_GOT32,
+        Plt32 = R_386_PLT32,
+        Copy = R_386_COPY,
+        GlobDat = R_386_GLOB_DAT,
+        JmpSlot = R_386_JMP_SLOT,
+        Relative = R_386_RELATIVE,
+        Gotoff = R_386_GOTOFF,
+        Gotpc = R_386_GOTPC,
+        R32plt = R_386_32PLT,
+        TlsTpoff = R_386_TLS_TPOFF,
+        TlsIe = R_386_TLS_IE,
+        TlsGotie = R_386_TLS_GOTIE,
+        TlsLe = R_386_TLS_LE,
+        TlsGd = R_386_TLS_GD,
+        TlsLdm = R_386_TLS_LDM,
+        R16 = R_386_16,
+        Pc16 = R_386_PC16,
+        R8 = R_386_8,
+        Pc8 = R_386_PC8,
+        TlsGd32 = R_386_TLS_GD_32,
+        TlsGdPush = R_386_TLS_GD_PUSH,
+        TlsGdCall = R_386_TLS_GD_CALL,
+        TlsGdPop = R_386_TLS_GD_POP,
+        TlsLdm32 = R_386_TLS_LDM_32,
+        TlsLdmPush = R_386_TLS_LDM_PUSH,
+        TlsLdmCall = R_386_TLS_LDM_CALL,
+        TlsLdmPop = R_386_TLS_LDM_POP,
+        TlsLdo32 = R_386_TLS_LDO_32,
+        TlsIe32 = R_386_TLS_IE_32,
+        TlsLe32 = R_386_TLS_LE_32,
+        TlsDtpmod32 = R_386_TLS_DTPMOD32,
+        TlsDtpoff32 = R_386_TLS_DTPOFF32,
+        TlsTpoff32 = R_386_TLS_TPOFF32,
+        Size32 = R_386_SIZE32,
+        TlsGotdesc = R_386_TLS_GOTDESC,
+        TlsDescCall = R_386_TLS_DESC_CALL,
+        TlsDesc = R_386_TLS_DESC,
+        Irelative = R_386_IRELATIVE,
+        Got32x = R_386_GOT32X,
+    }
+}
+
+elf_enum! {
+    values
+    // the relocation types of EM_AARCH64
+    Aarch64Relocation {
+        None = R_AARCH64_NONE,
+        P32Abs32 = R_AARCH64_P32_ABS32,
+        P32Copy = R_AARCH64_P32_COPY,
+        P32GlobDat = R_AARCH64_P32_GLOB_DAT,
+        P32JumpSlot = R_AARCH64_P32_JUMP_SLOT,
+        P32Relative = R_AARCH64_P32_RELATIVE,
+        P32TlsDtpmod = R_AARCH64_P32_TLS_DTPMOD,
+        P32TlsDtprel = R_AARCH64_P32_TLS_DTPREL,
+        P32TlsTprel = R_AARCH64_P32_TLS_TPREL,
+        P32Tlsdesc = R_AARCH64_P32_TLSDESC,
+        P32Irelative = R_AARCH64_P32_IRELATIVE,
+        Abs64 = R_AARCH64_ABS64,
+        Abs32 = R_AARCH64_ABS32,
+        Abs16 = R_AARCH64_ABS16,
+        Prel64 = R_AARCH64_PREL64,
+        Prel32 = R_AARCH64_PREL32,
+        Prel16 = R_AARCH64_PREL16,
+        MovwUabsG0 = R_AARCH64_MOVW_UABS_G0,
+        MovwUabsG0Nc = R_AARCH64_MOVW_UABS_G0_NC,
+        MovwUabsG1 = R_AARCH64_MOVW_UABS_G1,
+        MovwUabsG1Nc = R_AARCH64_MOVW_UABS_G1_NC,
+        MovwUabsG2 = R_AARCH64_MOVW_UABS_G2,
+        MovwUabsG2Nc = R_AARCH64_MOVW_UABS_G2_NC,
+        MovwUabsG3 = R_AARCH64_MOVW_UABS_G3,
+        MovwSabsG0 = R_AARCH64_MOVW_SABS_G0,
+        MovwSabsG1 = R_AARCH64_MOVW_SABS_G1,
+        MovwSabsG2 = R_AARCH64_MOVW_SABS_G2,
+        LdPrelLo19 = R_AARCH64_LD_PREL_LO19,
+        AdrPrelLo21 = R_AARCH64_ADR_PREL_LO21,
+        AdrPrelPgHi21 = R_AARCH64_ADR_PREL_PG_HI21,
+        AdrPrelPgHi21Nc = R_AARCH64_ADR_PREL_PG_HI21_NC,
+        AddAbsLo12Nc = R_AARCH64_ADD_ABS_LO12_NC,
+        Ldst8AbsLo12Nc = R_AARCH64_LDST8_ABS_LO12_NC,
+        Tstbr14 = R_AARCH64_TSTBR14,
+        Condbr19 = R_AARCH64_CONDBR19,
+        Jump26 = R_AARCH64_JUMP26,
+        Call26 = R_AARCH64_CALL26,
+        Ldst16AbsLo12Nc = R_AARCH64_LDST16_ABS_LO12_NC,
+        Ldst32AbsLo12Nc = R_AARCH64_LDST32_ABS_LO12_NC,
+        Ldst64AbsLo12Nc = R_AARCH64_LDST64_ABS_LO12_NC,
+        MovwPrelG0 = R_AARCH64_MOVW_PREL_G0,
+        MovwPrelG0Nc = R_AARCH64_MOVW_PREL_G0_NC,
+        MovwPrelG1 = R_AARCH64_MOVW_PREL_G1,
+        MovwPrelG1Nc = R_AARCH64_MOVW_PREL_G1_NC,
+        MovwPrelG2 = R_AARCH64_MOVW_PREL_G2,
+        MovwPrelG2Nc = R_AARCH64_MOVW_PREL_G2_NC,
+        MovwPrelG3 = R_AARCH64_MOVW_PREL_G3,
+        Ldst128AbsLo12Nc = R_AARCH64_LDST128_ABS_LO12_NC,
+        MovwGotoffG0 = R_AARCH64_MOVW_GOTOFF_G0,
+        MovwGotoffG0Nc = R_AARCH64_MOVW_GOTOFF_G0_NC,
+        MovwGotoffG1 = R_AARCH64_MOVW_GOTOFF_G1,
+        MovwGotoffG1Nc = R_AARCH64_MOVW_GOTOFF_G1_NC,
+        MovwGotoffG2 = R_AARCH64_MOVW_GOTOFF_G2,
+        MovwGotoffG2Nc = R_AARCH64_MOVW_GOTOFF_G2_NC,
+        MovwGotoffG3 = R_AARCH64_MOVW_GOTOFF_G3,
+        Gotrel64 = R_AARCH64_GOTREL64,
+        Gotrel32 = R_AARCH64_GOTREL32,
+        GotLdPrel19 = R_AARCH64_GOT_LD_PREL19,
+        Ld64GotoffLo15 = R_AARCH64_LD64_GOTOFF_LO15,
+        AdrGotPage = R_AARCH64_ADR_GOT_PAGE,
+        Ld64GotLo12Nc = R_AARCH64_LD64_GOT_LO12_NC,
+        Ld64GotpageLo15 = R_AARCH64_LD64_GOTPAGE_LO15,
+        TlsgdAdrPrel21 = R_AARCH64_TLSGD_ADR_PREL21,
+        TlsgdAdrPage21 = R_AARCH64_TLSGD_ADR_PAGE21,
+        TlsgdAddLo12Nc = R_AARCH64_TLSGD_ADD_LO12_NC,
+        TlsgdMovwG1 = R_AARCH64_TLSGD_MOVW_G1,
+        TlsgdMovwG0Nc = R_AARCH64_TLSGD_MOVW_G0_NC,
+        TlsldAdrPrel21 = R_AARCH64_TLSLD_ADR_PREL21,
+        TlsldAdrPage21 = R_AARCH64_TLSLD_ADR_PAGE21,
+        TlsldAddLo12Nc = R_AARCH64_TLSLD_ADD_LO12_NC,
+        TlsldMovwG1 = R_AARCH64_TLSLD_MOVW_G1,
+        TlsldMovwG0Nc = R_AARCH64_TLSLD_MOVW_G0_NC,
+        TlsldLdPrel19 = R_AARCH64_TLSLD_LD_PREL19,
+        TlsldMovwDtprelG2 = R_AARCH64_TLSLD_MOVW_DTPREL_G2,
+        TlsldMovwDtprelG1 = R_AARCH64_TLSLD_MOVW_DTPREL_G1,
+        TlsldMovwDtprelG1Nc = R_AARCH64_TLSLD_MOVW_DTPREL_G1_NC,
+        TlsldMovwDtprelG0 = R_AARCH64_TLSLD_MOVW_DTPREL_G0,
+        TlsldMovwDtprelG0Nc = R_AARCH64_TLSLD_MOVW_DTPREL_G0_NC,
+        TlsldAddDtprelHi12 = R_AARCH64_TLSLD_ADD_DTPREL_HI12,
+        TlsldAddDtprelLo12 = R_AARCH64_TLSLD_ADD_DTPREL_LO12,
+        TlsldAddDtprelLo12Nc = R_AARCH64_TLSLD_ADD_DTPREL_LO12_NC,
+        TlsldLdst8DtprelLo12 = R_AARCH64_TLSLD_LDST8_DTPREL_LO12,
+        TlsldLdst8DtprelLo12Nc = R_AARCH64_TLSLD_LDST8_DTPREL_LO12_NC,
+        TlsldLdst16DtprelLo12 = R_AARCH64_TLSLD_LDST16_DTPREL_LO12,
+        TlsldLdst16DtprelLo12Nc = R_AARCH64_TLSLD_LDST16_DTPREL_LO12_NC,
+        TlsldLdst32DtprelLo12 = R_AARCH64_TLSLD_LDST32_DTPREL_LO12,
+        TlsldLdst32DtprelLo12Nc = R_AARCH64_TLSLD_LDST32_DTPREL_LO12_NC,
+        TlsldLdst64DtprelLo12 = R_AARCH64_TLSLD_LDST64_DTPREL_LO12,
+        TlsldLdst64DtprelLo12Nc = R_AARCH64_TLSLD_LDST64_DTPREL_LO12_NC,
+        TlsieMovwGottprelG1 = R_AARCH64_TLSIE_MOVW_GOTTPREL_G1,
+        TlsieMovwGottprelG0Nc = R_AARCH64_TLSIE_MOVW_GOTTPREL_G0_NC,
+        TlsieAdrGottprelPage21 = R_AARCH64_TLSIE_ADR_GOTTPREL_PAGE21,
+        TlsieLd64GottprelLo12Nc = R_AARCH64_TLSIE_LD64_GOTTPREL_LO12_NC,
+        TlsieLdGottprelPrel19 = R_AARCH64_TLSIE_LD_GOTTPREL_PREL19,
+        TlsleMovwTprelG2 = R_AARCH64_TLSLE_MOVW_TPREL_G2,
+        TlsleMovwTprelG1 = R_AARCH64_TLSLE_MOVW_TPREL_G1,
+        TlsleMovwTprelG1Nc = R_AARCH64_TLSLE_MOVW_TPREL_G1_NC,
+        TlsleMovwTprelG0 = R_AARCH64_TLSLE_MOVW_TPREL_G0,
+        TlsleMovwTprelG0Nc = R_AARCH64_TLSLE_MOVW_TPREL_G0_NC,
+        TlsleAddTprelHi12 = R_AARCH64_TLSLE_ADD_TPREL_HI12,
+        TlsleAddTprelLo12 = R_AARCH64_TLSLE_ADD_TPREL_LO12,
+        TlsleAddTprelLo12Nc = R_AARCH64_TLSLE_ADD_TPREL_LO12_NC,
+        TlsleLdst8TprelLo12 = R_AARCH64_TLSLE_LDST8_TPREL_LO12,
+        TlsleLdst8TprelLo12Nc = R_AARCH64_TLSLE_LDST8_TPREL_LO12_NC,
+        TlsleLdst16TprelLo12 = R_AARCH64_TLSLE_LDST16_TPREL_LO12,
+        TlsleLdst16TprelLo12Nc = R_AARCH64_TLSLE_LDST16_TPREL_LO12_NC,
+        TlsleLdst32TprelLo12 = R_AARCH64_TLSLE_LDST32_TPREL_LO12,
+        TlsleLdst32TprelLo12Nc = R_AARCH64_TLSLE_LDST32_TPREL_LO12_NC,
+        TlsleLdst64TprelLo12 = R_AARCH64_TLSLE_LDST64_TPREL_LO12,
+        TlsleLdst64TprelLo12Nc = R_AARCH64_TLSLE_LDST64_TPREL_LO12_NC,
+        TlsdescLdPrel19 = R_AARCH64_TLSDESC_LD_PREL19,
+        TlsdescAdrPrel21 = R_AARCH64_TLSDESC_ADR_PREL21,
+        TlsdescAdrPage21 = R_AARCH64_TLSDESC_ADR_PAGE21,
+        TlsdescLd64Lo12 = R_AARCH64_TLSDESC_LD64_LO12,
+        TlsdescAddLo12 = R_AARCH64_TLSDESC_ADD_LO12,
+        TlsdescOffG1 = R_AARCH64_TLSDESC_OFF_G1,
+        TlsdescOffG0Nc = R_AARCH64_TLSDESC_OFF_G0_NC,
+        TlsdescLdr = R_AARCH64_TLSDESC_LDR,
+        TlsdescAdd = R_AARCH64_TLSDESC_ADD,
+        TlsdescCall = R_AARCH64_TLSDESC_CALL,
+        TlsleLdst128TprelLo12 = R_AARCH64_TLSLE_LDST128_TPREL_LO12,
+        TlsleLdst128TprelLo12Nc = R_AARCH64_TLSLE_LDST128_TPREL_LO12_NC,
+        TlsldLdst128DtprelLo12 = R_AARCH64_TLSLD_LDST128_DTPREL_LO12,
+        TlsldLdst128DtprelLo12Nc = R_AARCH64_TLSLD_LDST128_DTPREL_LO12_NC,
+        Copy = R_AARCH64_COPY,
+        GlobDat = R_AARCH64_GLOB_DAT,
+        JumpSlot = R_AARCH64_JUMP_SLOT,
+        Relative = R_AARCH64_RELATIVE,
+        TlsDtpmod = R_AARCH64_TLS_DTPMOD,
+        TlsDtprel = R_AARCH64_TLS_DTPREL,
+        TlsTprel = R_AARCH64_TLS_TPREL,
+        Tlsdesc = R_AARCH64_TLSDESC,
+        Irelative = R_AARCH64_IRELATIVE,
+    }
+}
+
+elf_enum! {
+    values
+    // the relocation types of EM_ARM
+    ArmRelocation {
+        None = R_ARM_NONE,
+        Pc24 = R_ARM_PC24,
+        Abs32 = R_ARM_ABS32,
+        Rel32 = R_ARM_REL32,
+        Pc13 = R_ARM_PC13,
+        Abs16 = R_ARM_ABS16,
+        Abs12 = R_ARM_ABS12,
+        ThmAbs5 = R_ARM_THM_ABS5,
+        Abs8 = R_ARM_ABS8,
+        Sbrel32 = R_ARM_SBREL32,
+        ThmPc22 = R_ARM_THM_PC22,
+        ThmPc8 = R_ARM_THM_PC8,
+        AmpVcall9 = R_ARM_AMP_VCALL9,
+        Swi24 = R_ARM_SWI24,
+        ThmSwi8 = R_ARM_THM_SWI8,
+        Xpc25 = R_ARM_XPC25,
+        ThmXpc22 = R_ARM_THM_XPC22,
+        TlsDtpmod32 = R_ARM_TLS_DTPMOD32,
+        TlsDtpoff32 = R_ARM_TLS_DTPOFF32,
+        TlsTpoff32 = R_ARM_TLS_TPOFF32,
+        Copy = R_ARM_COPY,
+        GlobDat = R_ARM_GLOB_DAT,
+        JumpSlot = R_ARM_JUMP_SLOT,
+        Relative = R_ARM_RELATIVE,
+        Gotoff = R_ARM_GOTOFF,
+        Gotpc = R_ARM_GOTPC,
+        Got32 = R_ARM_GOT32,
+        Plt32 = R_ARM_PLT32,
+        Call = R_ARM_CALL,
+        Jump24 = R_ARM_JUMP24,
+        ThmJump24 = R_ARM_THM_JUMP24,
+        BaseAbs = R_ARM_BASE_ABS,
+        AluPcrel7_0 = R_ARM_ALU_PCREL_7_0,
+        AluPcrel15_8 = R_ARM_ALU_PCREL_15_8,
+        AluPcrel23_15 = R_ARM_ALU_PCREL_23_15,
+        LdrSbrel11_0 = R_ARM_LDR_SBREL_11_0,
+        AluSbrel19_12 = R_ARM_ALU_SBREL_19_12,
+        AluSbrel27_20 = R_ARM_ALU_SBREL_27_20,
+        Target1 = R_ARM_TARGET1,
+        Sbrel31 = R_ARM_SBREL31,
+        V4bx = R_ARM_V4BX,
+        Target2 = R_ARM_TARGET2,
+        Prel31 = R_ARM_PREL31,
+        MovwAbsNc = R_ARM_MOVW_ABS_NC,
+        MovtAbs = R_ARM_MOVT_ABS,
+        MovwPrelNc = R_ARM_MOVW_PREL_NC,
+        MovtPrel = R_ARM_MOVT_PREL,
+        ThmMovwAbsNc = R_ARM_THM_MOVW_ABS_NC,
+        ThmMovtAbs = R_ARM_THM_MOVT_ABS,
+        ThmMovwPrelNc = R_ARM_THM_MOVW_PREL_NC,
+        ThmMovtPrel = R_ARM_THM_MOVT_PREL,
+        ThmJump19 = R_ARM_THM_JUMP19,
+        ThmJump6 = R_ARM_THM_JUMP6,
+        ThmAluPrel11_0 = R_ARM_THM_ALU_PREL_11_0,
+        ThmPc12 = R_ARM_THM_PC12,
+        Abs32Noi = R_ARM_ABS32_NOI,
+        Rel32Noi = R_ARM_REL32_NOI,
+        AluPcG0Nc = R_ARM_ALU_PC_G0_NC,
+        AluPcG0 = R_ARM_ALU_PC_G0,
+        AluPcG1Nc = R_ARM_ALU_PC_G1_NC,
+        AluPcG1 = R_ARM_ALU_PC_G1,
+        AluPcG2 = R_ARM_ALU_PC_G2,
+        LdrPcG1 = R_ARM_LDR_PC_G1,
+        LdrPcG2 = R_ARM_LDR_PC_G2,
+        LdrsPcG0 = R_ARM_LDRS_PC_G0,
+        LdrsPcG1 = R_ARM_LDRS_PC_G1,
+        LdrsPcG2 = R_ARM_LDRS_PC_G2,
+        LdcPcG0 = R_ARM_LDC_PC_G0,
+        LdcPcG1 = R_ARM_LDC_PC_G1,
+        LdcPcG2 = R_ARM_LDC_PC_G2,
+        AluSbG0Nc = R_ARM_ALU_SB_G0_NC,
+        AluSbG0 = R_ARM_ALU_SB_G0,
+        AluSbG1Nc = R_ARM_ALU_SB_G1_NC,
+        AluSbG1 = R_ARM_ALU_SB_G1,
+        AluSbG2 = R_ARM_ALU_SB_G2,
+        LdrSbG0 = R_ARM_LDR_SB_G0,
+        LdrSbG1 = R_ARM_LDR_SB_G1,
+        LdrSbG2 = R_ARM_LDR_SB_G2,
+        LdrsSbG0 = R_ARM_LDRS_SB_G0,
+        LdrsSbG1 = R_ARM_LDRS_SB_G1,
+        LdrsSbG2 = R_ARM_LDRS_SB_G2,
+        LdcSbG0 = R_ARM_LDC_SB_G0,
+        LdcSbG1 = R_ARM_LDC_SB_G1,
+        LdcSbG2 = R_ARM_LDC_SB_G2,
+        MovwBrelNc = R_ARM_MOVW_BREL_NC,
+        MovtBrel = R_ARM_MOVT_BREL,
+        MovwBrel = R_ARM_MOVW_BREL,
+        ThmMovwBrelNc = R_ARM_THM_MOVW_BREL_NC,
+        ThmMovtBrel = R_ARM_THM_MOVT_BREL,
+        ThmMovwBrel = R_ARM_THM_MOVW_BREL,
+        TlsGotdesc = R_ARM_TLS_GOTDESC,
+        TlsCall = R_ARM_TLS_CALL,
+        TlsDescseq = R_ARM_TLS_DESCSEQ,
+        ThmTlsCall = R_ARM_THM_TLS_CALL,
+        Plt32Abs = R_ARM_PLT32_ABS,
+        GotAbs = R_ARM_GOT_ABS,
+        GotPrel = R_ARM_GOT_PREL,
+        GotBrel12 = R_ARM_GOT_BREL12,
+        Gotoff12 = R_ARM_GOTOFF12,
+        Gotrelax = R_ARM_GOTRELAX,
+        GnuVtentry = R_ARM_GNU_VTENTRY,
+        GnuVtinherit = R_ARM_GNU_VTINHERIT,
+        ThmPc11 = R_ARM_THM_PC11,
+        ThmPc9 = R_ARM_THM_PC9,
+        TlsGd32 = R_ARM_TLS_GD32,
+        TlsLdm32 = R_ARM_TLS_LDM32,
+        TlsLdo32 = R_ARM_TLS_LDO32,
+        TlsIe32 = R_ARM_TLS_IE32,
+        TlsLe32 = R_ARM_TLS_LE32,
+        TlsLdo12 = R_ARM_TLS_LDO12,
+        TlsLe12 = R_ARM_TLS_LE12,
+        TlsIe12gp = R_ARM_TLS_IE12GP,
+        MeToo = R_ARM_ME_TOO,
+        ThmTlsDescseq = R_ARM_THM_TLS_DESCSEQ,
+        ThmTlsDescseq32 = R_ARM_THM_TLS_DESCSEQ32,
+        ThmGotBrel12 = R_ARM_THM_GOT_BREL12,
+        Irelative = R_ARM_IRELATIVE,
+        Rxpc25 = R_ARM_RXPC25,
+        Rsbrel32 = R_ARM_RSBREL32,
+        ThmRpc22 = R_ARM_THM_RPC22,
+        Rrel32 = R_ARM_RREL32,
+        Rabs22 = R_ARM_RABS22,
+        Rpc24 = R_ARM_RPC24,
+        Rbase = R_ARM_RBASE,
+    }
+}
+
+elf_enum! {
+    values
+    // the relocation types of EM_RISCV
+    RiscvRelocation {
+        None = R_RISCV_NONE,
+        R32 = R_RISCV_32,
+        R64 = R_RISCV_64,
+        Relative = R_RISCV_RELATIVE,
+        Copy = R_RISCV_COPY,
+        JumpSlot = R_RISCV_JUMP_SLOT,
+        TlsDtpmod32 = R_RISCV_TLS_DTPMOD32,
+        TlsDtpmod64 = R_RISCV_TLS_DTPMOD64,
+        TlsDtprel32 = R_RISCV_TLS_DTPREL32,
+        TlsDtprel64 = R_RISCV_TLS_DTPREL64,
+        TlsTprel32 = R_RISCV_TLS_TPREL32,
+        TlsTprel64 = R_RISCV_TLS_TPREL64,
+        Branch = R_RISCV_BRANCH,
+        Jal = R_RISCV_JAL,
+        Call = R_RISCV_CALL,
+        CallPlt = R_RISCV_CALL_PLT,
+        GotHi20 = R_RISCV_GOT_HI20,
+        TlsGotHi20 = R_RISCV_TLS_GOT_HI20,
+        TlsGdHi20 = R_RISCV_TLS_GD_HI20,
+        PcrelHi20 = R_RISCV_PCREL_HI20,
+        PcrelLo12I = R_RISCV_PCREL_LO12_I,
+        PcrelLo12S = R_RISCV_PCREL_LO12_S,
+        Hi20 = R_RISCV_HI20,
+        Lo12I = R_RISCV_LO12_I,
+        Lo12S = R_RISCV_LO12_S,
+        TprelHi20 = R_RISCV_TPREL_HI20,
+        TprelLo12I = R_RISCV_TPREL_LO12_I,
+        TprelLo12S = R_RISCV_TPREL_LO12_S,
+        TprelAdd = R_RISCV_TPREL_ADD,
+        Add8 = R_RISCV_ADD8,
+        Add16 = R_RISCV_ADD16,
+        Add32 = R_RISCV_ADD32,
+        Add64 = R_RISCV_ADD64,
+        Sub8 = R_RISCV_SUB8,
+        Sub16 = R_RISCV_SUB16,
+        Sub32 = R_RISCV_SUB32,
+        Sub64 = R_RISCV_SUB64,
+        GnuVtinherit = R_RISCV_GNU_VTINHERIT,
+        GnuVtentry = R_RISCV_GNU_VTENTRY,
+        Align = R_RISCV_ALIGN,
+        RvcBranch = R_RISCV_RVC_BRANCH,
+        RvcJump = R_RISCV_RVC_JUMP,
+        RvcLui = R_RISCV_RVC_LUI,
+        GprelI = R_RISCV_GPREL_I,
+        GprelS = R_RISCV_GPREL_S,
+        TprelI = R_RISCV_TPREL_I,
+        TprelS = R_RISCV_TPREL_S,
+        Relax = R_RISCV_RELAX,
+        Sub6 = R_RISCV_SUB6,
+        Set6 = R_RISCV_SET6,
+        Set8 = R_RISCV_SET8,
+        Set16 = R_RISCV_SET16,
+        Set32 = R_RISCV_SET32,
+        R32Pcrel = R_RISCV_32_PCREL,
+        Irelative = R_RISCV_IRELATIVE,
+    }
+}
+
+elf_enum! {
+    values
+    // the relocation types of EM_PPC64
+    Ppc64Relocation {
+        None = R_PPC64_NONE,
+        Addr32 = R_PPC64_ADDR32,
+        Addr24 = R_PPC64_ADDR24,
+        Addr16 = R_PPC64_ADDR16,
+        Addr16Ha = R_PPC64_ADDR16_HA,
+        Addr14 = R_PPC64_ADDR14,
+        Addr14Brtaken = R_PPC64_ADDR14_BRTAKEN,
+        Addr14Brntaken = R_PPC64_ADDR14_BRNTAKEN,
+        Rel24 = R_PPC64_REL24,
+        Rel14 = R_PPC64_REL14,
+        Rel14Brtaken = R_PPC64_REL14_BRTAKEN,
+        Rel14Brntaken = R_PPC64_REL14_BRNTAKEN,
+        Got16 = R_PPC64_GOT16,
+        Got16Ha = R_PPC64_GOT16_HA,
+        Copy = R_PPC64_COPY,
+        GlobDat = R_PPC64_GLOB_DAT,
+        JmpSlot = R_PPC64_JMP_SLOT,
+        Relative = R_PPC64_RELATIVE,
+        Uaddr32 = R_PPC64_UADDR32,
+        Uaddr16 = R_PPC64_UADDR16,
+        Rel32 = R_PPC64_REL32,
+        Plt32 = R_PPC64_PLT32,
+        Pltrel32 = R_PPC64_PLTREL32,
+        Plt16Ha = R_PPC64_PLT16_HA,
+        Sectoff = R_PPC64_SECTOFF,
+        SectoffHa = R_PPC64_SECTOFF_HA,
+        Addr30 = R_PPC64_ADDR30,
+        Addr64 = R_PPC64_ADDR64,
+        Addr16Higher = R_PPC64_ADDR16_HIGHER,
+        Addr16Highera = R_PPC64_ADDR16_HIGHERA,
+        Addr16Highest = R_PPC64_ADDR16_HIGHEST,
+        Addr16Highesta = R_PPC64_ADDR16_HIGHESTA,
+        Uaddr64 = R_PPC64_UADDR64,
+        Rel64 = R_PPC64_REL64,
+        Plt64 = R_PPC64_PLT64,
+        Pltrel64 = R_PPC64_PLTREL64,
+        Toc16 = R_PPC64_TOC16,
+        Toc16Ha = R_PPC64_TOC16_HA,
+        Toc = R_PPC64_TOC,
+        Pltgot16 = R_PPC64_PLTGOT16,
+        Pltgot16Ha = R_PPC64_PLTGOT16_HA,
+        Addr16Ds = R_PPC64_ADDR16_DS,
+        Addr16LoDs = R_PPC64_ADDR16_LO_DS,
+        Got16Ds = R_PPC64_GOT16_DS,
+        Got16LoDs = R_PPC64_GOT16_LO_DS,
+        Plt16LoDs = R_PPC64_PLT16_LO_DS,
+        SectoffDs = R_PPC64_SECTOFF_DS,
+        SectoffLoDs = R_PPC64_SECTOFF_LO_DS,
+        Toc16Ds = R_PPC64_TOC16_DS,
+        Toc16LoDs = R_PPC64_TOC16_LO_DS,
+        Pltgot16Ds = R_PPC64_PLTGOT16_DS,
+        Pltgot16LoDs = R_PPC64_PLTGOT16_LO_DS,
+        Tls = R_PPC64_TLS,
+        Dtpmod64 = R_PPC64_DTPMOD64,
+        Tprel16 = R_PPC64_TPREL16,
+        Tprel16Ha = R_PPC64_TPREL16_HA,
+        Tprel64 = R_PPC64_TPREL64,
+        Dtprel16 = R_PPC64_DTPREL16,
+        Dtprel16Ha = R_PPC64_DTPREL16_HA,
+        Dtprel64 = R_PPC64_DTPREL64,
+        GotTlsgd16 = R_PPC64_GOT_TLSGD16,
+        GotTlsgd16Ha = R_PPC64_GOT_TLSGD16_HA,
+        GotTlsld16 = R_PPC64_GOT_TLSLD16,
+        GotTlsld16Ha = R_PPC64_GOT_TLSLD16_HA,
+        GotTprel16Ds = R_PPC64_GOT_TPREL16_DS,
+        GotTprel16LoDs = R_PPC64_GOT_TPREL16_LO_DS,
+        GotTprel16Ha = R_PPC64_GOT_TPREL16_HA,
+        GotDtprel16Ds = R_PPC64_GOT_DTPREL16_DS,
+        GotDtprel16LoDs = R_PPC64_GOT_DTPREL16_LO_DS,
+        GotDtprel16Ha = R_PPC64_GOT_DTPREL16_HA,
+        Tprel16Ds = R_PPC64_TPREL16_DS,
+        Tprel16LoDs = R_PPC64_TPREL16_LO_DS,
+        Tprel16Higher = R_PPC64_TPREL16_HIGHER,
+        Tprel16Highera = R_PPC64_TPREL16_HIGHERA,
+        Tprel16Highest = R_PPC64_TPREL16_HIGHEST,
+        Tprel16Highesta = R_PPC64_TPREL16_HIGHESTA,
+        Dtprel16Ds = R_PPC64_DTPREL16_DS,
+        Dtprel16LoDs = R_PPC64_DTPREL16_LO_DS,
+        Dtprel16Higher = R_PPC64_DTPREL16_HIGHER,
+        Dtprel16Highera = R_PPC64_DTPREL16_HIGHERA,
+        Dtprel16Highest = R_PPC64_DTPREL16_HIGHEST,
+        Dtprel16Highesta = R_PPC64_DTPREL16_HIGHESTA,
+        Tlsgd = R_PPC64_TLSGD,
+        Tlsld = R_PPC64_TLSLD,
+        Tocsave = R_PPC64_TOCSAVE,
+        Addr16High = R_PPC64_ADDR16_HIGH,
+        Addr16Higha = R_PPC64_ADDR16_HIGHA,
+        Tprel16High = R_PPC64_TPREL16_HIGH,
+        Tprel16Higha = R_PPC64_TPREL16_HIGHA,
+        Dtprel16High = R_PPC64_DTPREL16_HIGH,
+        Dtprel16Higha = R_PPC64_DTPREL16_HIGHA,
+        JmpIrel = R_PPC64_JMP_IREL,
+        Irelative = R_PPC64_IRELATIVE,
+        Rel16 = R_PPC64_REL16,
+        Rel16Ha = R_PPC64_REL16_HA,
+    }
+}
+
+elf_enum! {
+    values
+    // the relocation types of EM_S390
+    S390Relocation {
+        None = R_390_NONE,
+        R8 = R_390_8,
+        R12 = R_390_12,
+        R16 = R_390_16,
+        R32 = R_390_32,
+        Pc32 = R_390_PC32,
+        Got12 = R_390_GOT12,
+        Got32 = R_390_GOT32,
+        Plt32 = R_390_PLT32,
+        Copy = R_390_COPY,
+        GlobDat = R_390_GLOB_DAT,
+        JmpSlot = R_390_JMP_SLOT,
+        Relative = R_390_RELATIVE,
+        Gotoff32 = R_390_GOTOFF32,
+        Gotpc = R_390_GOTPC,
+        Got16 = R_390_GOT16,
+        Pc16 = R_390_PC16,
+        Pc16dbl = R_390_PC16DBL,
+        Plt16dbl = R_390_PLT16DBL,
+        Pc32dbl = R_390_PC32DBL,
+        Plt32dbl = R_390_PLT32DBL,
+        Gotpcdbl = R_390_GOTPCDBL,
+        R64 = R_390_64,
+        Pc64 = R_390_PC64,
+        Got64 = R_390_GOT64,
+        Plt64 = R_390_PLT64,
+        Gotent = R_390_GOTENT,
+        Gotoff16 = R_390_GOTOFF16,
+        Gotoff64 = R_390_GOTOFF64,
+        Gotplt12 = R_390_GOTPLT12,
+        Gotplt16 = R_390_GOTPLT16,
+        Gotplt32 = R_390_GOTPLT32,
+        Gotplt64 = R_390_GOTPLT64,
+        Gotpltent = R_390_GOTPLTENT,
+        Pltoff16 = R_390_PLTOFF16,
+        Pltoff32 = R_390_PLTOFF32,
+        Pltoff64 = R_390_PLTOFF64,
+        TlsLoad = R_390_TLS_LOAD,
+        TlsGdcall = R_390_TLS_GDCALL,
+        TlsLdcall = R_390_TLS_LDCALL,
+        TlsGd32 = R_390_TLS_GD32,
+        TlsGd64 = R_390_TLS_GD64,
+        TlsGotie12 = R_390_TLS_GOTIE12,
+        TlsGotie32 = R_390_TLS_GOTIE32,
+        TlsGotie64 = R_390_TLS_GOTIE64,
+        TlsLdm32 = R_390_TLS_LDM32,
+        TlsLdm64 = R_390_TLS_LDM64,
+        TlsIe32 = R_390_TLS_IE32,
+        TlsIe64 = R_390_TLS_IE64,
+        TlsIeent = R_390_TLS_IEENT,
+        TlsLe32 = R_390_TLS_LE32,
+        TlsLe64 = R_390_TLS_LE64,
+        TlsLdo32 = R_390_TLS_LDO32,
+        TlsLdo64 = R_390_TLS_LDO64,
+        TlsDtpmod = R_390_TLS_DTPMOD,
+        TlsDtpoff = R_390_TLS_DTPOFF,
+        TlsTpoff = R_390_TLS_TPOFF,
+        R20 = R_390_20,
+        Got20 = R_390_GOT20,
+        Gotplt20 = R_390_GOTPLT20,
+        TlsGotie20 = R_390_TLS_GOTIE20,
+        Irelative = R_390_IRELATIVE,
+    }
+}
+
+// the relocation types only mean something together with the machine of the
+// file, the same value has a different name in each one. Their names are the
+// ones of binutils (R_386_JUMP_SLOT, not elf.h's R_386_JMP_SLOT), so they come
+// from the decoders of elf::relocation_types instead of the enums
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RelocationType {
+    X86_64(X86_64Relocation),
+    I386(I386Relocation),
+    Aarch64(Aarch64Relocation),
+    Arm(ArmRelocation),
+    Riscv(RiscvRelocation),
+    Ppc64(Ppc64Relocation),
+    S390(S390Relocation),
+    // a machine without a table here
+    Unknown(u32),
+}
+
+impl RelocationType {
+    pub fn new(machine: Machine, r_type: u32) -> Self {
+        match machine {
+            Machine::X86_64 => RelocationType::X86_64(r_type.into()),
+            Machine::Em386 | Machine::Iamcu => RelocationType::I386(r_type.into()),
+            Machine::Aarch64 => RelocationType::Aarch64(r_type.into()),
+            Machine::Arm => RelocationType::Arm(r_type.into()),
+            Machine::Riscv => RelocationType::Riscv(r_type.into()),
+            Machine::Ppc64 => RelocationType::Ppc64(r_type.into()),
+            Machine::S390 => RelocationType::S390(r_type.into()),
+            _ => RelocationType::Unknown(r_type),
+        }
+    }
+
+    pub fn name(self) -> Option<&'static str> {
+        match self {
+            RelocationType::X86_64(r_type) => x86_64_relocation_name(r_type.into()),
+            RelocationType::I386(r_type) => i386_relocation_name(r_type.into()),
+            RelocationType::Aarch64(r_type) => aarch64_relocation_name(r_type.into()),
+            RelocationType::Arm(r_type) => arm_relocation_name(r_type.into()),
+            RelocationType::Riscv(r_type) => riscv_relocation_name(r_type.into()),
+            RelocationType::Ppc64(r_type) => ppc64_relocation_name(r_type.into()),
+            RelocationType::S390(r_type) => s390_relocation_name(r_type.into()),
+            RelocationType::Unknown(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DynamicTag, Machine, OsAbi, RelocationType, SectionType, X86_64Relocation};
+
+    #[test]
+    fn from_returns_the_variant_of_the_value() {
+        assert_eq!(Machine::from(62), Machine::X86_64);
+        assert_eq!(SectionType::from(0x6ffffff6), SectionType::GnuHash);
+        assert_eq!(DynamicTag::from(0x6ffffffb), DynamicTag::Flags1);
+        assert_eq!(u32::from(Machine::Riscv), 243);
+    }
+
+    #[test]
+    fn from_returns_unknown_for_values_without_a_name() {
+        assert_eq!(
+            SectionType::from(0x70000001),
+            SectionType::Unknown(0x70000001)
+        );
+        assert_eq!(u32::from(SectionType::Unknown(0x70000001)), 0x70000001);
+        assert_eq!(SectionType::Unknown(0x70000001).c_name(), None);
+    }
+
+    #[test]
+    fn aliases_use_the_first_name_of_the_value() {
+        assert_eq!(OsAbi::from(3), OsAbi::Gnu);
+        assert_eq!(OsAbi::Gnu.c_name(), Some("ELFOSABI_GNU"));
+    }
+
+    #[test]
+    fn relocation_type_depends_on_the_machine() {
+        let r_type = RelocationType::new(Machine::X86_64, 7);
+
+        assert_eq!(r_type, RelocationType::X86_64(X86_64Relocation::JumpSlot));
+        assert_eq!(r_type.name(), Some("R_X86_64_JUMP_SLOT"));
+        assert_eq!(
+            RelocationType::new(Machine::Em386, 7).name(),
+            Some("R_386_JUMP_SLOT")
+        );
+        assert_eq!(
+            RelocationType::new(Machine::Mips, 7),
+            RelocationType::Unknown(7)
+        );
+    }
+}
@@ -1 +1 @@
-pub mod arch;
\ No newline at end of file
+pub mod header;
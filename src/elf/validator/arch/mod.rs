@@ -1 +0,0 @@
-pub mod arch64;
\ No newline at end of file
@@ -0,0 +1,632 @@
+use crate::elf::error::{ElfError, ElfErrorKind};
+use crate::utils::endianess::EndianRead;
+
+// checks e_ident, e_type and e_machine before the header is read, for both
+// classes (ELFCLASS32 and ELFCLASS64 have these fields at the same offsets)
+pub struct ElfHeaderValidator<'a> {
+    base: &'a [u8],
+    // in the lenient mode the values that dont stop us from reading the rest
+    // of the header (an unknown EI_VERSION or e_type, some garbage in the
+    // padding) are kept here instead of being returned as errors
+    lenient: bool,
+    warnings: Vec<ElfError>,
+}
+
+impl<'a> ElfHeaderValidator<'a> {
+    const EI_DATA: usize = 5;
+
+    pub fn new(base_bytes: &'a [u8]) -> Self {
+        ElfHeaderValidator {
+            base: base_bytes,
+            lenient: false,
+            warnings: Vec::new(),
+        }
+    }
+
+    pub fn lenient(base_bytes: &'a [u8]) -> Self {
+        ElfHeaderValidator {
+            lenient: true,
+            ..Self::new(base_bytes)
+        }
+    }
+
+    pub fn into_warnings(self) -> Vec<ElfError> {
+        self.warnings
+    }
+
+    pub fn validate_e_ident(&mut self) -> Result<&'a [u8], ElfError> {
+        // offset: 0x0 -> 0x0F
+
+        // first, we need to verify if the e_ident byte arr is more than 16 bytes
+        // of size
+        if self.base.len() < 16 {
+            return Err(ElfError::truncated(0, "e_ident", 16, self.base.len()));
+        }
+        let e_ident = &self.base[..16];
+
+        // First part of the e_ident array is 4 bytes that should be always equal
+        // to: 7f, 45, 4c, 46, respectively. They are called 'magic number' for some
+        // reason that idk, but they serve for identify the elf file as an valid
+        // elf file.
+
+        // any of the four bytes being wrong is enough to say that this is not
+        // an elf file, so we cannot require all of them to be wrong at once
+        if e_ident[..4] != [0x7f, 0x45, 0x4c, 0x46] {
+            return Err(ElfError::invalid_value(
+                0,
+                self.structure(),
+                "EI_MAG",
+                "the magic number 0x7f454c46",
+                u32::from_be_bytes([e_ident[0], e_ident[1], e_ident[2], e_ident[3]]),
+            ));
+        }
+
+        // the next byte identifies the file class, if is 32 bit or 64 bit
+        // file or an Invalid Class (idk why this exists instead of simply
+        // throw a compiler exception or something like this)
+
+        // both classes are accepted, 1 is a 32-bit object and 2 is a 64-bit
+        // object. The 32-bit ones are widened after being parsed, so this
+        // validator works for both of them
+        if e_ident[4] != 1 && e_ident[4] != 2 {
+            return Err(ElfError::invalid_value(
+                4,
+                self.structure(),
+                "EI_CLASS",
+                "1 (ELFCLASS32) or 2 (ELFCLASS64)",
+                e_ident[4],
+            ));
+        }
+
+        // the next byte identifies the endiannes enconding of most of the
+        // data present in this file.
+        self.check_endianness()?;
+
+        // the next byte identify if the ELF file is version number
+        // witch is 1 (one) since 1995, so we dont have to think that
+        // will be changed now (right?)
+
+        if e_ident[6] != 1 {
+            self.recoverable(ElfError::invalid_value(
+                6,
+                self.structure(),
+                "EI_VERSION",
+                "1 (EV_CURRENT)",
+                e_ident[6],
+            ))?;
+        }
+
+        // the next two bytes are EI_OSABI and EI_ABIVERSION, witch describes
+        // respectivily, the operational system ABI and it version. Their names
+        // are in elf::osabi, but any value is accepted here: new ABIs appear
+        // from time to time, and the values from 64 depend on the machine, so
+        // an unknown one is only a warning (with --strict)
+
+        // the padding is the first byte that is not zero
+        if let Some(at) = e_ident[9..16].iter().position(|&byte| byte != 0) {
+            self.recoverable(ElfError::invalid_value(
+                9 + at as u64,
+                self.structure(),
+                "EI_PAD",
+                "0",
+                e_ident[9 + at],
+            ))?;
+        }
+
+        Ok(e_ident)
+    }
+
+    pub fn validate_e_type(&mut self) -> Result<&'a [u8], ElfError> {
+        // the e_type field uses u16, that occuppes 2 bytes, so we have to cast to apropriatte endiannes
+        // offset: 0x10 -> 0x11
+
+        // the e_type field describes an way to say the type of file that are this elf. Usually, this
+        // value is ET_REL, witch means that is an relocatable file (.o file without linking), ET_EXEC
+        // witch means that is an executable file, like /bin/ls, ET_DYN for shared object files (.so)
+        // and finally ET_CORE for core dumps (when the file crashes)
+
+        if self.base.len() < 18 {
+            // this means that e_type has not the required size for e_type, that is 2 bytes
+            return Err(self.truncated_field(16, "e_type"));
+        }
+
+        let end_blk_anlzr = self.check_endianness()? == 1;
+
+        let e_type_bytes = &self.base[16..18];
+
+        let e_type = u16::read_from(e_type_bytes, end_blk_anlzr);
+
+        // validate e_type value (common values are 1=REL, 2=EXEC, 3=SHARED, 4=CORE, 0xff00=Processor-specific, 0xffff=Processor-specific)
+        if !((0..=4).contains(&e_type)
+            || (0xfe00..=0xfeff).contains(&e_type)
+            || (0xff00..=0xffff).contains(&e_type))
+        {
+            self.recoverable(ElfError::invalid_value(
+                16,
+                self.structure(),
+                "e_type",
+                "0 to 4 or an OS or processor specific type",
+                e_type,
+            ))?;
+        }
+
+        Ok(e_type_bytes)
+    }
+
+    pub fn validate_e_machine(&self) -> Result<&'a [u8], ElfError> {
+        // offsett: 0x12 -> 0x13
+
+        // here we are using also u16 (64 half) as representational val for theses bytes
+        // the e_machine fields specifies some bunch of macros that expands to some valeus
+        // that define specific arch for the binary. The kernel analizes this val and decides
+        // if can be enable to run it or it should panic
+        // Every machine known by binutils (and its name, class, endianness and decoders) is
+        // in elf::machine, but an unknown value is still not an error here. New machines get
+        // a number all the time, and there is no range for them, so like binutils we just
+        // show them as "<unknown>" and keep reading the rest of the file.
+
+        if self.base.len() < 20 {
+            return Err(self.truncated_field(18, "e_machine"));
+        }
+
+        // we dont decode e_machine here (see above), but the caller will, so
+        // the endianness still have to be something that we can read with
+        self.check_endianness()?;
+
+        let e_machine_bytes = &self.base[18..20];
+
+        Ok(e_machine_bytes)
+    }
+
+    fn get_endianness(&self) -> u8 {
+        // offset: 0x5
+
+        self.base[Self::EI_DATA]
+    }
+
+    // the problems that only make a field meaningless (but not the ones
+    // after it) are only errors when we are not lenient
+    fn recoverable(&mut self, err: ElfError) -> Result<(), ElfError> {
+        if !self.lenient {
+            return Err(err);
+        }
+
+        self.warnings.push(err);
+        Ok(())
+    }
+
+    // every field after e_ident is read with EI_DATA, so all of them need it
+    // to be one of the two encodings
+    fn check_endianness(&self) -> Result<u8, ElfError> {
+        match self.get_endianness() {
+            endianness @ (1 | 2) => Ok(endianness),
+            endianness => Err(ElfError::invalid_value(
+                Self::EI_DATA as u64,
+                self.structure(),
+                "EI_DATA",
+                "1 (ELFDATA2LSB) or 2 (ELFDATA2MSB)",
+                endianness,
+            )),
+        }
+    }
+
+    // the header has the same name of its class, once the class is known
+    fn structure(&self) -> &'static str {
+        match self.base.get(4) {
+            Some(1) => "Elf32_Ehdr",
+            _ => "Elf64_Ehdr",
+        }
+    }
+
+    fn truncated_field(&self, offset: usize, field: &'static str) -> ElfError {
+        ElfError {
+            offset: offset as u64,
+            structure: self.structure(),
+            field,
+            kind: ElfErrorKind::Truncated {
+                expected: 2,
+                actual: self.base.len().saturating_sub(offset) as u64,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    macro_rules! assert_err_variant {
+        ($result:expr, $pattern:pat_param) => {
+            match &$result {
+                Err($pattern) => {}
+                _ => panic!(
+                    "Expected Err({}), but was {:?}",
+                    stringify!($pattern),
+                    $result
+                ),
+            }
+        };
+    }
+
+    mod validate_e_ident {
+        use super::super::ElfHeaderValidator;
+        use crate::elf::error::{ElfError, ElfErrorKind};
+
+        #[test]
+        fn validate_e_ident_returns_non_elf_file_err_when_arr_size_is_less_than_16() {
+            let file = [0u8; 15];
+
+            let result = ElfHeaderValidator::new(&file).validate_e_ident();
+
+            assert_err_variant!(
+                result,
+                ElfError {
+                    field: "e_ident",
+                    kind: ElfErrorKind::Truncated { .. },
+                    ..
+                }
+            )
+        }
+
+        #[test]
+        fn validate_e_ident_return_non_elf_file_err_when_the_first_four_bytes_are_diff_than_the_recognized_magnumbers()
+         {
+            // the magic numbers are 0x7x, 0x45, 0x4c, 0x46
+            let mut file = [0u8; 16];
+            file[0] = 0;
+            file[1] = 1;
+            file[2] = 2;
+            file[3] = 3;
+
+            let result = ElfHeaderValidator::new(&file).validate_e_ident();
+
+            assert_err_variant!(
+                result,
+                ElfError {
+                    field: "EI_MAG",
+                    kind: ElfErrorKind::InvalidValue {
+                        actual: 0x00010203,
+                        ..
+                    },
+                    ..
+                }
+            )
+        }
+
+        #[test]
+        fn validate_e_ident_returns_invalid_class_err_if_the_elf_class_is_not_1_or_2() {
+            let mut file = [0u8; 16];
+            file[0] = 0x7f;
+            file[1] = 0x45;
+            file[2] = 0x4c;
+            file[3] = 0x46;
+            file[4] = 3; // there is no class 3, only 32-bit (1) and 64-bit (2)
+
+            let result = ElfHeaderValidator::new(&file).validate_e_ident();
+
+            assert_err_variant!(
+                result,
+                ElfError {
+                    field: "EI_CLASS",
+                    kind: ElfErrorKind::InvalidValue { actual: 3, .. },
+                    ..
+                }
+            )
+        }
+
+        #[test]
+        fn validate_e_ident_accepts_32bit_files() {
+            let mut file = [0u8; 16];
+            file[0..4].copy_from_slice(&[0x7f, 0x45, 0x4c, 0x46]);
+            file[4] = 1; // means that is a 32-bit object
+            file[5] = 1;
+            file[6] = 1;
+
+            let result = ElfHeaderValidator::new(&file).validate_e_ident();
+
+            assert!(result.is_ok());
+        }
+
+        #[test]
+        fn validate_e_ident_returns_invalid_endianness_error_if_e_ident5_is_invalid() {
+            let mut file = [0u8; 16];
+            file[0] = 0x7f;
+            file[1] = 0x45;
+            file[2] = 0x4c;
+            file[3] = 0x46;
+            file[4] = 2; // valid 64-bit class
+            file[5] = 3; // invalid endianness (should be 1 or 2)
+
+            let result = ElfHeaderValidator::new(&file).validate_e_ident();
+
+            assert_err_variant!(
+                result,
+                ElfError {
+                    field: "EI_DATA",
+                    kind: ElfErrorKind::InvalidValue { actual: 3, .. },
+                    ..
+                }
+            )
+        }
+
+        #[test]
+        fn validate_e_ident_returns_invalid_elf_version_error_if_e_ident6_is_not_1() {
+            let mut file = [0u8; 16];
+            file[0] = 0x7f;
+            file[1] = 0x45;
+            file[2] = 0x4c;
+            file[3] = 0x46;
+            file[4] = 2; // valid 64-bit class
+            file[5] = 1; // valid endianness (little-endian)
+            file[6] = 2; // invalid ELF version (should be 1)
+
+            let result = ElfHeaderValidator::new(&file).validate_e_ident();
+
+            assert_err_variant!(
+                result,
+                ElfError {
+                    field: "EI_VERSION",
+                    kind: ElfErrorKind::InvalidValue { actual: 2, .. },
+                    ..
+                }
+            )
+        }
+
+        #[test]
+        fn validate_e_ident_returns_invalid_padding_error_if_padding_bytes_are_not_zero() {
+            let mut file = [0u8; 16];
+            file[0] = 0x7f;
+            file[1] = 0x45;
+            file[2] = 0x4c;
+            file[3] = 0x46;
+            file[4] = 2; // valid 64-bit class
+            file[5] = 1; // valid endianness
+            file[6] = 1; // valid ELF version
+            file[9] = 1; // padding should be zero, but here it's 1
+
+            let result = ElfHeaderValidator::new(&file).validate_e_ident();
+
+            assert_err_variant!(
+                result,
+                ElfError {
+                    offset: 9,
+                    field: "EI_PAD",
+                    ..
+                }
+            )
+        }
+
+        #[test]
+        fn validate_e_ident_only_warns_about_the_padding_when_lenient() {
+            let mut file = [0u8; 16];
+            file[0..4].copy_from_slice(&[0x7f, 0x45, 0x4c, 0x46]);
+            file[4] = 2;
+            file[5] = 1;
+            file[6] = 1;
+            file[12] = 0xaa; // garbage in the padding
+
+            let mut validator = ElfHeaderValidator::lenient(&file);
+
+            assert!(validator.validate_e_ident().is_ok());
+            assert!(matches!(
+                validator.into_warnings().as_slice(),
+                [ElfError {
+                    offset: 12,
+                    field: "EI_PAD",
+                    ..
+                }]
+            ));
+        }
+
+        #[test]
+        fn validate_e_ident_returns_ok_if_all_bytes_are_valid() {
+            let mut file = [0u8; 16];
+            file[0] = 0x7f;
+            file[1] = 0x45;
+            file[2] = 0x4c;
+            file[3] = 0x46;
+            file[4] = 2; // valid 64-bit
+            file[5] = 1; // valid endianness
+            file[6] = 1; // valid version
+            file[7] = 0; // OS ABI
+            file[8] = 0; // ABI Version
+            // padding already 0 by default
+
+            let result = ElfHeaderValidator::new(&file).validate_e_ident();
+
+            assert!(result.is_ok())
+        }
+    }
+
+    mod validate_e_type {
+        use super::super::ElfHeaderValidator;
+        use crate::elf::error::{ElfError, ElfErrorKind};
+
+        fn create_valid_file() -> Box<[u8]> {
+            let mut file = [0u8; 52];
+            file[0..4].copy_from_slice(&[0x7f, 0x45, 0x4c, 0x46]);
+            file[5] = 2;
+            file[4] = 2;
+            file[6] = 1;
+
+            Box::new(file)
+        }
+
+        #[test]
+        fn validate_e_type_returns_invalid_elf_file_err_when_endianness_is_invalid() {
+            let mut file = create_valid_file();
+            file[5] = 3;
+
+            let result = ElfHeaderValidator::new(&file).validate_e_type();
+
+            assert_err_variant!(
+                result,
+                ElfError {
+                    field: "EI_DATA",
+                    kind: ElfErrorKind::InvalidValue { actual: 3, .. },
+                    ..
+                }
+            )
+        }
+
+        #[test]
+        fn validate_e_type_returns_invalid_e_type_size_when_file_size_is_less_than_18() {
+            let file = [0u8; 17];
+
+            let result = ElfHeaderValidator::new(&file).validate_e_type();
+
+            assert_err_variant!(
+                result,
+                ElfError {
+                    field: "e_type",
+                    kind: ElfErrorKind::Truncated { .. },
+                    ..
+                }
+            )
+        }
+
+        #[test]
+        fn validate_e_type_returns_invalid_e_type_value_when_value_is_invalid() {
+            let mut file = create_valid_file();
+            file[16..18].copy_from_slice(&[0x00, 0x05]);
+
+            let result = ElfHeaderValidator::new(&file).validate_e_type();
+
+            assert_err_variant!(
+                result,
+                ElfError {
+                    field: "e_type",
+                    kind: ElfErrorKind::InvalidValue { actual: 5, .. },
+                    ..
+                }
+            )
+        }
+
+        #[test]
+        fn validate_e_type_returns_ok_for_valid_e_type() {
+            let mut file = create_valid_file();
+            file[5] = 1; // little endiann
+            file[16..18].copy_from_slice(&[0x01, 0x00]);
+            let result = ElfHeaderValidator::new(&file).validate_e_type();
+
+            assert!(result.is_ok());
+        }
+
+        #[test]
+        fn validate_e_type_return_bytes_accordding_to_the_le_of_file() {
+            let mut file = create_valid_file();
+            file[5] = 1; // LE
+            file[16..18].copy_from_slice(&[0x01, 0x00]); // one in LE
+
+            let result = ElfHeaderValidator::new(&file).validate_e_type().unwrap();
+
+            assert!(result[0] == 0x01 && result[1] == 0x00);
+        }
+
+        #[test]
+        fn validate_e_type_returns_bytes_according_to_the_be_of_file() {
+            let mut file = create_valid_file();
+            file[5] = 2; // BE
+            file[16..18].copy_from_slice(&[0x00, 0x01]); // one in BE
+
+            let result = ElfHeaderValidator::new(&file).validate_e_type().unwrap();
+
+            assert!(result[0] == 0x00 && result[1] == 0x01);
+        }
+    }
+
+    mod validate_e_machine {
+        use super::super::ElfHeaderValidator;
+        use crate::elf::error::{ElfError, ElfErrorKind};
+
+        fn create_valid_file() -> Box<[u8]> {
+            let mut file = [0u8; 52];
+            file[0..4].copy_from_slice(&[0x7f, 0x45, 0x4c, 0x46]);
+            file[4] = 2; // 64-bit
+            file[5] = 2; // BE
+            file[6] = 1; // version
+            file[16..18].copy_from_slice(&[0x01, 0x00]);
+
+            Box::new(file)
+        }
+
+        #[test]
+        fn validate_e_machine_returns_invalid_e_machine_size_when_file_size_is_less_than_20() {
+            let file = [0u8; 19];
+
+            let result = ElfHeaderValidator::new(&file).validate_e_machine();
+
+            assert_err_variant!(
+                result,
+                ElfError {
+                    field: "e_machine",
+                    kind: ElfErrorKind::Truncated { .. },
+                    ..
+                }
+            )
+        }
+
+        #[test]
+        fn validate_e_machine_returns_invalid_endianness_error_when_endianness_is_invalid() {
+            let mut file = create_valid_file();
+            file[5] = 3; // invalid endianness
+
+            let result = ElfHeaderValidator::new(&file).validate_e_machine();
+
+            assert_err_variant!(
+                result,
+                ElfError {
+                    field: "EI_DATA",
+                    kind: ElfErrorKind::InvalidValue { actual: 3, .. },
+                    ..
+                }
+            )
+        }
+
+        #[test]
+        fn validate_e_machine_returns_ok_for_known_machine_types() {
+            let mut file = create_valid_file();
+
+            // test some know machine
+            let machine_types = [
+                0x00, // no one specific. This is interessant, cause our bin may describe no spec for atch
+                0x02, // SPARC
+                0x03, // x86
+                0x08, // MIPS
+                0x14, // PowerPC
+                0x28, // ARM
+                0x2A, // SuperH
+                0x32, // IA-64
+                0x3E, // x86-64
+                0xB7, // AArch64
+                0xF3, // RISC-V
+            ];
+
+            for machine_type in machine_types {
+                file[18..20].copy_from_slice(&[0x00, machine_type]);
+                let result = ElfHeaderValidator::new(&file).validate_e_machine();
+                assert!(result.is_ok());
+            }
+        }
+
+        #[test]
+        fn validate_e_machine_returns_bytes_according_to_le_endianness() {
+            let mut file = create_valid_file();
+            file[5] = 1; // LE
+            file[18..20].copy_from_slice(&[0x3E, 0x00]); // x86-64 in LE
+
+            let result = ElfHeaderValidator::new(&file).validate_e_machine().unwrap();
+
+            assert!(result[0] == 0x3E && result[1] == 0x00);
+        }
+
+        #[test]
+        fn validate_e_machine_returns_bytes_according_to_be_endianness() {
+            let mut file = create_valid_file();
+            file[5] = 2; // BE
+            file[18..20].copy_from_slice(&[0x00, 0x3E]); // x86-64 in BE
+
+            let result = ElfHeaderValidator::new(&file).validate_e_machine().unwrap();
+
+            assert!(result[0] == 0x00 && result[1] == 0x3E);
+        }
+    }
+}
@@ -0,0 +1,664 @@
+use crate::elf::def::elf64fields::Elf64_Versym;
+use crate::elf::def::elf64strc::{Elf64_Verdaux, Elf64_Verdef, Elf64_Vernaux, Elf64_Verneed};
+use crate::elf::def::elfconsts::*;
+use crate::elf::error::ElfError;
+use crate::elf::section::{Elf64Section, section_data};
+use crate::elf::strtab::get_string;
+use crate::elf::symbol::Elf64Symbol;
+use crate::utils::endianess::EndianRead;
+use crate::utils::parser::EndianParseable;
+use std::borrow::Cow;
+
+// symbol versioning is a GNU extension: .gnu.version (SHT_GNU_versym) has a
+// version index for each symbol of .dynsym, .gnu.version_d (SHT_GNU_verdef)
+// has the versions that the file defines and .gnu.version_r
+// (SHT_GNU_verneed) the ones that it needs from its libraries, with the
+// index that .gnu.version uses for them. The structures are the same in
+// both classes
+
+impl EndianParseable for Elf64_Verdef {
+    fn parse_endian(bytes: &[u8], is_little_endian: bool) -> Result<Self, ElfError> {
+        // offset: 0x0 -> 0x14 (relative to the start of the entry)
+        if bytes.len() < 20 {
+            return Err(ElfError::truncated(0, "Elf64_Verdef", 20, bytes.len()));
+        }
+
+        Ok(Elf64_Verdef {
+            // offset: 0x0 -> 0x1, always VER_DEF_CURRENT
+            vd_version: u16::read_from(&bytes[0..2], is_little_endian),
+            // offset: 0x2 -> 0x3, VER_FLG_BASE for the definition of the file itself
+            vd_flags: u16::read_from(&bytes[2..4], is_little_endian),
+            // offset: 0x4 -> 0x5, the index used by .gnu.version
+            vd_ndx: u16::read_from(&bytes[4..6], is_little_endian),
+            // offset: 0x6 -> 0x7, the number of Verdaux entries
+            vd_cnt: u16::read_from(&bytes[6..8], is_little_endian),
+            // offset: 0x8 -> 0xB, the ELF hash of the name
+            vd_hash: u32::read_from(&bytes[8..12], is_little_endian),
+            // offset: 0xC -> 0xF, from the start of this entry
+            vd_aux: u32::read_from(&bytes[12..16], is_little_endian),
+            // offset: 0x10 -> 0x13, from the start of this entry, 0 for the last one
+            vd_next: u32::read_from(&bytes[16..20], is_little_endian),
+        })
+    }
+}
+
+impl EndianParseable for Elf64_Verdaux {
+    fn parse_endian(bytes: &[u8], is_little_endian: bool) -> Result<Self, ElfError> {
+        // offset: 0x0 -> 0x8 (relative to the start of the entry)
+        if bytes.len() < 8 {
+            return Err(ElfError::truncated(0, "Elf64_Verdaux", 8, bytes.len()));
+        }
+
+        Ok(Elf64_Verdaux {
+            // offset: 0x0 -> 0x3, in the string table of sh_link
+            vda_name: u32::read_from(&bytes[0..4], is_little_endian),
+            // offset: 0x4 -> 0x7, from the start of this entry
+            vda_next: u32::read_from(&bytes[4..8], is_little_endian),
+        })
+    }
+}
+
+impl EndianParseable for Elf64_Verneed {
+    fn parse_endian(bytes: &[u8], is_little_endian: bool) -> Result<Self, ElfError> {
+        // offset: 0x0 -> 0x10 (relative to the start of the entry)
+        if bytes.len() < 16 {
+            return Err(ElfError::truncated(0, "Elf64_Verneed", 16, bytes.len()));
+        }
+
+        Ok(Elf64_Verneed {
+            // offset: 0x0 -> 0x1, always VER_NEED_CURRENT
+            vn_version: u16::read_from(&bytes[0..2], is_little_endian),
+            // offset: 0x2 -> 0x3, the number of Vernaux entries
+            vn_cnt: u16::read_from(&bytes[2..4], is_little_endian),
+            // offset: 0x4 -> 0x7, the library (its DT_NEEDED name)
+            vn_file: u32::read_from(&bytes[4..8], is_little_endian),
+            // offset: 0x8 -> 0xB, from the start of this entry
+            vn_aux: u32::read_from(&bytes[8..12], is_little_endian),
+            // offset: 0xC -> 0xF, from the start of this entry, 0 for the last one
+            vn_next: u32::read_from(&bytes[12..16], is_little_endian),
+        })
+    }
+}
+
+impl EndianParseable for Elf64_Vernaux {
+    fn parse_endian(bytes: &[u8], is_little_endian: bool) -> Result<Self, ElfError> {
+        // offset: 0x0 -> 0x10 (relative to the start of the entry)
+        if bytes.len() < 16 {
+            return Err(ElfError::truncated(0, "Elf64_Vernaux", 16, bytes.len()));
+        }
+
+        Ok(Elf64_Vernaux {
+            // offset: 0x0 -> 0x3, the ELF hash of the name
+            vna_hash: u32::read_from(&bytes[0..4], is_little_endian),
+            // offset: 0x4 -> 0x5, VER_FLG_WEAK when the version is optional
+            vna_flags: u16::read_from(&bytes[4..6], is_little_endian),
+            // offset: 0x6 -> 0x7, the index used by .gnu.version
+            vna_other: u16::read_from(&bytes[6..8], is_little_endian),
+            // offset: 0x8 -> 0xB, in the string table of sh_link
+            vna_name: u32::read_from(&bytes[8..12], is_little_endian),
+            // offset: 0xC -> 0xF, from the start of this entry
+            vna_next: u32::read_from(&bytes[12..16], is_little_endian),
+        })
+    }
+}
+
+// a version defined by the file, with its offset in the section
+#[derive(Debug, Clone)]
+pub struct Elf64VersionDefinition {
+    pub offset: u64,
+    pub verdef: Elf64_Verdef,
+    // the first one is the name of the version, the others the names of
+    // the versions that it inherits from
+    pub names: Vec<Elf64VersionName>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Elf64VersionName {
+    pub offset: u64,
+    pub verdaux: Elf64_Verdaux,
+    // None when vda_name points outside of the string table
+    pub name: Option<String>,
+}
+
+impl Elf64VersionDefinition {
+    pub fn name(&self) -> Option<&str> {
+        self.names.first().and_then(|name| name.name.as_deref())
+    }
+}
+
+// the versions needed from one library, with its offset in the section
+#[derive(Debug, Clone)]
+pub struct Elf64VersionNeed {
+    pub offset: u64,
+    pub verneed: Elf64_Verneed,
+    // None when vn_file points outside of the string table
+    pub file: Option<String>,
+    pub versions: Vec<Elf64NeededVersion>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Elf64NeededVersion {
+    pub offset: u64,
+    pub vernaux: Elf64_Vernaux,
+    pub name: Option<String>,
+}
+
+// the three sections together, which is what the version of a symbol needs.
+// The sections that the file does not have are left empty
+#[derive(Debug, Clone, Default)]
+pub struct Elf64SymbolVersions {
+    pub versym: Vec<Elf64_Versym>,
+    pub definitions: Vec<Elf64VersionDefinition>,
+    pub needs: Vec<Elf64VersionNeed>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolVersion {
+    pub name: String,
+    // the value in .gnu.version, without VERSYM_HIDDEN
+    pub index: u16,
+    pub kind: VersionKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VersionKind {
+    // defined by the file, and the one that the linker picks for a symbol
+    // without a version (foo@@VERS_2)
+    Default,
+    // defined by the file, but only for who asks for the version (foo@VERS_1)
+    Hidden,
+    // from one of the libraries (memcpy@GLIBC_2.14)
+    Needed,
+}
+
+impl Elf64SymbolVersions {
+    // the version defined with the index
+    pub fn definition(&self, index: u16) -> Option<&Elf64VersionDefinition> {
+        self.definitions
+            .iter()
+            .find(|definition| definition.verdef.vd_ndx == index)
+    }
+
+    // the version needed with the index
+    pub fn needed(&self, index: u16) -> Option<&Elf64NeededVersion> {
+        self.needs
+            .iter()
+            .flat_map(|need| &need.versions)
+            .find(|version| version.vernaux.vna_other == index)
+    }
+
+    // the definition or the needed version of a value of .gnu.version. The
+    // defined symbols look at the definitions first, and fall back to the
+    // needed versions for the ones copied from a library (to .dynbss or to
+    // .data.rel.ro), that are defined here but versioned there. The
+    // undefined symbols only look at the needed versions
+    pub fn lookup(&self, value: Elf64_Versym, is_defined: bool) -> Option<VersionEntry<'_>> {
+        let number = (value as u32 & VERSYM_VERSION) as u16;
+
+        let definition = self
+            .definition(number)
+            .filter(|_| is_defined && value as u32 != VERSYM_HIDDEN | VER_NDX_GLOBAL);
+        match definition {
+            Some(definition) => Some(VersionEntry::Definition(definition)),
+            None => self.needed(number).map(VersionEntry::Needed),
+        }
+    }
+
+    // the version of the symbol at index of .dynsym, with the same rules of
+    // binutils: None for the local and unversioned symbols, for the ones of
+    // the base version (the file itself) and for the symbols that only name
+    // a version (an ABS symbol called like it)
+    pub fn symbol_version(&self, index: usize, symbol: &Elf64Symbol) -> Option<SymbolVersion> {
+        let value = *self.versym.get(index)?;
+        if value as u32 == VER_NDX_LOCAL {
+            return None;
+        }
+
+        let number = (value as u32 & VERSYM_VERSION) as u16;
+        let is_defined = symbol.sym.st_shndx as u32 != SHN_UNDEF;
+
+        if let VersionEntry::Definition(definition) = self.lookup(value, is_defined)? {
+            if number as u32 == VER_NDX_GLOBAL && definition.verdef.vd_flags as u32 == VER_FLG_BASE
+            {
+                return None;
+            }
+
+            let name = definition.names.first()?;
+            if name.verdaux.vda_name != symbol.sym.st_name {
+                let kind = if value as u32 & VERSYM_HIDDEN != 0 {
+                    VersionKind::Hidden
+                } else {
+                    VersionKind::Default
+                };
+
+                return Some(SymbolVersion {
+                    name: name.name.clone()?,
+                    index: number,
+                    kind,
+                });
+            }
+        }
+
+        let needed = self.needed(number)?;
+        Some(SymbolVersion {
+            name: needed.name.clone()?,
+            index: number,
+            kind: VersionKind::Needed,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum VersionEntry<'a> {
+    Definition(&'a Elf64VersionDefinition),
+    Needed(&'a Elf64NeededVersion),
+}
+
+impl<'a> VersionEntry<'a> {
+    // None when the name is not in the string table
+    pub fn name(self) -> Option<&'a str> {
+        match self {
+            VersionEntry::Definition(definition) => definition.name(),
+            VersionEntry::Needed(version) => version.name.as_deref(),
+        }
+    }
+}
+
+// the version index of each symbol, in the same order of the symbol table
+pub fn parse_version_symbols(
+    bytes: &[u8],
+    section: &Elf64Section,
+    is_little_endian: bool,
+) -> Result<Vec<Elf64_Versym>, ElfError> {
+    let data = version_data(bytes, section, "Elf64_Versym")?;
+
+    Ok(data
+        .chunks_exact(2)
+        .map(|entry| u16::read_from(entry, is_little_endian))
+        .collect())
+}
+
+// the definitions are a list of Elf*_Verdef (sh_info of them), each with
+// its own list of Elf*_Verdaux. Both lists are linked by offsets, and a 0
+// ends them early
+pub fn parse_version_definitions(
+    bytes: &[u8],
+    sections: &[Elf64Section],
+    section: &Elf64Section,
+    is_little_endian: bool,
+) -> Result<Vec<Elf64VersionDefinition>, ElfError> {
+    let data = version_data(bytes, section, "Elf64_Verdef")?;
+    let strtab = version_strings(bytes, sections, section, "Elf64_Verdef")?;
+
+    let mut definitions = Vec::new();
+    let mut offset = 0u64;
+
+    for _ in 0..section.header.sh_info {
+        let verdef: Elf64_Verdef = parse_entry(data, section, offset, is_little_endian)?;
+
+        let mut names = Vec::new();
+        let mut aux_offset = offset + verdef.vd_aux as u64;
+        for _ in 0..verdef.vd_cnt {
+            let verdaux: Elf64_Verdaux = parse_entry(data, section, aux_offset, is_little_endian)?;
+            let name = get_string(strtab, verdaux.vda_name as usize).map(Cow::into_owned);
+            names.push(Elf64VersionName {
+                offset: aux_offset,
+                verdaux,
+                name,
+            });
+
+            if verdaux.vda_next == 0 {
+                break;
+            }
+            aux_offset += verdaux.vda_next as u64;
+        }
+
+        definitions.push(Elf64VersionDefinition {
+            offset,
+            verdef,
+            names,
+        });
+
+        if verdef.vd_next == 0 {
+            break;
+        }
+        offset += verdef.vd_next as u64;
+    }
+
+    Ok(definitions)
+}
+
+// the same layout of the definitions, with Elf*_Verneed and Elf*_Vernaux
+pub fn parse_version_needs(
+    bytes: &[u8],
+    sections: &[Elf64Section],
+    section: &Elf64Section,
+    is_little_endian: bool,
+) -> Result<Vec<Elf64VersionNeed>, ElfError> {
+    let data = version_data(bytes, section, "Elf64_Verneed")?;
+    let strtab = version_strings(bytes, sections, section, "Elf64_Verneed")?;
+
+    let mut needs = Vec::new();
+    let mut offset = 0u64;
+
+    for _ in 0..section.header.sh_info {
+        let verneed: Elf64_Verneed = parse_entry(data, section, offset, is_little_endian)?;
+        let file = get_string(strtab, verneed.vn_file as usize).map(Cow::into_owned);
+
+        let mut versions = Vec::new();
+        let mut aux_offset = offset + verneed.vn_aux as u64;
+        for _ in 0..verneed.vn_cnt {
+            let vernaux: Elf64_Vernaux = parse_entry(data, section, aux_offset, is_little_endian)?;
+            let name = get_string(strtab, vernaux.vna_name as usize).map(Cow::into_owned);
+            versions.push(Elf64NeededVersion {
+                offset: aux_offset,
+                vernaux,
+                name,
+            });
+
+            if vernaux.vna_next == 0 {
+                break;
+            }
+            aux_offset += vernaux.vna_next as u64;
+        }
+
+        needs.push(Elf64VersionNeed {
+            offset,
+            verneed,
+            file,
+            versions,
+        });
+
+        if verneed.vn_next == 0 {
+            break;
+        }
+        offset += verneed.vn_next as u64;
+    }
+
+    Ok(needs)
+}
+
+#[allow(non_upper_case_globals)]
+pub fn is_version_section(section: &Elf64Section) -> bool {
+    matches!(
+        section.header.sh_type,
+        SHT_GNU_versym | SHT_GNU_verdef | SHT_GNU_verneed
+    )
+}
+
+fn version_data<'a>(
+    bytes: &'a [u8],
+    section: &Elf64Section,
+    structure: &'static str,
+) -> Result<&'a [u8], ElfError> {
+    let shdr = &section.header;
+
+    section_data(bytes, shdr).ok_or_else(|| {
+        ElfError::out_of_bounds(
+            shdr.sh_offset,
+            structure,
+            "sh_offset",
+            (shdr.sh_offset, shdr.sh_size),
+            bytes.len(),
+        )
+    })
+}
+
+// the names are in the string table of sh_link (.dynstr)
+fn version_strings<'a>(
+    bytes: &'a [u8],
+    sections: &[Elf64Section],
+    section: &Elf64Section,
+    structure: &'static str,
+) -> Result<&'a [u8], ElfError> {
+    let shdr = &section.header;
+
+    sections
+        .get(shdr.sh_link as usize)
+        .and_then(|strtab| section_data(bytes, &strtab.header))
+        .ok_or_else(|| {
+            ElfError::invalid_value(
+                shdr.sh_offset,
+                structure,
+                "sh_link",
+                "the index of a string table in the file",
+                shdr.sh_link,
+            )
+        })
+}
+
+// an entry at offset of the section, with the errors at its place in the file
+fn parse_entry<T: EndianParseable>(
+    data: &[u8],
+    section: &Elf64Section,
+    offset: u64,
+    is_little_endian: bool,
+) -> Result<T, ElfError> {
+    let entry = usize::try_from(offset)
+        .ok()
+        .and_then(|offset| data.get(offset..))
+        .unwrap_or_default();
+
+    T::parse_endian(entry, is_little_endian)
+        .map_err(|err| err.at(section.header.sh_offset.wrapping_add(offset)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Elf64SymbolVersions, SymbolVersion, VersionEntry, VersionKind, parse_version_definitions,
+        parse_version_needs, parse_version_symbols,
+    };
+    use crate::elf::def::elf64strc::{Elf64_Shdr, Elf64_Sym};
+    use crate::elf::error::{ElfError, ElfErrorKind};
+    use crate::elf::section::Elf64Section;
+    use crate::elf::symbol::Elf64Symbol;
+    use crate::test_utils::create_section;
+
+    fn create_symbol(st_name: u32, st_shndx: u16) -> Elf64Symbol {
+        Elf64Symbol {
+            sym: Elf64_Sym {
+                st_name,
+                st_info: 0x12, // GLOBAL FUNC
+                st_other: 0,
+                st_shndx,
+                st_value: 0,
+                st_size: 0,
+            },
+            name: None,
+            xindex: None,
+            version: None,
+        }
+    }
+
+    // a LE library "libv.so" that defines VERS_1 and VERS_2 (child of
+    // VERS_1) and needs GLIBC_2.2.5 from libc.so.6, with .gnu.version for
+    // 4 symbols
+    fn create_file() -> (Vec<u8>, Vec<Elf64Section>) {
+        let strtab = b"\0libv.so\0VERS_1\0VERS_2\0libc.so.6\0GLIBC_2.2.5\0";
+        let mut bytes = strtab.to_vec();
+
+        let versym_offset = bytes.len() as u64;
+        for value in [0u16, 2, 0x8003, 4] {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+
+        // vd_version, vd_flags, vd_ndx, vd_cnt, vd_aux, vd_next and the names
+        let verdef_offset = bytes.len() as u64;
+        let definitions: [(u16, u16, u16, u32, &[u32]); 3] = [
+            (1, 1, 1, 28, &[1]),
+            (1, 0, 2, 28, &[9]),
+            (1, 0, 3, 0, &[16, 9]),
+        ];
+        for (version, flags, index, next, names) in definitions {
+            bytes.extend_from_slice(&version.to_le_bytes());
+            bytes.extend_from_slice(&flags.to_le_bytes());
+            bytes.extend_from_slice(&index.to_le_bytes());
+            bytes.extend_from_slice(&(names.len() as u16).to_le_bytes());
+            bytes.extend_from_slice(&0u32.to_le_bytes());
+            bytes.extend_from_slice(&20u32.to_le_bytes());
+            bytes.extend_from_slice(&next.to_le_bytes());
+            for (i, name) in names.iter().enumerate() {
+                let next = if i + 1 < names.len() { 8u32 } else { 0 };
+                bytes.extend_from_slice(&name.to_le_bytes());
+                bytes.extend_from_slice(&next.to_le_bytes());
+            }
+        }
+        let verdef_size = bytes.len() as u64 - verdef_offset;
+
+        let verneed_offset = bytes.len() as u64;
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.extend_from_slice(&23u32.to_le_bytes());
+        bytes.extend_from_slice(&16u32.to_le_bytes());
+        bytes.extend_from_slice(&0u32.to_le_bytes());
+        bytes.extend_from_slice(&0x09691a75u32.to_le_bytes());
+        bytes.extend_from_slice(&0u16.to_le_bytes());
+        bytes.extend_from_slice(&4u16.to_le_bytes());
+        bytes.extend_from_slice(&33u32.to_le_bytes());
+        bytes.extend_from_slice(&0u32.to_le_bytes());
+
+        let section = |sh_type, sh_offset, sh_size, sh_link, sh_info| {
+            let section = create_section("", sh_type);
+            Elf64Section {
+                header: Elf64_Shdr {
+                    sh_offset,
+                    sh_size,
+                    sh_link,
+                    sh_info,
+                    ..section.header
+                },
+                ..section
+            }
+        };
+        let sections = vec![
+            create_section("", 0),
+            section(3, 0, strtab.len() as u64, 0, 0),
+            section(0x6fffffff, versym_offset, 8, 0, 0),
+            section(0x6ffffffd, verdef_offset, verdef_size, 1, 3),
+            section(0x6ffffffe, verneed_offset, 32, 1, 1),
+        ];
+
+        (bytes, sections)
+    }
+
+    fn create_versions() -> Elf64SymbolVersions {
+        let (bytes, sections) = create_file();
+
+        Elf64SymbolVersions {
+            versym: parse_version_symbols(&bytes, &sections[2], true).unwrap(),
+            definitions: parse_version_definitions(&bytes, &sections, &sections[3], true).unwrap(),
+            needs: parse_version_needs(&bytes, &sections, &sections[4], true).unwrap(),
+        }
+    }
+
+    #[test]
+    fn parse_version_definitions_follows_the_entries_and_their_names() {
+        let versions = create_versions();
+
+        assert_eq!(versions.versym, [0, 2, 0x8003, 4]);
+
+        let definitions = &versions.definitions;
+        assert_eq!(definitions.len(), 3);
+        assert_eq!(definitions[0].name(), Some("libv.so"));
+        assert_eq!(definitions[0].verdef.vd_flags, 1);
+        assert_eq!(definitions[1].offset, 28);
+        assert_eq!(definitions[1].name(), Some("VERS_1"));
+        assert_eq!(definitions[2].offset, 56);
+        assert_eq!(definitions[2].verdef.vd_ndx, 3);
+
+        let names: Vec<_> = definitions[2]
+            .names
+            .iter()
+            .map(|name| name.name.as_deref())
+            .collect();
+        assert_eq!(names, [Some("VERS_2"), Some("VERS_1")]);
+        assert_eq!(definitions[2].names[1].offset, 84);
+    }
+
+    #[test]
+    fn parse_version_needs_reads_the_files_and_their_versions() {
+        let versions = create_versions();
+
+        assert_eq!(versions.needs.len(), 1);
+        assert_eq!(versions.needs[0].file.as_deref(), Some("libc.so.6"));
+        assert_eq!(versions.needs[0].versions[0].offset, 16);
+        assert_eq!(
+            versions.needs[0].versions[0].name.as_deref(),
+            Some("GLIBC_2.2.5")
+        );
+        assert_eq!(versions.needs[0].versions[0].vernaux.vna_other, 4);
+    }
+
+    #[test]
+    fn symbol_version_uses_the_definitions_for_the_defined_symbols() {
+        let versions = create_versions();
+
+        assert_eq!(versions.symbol_version(0, &create_symbol(0, 0)), None);
+        assert_eq!(
+            versions.symbol_version(1, &create_symbol(40, 11)),
+            Some(SymbolVersion {
+                name: "VERS_1".to_string(),
+                index: 2,
+                kind: VersionKind::Default,
+            })
+        );
+        assert_eq!(
+            versions
+                .symbol_version(2, &create_symbol(40, 11))
+                .unwrap()
+                .kind,
+            VersionKind::Hidden
+        );
+        assert_eq!(
+            versions.symbol_version(3, &create_symbol(40, 0)),
+            Some(SymbolVersion {
+                name: "GLIBC_2.2.5".to_string(),
+                index: 4,
+                kind: VersionKind::Needed,
+            })
+        );
+        // the symbol that only names VERS_1
+        assert_eq!(versions.symbol_version(1, &create_symbol(9, 0xfff1)), None);
+    }
+
+    #[test]
+    fn lookup_falls_back_to_the_needed_versions_for_the_defined_symbols() {
+        let versions = create_versions();
+
+        // a copy relocated object in .data.rel.ro (a PROGBITS section), that
+        // is defined here but has a version of libc.so.6
+        let entry = versions.lookup(4, true);
+        assert!(matches!(entry, Some(VersionEntry::Needed(_))));
+        assert_eq!(entry.unwrap().name(), Some("GLIBC_2.2.5"));
+        assert_eq!(
+            versions.symbol_version(3, &create_symbol(40, 11)),
+            Some(SymbolVersion {
+                name: "GLIBC_2.2.5".to_string(),
+                index: 4,
+                kind: VersionKind::Needed,
+            })
+        );
+
+        // the undefined ones never look at the definitions
+        assert!(versions.lookup(2, false).is_none());
+    }
+
+    #[test]
+    fn parse_version_definitions_returns_truncated_err_for_entries_past_the_end() {
+        let (bytes, mut sections) = create_file();
+        sections[3].header.sh_size = 30;
+
+        let err = parse_version_definitions(&bytes, &sections, &sections[3], true).unwrap_err();
+
+        assert!(matches!(
+            err,
+            ElfError {
+                structure: "Elf64_Verdef",
+                kind: ElfErrorKind::Truncated { actual: 2, .. },
+                ..
+            }
+        ));
+        assert_eq!(err.offset, sections[3].header.sh_offset + 28);
+    }
+}
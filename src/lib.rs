@@ -0,0 +1,11 @@
+// the parsing side of readelf, as a library. ElfFile is the entry point, and
+// the modules below it have the raw structs (elf::def) and the parsers of
+// each table, for who needs more than what ElfFile gives
+pub mod elf;
+pub mod utils;
+#[cfg(test)]
+mod test_utils;
+
+pub use elf::class::ElfClass;
+pub use elf::error::{ElfError, ElfErrorKind};
+pub use elf::file::{ElfFile, ParseMode};
@@ -1,9 +1,631 @@
-mod config;
-mod elf;
-mod utils;
-
-fn main() {
-    // this configs the bindgen lib to enable the 
-    // (re)use of the structs defined in /usr/include/elf.h
-    config::init();
-}
\ No newline at end of file
+mod cli;
+mod output;
+#[cfg(test)]
+mod test_utils;
+
+// the output modules use the parsers as crate::elf and crate::utils
+use readelf::{ElfError, ElfFile, ParseMode, elf, utils};
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::io::{self, Write};
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let options = match cli::parse_args(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("readelf: Error: {err}");
+            eprintln!("{}", cli::USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if options.help {
+        println!("{}", cli::USAGE);
+        return ExitCode::SUCCESS;
+    }
+
+    if options.version {
+        println!("readelf {}", env!("CARGO_PKG_VERSION"));
+        return ExitCode::SUCCESS;
+    }
+
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    let mut exit_code = ExitCode::SUCCESS;
+
+    for path in &options.files {
+        if let Err(err) = process_path(&mut out, path, &options) {
+            _ = out.flush();
+            eprintln!("readelf: Error: '{path}': {err}");
+            exit_code = ExitCode::FAILURE;
+        }
+    }
+
+    exit_code
+}
+
+// a static archive has each of its members shown as a file of its own,
+// anything else is read as a single ELF file
+fn process_path(
+    out: &mut dyn Write,
+    path: &str,
+    options: &cli::Options,
+) -> Result<(), Box<dyn Error>> {
+    let bytes = std::fs::read(path).map_err(ElfError::from)?;
+    if elf::archive::is_archive(&bytes) {
+        return process_archive(out, path, &bytes, options);
+    }
+
+    // like binutils, this is not what fails the file
+    if options.archive_index {
+        _ = out.flush();
+        eprintln!(
+            "readelf: Error: File {path} is not an archive so its index cannot be displayed."
+        );
+    }
+
+    // like binutils, the file name is only shown when there is more than one
+    // file to look at
+    if options.files.len() > 1 {
+        writeln!(out, "\nFile: {path}")?;
+    }
+
+    if !options.has_object_dump() {
+        return Ok(());
+    }
+
+    let file = ElfFile::from_vec_with(bytes, parse_mode(options))?;
+    process_file(out, path, &file, options)
+}
+
+// -c shows the index before the members, and with nothing else asked for
+// the members are not read at all. A member that is not an ELF file (or is
+// broken) is an error of its own, and the rest of them are still shown
+fn process_archive(
+    out: &mut dyn Write,
+    path: &str,
+    bytes: &[u8],
+    options: &cli::Options,
+) -> Result<(), Box<dyn Error>> {
+    let archive = elf::archive::Archive::parse(bytes)?;
+
+    if options.archive_index {
+        match &archive.index {
+            Some(index) => {
+                _ = out.flush();
+                for warning in &archive.warnings {
+                    eprintln!("readelf: Warning: '{path}': {warning}");
+                }
+                output::archive::print_archive_index(out, path, &archive, index)?;
+            }
+            None => {
+                output::archive::print_no_archive_index(out, path)?;
+                _ = out.flush();
+                eprintln!("readelf: Error: {path}: unable to dump the index as none was found");
+            }
+        }
+    }
+
+    if !options.has_object_dump() {
+        return Ok(());
+    }
+
+    let mode = parse_mode(options);
+    let mut failed = 0;
+
+    for member in &archive.members {
+        let name = output::archive::member_path(path, &archive, member);
+
+        // the members of a thin archive are the files that were added to it,
+        // relative to the archive itself
+        let file = match archive.member_data(member) {
+            Some(data) => ElfFile::parse_with(data, mode),
+            None => {
+                let directory = std::path::Path::new(path).parent().unwrap_or(".".as_ref());
+                ElfFile::open_with(directory.join(&member.name), mode)
+            }
+        };
+
+        let result = file.map_err(Box::from).and_then(|file| {
+            writeln!(out, "\nFile: {name}")?;
+            process_file(out, &name, &file, options)
+        });
+
+        if let Err(err) = result {
+            _ = out.flush();
+            eprintln!("readelf: Error: '{name}': {err}");
+            failed += 1;
+        }
+    }
+
+    if failed != 0 {
+        return Err(format!("{failed} member(s) of the archive could not be shown").into());
+    }
+
+    Ok(())
+}
+
+fn parse_mode(options: &cli::Options) -> ParseMode {
+    if options.lenient {
+        ParseMode::Lenient
+    } else {
+        ParseMode::Strict
+    }
+}
+
+fn process_file(
+    out: &mut dyn Write,
+    path: &str,
+    file: &ElfFile,
+    options: &cli::Options,
+) -> Result<(), Box<dyn Error>> {
+    let bytes = file.bytes();
+    let header = file.header();
+    print_warnings(out, path, file);
+
+    // any EI_OSABI is accepted, the strict mode only warns about the ones
+    // that no one (that we know of) has defined
+    let osabi = header.e_ident[7];
+    if options.strict && elf::osabi::osabi_name(osabi, header.e_machine).is_none() {
+        _ = out.flush();
+        eprintln!("readelf: Warning: '{path}': unknown OS/ABI value: {osabi:#x}");
+    }
+
+    if options.file_header {
+        let is_pie = elf::dynamic::is_pie(bytes, header);
+        // a broken section header table is an error of the dumps that use it
+        let first_section = file.first_section_header().ok().flatten();
+        output::header::print_file_header(out, header, first_section.as_ref(), is_pie)?;
+    }
+
+    // the segment mapping also needs the sections (and their names)
+    let sections = if options.section_headers
+        || options.program_headers
+        || options.dynamic
+        || options.relocs
+        || options.syms
+        || options.dyn_syms
+        || options.notes
+        || options.version_info
+        || options.histogram
+        || !options.dumps.is_empty()
+    {
+        file.sections()?
+    } else {
+        &[]
+    };
+    print_warnings(out, path, file);
+
+    if options.section_headers {
+        // a broken Elf*_Chdr is an error of the dumps of that section
+        let compression: Vec<_> = sections
+            .iter()
+            .map(|section| file.compression_header(section).ok().flatten())
+            .collect();
+        output::sections::print_section_headers(
+            out,
+            header,
+            sections,
+            &compression,
+            options.wide,
+            !options.file_header,
+        )?;
+    }
+
+    // the dynamic section only have virtual addresses, the segments are
+    // needed to find them in the file
+    let segments = if options.program_headers || options.dynamic || options.relocs || options.notes
+    {
+        file.segments()?
+    } else {
+        &[]
+    };
+    print_warnings(out, path, file);
+
+    if options.program_headers {
+        output::segments::print_program_headers(
+            out,
+            bytes,
+            header,
+            segments,
+            sections,
+            options.wide,
+            !options.file_header,
+        )?;
+    }
+
+    if options.dynamic {
+        let dynamic = file.dynamic()?;
+        let strings = file.dynamic_strings()?;
+        let interpreter = file.interpreter()?;
+        print_warnings(out, path, file);
+
+        output::dynamic::print_dynamic_section(
+            out,
+            header,
+            dynamic,
+            strings,
+            interpreter.as_deref(),
+        )?;
+    }
+
+    if options.relocs {
+        let mut found = false;
+
+        for section in file.relocation_sections()? {
+            if section.header.sh_size == 0 {
+                continue;
+            }
+            found = true;
+
+            let relocations = file.relocations(section)?;
+            let symbols = file.relocation_symbols(section)?;
+            print_warnings(out, path, file);
+
+            output::relocations::print_relocation_section(
+                out,
+                header,
+                sections,
+                section,
+                &relocations,
+                symbols.as_deref(),
+                options.wide,
+            )?;
+        }
+
+        if !found {
+            let entries = file
+                .dynamic()?
+                .map(|dynamic| dynamic.entries.as_slice())
+                .unwrap_or_default();
+            output::relocations::print_no_relocations(out, entries)?;
+        }
+    }
+
+    // -s shows both .symtab and .dynsym, --dyn-syms only the dynamic one
+    for table in sections.iter().filter(|section| elf::symbol::is_symbol_table(section)) {
+        let is_dynsym = table.header.sh_type == elf::def::elfconsts::SHT_DYNSYM;
+        if !(options.syms || options.dyn_syms && is_dynsym) {
+            continue;
+        }
+
+        let symbols = file.symbols(table)?;
+        print_warnings(out, path, file);
+        output::symbols::print_symbol_table(out, header, sections, table, &symbols, options.wide)?;
+    }
+
+    if options.histogram {
+        print_histograms(out, path, file, sections)?;
+    }
+
+    if options.version_info {
+        print_version_info(out, path, file, sections)?;
+    }
+
+    // like binutils, a missing index fails the file (after the other dumps),
+    // but a missing name is only a warning
+    let mut missing_index = None;
+    if !options.dumps.is_empty() {
+        missing_index = dump_sections(out, path, file, sections, options)?;
+    }
+
+    if !options.debug_dumps.is_empty() {
+        dump_debug_sections(out, path, file, options)?;
+    }
+
+    if options.notes {
+        let areas = file.note_areas()?;
+
+        // only the build attributes look at the symbols, so a bad .symtab
+        // doesnt stop the notes from being shown
+        let symbols = file.static_symbols().ok().flatten().unwrap_or_default();
+
+        if areas.is_empty() && header.e_type as u32 == elf::def::elfconsts::ET_CORE {
+            output::notes::print_no_notes(out)?;
+        }
+
+        // empty areas are skipped, there is nothing to show about them
+        for area in areas.iter().filter(|area| area.size != 0) {
+            let notes = file.notes(area)?;
+            print_warnings(out, path, file);
+            output::notes::print_notes(out, header, area, &notes, &symbols, options.wide)?;
+        }
+    }
+
+    // the lint goes last, and a file with problems fails like a broken one,
+    // so a script can tell them apart from the fine ones by the exit status
+    if options.lint {
+        let problems = elf::lint::lint(file);
+        print_warnings(out, path, file);
+        output::lint::print_lint(out, &problems)?;
+
+        if !problems.is_empty() {
+            return Err(format!("the lint found {} problem(s)", problems.len()).into());
+        }
+    }
+
+    if let Some(index) = missing_index {
+        return Err(format!("Section {index} was not dumped because it does not exist!").into());
+    }
+
+    Ok(())
+}
+
+// -I, the SysV table before the GNU one, like binutils
+fn print_histograms(
+    out: &mut dyn Write,
+    path: &str,
+    file: &ElfFile,
+    sections: &[elf::section::Elf64Section],
+) -> Result<(), Box<dyn Error>> {
+    if let Some(hash) = file.sysv_hash()? {
+        let (lengths, err) = hash.chain_lengths();
+        print_warnings(out, path, file);
+
+        if let Some(err) = err {
+            _ = out.flush();
+            eprintln!("readelf: Warning: '{path}': {err}");
+        }
+        output::hash::print_histogram(out, None, &lengths)?;
+    }
+
+    if let Some(hash) = file.gnu_hash()? {
+        print_warnings(out, path, file);
+
+        // binutils skips the table without symbols, the longest chain that
+        // it looks for is never found
+        if hash.is_empty() {
+            return Ok(());
+        }
+
+        let name = sections
+            .iter()
+            .find(|section| section.header.sh_type == elf::def::elfconsts::SHT_GNU_HASH)
+            .map(|section| section.name.as_str());
+        output::hash::print_histogram(out, name, &hash.chain_lengths())?;
+    }
+
+    Ok(())
+}
+
+// -V, the version sections in the order of the file
+fn print_version_info(
+    out: &mut dyn Write,
+    path: &str,
+    file: &ElfFile,
+    sections: &[elf::section::Elf64Section],
+) -> Result<(), Box<dyn Error>> {
+    use elf::def::elfconsts::{SHT_GNU_verdef, SHT_GNU_verneed, SHT_GNU_versym};
+
+    let versions = file.symbol_versions()?.unwrap_or_default();
+    let mut found = false;
+
+    for section in sections.iter().filter(|section| elf::version::is_version_section(section)) {
+        found = true;
+        let sh_type = section.header.sh_type;
+
+        if sh_type == SHT_GNU_versym {
+            let versym = file.version_symbols(section)?;
+            // the table that the versions are for, needed to look them up
+            let table = sections
+                .get(section.header.sh_link as usize)
+                .filter(|table| elf::symbol::is_symbol_table(table));
+            let symbols = table.map(|table| file.symbols(table)).transpose()?;
+            print_warnings(out, path, file);
+
+            output::versions::print_version_symbols(
+                out,
+                sections,
+                section,
+                &versym,
+                &versions,
+                symbols.as_deref().unwrap_or_default(),
+            )?;
+        } else if sh_type == SHT_GNU_verdef {
+            let definitions = file.version_definitions(section)?;
+            print_warnings(out, path, file);
+            output::versions::print_version_definitions(out, sections, section, &definitions)?;
+        } else if sh_type == SHT_GNU_verneed {
+            let needs = file.version_needs(section)?;
+            print_warnings(out, path, file);
+            output::versions::print_version_needs(out, sections, section, &needs)?;
+        }
+    }
+
+    if !found {
+        output::versions::print_no_version_info(out)?;
+    }
+
+    Ok(())
+}
+
+// -x, -R and -p. The dumps are shown in the order of the sections (and of the
+// kinds, for the same section), not in the one of the command line, and each
+// one only once. Returns the first index that there is no section for
+fn dump_sections(
+    out: &mut dyn Write,
+    path: &str,
+    file: &ElfFile,
+    sections: &[elf::section::Elf64Section],
+    options: &cli::Options,
+) -> Result<Option<usize>, Box<dyn Error>> {
+    let mut dumps = BTreeSet::new();
+    let mut missing_index = None;
+
+    for dump in &options.dumps {
+        match &dump.section {
+            cli::SectionSelector::Index(index) => {
+                if *index < sections.len() {
+                    dumps.insert((*index, dump.kind));
+                } else if missing_index.is_none() {
+                    missing_index = Some(*index);
+                }
+            }
+            cli::SectionSelector::Name(name) => {
+                let mut found = false;
+                for (index, _) in sections.iter().enumerate().filter(|(_, s)| s.name == *name) {
+                    dumps.insert((index, dump.kind));
+                    found = true;
+                }
+
+                if !found {
+                    _ = out.flush();
+                    eprintln!(
+                        "readelf: Warning: '{path}': Section '{name}' was not dumped because it does not exist"
+                    );
+                }
+            }
+        }
+    }
+
+    for (index, kind) in dumps {
+        let section = &sections[index];
+        if section.header.sh_size == 0 || section.header.sh_type == elf::def::elfconsts::SHT_NOBITS
+        {
+            output::dump::print_no_data(out, &section.name)?;
+            continue;
+        }
+
+        let has_relocations = !file.relocations_for(index)?.is_empty();
+        print_warnings(out, path, file);
+
+        match kind {
+            cli::DumpKind::Relocated => {
+                let (data, skipped) = file.relocated_data(index, options.decompress)?;
+                print_warnings(out, path, file);
+
+                _ = out.flush();
+                for problem in skipped {
+                    eprintln!(
+                        "readelf: Warning: '{path}': skipped a relocation of {}: {problem}",
+                        section.name
+                    );
+                }
+                output::dump::print_hex_dump(
+                    out,
+                    &section.name,
+                    section.header.sh_addr,
+                    &data,
+                    false,
+                )?;
+            }
+            cli::DumpKind::Hex | cli::DumpKind::Strings => {
+                let data = file.section_contents(section, options.decompress)?;
+
+                if kind == cli::DumpKind::Hex {
+                    output::dump::print_hex_dump(
+                        out,
+                        &section.name,
+                        section.header.sh_addr,
+                        &data,
+                        has_relocations,
+                    )?;
+                } else {
+                    output::dump::print_string_dump(out, &section.name, &data, has_relocations)?;
+                }
+            }
+        }
+    }
+
+    Ok(missing_index)
+}
+
+// -w, the DWARF sections in the order of the file, each one with the dumps
+// that were asked for it. A broken unit or table only stops the dump of its
+// own part of the section, and what was wrong with it is a warning
+fn dump_debug_sections(
+    out: &mut dyn Write,
+    path: &str,
+    file: &ElfFile,
+    options: &cli::Options,
+) -> Result<(), Box<dyn Error>> {
+    let dumps = &options.debug_dumps;
+    let dwarf = elf::dwarf::Dwarf::load(file)?;
+    print_warnings(out, path, file);
+
+    _ = out.flush();
+    for (name, problem) in &dwarf.skipped {
+        eprintln!("readelf: Warning: '{path}': skipped a relocation of {name}: {problem}");
+    }
+
+    for section in dwarf.sections.iter().filter(|section| !section.data.is_empty()) {
+        let name = section.name;
+        let mut problems = Vec::new();
+
+        match name {
+            ".debug_abbrev" | ".debug_abbrev.dwo" if dumps.abbrev => {
+                problems = output::dwarf::print_debug_abbrev(out, &dwarf, name)?;
+            }
+            ".debug_info" | ".debug_info.dwo" if dumps.info => {
+                problems = output::dwarf::info::print_debug_info(out, &dwarf, name)?;
+            }
+            ".debug_line" | ".debug_line.dwo" => {
+                if dumps.raw_line {
+                    problems = output::dwarf::line::print_debug_line_raw(out, &dwarf, name)?;
+                }
+                if dumps.decoded_line {
+                    problems.extend(output::dwarf::line::print_debug_line_decoded(
+                        out,
+                        &dwarf,
+                        name,
+                        options.wide,
+                    )?);
+                }
+            }
+            ".debug_str" | ".debug_str.dwo" | ".debug_line_str" if dumps.str => {
+                output::dwarf::print_debug_str(out, &section.file_name, &section.data)?;
+            }
+            ".debug_str_offsets" | ".debug_str_offsets.dwo" if dumps.str => {
+                problems = output::dwarf::print_debug_str_offsets(out, &dwarf, name)?;
+            }
+            ".debug_aranges" if dumps.aranges => {
+                problems = output::dwarf::print_debug_aranges(out, &dwarf)?;
+            }
+            ".debug_ranges" if dumps.ranges => {
+                problems = output::dwarf::lists::print_debug_ranges(out, &dwarf, name)?;
+            }
+            ".debug_rnglists" | ".debug_rnglists.dwo" if dumps.ranges => {
+                problems = output::dwarf::lists::print_debug_rnglists(out, &dwarf, name)?;
+            }
+            ".debug_loc" | ".debug_loc.dwo" | ".debug_loclists" | ".debug_loclists.dwo"
+                if dumps.loc =>
+            {
+                problems = output::dwarf::lists::print_debug_loc(out, &dwarf, name)?;
+            }
+            ".eh_frame" | ".debug_frame" if dumps.frames || dumps.frames_interp => {
+                problems = output::dwarf::frame::print_debug_frames(
+                    out,
+                    &dwarf,
+                    name,
+                    dumps.frames_interp,
+                )?;
+            }
+            ".eh_frame_hdr" if dumps.frames || dumps.frames_interp => {
+                problems = output::dwarf::frame::print_eh_frame_hdr(out, &dwarf)?;
+            }
+            _ => {}
+        }
+
+        _ = out.flush();
+        for problem in problems {
+            eprintln!("readelf: Warning: '{path}': {problem}");
+        }
+    }
+
+    Ok(())
+}
+
+// the problems that the lenient mode went past are shown as soon as they are
+// found, so they end up next to the part of the output that they are about
+fn print_warnings(out: &mut dyn Write, path: &str, file: &ElfFile) {
+    let warnings = file.take_warnings();
+    if warnings.is_empty() {
+        return;
+    }
+
+    _ = out.flush();
+    for warning in warnings {
+        eprintln!("readelf: Warning: '{path}': {warning}");
+    }
+}
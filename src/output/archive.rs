@@ -0,0 +1,114 @@
+use crate::elf::archive::{Archive, ArchiveIndex, ArchiveMember};
+use std::io::{self, Write};
+
+// the name that a member is shown with, "libfoo.a(bar.o)" like binutils, and
+// "libfoo.a[bar.o]" for the thin archives (where it is the path of the file)
+pub fn member_path(path: &str, archive: &Archive, member: &ArchiveMember) -> String {
+    if archive.is_thin {
+        format!("{path}[{}]", member.name)
+    } else {
+        format!("{path}({})", member.name)
+    }
+}
+
+// -c, the symbols of the index grouped by the member that defines them. The
+// size is the one of the names, the offsets are not counted
+pub fn print_archive_index(
+    out: &mut dyn Write,
+    path: &str,
+    archive: &Archive,
+    index: &ArchiveIndex,
+) -> io::Result<()> {
+    let size = if index.strings_size == 0 {
+        "0".to_string()
+    } else {
+        format!("{:#x}", index.strings_size)
+    };
+    writeln!(
+        out,
+        "Index of archive {path}: ({} entries, {size} bytes in the symbol table)",
+        index.offsets.len()
+    )?;
+
+    let mut previous = None;
+    for (name, offset) in index.symbols() {
+        if previous != Some(offset) {
+            previous = Some(offset);
+
+            // an offset that is not a member only has its symbols shown
+            if let Some(member) = archive.member_at(offset) {
+                writeln!(
+                    out,
+                    "Contents of binary {} at offset {offset:#x}",
+                    member_path(path, archive, member)
+                )?;
+            }
+        }
+
+        writeln!(out, "\t{name}")?;
+    }
+
+    Ok(())
+}
+
+pub fn print_no_archive_index(out: &mut dyn Write, path: &str) -> io::Result<()> {
+    writeln!(out, "{path} has no archive index")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::print_archive_index;
+    use crate::elf::archive::Archive;
+
+    fn member(bytes: &mut Vec<u8>, name: &str, data: &[u8]) {
+        let header = format!(
+            "{name:<16}{:<12}{:<6}{:<6}{:<8}{:<10}`\n",
+            0,
+            0,
+            0,
+            644,
+            data.len()
+        );
+        bytes.extend_from_slice(header.as_bytes());
+        bytes.extend_from_slice(data);
+        if !bytes.len().is_multiple_of(2) {
+            bytes.push(b'\n');
+        }
+    }
+
+    #[test]
+    fn print_archive_index_groups_the_symbols_by_member() {
+        let mut bytes = b"!<arch>\n".to_vec();
+        let mut index = 3u32.to_be_bytes().to_vec();
+        // the index is 22 bytes, so the first member is at 0x5a
+        for offset in [0x5au32, 0x5a, 0x9a] {
+            index.extend_from_slice(&offset.to_be_bytes());
+        }
+        index.extend_from_slice(b"a\0b\0c\0");
+        member(&mut bytes, "/", &index);
+        member(&mut bytes, "a.o/", b"one");
+        member(&mut bytes, "b.o/", b"two");
+
+        let archive = Archive::parse(&bytes).unwrap();
+        let mut out = Vec::new();
+
+        print_archive_index(
+            &mut out,
+            "libfoo.a",
+            &archive,
+            archive.index.as_ref().unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "Index of archive libfoo.a: (3 entries, 0x6 bytes in the symbol table)
+Contents of binary libfoo.a(a.o) at offset 0x5a
+\ta
+\tb
+Contents of binary libfoo.a(b.o) at offset 0x9a
+\tc
+"
+        );
+    }
+}
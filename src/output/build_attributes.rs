@@ -0,0 +1,323 @@
+// the GNU build attribute notes (written by the annobin plugin of gcc) keep
+// the attribute itself in the name of the note, as "GA" + a type + the
+// attribute + its value, and only a range of addresses in the descriptor
+use crate::elf::def::elf64strc::Elf64_Ehdr;
+use crate::elf::def::elfconsts::*;
+use crate::elf::note::Elf64Note;
+use crate::elf::symbol::Elf64Symbol;
+use crate::output::{alternate_hex, fit_name};
+use crate::utils::endianess::EndianRead;
+use std::io::{self, Write};
+
+const VALUE_TYPES: &[u32] = &[
+    GNU_BUILD_ATTRIBUTE_TYPE_NUMERIC,
+    GNU_BUILD_ATTRIBUTE_TYPE_STRING,
+    GNU_BUILD_ATTRIBUTE_TYPE_BOOL_TRUE,
+    GNU_BUILD_ATTRIBUTE_TYPE_BOOL_FALSE,
+];
+
+// binutils only looks at the first bytes of the name (the NUL is accepted
+// as a type too, since it uses strchr for that)
+pub fn is_build_attribute(note: &Elf64Note) -> bool {
+    let name = &note.raw_name;
+    let is_type = |byte: Option<&u8>| {
+        byte.is_some_and(|&byte| byte == 0 || VALUE_TYPES.contains(&(byte as u32)))
+    };
+
+    matches!(
+        note.nhdr.n_type,
+        NT_GNU_BUILD_ATTRIBUTE_OPEN | NT_GNU_BUILD_ATTRIBUTE_FUNC
+    ) && ((name.starts_with(b"GA") && is_type(name.get(2))) || is_type(name.first()))
+}
+
+// the attribute takes the place of the owner, in a column of 20 chars (28 in
+// the wide mode), and the value is cut when the name of the attribute
+// already used the whole column
+pub fn build_attribute_owner(note: &Elf64Note, wide: bool) -> String {
+    let name = note.raw_name.as_slice();
+    let mut shown = String::new();
+    let mut left: isize = if wide { 28 } else { 20 };
+    let mut position = 0;
+
+    if name.len() < 2 || name.starts_with(b"GA") && name.len() < 4 {
+        return fit_name("  <corrupt name>", 20, wide);
+    }
+
+    // the "GA" prefix was added in the version 2 of the notes
+    if name.starts_with(b"GA") {
+        shown.push_str("GA");
+        left -= 2;
+        position = 2;
+    }
+
+    let value_type = name[position] as u32;
+    if !VALUE_TYPES.contains(&value_type) {
+        shown.push_str(&fit_name("<unknown name type>", 20, wide));
+        return shown;
+    }
+
+    shown.push(name[position] as char);
+    left -= 1;
+    position += 1;
+
+    let attribute = name[position] as u32;
+    let text = match attribute {
+        GNU_BUILD_ATTRIBUTE_VERSION => Some("<version>".to_string()),
+        GNU_BUILD_ATTRIBUTE_STACK_PROT => Some("<stack prot>".to_string()),
+        GNU_BUILD_ATTRIBUTE_RELRO => Some("<relro>".to_string()),
+        GNU_BUILD_ATTRIBUTE_STACK_SIZE => Some("<stack size>".to_string()),
+        GNU_BUILD_ATTRIBUTE_TOOL => Some("<tool>".to_string()),
+        GNU_BUILD_ATTRIBUTE_ABI => Some("<ABI>".to_string()),
+        GNU_BUILD_ATTRIBUTE_PIC => Some("<PIC>".to_string()),
+        GNU_BUILD_ATTRIBUTE_SHORT_ENUM => Some("<short enum>".to_string()),
+        // any other attribute has its name written as a string, that ends
+        // with a NUL (that is counted, but never shown)
+        0x20..=0x7e => {
+            let rest = &name[position..];
+            let length = c_string(rest).len();
+            let mut used = length as isize + 1;
+            if used > left && !wide {
+                used = left;
+            }
+
+            let used = used as usize;
+            shown.push_str(&String::from_utf8_lossy(&rest[..used.min(length)]));
+            shown.push(':');
+            left -= used as isize;
+            position += used;
+            None
+        }
+        _ => Some(format!("<unknown:_{}>", name[position] as i8)),
+    };
+
+    if let Some(text) = text {
+        left -= text.len() as isize;
+        shown.push_str(&text);
+        position += 1;
+    }
+
+    if position > name.len() || left < 1 && !wide {
+        return shown;
+    }
+
+    let value = &name[position..];
+    let width = left.max(0) as usize;
+
+    let value = match value_type {
+        GNU_BUILD_ATTRIBUTE_TYPE_NUMERIC => {
+            let number = numeric_value(value);
+
+            match numeric_name(attribute, number) {
+                Some(decoded) => fit_name(decoded, width, wide),
+                None if number == 0 => "0x0".to_string(),
+                // the precision of "%.*x" pads the value with zeros
+                None if !wide => format!("0x{number:0width$x}"),
+                None => format!("0x{number:x}"),
+            }
+        }
+        GNU_BUILD_ATTRIBUTE_TYPE_STRING => {
+            fit_name(&String::from_utf8_lossy(c_string(value)), width, wide)
+        }
+        GNU_BUILD_ATTRIBUTE_TYPE_BOOL_TRUE => fit_name("true", width, wide),
+        _ => fit_name("false", width, wide),
+    };
+
+    shown.push_str(&value);
+    let used = value.chars().count() as isize;
+    left -= used;
+
+    if wide && left > 0 {
+        shown.push_str(&" ".repeat(left as usize));
+    }
+
+    shown
+}
+
+fn c_string(bytes: &[u8]) -> &[u8] {
+    bytes.split(|&byte| byte == 0).next().unwrap_or_default()
+}
+
+// the numbers are little endian, no matter the file, and the last byte is
+// always the NUL of the name
+fn numeric_value(bytes: &[u8]) -> u64 {
+    let count = bytes.len().saturating_sub(1).min(8);
+
+    bytes[..count]
+        .iter()
+        .rev()
+        .fold(0, |value, &byte| value << 8 | byte as u64)
+}
+
+fn numeric_name(attribute: u32, value: u64) -> Option<&'static str> {
+    let name = match (attribute, value) {
+        (GNU_BUILD_ATTRIBUTE_PIC, 0) => "static",
+        (GNU_BUILD_ATTRIBUTE_PIC, 1) => "pic",
+        (GNU_BUILD_ATTRIBUTE_PIC, 2) => "PIC",
+        (GNU_BUILD_ATTRIBUTE_PIC, 3) => "pie",
+        (GNU_BUILD_ATTRIBUTE_PIC, 4) => "PIE",
+        // the same values of the -fstack-protector options of gcc
+        (GNU_BUILD_ATTRIBUTE_STACK_PROT, 0) => "off",
+        (GNU_BUILD_ATTRIBUTE_STACK_PROT, 1) => "on",
+        (GNU_BUILD_ATTRIBUTE_STACK_PROT, 2) => "all",
+        (GNU_BUILD_ATTRIBUTE_STACK_PROT, 3) => "strong",
+        (GNU_BUILD_ATTRIBUTE_STACK_PROT, 4) => "explicit",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+// an empty descriptor means that the note applies to the same range of the
+// last note of its kind (OPEN or FUNC). The symbols of .symtab give a name
+// to the ranges, and their size is the end of the ranges without one
+#[derive(Debug, Default)]
+pub struct BuildAttributeRanges<'a> {
+    symbols: &'a [Elf64Symbol],
+    open: (u64, u64),
+    func: (u64, u64),
+}
+
+impl<'a> BuildAttributeRanges<'a> {
+    pub fn new(symbols: &'a [Elf64Symbol]) -> Self {
+        BuildAttributeRanges {
+            symbols,
+            ..Default::default()
+        }
+    }
+
+    pub fn print(
+        &mut self,
+        out: &mut dyn Write,
+        header: &Elf64_Ehdr,
+        note: &Elf64Note,
+    ) -> io::Result<()> {
+        let is_open = note.nhdr.n_type == NT_GNU_BUILD_ATTRIBUTE_OPEN;
+        let is_little_endian = header.is_little_endian();
+        let desc = note.desc.as_slice();
+
+        let (start, mut end) = match desc.len() {
+            0 => {
+                let (start, end) = if is_open { self.open } else { self.func };
+                write!(out, "    Applies to region from {}", alternate_hex(start))?;
+                if end > start {
+                    write!(out, " to {}", alternate_hex(end))?;
+                }
+                return writeln!(out);
+            }
+            4 => (u32::read_from(desc, is_little_endian) as u64, 0),
+            8 => (
+                u32::read_from(&desc[0..4], is_little_endian) as u64,
+                u32::read_from(&desc[4..8], is_little_endian) as u64,
+            ),
+            16 => (
+                u64::read_from(&desc[0..8], is_little_endian),
+                u64::read_from(&desc[8..16], is_little_endian),
+            ),
+            _ => return write!(out, "    <invalid descsz>"),
+        };
+
+        // since the version 5 of annobin, the symbols of the files are 2
+        // bytes after the start of the range
+        let symbol = self
+            .symbol_at(start, is_open)
+            .or_else(|| self.symbol_at(start + 2, is_open).filter(|_| is_open));
+
+        if end == 0
+            && let Some(symbol) = symbol
+        {
+            end = start + symbol.sym.st_size;
+        }
+
+        if is_open {
+            self.open = (start, end);
+        } else {
+            self.func = (start, end);
+        }
+
+        write!(out, "    Applies to region from {}", alternate_hex(start))?;
+        if end != 0 {
+            write!(out, " to {}", alternate_hex(end))?;
+        }
+        if let Some(name) = symbol.and_then(|symbol| symbol.name.as_deref()) {
+            write!(out, " ({name})")?;
+        }
+
+        writeln!(out)
+    }
+
+    // the OPEN notes only take files, objects and symbols without a type,
+    // and prefer the global ones. The FUNC notes only take the functions
+    fn symbol_at(&self, address: u64, is_open: bool) -> Option<&'a Elf64Symbol> {
+        let mut symbols = self.symbols.iter().filter(|symbol| {
+            let name = symbol.name.as_deref().unwrap_or_default().as_bytes();
+            let st_type = symbol.sym.st_type();
+
+            // the mapping symbols of ARM, AArch64 and RISC-V ($d, $x...)
+            // are not a real name for the range
+            symbol.sym.st_value == address
+                && !name.is_empty()
+                && !(name.len() == 2 && name[0] == b'$')
+                && if is_open {
+                    matches!(st_type, STT_FILE | STT_OBJECT | STT_NOTYPE)
+                } else {
+                    st_type == STT_FUNC
+                }
+        });
+
+        if !is_open {
+            return symbols.next();
+        }
+
+        let first = symbols.clone().next();
+        symbols
+            .find(|symbol| symbol.sym.st_bind() == STB_GLOBAL)
+            .or(first)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_attribute_owner, is_build_attribute};
+    use crate::elf::def::elf64strc::Elf64_Nhdr;
+    use crate::elf::note::Elf64Note;
+
+    fn create_note(raw_name: &[u8]) -> Elf64Note {
+        Elf64Note {
+            nhdr: Elf64_Nhdr {
+                n_namesz: raw_name.len() as u32,
+                n_descsz: 0,
+                n_type: 0x100,
+            },
+            name: String::new(),
+            raw_name: raw_name.to_vec(),
+            desc: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn build_attribute_owner_names_the_known_attributes() {
+        let version = create_note(b"GA$\x013a1\0");
+        let pic = create_note(b"GA*\x07\x02\0");
+
+        assert!(is_build_attribute(&version));
+        assert_eq!(
+            build_attribute_owner(&version, false),
+            "GA$<version>3a1     "
+        );
+        assert_eq!(
+            build_attribute_owner(&version, true),
+            "GA$<version>3a1             "
+        );
+        assert_eq!(build_attribute_owner(&pic, false), "GA*<PIC>PIC         ");
+    }
+
+    #[test]
+    fn build_attribute_owner_shows_other_attributes_by_name() {
+        let note = create_note(b"GA*GOW\0\x07\x01\0");
+
+        assert_eq!(
+            build_attribute_owner(&note, false),
+            "GA*GOW:0x0000000000107"
+        );
+    }
+}
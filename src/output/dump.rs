@@ -0,0 +1,188 @@
+use crate::output::truncate_name;
+use std::io::{self, Write};
+
+// sections without bytes in the file (NOBITS, or just empty)
+pub fn print_no_data(out: &mut dyn Write, name: &str) -> io::Result<()> {
+    writeln!(out, "Section '{}' has no data to dump.", printable(name))
+}
+
+// 16 bytes per line, in groups of 4, with the address of the first one (the
+// one of the section when it is loaded, so 0 for the relocatable files) and
+// the bytes that are printable at the end
+pub fn print_hex_dump(
+    out: &mut dyn Write,
+    name: &str,
+    address: u64,
+    data: &[u8],
+    has_relocations: bool,
+) -> io::Result<()> {
+    writeln!(out, "\nHex dump of section '{}':", printable(name))?;
+    if has_relocations {
+        writeln!(
+            out,
+            " NOTE: This section has relocations against it, but these have NOT been applied to this dump."
+        )?;
+    }
+
+    print_hex_lines(out, address, data)?;
+    writeln!(out)
+}
+
+// the lines of print_hex_dump, the DWARF string dumps show them too
+pub fn print_hex_lines(out: &mut dyn Write, address: u64, data: &[u8]) -> io::Result<()> {
+    for (i, line) in data.chunks(16).enumerate() {
+        write!(out, "  0x{:08x} ", address.wrapping_add(i as u64 * 16))?;
+
+        for j in 0..16 {
+            match line.get(j) {
+                Some(byte) => write!(out, "{byte:02x}")?,
+                None => write!(out, "  ")?,
+            }
+            if j % 4 == 3 {
+                write!(out, " ")?;
+            }
+        }
+
+        for &byte in line {
+            let c = if (b' '..0x7f).contains(&byte) {
+                byte as char
+            } else {
+                '.'
+            };
+            write!(out, "{c}")?;
+        }
+        writeln!(out)?;
+    }
+
+    Ok(())
+}
+
+// every run of printable chars (up to a NUL) with its offset in the section,
+// the same way binutils does it: the chars before a string that are not
+// printable are skipped, the control chars inside of it are shown as ^X, the
+// other bytes are written as they are (they are usually UTF-8) and a new line
+// ends the line but not the string, that goes on in the next one
+pub fn print_string_dump(
+    out: &mut dyn Write,
+    name: &str,
+    data: &[u8],
+    has_relocations: bool,
+) -> io::Result<()> {
+    writeln!(out, "\nString dump of section '{}':", printable(name))?;
+    if has_relocations {
+        writeln!(
+            out,
+            "  Note: This section has relocations against it, but these have NOT been applied to this dump."
+        )?;
+    }
+
+    let is_printable = |byte: u8| (b' '..0x7f).contains(&byte);
+    let mut at = 0;
+    let mut continuing = false;
+    let mut found = false;
+
+    while at < data.len() {
+        while at < data.len() && !is_printable(data[at]) {
+            at += 1;
+        }
+        if at == data.len() {
+            break;
+        }
+
+        if continuing {
+            write!(out, "            ")?;
+            continuing = false;
+        } else {
+            write!(out, "  [{at:6x}]  ")?;
+        }
+
+        while at < data.len() {
+            let byte = data[at];
+            at += 1;
+
+            match byte {
+                0 => break,
+                b'\n' => {
+                    write!(out, "\\n")?;
+                    continuing = data.get(at).is_some_and(|&next| next != 0);
+                    break;
+                }
+                0x01..0x20 | 0x7f => out.write_all(&[b'^', byte.wrapping_add(0x40)])?,
+                _ => out.write_all(&[byte])?,
+            }
+        }
+        writeln!(out)?;
+
+        found = true;
+    }
+
+    if !found {
+        write!(out, "  No strings found in this section.")?;
+    }
+
+    writeln!(out)
+}
+
+// the control chars of the names are shown as ^X, like everywhere else
+fn printable(name: &str) -> String {
+    truncate_name(name, usize::MAX, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{print_hex_dump, print_string_dump};
+
+    #[test]
+    fn print_hex_dump_matches_gnu_readelf_layout() {
+        let mut out = Vec::new();
+
+        print_hex_dump(
+            &mut out,
+            ".comment",
+            0x350,
+            b"GCC: (Debian 12.2.0)\0",
+            false,
+        )
+        .unwrap();
+
+        let expected = "
+Hex dump of section '.comment':
+  0x00000350 4743433a 20284465 6269616e 2031322e GCC: (Debian 12.
+  0x00000360 322e3029 00                         2.0).
+
+";
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+    }
+
+    #[test]
+    fn print_string_dump_splits_the_strings_like_gnu_readelf() {
+        let mut out = Vec::new();
+        let data = b"\tabc\ndef\x01g\0\0xyz\0tail\n\0\n\nq";
+
+        print_string_dump(&mut out, ".mystr", data, true).unwrap();
+
+        let expected = "
+String dump of section '.mystr':
+  Note: This section has relocations against it, but these have NOT been applied to this dump.
+  [     1]  abc\\n
+            def^Ag
+  [     c]  xyz
+  [    10]  tail\\n
+  [    18]  q
+
+";
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+    }
+
+    #[test]
+    fn print_string_dump_says_when_there_are_no_strings() {
+        let mut out = Vec::new();
+
+        print_string_dump(&mut out, ".data", &[3, 0, 0, 0], false).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\nString dump of section '.data':\n  No strings found in this section.\n"
+        );
+    }
+}
@@ -0,0 +1,386 @@
+// the DWARF dumps of -w (--debug-dump), in the layout of binutils. A problem
+// in a unit (or a table) stops the dump of that one only, the dumps go on
+// with the next and return the problems, that are shown as warnings
+#![allow(non_upper_case_globals)]
+
+pub mod frame;
+pub mod info;
+pub mod line;
+pub mod lists;
+
+use crate::elf::dwarf::Dwarf;
+use crate::elf::dwarf::abbrev::parse_abbrev_table;
+use crate::elf::dwarf::aranges::parse_arange_set;
+use crate::elf::dwarf::constants::*;
+use crate::elf::dwarf::expr::{Operand, Operation, parse_expression};
+use crate::elf::dwarf::reader::{DwarfFormat, Encoding};
+use crate::elf::error::ElfError;
+use crate::elf::machine::machine_info;
+use crate::output::dump::print_hex_lines;
+use std::io::{self, Write};
+
+// the %#x of C, that binutils uses for most of the offsets: 0 has no 0x
+pub fn c_hex(value: u64) -> String {
+    if value == 0 {
+        "0".to_string()
+    } else {
+        format!("{value:#x}")
+    }
+}
+
+// "%x " for each byte, the way the blocks are shown
+pub fn block_bytes(block: &[u8]) -> String {
+    block.iter().map(|byte| format!("{byte:x} ")).collect()
+}
+
+pub fn tag_text(tag: u64) -> String {
+    match tag_name(tag) {
+        Some(name) => name.to_string(),
+        None => format!("Unknown TAG value: {tag:x}"),
+    }
+}
+
+pub fn attribute_text(name: u64) -> String {
+    match attribute_name(name) {
+        Some(name) => name.to_string(),
+        None if name == 0 => "DW_AT value: 0".to_string(),
+        None => format!("Unknown AT value: {name:x}"),
+    }
+}
+
+pub fn form_text(form: u64) -> String {
+    match form_name(form) {
+        Some(name) => name.to_string(),
+        None if form == 0 => "DW_FORM value: 0".to_string(),
+        None => format!("Unknown FORM value: {form:x}"),
+    }
+}
+
+// the name of a DWARF register of the machine, r<number> for the ones (and
+// the machines) that we dont know
+pub fn register_name(dwarf: &Dwarf, register: u64) -> String {
+    machine_info(dwarf.e_machine)
+        .and_then(|info| info.dwarf_register)
+        .and_then(|name_of| name_of(register))
+        .map_or_else(|| format!("r{register}"), str::to_string)
+}
+
+// the operations of an expression, joined by "; " like binutils shows them.
+// The references to DIEs of the unit are shown with their offset in the
+// section, unit_offset is where the unit starts
+pub fn format_expression(
+    data: &[u8],
+    dwarf: &Dwarf,
+    encoding: &Encoding,
+    unit_offset: u64,
+) -> String {
+    match parse_expression(data, dwarf.is_little_endian, encoding) {
+        Ok(operations) => format_operations(&operations, dwarf, unit_offset),
+        Err(err) => format!("<corrupt expression: {err}>"),
+    }
+}
+
+fn format_operations(operations: &[Operation], dwarf: &Dwarf, unit_offset: u64) -> String {
+    operations
+        .iter()
+        .map(|operation| format_operation(operation, dwarf, unit_offset))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+fn format_operation(operation: &Operation, dwarf: &Dwarf, unit_offset: u64) -> String {
+    let opcode = operation.opcode;
+    let name = match opcode {
+        DW_OP_lit0..=DW_OP_lit31 => format!("DW_OP_lit{}", opcode - DW_OP_lit0),
+        DW_OP_reg0..=DW_OP_reg31 => format!("DW_OP_reg{}", opcode - DW_OP_reg0),
+        DW_OP_breg0..=DW_OP_breg31 => format!("DW_OP_breg{}", opcode - DW_OP_breg0),
+        _ => operation_name(opcode).unwrap_or_default().to_string(),
+    };
+    let register = |register| register_name(dwarf, register);
+
+    match operation.operand {
+        Operand::None => name,
+        Operand::Unknown if opcode >= DW_OP_LO_USER => {
+            format!("(User defined location op {opcode:#x})")
+        }
+        Operand::Unknown => format!("(Unknown location op {opcode:#x})"),
+        Operand::Address(address) => format!("{name}: {address:x}"),
+        Operand::Unsigned(value) => match opcode {
+            DW_OP_addrx | DW_OP_constx | DW_OP_GNU_addr_index | DW_OP_GNU_const_index => {
+                format!("{name} <{value:#x}>")
+            }
+            _ => format!("{name}: {value}"),
+        },
+        Operand::Signed(value) => format!("{name}: {value}"),
+        Operand::Register(number) if opcode == DW_OP_regx => {
+            format!("{name}: {number} ({})", register(number))
+        }
+        Operand::Register(number) => format!("{name} ({})", register(number)),
+        Operand::RegisterOffset(number, offset) if opcode == DW_OP_bregx => {
+            format!("{name}: {number} ({}) {offset}", register(number))
+        }
+        Operand::RegisterOffset(number, offset) => {
+            format!("{name} ({}): {offset}", register(number))
+        }
+        Operand::BitPiece { size, offset } => format!("{name}: size: {size} offset: {offset} "),
+        Operand::Block(ref block) => {
+            format!("{name} {} byte block: {}", block.len(), block_bytes(block))
+        }
+        // DW_OP_call_ref and DW_OP_GNU_variable_value point in .debug_info,
+        // the others in the unit
+        Operand::Reference(die) if matches!(opcode, DW_OP_call_ref | DW_OP_GNU_variable_value) => {
+            format!("{name}: <{die:#x}>")
+        }
+        Operand::Reference(die)
+            if matches!(opcode, DW_OP_call2 | DW_OP_call4 | DW_OP_GNU_parameter_ref) =>
+        {
+            format!("{name}: <{:#x}>", unit_offset.wrapping_add(die))
+        }
+        Operand::Reference(die) => format!("{name} <{:#x}>", unit_offset.wrapping_add(die)),
+        Operand::ImplicitPointer { die, offset } => format!("{name}: <{die:#x}> {offset}"),
+        Operand::Expression(ref operations) => {
+            format!(
+                "{name}: ({})",
+                format_operations(operations, dwarf, unit_offset)
+            )
+        }
+        Operand::TypedConstant { die, ref value } => format!(
+            "{name}: <{:#x}> {} byte block: {}",
+            unit_offset.wrapping_add(die),
+            value.len(),
+            block_bytes(value)
+        ),
+        Operand::TypedRegister {
+            register: number,
+            die,
+        } => format!(
+            "{name}: {number} ({}) <{:#x}>",
+            register(number),
+            unit_offset.wrapping_add(die)
+        ),
+        Operand::TypedDeref { size, die } => {
+            format!("{name}: {size} <{:#x}>", unit_offset.wrapping_add(die))
+        }
+    }
+}
+
+// .debug_str, .debug_line_str and .debug_str.dwo, as a hex dump
+pub fn print_debug_str(out: &mut dyn Write, name: &str, data: &[u8]) -> io::Result<()> {
+    writeln!(out, "Contents of the {name} section:\n")?;
+    print_hex_lines(out, 0, data)?;
+    writeln!(out)
+}
+
+// .debug_str_offsets(.dwo), each offset with its string. The tables of
+// DWARF 5 have a header, the ones of the split DWARF of gcc (before DWARF
+// 5) are only the offsets
+pub fn print_debug_str_offsets(
+    out: &mut dyn Write,
+    dwarf: &Dwarf,
+    name: &'static str,
+) -> io::Result<Vec<ElfError>> {
+    let mut problems = Vec::new();
+    let dwo = name.ends_with(".dwo");
+    let mut reader = dwarf.reader(name);
+    writeln!(out, "Contents of the {} section:\n", dwarf.title(name))?;
+
+    while !reader.is_empty() {
+        // without the header of DWARF 5 the rest of the section is offsets
+        let mut header = reader.clone();
+        let (mut offsets, format) = match header.initial_length() {
+            Ok((length, format)) if header.u16("version").ok() == Some(5) => {
+                let mut offsets = reader.split(length.saturating_add(format.length_size() as u64));
+                // past the padding after the version
+                offsets.seek(header.offset() + 2);
+                writeln!(out, "    Length: {length:#x}")?;
+                writeln!(out, "    Version: {:#x}", 5)?;
+                (offsets, format)
+            }
+            _ => (reader.split(u64::MAX), DwarfFormat::Dwarf32),
+        };
+
+        writeln!(out, "       Index   Offset [String]")?;
+        let mut index = 0;
+        while !offsets.is_empty() {
+            let offset = match offsets.offset_of(format, "offset") {
+                Ok(offset) => offset,
+                Err(err) => {
+                    problems.push(err);
+                    break;
+                }
+            };
+            let string = dwarf.string(offset, dwo).unwrap_or_default();
+            writeln!(
+                out,
+                "{index:12} {offset:08x}  {}",
+                String::from_utf8_lossy(string)
+            )?;
+            index += 1;
+        }
+    }
+
+    Ok(problems)
+}
+
+// .debug_abbrev(.dwo), every table until the end of the section
+pub fn print_debug_abbrev(
+    out: &mut dyn Write,
+    dwarf: &Dwarf,
+    name: &'static str,
+) -> io::Result<Vec<ElfError>> {
+    let mut problems = Vec::new();
+    let mut reader = dwarf.reader(name);
+    writeln!(out, "Contents of the {} section:\n", dwarf.title(name))?;
+
+    while !reader.is_empty() {
+        let table = match parse_abbrev_table(&mut reader) {
+            Ok(table) => table,
+            Err(err) => {
+                problems.push(err);
+                break;
+            }
+        };
+
+        writeln!(out, "  Number TAG ({})", c_hex(table.offset))?;
+        for abbrev in &table.abbrevs {
+            let children = if abbrev.has_children {
+                "has children"
+            } else {
+                "no children"
+            };
+            writeln!(
+                out,
+                "   {}      {}    [{children}]",
+                abbrev.code,
+                tag_text(abbrev.tag)
+            )?;
+
+            for attribute in &abbrev.attributes {
+                write!(
+                    out,
+                    "    {:<18} {}",
+                    attribute_text(attribute.name),
+                    form_text(attribute.form)
+                )?;
+                if let Some(value) = attribute.implicit_const {
+                    write!(out, ": {value}")?;
+                }
+                writeln!(out)?;
+            }
+            writeln!(out, "    DW_AT value: 0     DW_FORM value: 0")?;
+        }
+    }
+
+    writeln!(out)?;
+    Ok(problems)
+}
+
+// .debug_aranges, the tuples of every set with the one that ends it
+pub fn print_debug_aranges(out: &mut dyn Write, dwarf: &Dwarf) -> io::Result<Vec<ElfError>> {
+    let mut problems = Vec::new();
+    let mut reader = dwarf.reader(".debug_aranges");
+    writeln!(
+        out,
+        "Contents of the {} section:\n",
+        dwarf.title(".debug_aranges")
+    )?;
+
+    while !reader.is_empty() {
+        let set = match parse_arange_set(&mut reader) {
+            Ok(set) => set,
+            Err(err) => {
+                problems.push(err);
+                break;
+            }
+        };
+
+        writeln!(out, "  Length:                   {}", set.length)?;
+        writeln!(out, "  Version:                  {}", set.version)?;
+        writeln!(
+            out,
+            "  Offset into .debug_info:  {}",
+            c_hex(set.debug_info_offset)
+        )?;
+        writeln!(out, "  Pointer Size:             {}", set.address_size)?;
+        writeln!(
+            out,
+            "  Segment Size:             {}",
+            set.segment_selector_size
+        )?;
+        writeln!(out)?;
+
+        if set.address_size == 8 {
+            writeln!(out, "    Address            Length")?;
+        } else {
+            writeln!(out, "    Address    Length")?;
+        }
+        let width = 2 * set.address_size as usize;
+        for (address, length) in set.ranges {
+            writeln!(out, "    {address:0width$x} {length:0width$x}")?;
+        }
+    }
+
+    writeln!(out)?;
+    Ok(problems)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{c_hex, format_expression};
+    use crate::elf::dwarf::reader::{DwarfFormat, Encoding};
+    use crate::elf::dwarf::{Dwarf, DwarfSection};
+
+    // the DWARF of a little endian x86-64 file with the sections (name,
+    // data), that the tests of every dump use. The tests change the rest,
+    // like Dwarf { e_machine: 40, ..create_dwarf(Vec::new()) }
+    pub fn create_dwarf(sections: Vec<(&'static str, Vec<u8>)>) -> Dwarf {
+        Dwarf {
+            sections: sections
+                .into_iter()
+                .enumerate()
+                .map(|(index, (name, data))| DwarfSection {
+                    name,
+                    file_name: name.to_string(),
+                    // the section 0 is the null one
+                    index: index + 1,
+                    address: 0,
+                    data,
+                    relocated: Vec::new(),
+                })
+                .collect(),
+            is_little_endian: true,
+            e_machine: 62,
+            address_size: 8,
+            skipped: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn format_expression_matches_gnu_readelf() {
+        let encoding = Encoding {
+            address_size: 8,
+            format: DwarfFormat::Dwarf32,
+            version: 5,
+        };
+        // DW_OP_entry_value(DW_OP_reg5), DW_OP_stack_value
+        let data = [0xa3, 1, 0x55, 0x9f];
+
+        assert_eq!(
+            format_expression(&data, &create_dwarf(Vec::new()), &encoding, 0),
+            "DW_OP_entry_value: (DW_OP_reg5 (rdi)); DW_OP_stack_value"
+        );
+        // DW_OP_breg7 -8 and a vendor opcode on a machine without names
+        let dwarf = Dwarf {
+            e_machine: 0,
+            ..create_dwarf(Vec::new())
+        };
+        assert_eq!(
+            format_expression(&[0x77, 0x78, 0xe1], &dwarf, &encoding, 0),
+            "DW_OP_breg7 (r7): -8; (User defined location op 0xe1)"
+        );
+        assert_eq!(
+            (c_hex(0), c_hex(0x2c)),
+            ("0".to_string(), "0x2c".to_string())
+        );
+    }
+}
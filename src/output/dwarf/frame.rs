@@ -0,0 +1,513 @@
+use crate::elf::def::elfconsts::EM_AARCH64;
+use crate::elf::dwarf::Dwarf;
+use crate::elf::dwarf::constants::*;
+use crate::elf::dwarf::frame::{
+    CallFrameInstruction, CfaRule, Cie, FrameEntry, FrameSection, RegisterRule, UnwindRow,
+    parse_call_frame_instruction, parse_eh_frame_hdr, parse_frame_entry,
+};
+use crate::elf::dwarf::reader::{DwarfReader, Encoding};
+use crate::elf::error::ElfError;
+use crate::elf::machine::machine_info;
+use crate::output::dwarf::{format_expression, register_name};
+use std::io::{self, Write};
+
+// what the instructions and the rows of the entries are shown with
+struct FrameContext<'a> {
+    dwarf: &'a Dwarf,
+    section: FrameSection,
+}
+
+impl FrameContext<'_> {
+    // the digits of an address, and of the lengths
+    fn width(&self) -> usize {
+        2 * self.dwarf.address_size as usize
+    }
+
+    // "r6 (rbp)", the way the instructions name the registers
+    fn register(&self, register: u64) -> String {
+        match machine_info(self.dwarf.e_machine)
+            .and_then(|info| info.dwarf_register)
+            .and_then(|name_of| name_of(register))
+        {
+            Some(name) => format!("r{register} ({name})"),
+            None => format!("r{register}"),
+        }
+    }
+
+    fn expression(&self, data: &[u8], cie: &Cie) -> String {
+        let encoding = Encoding {
+            address_size: cie.address_size,
+            format: cie.format,
+            version: cie.version as u16,
+        };
+        format_expression(data, self.dwarf, &encoding, 0)
+    }
+}
+
+// .eh_frame or .debug_frame, with the instructions of each entry as they are
+// or (interpret) as the table of the rules at each address
+pub fn print_debug_frames(
+    out: &mut dyn Write,
+    dwarf: &Dwarf,
+    name: &'static str,
+    interpret: bool,
+) -> io::Result<Vec<ElfError>> {
+    let mut problems = Vec::new();
+    let context = FrameContext {
+        dwarf,
+        section: FrameSection {
+            is_eh_frame: name == ".eh_frame",
+            address: dwarf.section(name).map_or(0, |section| section.address),
+            address_size: dwarf.address_size,
+        },
+    };
+    let width = context.width();
+
+    writeln!(out, "Contents of the {} section:\n", dwarf.title(name))?;
+
+    let mut reader = dwarf.reader(name);
+    while !reader.is_empty() {
+        let entry = match parse_frame_entry(&mut reader, &context.section) {
+            Ok(entry) => entry,
+            Err(err) => {
+                problems.push(err);
+                continue;
+            }
+        };
+
+        let (cie, instructions, pc) = match &entry {
+            FrameEntry::Terminator(offset) => {
+                write!(out, "\n{offset:08x} ZERO terminator\n\n")?;
+                continue;
+            }
+            FrameEntry::Cie(cie) => {
+                write!(
+                    out,
+                    "\n{:08x} {:0width$x} {:08x} CIE",
+                    cie.offset, cie.length, cie.id
+                )?;
+                if interpret {
+                    writeln!(
+                        out,
+                        " \"{}\" cf={} df={} ra={}",
+                        String::from_utf8_lossy(cie.augmentation),
+                        cie.code_alignment_factor,
+                        cie.data_alignment_factor,
+                        cie.return_address_register
+                    )?;
+                } else {
+                    writeln!(out)?;
+                    print_cie_fields(out, cie)?;
+                }
+                (cie, &cie.instructions, 0)
+            }
+            FrameEntry::Fde(fde) => {
+                writeln!(
+                    out,
+                    "\n{:08x} {:0width$x} {:08x} FDE cie={:08x} pc={:0width$x}..{:0width$x}",
+                    fde.offset,
+                    fde.length,
+                    fde.cie_pointer,
+                    fde.cie.offset,
+                    fde.pc_begin,
+                    fde.pc_begin.wrapping_add(fde.pc_range)
+                )?;
+                if !interpret && !fde.augmentation_data.is_empty() {
+                    writeln!(
+                        out,
+                        "  Augmentation data:     {}",
+                        hex_bytes(fde.augmentation_data)
+                    )?;
+                }
+                (&fde.cie, &fde.instructions, fde.pc_begin)
+            }
+        };
+
+        if interpret {
+            let rows = match &entry {
+                FrameEntry::Fde(fde) => fde.unwind_table(&context.section),
+                _ => cie.unwind_table(&context.section),
+            };
+            match rows {
+                Ok(rows) => print_unwind_rows(out, &context, cie, &rows)?,
+                Err(err) => problems.push(err),
+            }
+        } else {
+            let instructions = instructions.clone();
+            print_instructions(out, &context, cie, instructions, pc, &mut problems)?;
+        }
+    }
+
+    writeln!(out)?;
+    Ok(problems)
+}
+
+fn print_cie_fields(out: &mut dyn Write, cie: &Cie) -> io::Result<()> {
+    writeln!(out, "  Version:               {}", cie.version)?;
+    writeln!(
+        out,
+        "  Augmentation:          \"{}\"",
+        String::from_utf8_lossy(cie.augmentation)
+    )?;
+    if cie.version >= 4 {
+        writeln!(out, "  Pointer Size:          {}", cie.address_size)?;
+        writeln!(
+            out,
+            "  Segment Size:          {}",
+            cie.segment_selector_size
+        )?;
+    }
+    writeln!(
+        out,
+        "  Code alignment factor: {}",
+        cie.code_alignment_factor
+    )?;
+    writeln!(
+        out,
+        "  Data alignment factor: {}",
+        cie.data_alignment_factor
+    )?;
+    writeln!(
+        out,
+        "  Return address column: {}",
+        cie.return_address_register
+    )?;
+    // binutils leaves the line empty when there is no data
+    if cie.augmentation_data.is_empty() {
+        writeln!(out)
+    } else {
+        writeln!(
+            out,
+            "  Augmentation data:     {}",
+            hex_bytes(cie.augmentation_data)
+        )
+    }
+}
+
+fn hex_bytes(data: &[u8]) -> String {
+    data.iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// the instructions one by one, the ones before a bad one are shown. pc is
+// where the entry starts, for the locations of the advances
+fn print_instructions(
+    out: &mut dyn Write,
+    context: &FrameContext,
+    cie: &Cie,
+    mut reader: DwarfReader,
+    mut pc: u64,
+    problems: &mut Vec<ElfError>,
+) -> io::Result<()> {
+    let width = context.width();
+    let name = |opcode| call_frame_name(opcode).unwrap_or("DW_CFA_???");
+
+    while !reader.is_empty() {
+        let instruction = match parse_call_frame_instruction(&mut reader, cie, &context.section) {
+            Ok(instruction) => instruction,
+            Err(err) => {
+                problems.push(err);
+                break;
+            }
+        };
+
+        let text = match instruction {
+            CallFrameInstruction::AdvanceLoc { opcode, delta } => {
+                pc = pc.wrapping_add(delta);
+                format!("{}: {delta} to {pc:0width$x}", name(opcode))
+            }
+            CallFrameInstruction::SetLoc(address) => {
+                pc = address;
+                format!("DW_CFA_set_loc: {pc:0width$x}")
+            }
+            CallFrameInstruction::DefCfa {
+                opcode,
+                register,
+                offset,
+            } => format!(
+                "{}: {} ofs {offset}",
+                name(opcode),
+                context.register(register)
+            ),
+            CallFrameInstruction::DefCfaRegister(register) => {
+                format!("DW_CFA_def_cfa_register: {}", context.register(register))
+            }
+            CallFrameInstruction::DefCfaOffset { opcode, offset } => {
+                format!("{}: {offset}", name(opcode))
+            }
+            CallFrameInstruction::DefCfaExpression(expression) => format!(
+                "DW_CFA_def_cfa_expression ({})",
+                context.expression(expression, cie)
+            ),
+            CallFrameInstruction::Undefined(register) => {
+                format!("DW_CFA_undefined: {}", context.register(register))
+            }
+            CallFrameInstruction::SameValue(register) => {
+                format!("DW_CFA_same_value: {}", context.register(register))
+            }
+            CallFrameInstruction::Offset {
+                opcode,
+                register,
+                offset,
+            } => format!(
+                "{}: {} at cfa{offset:+}",
+                name(opcode),
+                context.register(register)
+            ),
+            CallFrameInstruction::ValOffset {
+                opcode,
+                register,
+                offset,
+            } => format!(
+                "{}: {} is cfa{offset:+}",
+                name(opcode),
+                context.register(register)
+            ),
+            CallFrameInstruction::Register { register, target } => format!(
+                "DW_CFA_register: {} in {}",
+                context.register(register),
+                context.register(target)
+            ),
+            CallFrameInstruction::Expression {
+                register,
+                expression,
+            } => format!(
+                "DW_CFA_expression: {} ({})",
+                context.register(register),
+                context.expression(expression, cie)
+            ),
+            CallFrameInstruction::ValExpression {
+                register,
+                expression,
+            } => format!(
+                "DW_CFA_val_expression: {} ({})",
+                context.register(register),
+                context.expression(expression, cie)
+            ),
+            CallFrameInstruction::Restore { opcode, register } => {
+                format!("{}: {}", name(opcode), context.register(register))
+            }
+            CallFrameInstruction::RememberState => "DW_CFA_remember_state".to_string(),
+            CallFrameInstruction::RestoreState => "DW_CFA_restore_state".to_string(),
+            CallFrameInstruction::ArgsSize(size) => format!("DW_CFA_GNU_args_size: {size}"),
+            // aarch64 reuses it to sign the return address
+            CallFrameInstruction::WindowSave if context.dwarf.e_machine as u32 == EM_AARCH64 => {
+                "DW_CFA_AARCH64_negate_ra_state".to_string()
+            }
+            CallFrameInstruction::WindowSave => "DW_CFA_GNU_window_save".to_string(),
+            CallFrameInstruction::Nop => "DW_CFA_nop".to_string(),
+        };
+        writeln!(out, "  {text}")?;
+    }
+
+    Ok(())
+}
+
+// the table of the rows, with a column for each register that the
+// instructions name. The return address is "ra"
+fn print_unwind_rows(
+    out: &mut dyn Write,
+    context: &FrameContext,
+    cie: &Cie,
+    rows: &[UnwindRow],
+) -> io::Result<()> {
+    let Some(first) = rows.first() else {
+        return Ok(());
+    };
+    let width = context.width();
+
+    write!(out, "{:<width$} CFA      ", "   LOC")?;
+    for &register in first.registers.keys() {
+        let name = if register == cie.return_address_register {
+            "ra".to_string()
+        } else {
+            register_name(context.dwarf, register)
+        };
+        write!(out, "{name:<5} ")?;
+    }
+    writeln!(out)?;
+
+    for row in rows {
+        let cfa = match row.cfa {
+            CfaRule::RegisterOffset { register, offset } => {
+                format!("{}{offset:+}", register_name(context.dwarf, register))
+            }
+            CfaRule::Expression(_) => "exp".to_string(),
+        };
+        write!(out, "{:0width$x} {cfa:<8} ", row.address)?;
+        for rule in row.registers.values() {
+            let text = match *rule {
+                RegisterRule::Undefined => "u".to_string(),
+                RegisterRule::SameValue => "s".to_string(),
+                RegisterRule::Offset(offset) => format!("c{offset:+}"),
+                RegisterRule::ValOffset(offset) => format!("v{offset:+}"),
+                RegisterRule::Register(register) => context.register(register),
+                RegisterRule::Expression(_) => "exp".to_string(),
+                RegisterRule::ValExpression(_) => "vexp".to_string(),
+            };
+            write!(out, "{text:<5} ")?;
+        }
+        writeln!(out)?;
+    }
+
+    Ok(())
+}
+
+// the names of the parts of a DW_EH_PE_* encoding, "DW_EH_PE_pcrel |
+// DW_EH_PE_sdata4"
+fn pointer_encoding_text(encoding: u8) -> String {
+    if encoding == DW_EH_PE_omit {
+        return "DW_EH_PE_omit".to_string();
+    }
+
+    let mut parts = Vec::new();
+    if encoding & DW_EH_PE_indirect != 0 {
+        parts.push("DW_EH_PE_indirect");
+    }
+    parts.push(match encoding & 0x70 {
+        DW_EH_PE_absptr => "",
+        DW_EH_PE_pcrel => "DW_EH_PE_pcrel",
+        DW_EH_PE_textrel => "DW_EH_PE_textrel",
+        DW_EH_PE_datarel => "DW_EH_PE_datarel",
+        DW_EH_PE_funcrel => "DW_EH_PE_funcrel",
+        DW_EH_PE_aligned => "DW_EH_PE_aligned",
+        _ => "unknown application",
+    });
+    parts.push(match encoding & 0x0f {
+        DW_EH_PE_absptr => "DW_EH_PE_absptr",
+        DW_EH_PE_uleb128 => "DW_EH_PE_uleb128",
+        DW_EH_PE_udata2 => "DW_EH_PE_udata2",
+        DW_EH_PE_udata4 => "DW_EH_PE_udata4",
+        DW_EH_PE_udata8 => "DW_EH_PE_udata8",
+        DW_EH_PE_sleb128 => "DW_EH_PE_sleb128",
+        DW_EH_PE_sdata2 => "DW_EH_PE_sdata2",
+        DW_EH_PE_sdata4 => "DW_EH_PE_sdata4",
+        DW_EH_PE_sdata8 => "DW_EH_PE_sdata8",
+        _ => "unknown format",
+    });
+    parts.retain(|part| !part.is_empty());
+
+    format!("{encoding:#x} ({})", parts.join(" | "))
+}
+
+// .eh_frame_hdr, that binutils doesnt dump: its fields and the search table
+// of the FDEs
+pub fn print_eh_frame_hdr(out: &mut dyn Write, dwarf: &Dwarf) -> io::Result<Vec<ElfError>> {
+    let name = ".eh_frame_hdr";
+    let address = dwarf.section(name).map_or(0, |section| section.address);
+    let width = 2 * dwarf.address_size as usize;
+
+    writeln!(out, "Contents of the {} section:\n", dwarf.title(name))?;
+
+    let header = match parse_eh_frame_hdr(&mut dwarf.reader(name), address, dwarf.address_size) {
+        Ok(header) => header,
+        Err(err) => return Ok(vec![err]),
+    };
+
+    writeln!(out, "  Version:                 {}", header.version)?;
+    writeln!(
+        out,
+        "  eh_frame_ptr encoding:   {}",
+        pointer_encoding_text(header.eh_frame_ptr_encoding)
+    )?;
+    writeln!(
+        out,
+        "  fde_count encoding:      {}",
+        pointer_encoding_text(header.fde_count_encoding)
+    )?;
+    writeln!(
+        out,
+        "  Table encoding:          {}",
+        pointer_encoding_text(header.table_encoding)
+    )?;
+    writeln!(out, "  eh_frame_ptr:            {:#x}", header.eh_frame_ptr)?;
+    match header.fde_count {
+        Some(count) => writeln!(out, "  FDE count:               {count}")?,
+        None => writeln!(out, "  FDE count:               omitted")?,
+    }
+
+    if !header.table.is_empty() {
+        writeln!(out, "\n  {:<width$}  FDE address", "Initial loc")?;
+        for (location, fde) in &header.table {
+            writeln!(out, "  {location:0width$x}  {fde:0width$x}")?;
+        }
+    }
+
+    writeln!(out)?;
+    Ok(Vec::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::print_debug_frames;
+    use crate::output::dwarf::tests::create_dwarf;
+
+    // a "zR" CIE and an FDE of 0x1000..0x1010 that pushes rbp
+    fn create_eh_frame() -> Vec<u8> {
+        let mut data = vec![
+            0x14, 0, 0, 0, 0, 0, 0, 0, 1, b'z', b'R', 0, 1, 0x78, 0x10, 1, 0x1b,
+        ];
+        data.extend([0x0c, 7, 8, 0x90, 1, 0, 0]);
+        data.extend([0x14, 0, 0, 0, 0x1c, 0, 0, 0]);
+        data.extend((0x1000u32 - 0x20).to_le_bytes());
+        data.extend(0x10u32.to_le_bytes());
+        data.extend([0, 0x41, 0x0e, 0x10, 0x86, 2, 0, 0, 0, 0, 0, 0]);
+        data
+    }
+
+    #[test]
+    fn print_debug_frames_shows_the_instructions_like_gnu_readelf() {
+        let dwarf = create_dwarf(vec![(".eh_frame", create_eh_frame())]);
+
+        let mut out = Vec::new();
+        let problems = print_debug_frames(&mut out, &dwarf, ".eh_frame", false).unwrap();
+
+        assert!(problems.is_empty());
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "Contents of the .eh_frame section:\n\n\n\
+             00000000 0000000000000014 00000000 CIE\n  \
+             Version:               1\n  \
+             Augmentation:          \"zR\"\n  \
+             Code alignment factor: 1\n  \
+             Data alignment factor: -8\n  \
+             Return address column: 16\n  \
+             Augmentation data:     1b\n  \
+             DW_CFA_def_cfa: r7 (rsp) ofs 8\n  \
+             DW_CFA_offset: r16 (rip) at cfa-8\n  \
+             DW_CFA_nop\n  \
+             DW_CFA_nop\n\n\
+             00000018 0000000000000014 0000001c FDE cie=00000000 pc=0000000000001000..0000000000001010\n  \
+             DW_CFA_advance_loc: 1 to 0000000000001001\n  \
+             DW_CFA_def_cfa_offset: 16\n  \
+             DW_CFA_offset: r6 (rbp) at cfa-16\n  \
+             DW_CFA_nop\n  \
+             DW_CFA_nop\n\n\
+             00000030 ZERO terminator\n\n\n"
+        );
+    }
+
+    #[test]
+    fn print_debug_frames_interprets_the_rows_of_each_entry() {
+        let dwarf = create_dwarf(vec![(".eh_frame", create_eh_frame())]);
+
+        let mut out = Vec::new();
+        let problems = print_debug_frames(&mut out, &dwarf, ".eh_frame", true).unwrap();
+
+        assert!(problems.is_empty());
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "Contents of the .eh_frame section:\n\n\n\
+             00000000 0000000000000014 00000000 CIE \"zR\" cf=1 df=-8 ra=16\n   \
+             LOC           CFA      ra    \n\
+             0000000000000000 rsp+8    c-8   \n\n\
+             00000018 0000000000000014 0000001c FDE cie=00000000 pc=0000000000001000..0000000000001010\n   \
+             LOC           CFA      rbp   ra    \n\
+             0000000000001000 rsp+8    u     c-8   \n\
+             0000000000001001 rsp+16   c-16  c-8   \n\n\
+             00000030 ZERO terminator\n\n\n"
+        );
+    }
+}
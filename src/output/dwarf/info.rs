@@ -0,0 +1,403 @@
+use crate::elf::dwarf::constants::*;
+use crate::elf::dwarf::info::{
+    Attribute, AttributeValue, Die, UnitHeader, is_location_attribute, parse_die, parse_unit_header,
+};
+use crate::elf::dwarf::reader::{DwarfFormat, DwarfReader, string_at};
+use crate::elf::dwarf::{Dwarf, UnitBases};
+use crate::elf::error::ElfError;
+use crate::output::dwarf::{attribute_text, block_bytes, c_hex, format_expression, tag_text};
+use std::io::{self, Write};
+
+// what the values of the DIEs of a unit are shown with
+struct UnitContext<'a> {
+    dwarf: &'a Dwarf,
+    header: &'a UnitHeader,
+    bases: UnitBases,
+    dwo: bool,
+}
+
+// .debug_info(.dwo), every unit with its header and the tree of its DIEs
+pub fn print_debug_info(
+    out: &mut dyn Write,
+    dwarf: &Dwarf,
+    name: &'static str,
+) -> io::Result<Vec<ElfError>> {
+    let mut problems = Vec::new();
+    let dwo = name.ends_with(".dwo");
+    let mut reader = dwarf.reader(name);
+    writeln!(out, "Contents of the {} section:\n", dwarf.title(name))?;
+
+    while !reader.is_empty() {
+        let mut unit = reader.clone();
+        let header = match parse_unit_header(&mut unit) {
+            Ok(header) => header,
+            Err(err) => {
+                problems.push(err);
+                // the units that we cant read are skipped when at least
+                // their length is there
+                match reader.clone().initial_length() {
+                    Ok((length, format)) => {
+                        reader.split(length.saturating_add(format.length_size() as u64));
+                        continue;
+                    }
+                    Err(_) => break,
+                }
+            }
+        };
+
+        let dies_offset = unit.offset();
+        let mut unit = reader.split(header.end - header.offset);
+        unit.seek(dies_offset);
+
+        print_unit_header(out, &header)?;
+        print_dies(out, dwarf, &header, &mut unit, dwo, &mut problems)?;
+    }
+
+    writeln!(out)?;
+    Ok(problems)
+}
+
+fn print_unit_header(out: &mut dyn Write, header: &UnitHeader) -> io::Result<()> {
+    let bits = match header.format {
+        DwarfFormat::Dwarf32 => "32-bit",
+        DwarfFormat::Dwarf64 => "64-bit",
+    };
+
+    writeln!(out, "  Compilation Unit @ offset {}:", c_hex(header.offset))?;
+    writeln!(out, "   Length:        0x{:x} ({bits})", header.length)?;
+    writeln!(out, "   Version:       {}", header.version)?;
+    if let Some(unit_type) = header.unit_type {
+        let name = unit_type_name(unit_type).unwrap_or("(null)");
+        writeln!(out, "   Unit Type:     {name} ({unit_type:x})")?;
+    }
+    writeln!(out, "   Abbrev Offset: {}", c_hex(header.abbrev_offset))?;
+    writeln!(out, "   Pointer Size:  {}", header.address_size)?;
+    if let Some(dwo_id) = header.dwo_id {
+        writeln!(out, "   DWO ID:        0x{dwo_id:x}")?;
+    }
+    if let Some(signature) = header.type_signature {
+        writeln!(out, "   Signature:     0x{signature:x}")?;
+    }
+    if let Some(type_offset) = header.type_offset {
+        writeln!(out, "   Type Offset:   {}", c_hex(type_offset))?;
+    }
+
+    Ok(())
+}
+
+fn print_dies(
+    out: &mut dyn Write,
+    dwarf: &Dwarf,
+    header: &UnitHeader,
+    reader: &mut DwarfReader,
+    dwo: bool,
+    problems: &mut Vec<ElfError>,
+) -> io::Result<()> {
+    let abbrevs = match dwarf.abbrev_table(header.abbrev_offset, dwo) {
+        Ok(abbrevs) => abbrevs,
+        Err(err) => {
+            problems.push(err);
+            return Ok(());
+        }
+    };
+
+    let mut depth = 0;
+    let mut bases = None;
+    while !reader.is_empty() {
+        let die = match parse_die(reader, header, &abbrevs, &mut depth) {
+            Ok(die) => die,
+            Err(err) => {
+                problems.push(err);
+                break;
+            }
+        };
+
+        // the unit DIE is the first one, it has the bases of the others
+        let context = UnitContext {
+            dwarf,
+            header,
+            bases: *bases.get_or_insert_with(|| dwarf.unit_bases(header, Some(&die), dwo)),
+            dwo,
+        };
+        print_die(out, &context, &die)?;
+    }
+
+    Ok(())
+}
+
+fn print_die(out: &mut dyn Write, context: &UnitContext, die: &Die) -> io::Result<()> {
+    if die.code == 0 {
+        return writeln!(out, " <{}><{:x}>: Abbrev Number: 0", die.depth, die.offset);
+    }
+
+    writeln!(
+        out,
+        " <{}><{:x}>: Abbrev Number: {} ({})",
+        die.depth,
+        die.offset,
+        die.code,
+        tag_text(die.tag)
+    )?;
+
+    for attribute in &die.attributes {
+        writeln!(
+            out,
+            "    <{:x}>   {:<18}: {}{}",
+            attribute.offset,
+            attribute_text(attribute.name),
+            format_value(context, attribute),
+            attribute_suffix(context, attribute)
+        )?;
+    }
+
+    Ok(())
+}
+
+fn format_value(context: &UnitContext, attribute: &Attribute) -> String {
+    let UnitContext {
+        dwarf,
+        header,
+        bases,
+        dwo,
+    } = *context;
+
+    match attribute.value {
+        AttributeValue::Address(address) => c_hex(address),
+        AttributeValue::AddressIndex(index) => {
+            let address = match dwarf.address(index, bases.addr_base, header.address_size) {
+                Some(address) => c_hex(address),
+                None if dwarf.section(".debug_addr").is_none() => {
+                    "<no .debug_addr section>".to_string()
+                }
+                None => "<index is too big>".to_string(),
+            };
+            format!("(index: {}): {address}", c_hex(index))
+        }
+        AttributeValue::Block(ref block) => {
+            format!("{} byte block: {}", block.len(), block_bytes(block))
+        }
+        AttributeValue::Data { value, size: 1 | 2 } => value.to_string(),
+        AttributeValue::Data { value, .. } => c_hex(value),
+        AttributeValue::Data16(bytes) => {
+            let mut value = bytes;
+            if dwarf.is_little_endian {
+                value.reverse();
+            }
+            let digits: String = value.iter().map(|byte| format!("{byte:02x}")).collect();
+            format!("0x{digits}")
+        }
+        AttributeValue::Sdata(value) | AttributeValue::ImplicitConst(value) => value.to_string(),
+        AttributeValue::Udata(value) => value.to_string(),
+        AttributeValue::Flag(flag) => flag.to_string(),
+        AttributeValue::FlagPresent => "1".to_string(),
+        AttributeValue::String(ref string) => String::from_utf8_lossy(string).into_owned(),
+        AttributeValue::Strp(offset) => format!(
+            "(indirect string, offset: {}): {}",
+            c_hex(offset),
+            indirect_string(dwarf, dwarf.name(".debug_str", dwo), offset)
+        ),
+        AttributeValue::LineStrp(offset) => format!(
+            "(indirect line string, offset: {}): {}",
+            c_hex(offset),
+            indirect_string(dwarf, ".debug_line_str", offset)
+        ),
+        AttributeValue::StringIndex(index) => {
+            let string =
+                match dwarf.string_offset(index, bases.str_offsets_base, header.format, dwo) {
+                    Some(offset) => indirect_string(dwarf, dwarf.name(".debug_str", dwo), offset),
+                    None => "<index offset is too big>".to_string(),
+                };
+            format!("(indexed string: {}): {string}", c_hex(index))
+        }
+        AttributeValue::AltStrp(offset) => {
+            format!("(alt indirect string, offset: {})", c_hex(offset))
+        }
+        AttributeValue::AltRef(offset) => format!("<alt {}>", c_hex(offset)),
+        // binutils shows DW_FORM_ref8 (the one of DWARF64) like data8
+        AttributeValue::UnitRef(offset) if attribute.form == DW_FORM_ref8 => {
+            format!("0x{:x}", header.offset.wrapping_add(offset))
+        }
+        AttributeValue::UnitRef(offset) => format!("<{:#x}>", header.offset.wrapping_add(offset)),
+        AttributeValue::InfoRef(offset) => format!("<{offset:#x}>"),
+        AttributeValue::TypeSignature(signature) => format!("signature: {signature:#x}"),
+        AttributeValue::SecOffset(offset) => c_hex(offset),
+        AttributeValue::LoclistIndex(index) => list_index(
+            dwarf,
+            dwarf.name(".debug_loclists", dwo),
+            index,
+            bases.loclists_base,
+            header.format,
+        ),
+        AttributeValue::RnglistIndex(index) => list_index(
+            dwarf,
+            dwarf.name(".debug_rnglists", dwo),
+            index,
+            bases.rnglists_base,
+            header.format,
+        ),
+    }
+}
+
+fn indirect_string(dwarf: &Dwarf, section: &'static str, offset: u64) -> String {
+    if dwarf.section(section).is_none() {
+        return format!("<no {section} section>");
+    }
+
+    match string_at(dwarf.data(section), offset) {
+        Some(string) => String::from_utf8_lossy(string).into_owned(),
+        None => "<offset is too big>".to_string(),
+    }
+}
+
+fn list_index(
+    dwarf: &Dwarf,
+    section: &'static str,
+    index: u64,
+    base: u64,
+    format: DwarfFormat,
+) -> String {
+    let offset = match dwarf.list_offset(section, index, base, format) {
+        Some(offset) => c_hex(offset),
+        None if dwarf.section(section).is_none() => format!("<no {section} section>"),
+        None => "<index is too big>".to_string(),
+    };
+    format!("(index: {}): {offset}", c_hex(index))
+}
+
+// what binutils shows after some of the values: the meaning of the
+// constants and the operations of the expressions
+fn attribute_suffix(context: &UnitContext, attribute: &Attribute) -> String {
+    let constant = attribute.value.constant();
+    let description = match attribute.name {
+        DW_AT_language => constant.map(language_description),
+        DW_AT_encoding => constant.map(|value| encoding_description(value).to_string()),
+        DW_AT_inline => constant.map(inline_description),
+        DW_AT_accessibility => constant.map(|value| accessibility_description(value).to_string()),
+        DW_AT_visibility => constant.map(|value| visibility_description(value).to_string()),
+        DW_AT_virtuality => constant.map(|value| virtuality_description(value).to_string()),
+        DW_AT_calling_convention => {
+            constant.map(|value| calling_convention_description(value).to_string())
+        }
+        _ => None,
+    };
+    if let Some(description) = description {
+        return format!("\t({description})");
+    }
+
+    let is_location = is_location_attribute(attribute.name);
+    let is_expression = is_location || has_expression(attribute.name);
+
+    match attribute.value {
+        AttributeValue::Block(ref block) if is_expression => {
+            let expression = format_expression(
+                block,
+                context.dwarf,
+                &context.header.encoding(),
+                context.header.offset,
+            );
+            format!("\t({expression})")
+        }
+        AttributeValue::SecOffset(_) | AttributeValue::LoclistIndex(_) if is_location => {
+            " (location list)".to_string()
+        }
+        // before DWARF 4 the offsets were data4 (or data8) too
+        AttributeValue::Data { size: 4 | 8, .. } if is_location && context.header.version < 4 => {
+            " (location list)".to_string()
+        }
+        _ => String::new(),
+    }
+}
+
+// the attributes (other than the locations) that can be an expression
+fn has_expression(name: u64) -> bool {
+    matches!(
+        name,
+        DW_AT_allocated
+            | DW_AT_associated
+            | DW_AT_data_location
+            | DW_AT_byte_stride
+            | DW_AT_bit_stride
+            | DW_AT_upper_bound
+            | DW_AT_lower_bound
+            | DW_AT_count
+            | DW_AT_rank
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::print_debug_info;
+    use crate::output::dwarf::tests::create_dwarf;
+
+    #[test]
+    fn print_debug_info_shows_the_unit_and_its_dies() {
+        // DW_TAG_compile_unit with DW_AT_name (string) and DW_AT_language
+        // (data1)
+        let abbrev = vec![1, 0x11, 0, 0x03, 0x08, 0x13, 0x0b, 0, 0, 0];
+        let mut info = vec![13, 0, 0, 0, 4, 0, 0, 0, 0, 0, 8, 1];
+        info.extend(b"u.c\0");
+        info.push(0x0c);
+        let dwarf = create_dwarf(vec![(".debug_abbrev", abbrev), (".debug_info", info)]);
+
+        let mut out = Vec::new();
+        let problems = print_debug_info(&mut out, &dwarf, ".debug_info").unwrap();
+
+        assert!(problems.is_empty());
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "Contents of the .debug_info section:\n\n  \
+             Compilation Unit @ offset 0:\n   \
+             Length:        0xd (32-bit)\n   \
+             Version:       4\n   \
+             Abbrev Offset: 0\n   \
+             Pointer Size:  8\n \
+             <0><b>: Abbrev Number: 1 (DW_TAG_compile_unit)\n    \
+             <c>   DW_AT_name        : u.c\n    \
+             <10>   DW_AT_language    : 12\t(ANSI C99)\n\n"
+        );
+    }
+
+    #[test]
+    fn print_debug_info_shows_the_ref8_of_dwarf64_without_brackets() {
+        // DW_TAG_compile_unit (with children), a DW_TAG_base_type and a
+        // DW_TAG_variable with its DW_AT_type as DW_FORM_ref8
+        let abbrev = vec![
+            1, 0x11, 1, 0x03, 0x08, 0, 0, 2, 0x24, 0, 0x03, 0x08, 0, 0, 3, 0x34, 0, 0x49, 0x14, 0,
+            0, 0,
+        ];
+        let mut info = vec![0xff, 0xff, 0xff, 0xff];
+        info.extend(0x1fu64.to_le_bytes());
+        info.extend([4, 0]);
+        info.extend(0u64.to_le_bytes());
+        info.push(8);
+        info.push(1);
+        info.extend(b"u.c\0");
+        info.push(2);
+        info.extend(b"int\0");
+        info.push(3);
+        info.extend(0x1cu64.to_le_bytes());
+        info.push(0);
+        let dwarf = create_dwarf(vec![(".debug_abbrev", abbrev), (".debug_info", info)]);
+
+        let mut out = Vec::new();
+        let problems = print_debug_info(&mut out, &dwarf, ".debug_info").unwrap();
+
+        assert!(problems.is_empty());
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "Contents of the .debug_info section:\n\n  \
+             Compilation Unit @ offset 0:\n   \
+             Length:        0x1f (64-bit)\n   \
+             Version:       4\n   \
+             Abbrev Offset: 0\n   \
+             Pointer Size:  8\n \
+             <0><17>: Abbrev Number: 1 (DW_TAG_compile_unit)\n    \
+             <18>   DW_AT_name        : u.c\n \
+             <1><1c>: Abbrev Number: 2 (DW_TAG_base_type)\n    \
+             <1d>   DW_AT_name        : int\n \
+             <1><21>: Abbrev Number: 3 (DW_TAG_variable)\n    \
+             <22>   DW_AT_type        : 0x1c\n \
+             <1><2a>: Abbrev Number: 0\n\n"
+        );
+    }
+}
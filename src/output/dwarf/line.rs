@@ -0,0 +1,562 @@
+use crate::elf::dwarf::Dwarf;
+use crate::elf::dwarf::constants::*;
+use crate::elf::dwarf::info::AttributeValue;
+use crate::elf::dwarf::line::{
+    LineEntry, LineInstruction, LineProgramHeader, LineState, parse_line_instruction,
+    parse_line_program_header,
+};
+use crate::elf::dwarf::reader::{DwarfReader, string_at};
+use crate::elf::error::ElfError;
+use crate::output::dwarf::c_hex;
+use std::io::{self, Write};
+
+// the longest file name of the decoded dump that isnt cut (in wide mode
+// none are)
+const MAX_FILE_NAME_LENGTH: usize = 35;
+
+// the next program that has a header we can read, the reader is left after
+// it. The ones with a broken header are skipped when their length can be
+// read
+fn next_program<'a>(
+    reader: &mut DwarfReader<'a>,
+    problems: &mut Vec<ElfError>,
+) -> Option<(LineProgramHeader, DwarfReader<'a>)> {
+    while !reader.is_empty() {
+        let mut program = reader.clone();
+        match parse_line_program_header(&mut program) {
+            Ok(header) => {
+                let program_offset = header.program_offset;
+                let mut program = reader.split(header.end - header.offset);
+                program.seek(program_offset);
+                return Some((header, program));
+            }
+            Err(err) => {
+                problems.push(err);
+                let (length, format) = reader.clone().initial_length().ok()?;
+                reader.split(length.saturating_add(format.length_size() as u64));
+            }
+        }
+    }
+
+    None
+}
+
+// -wl, the headers of the line number programs and their instructions one
+// by one
+pub fn print_debug_line_raw(
+    out: &mut dyn Write,
+    dwarf: &Dwarf,
+    name: &'static str,
+) -> io::Result<Vec<ElfError>> {
+    let mut problems = Vec::new();
+    let mut reader = dwarf.reader(name);
+    writeln!(
+        out,
+        "Raw dump of debug contents of section {}:\n",
+        dwarf.title(name)
+    )?;
+
+    while let Some((header, mut program)) = next_program(&mut reader, &mut problems) {
+        print_line_header(out, dwarf, &header)?;
+
+        if program.is_empty() {
+            writeln!(out, " No Line Number Statements.")?;
+            continue;
+        }
+
+        writeln!(out, " Line Number Statements:")?;
+        let mut state = LineState::new(&header);
+        // DW_LNE_define_file adds to the end of the table
+        let mut files = header.files.len();
+        while !program.is_empty() {
+            let offset = program.offset();
+            let instruction = match parse_line_instruction(&mut program, &header) {
+                Ok(instruction) => instruction,
+                Err(err) => {
+                    problems.push(err);
+                    break;
+                }
+            };
+
+            if let LineInstruction::DefineFile(ref file) = instruction {
+                files += 1;
+                writeln!(
+                    out,
+                    "  [0x{offset:08x}]  Extended opcode 3: define new File Table entry\n  \
+                     Entry\tDir\tTime\tSize\tName\n  {files}\t{}",
+                    old_file_text(dwarf, file)
+                )?;
+                continue;
+            }
+
+            let before = state.clone();
+            let row = state.execute(&instruction, &header);
+            let text = instruction_text(&instruction, &header, &before, &state, row.as_ref());
+            writeln!(out, "  [0x{offset:08x}]  {text}")?;
+        }
+        writeln!(out)?;
+    }
+
+    Ok(problems)
+}
+
+fn print_line_header(
+    out: &mut dyn Write,
+    dwarf: &Dwarf,
+    header: &LineProgramHeader,
+) -> io::Result<()> {
+    writeln!(
+        out,
+        "  Offset:                      {}",
+        c_hex(header.offset)
+    )?;
+    writeln!(out, "  Length:                      {}", header.length)?;
+    writeln!(out, "  DWARF Version:               {}", header.version)?;
+    if let Some(address_size) = header.address_size {
+        writeln!(out, "  Address size (bytes):        {address_size}")?;
+    }
+    if let Some(segment_selector_size) = header.segment_selector_size {
+        writeln!(
+            out,
+            "  Segment selector (bytes):    {segment_selector_size}"
+        )?;
+    }
+    writeln!(
+        out,
+        "  Prologue Length:             {}",
+        header.header_length
+    )?;
+    writeln!(
+        out,
+        "  Minimum Instruction Length:  {}",
+        header.minimum_instruction_length
+    )?;
+    if header.version >= 4 {
+        writeln!(
+            out,
+            "  Maximum Ops per Instruction: {}",
+            header.maximum_operations_per_instruction
+        )?;
+    }
+    writeln!(
+        out,
+        "  Initial value of 'is_stmt':  {}",
+        header.default_is_stmt as u8
+    )?;
+    writeln!(out, "  Line Base:                   {}", header.line_base)?;
+    writeln!(out, "  Line Range:                  {}", header.line_range)?;
+    writeln!(out, "  Opcode Base:                 {}", header.opcode_base)?;
+
+    writeln!(out, "\n Opcodes:")?;
+    for (index, &count) in header.standard_opcode_lengths.iter().enumerate() {
+        let plural = if count == 1 { "" } else { "s" };
+        writeln!(out, "  Opcode {} has {count} arg{plural}", index + 1)?;
+    }
+
+    if header.version >= 5 {
+        print_entry_table(
+            out,
+            dwarf,
+            "Directory Table",
+            header.directories_offset,
+            &header.directories,
+            header.directory_format.len(),
+        )?;
+        print_entry_table(
+            out,
+            dwarf,
+            "File Name Table",
+            header.files_offset,
+            &header.files,
+            header.file_format.len(),
+        )?;
+    } else {
+        if header.directories.is_empty() {
+            writeln!(out, "\n The Directory Table is empty.")?;
+        } else {
+            writeln!(
+                out,
+                "\n The Directory Table (offset {}):",
+                c_hex(header.directories_offset)
+            )?;
+            for (index, directory) in header.directories.iter().enumerate() {
+                writeln!(
+                    out,
+                    "  {}\t{}",
+                    index + 1,
+                    entry_text(dwarf, directory.path())
+                )?;
+            }
+        }
+
+        if header.files.is_empty() {
+            writeln!(out, "\n The File Name Table is empty.")?;
+        } else {
+            writeln!(
+                out,
+                "\n The File Name Table (offset {}):",
+                c_hex(header.files_offset)
+            )?;
+            writeln!(out, "  Entry\tDir\tTime\tSize\tName")?;
+            for (index, file) in header.files.iter().enumerate() {
+                writeln!(out, "  {}\t{}", index + 1, old_file_text(dwarf, file))?;
+            }
+        }
+    }
+
+    writeln!(out)
+}
+
+// a table of DWARF 5, with the columns that it has. binutils shows the
+// path last whatever its place in the format
+fn print_entry_table(
+    out: &mut dyn Write,
+    dwarf: &Dwarf,
+    title: &str,
+    offset: u64,
+    entries: &[LineEntry],
+    columns: usize,
+) -> io::Result<()> {
+    if entries.is_empty() {
+        return writeln!(out, "\n The {title} is empty.");
+    }
+
+    writeln!(
+        out,
+        "\n The {title} (offset {}, lines {}, columns {columns}):",
+        c_hex(offset),
+        entries.len()
+    )?;
+
+    let mut heading = String::from("  Entry");
+    for &(content_type, _) in &entries[0].values {
+        match content_type {
+            DW_LNCT_path => {}
+            DW_LNCT_directory_index => heading.push_str("\tDir"),
+            DW_LNCT_timestamp => heading.push_str("\tTime"),
+            DW_LNCT_size => heading.push_str("\tSize"),
+            DW_LNCT_MD5 => heading.push_str("\tMD5\t\t\t"),
+            _ => heading.push_str("\t(Unknown format content type)"),
+        }
+    }
+    writeln!(out, "{heading}\tName")?;
+
+    for (index, entry) in entries.iter().enumerate() {
+        let mut row = format!("  {index}");
+        for (content_type, value) in &entry.values {
+            if *content_type != DW_LNCT_path {
+                row.push('\t');
+                row.push_str(&entry_text(dwarf, Some(value)));
+            }
+        }
+        writeln!(out, "{row}\t{}", entry_text(dwarf, entry.path()))?;
+    }
+
+    Ok(())
+}
+
+// the columns of a file entry before DWARF 5 (and of DW_LNE_define_file)
+fn old_file_text(dwarf: &Dwarf, file: &LineEntry) -> String {
+    let constant = |content_type| {
+        file.value(content_type)
+            .and_then(AttributeValue::constant)
+            .unwrap_or(0)
+    };
+    format!(
+        "{}\t{}\t{}\t{}",
+        file.directory_index(),
+        constant(DW_LNCT_timestamp),
+        constant(DW_LNCT_size),
+        entry_text(dwarf, file.path())
+    )
+}
+
+fn entry_text(dwarf: &Dwarf, value: Option<&AttributeValue>) -> String {
+    match value {
+        Some(&AttributeValue::Strp(offset)) => format!(
+            "(indirect string, offset: {}): {}",
+            c_hex(offset),
+            entry_string(dwarf, value)
+        ),
+        Some(&AttributeValue::LineStrp(offset)) => format!(
+            "(indirect line string, offset: {}): {}",
+            c_hex(offset),
+            entry_string(dwarf, value)
+        ),
+        Some(&AttributeValue::StringIndex(index)) => {
+            format!("(indexed string: {})", c_hex(index))
+        }
+        Some(AttributeValue::Data16(bytes)) => {
+            let digits: String = bytes.iter().map(|byte| format!("{byte:02x}")).collect();
+            format!("0x{digits}")
+        }
+        Some(value) => match value.constant() {
+            Some(constant) => constant.to_string(),
+            None => entry_string(dwarf, Some(value)),
+        },
+        None => String::new(),
+    }
+}
+
+// the plain string of a path, wherever it is
+fn entry_string(dwarf: &Dwarf, value: Option<&AttributeValue>) -> String {
+    let string = match value {
+        Some(AttributeValue::String(string)) => Some(string.as_slice()),
+        Some(&AttributeValue::Strp(offset)) => string_at(dwarf.data(".debug_str"), offset),
+        Some(&AttributeValue::LineStrp(offset)) => string_at(dwarf.data(".debug_line_str"), offset),
+        _ => None,
+    };
+
+    match string {
+        Some(string) => String::from_utf8_lossy(string).into_owned(),
+        None => "<unknown>".to_string(),
+    }
+}
+
+// what an instruction did, from the state before it and after it (and the
+// row that it added)
+fn instruction_text(
+    instruction: &LineInstruction,
+    header: &LineProgramHeader,
+    before: &LineState,
+    after: &LineState,
+    row: Option<&LineState>,
+) -> String {
+    let view = |row: Option<&LineState>| match row {
+        Some(row) if row.view != 0 => format!(" (view {})", row.view),
+        _ => String::new(),
+    };
+
+    match *instruction {
+        LineInstruction::Special(opcode) => format!(
+            "Special opcode {}: advance Address by {} to {} and Line by {} to {}{}",
+            opcode - header.opcode_base,
+            header.special_address_advance(opcode),
+            c_hex(after.address),
+            header.special_line_advance(opcode),
+            after.line,
+            view(row)
+        ),
+        LineInstruction::Copy => format!("Copy{}", view(row)),
+        LineInstruction::AdvancePc(_) => format!(
+            "Advance PC by {} to {}",
+            after.address.wrapping_sub(before.address),
+            c_hex(after.address)
+        ),
+        LineInstruction::AdvanceLine(advance) => {
+            format!("Advance Line by {advance} to {}", after.line)
+        }
+        LineInstruction::SetFile(file) => {
+            format!("Set File Name to entry {file} in the File Name Table")
+        }
+        LineInstruction::SetColumn(column) => format!("Set column to {column}"),
+        LineInstruction::NegateStmt => format!("Set is_stmt to {}", after.is_stmt as u8),
+        LineInstruction::SetBasicBlock => "Set basic block".to_string(),
+        LineInstruction::ConstAddPc => format!(
+            "Advance PC by constant {} to {}",
+            header.const_add_pc_advance(),
+            c_hex(after.address)
+        ),
+        LineInstruction::FixedAdvancePc(advance) => format!(
+            "Advance PC by fixed size amount {advance} to {}",
+            c_hex(after.address)
+        ),
+        LineInstruction::SetPrologueEnd => "Set prologue_end to true".to_string(),
+        LineInstruction::SetEpilogueBegin => "Set epilogue_begin to true".to_string(),
+        LineInstruction::SetIsa(isa) => format!("Set ISA to {isa}"),
+        LineInstruction::UnknownStandard {
+            opcode,
+            ref operands,
+        } => {
+            let operands: Vec<String> = operands.iter().map(|operand| c_hex(*operand)).collect();
+            format!(
+                "Unknown opcode {opcode} with operands: {}",
+                operands.join(", ")
+            )
+        }
+        LineInstruction::EndSequence => "Extended opcode 1: End of Sequence\n".to_string(),
+        LineInstruction::SetAddress(address) => {
+            format!("Extended opcode 2: set Address to {}", c_hex(address))
+        }
+        // printed with its number by the caller
+        LineInstruction::DefineFile(_) => String::new(),
+        LineInstruction::SetDiscriminator(discriminator) => {
+            format!("Extended opcode 4: set Discriminator to {discriminator}")
+        }
+        LineInstruction::UnknownExtended { opcode, ref data } => {
+            let kind = if opcode >= DW_LNE_lo_user {
+                "user defined"
+            } else {
+                "UNKNOWN"
+            };
+            let bytes: String = data.iter().map(|byte| format!(" {byte:02x}")).collect();
+            format!(
+                "Extended opcode {opcode}: {kind}: length {} [{bytes}]",
+                data.len()
+            )
+        }
+    }
+}
+
+// -wL, the rows of the line tables with the file names
+pub fn print_debug_line_decoded(
+    out: &mut dyn Write,
+    dwarf: &Dwarf,
+    name: &'static str,
+    wide: bool,
+) -> io::Result<Vec<ElfError>> {
+    let mut problems = Vec::new();
+    let mut reader = dwarf.reader(name);
+    writeln!(out, "Contents of the {} section:\n", dwarf.title(name))?;
+
+    while let Some((header, mut program)) = next_program(&mut reader, &mut problems) {
+        writeln!(out, "{}", unit_label(dwarf, &header, wide))?;
+        writeln!(
+            out,
+            "File name                            Line number    Starting address    View    Stmt"
+        )?;
+
+        let mut state = LineState::new(&header);
+        while !program.is_empty() {
+            let instruction = match parse_line_instruction(&mut program, &header) {
+                Ok(instruction) => instruction,
+                Err(err) => {
+                    problems.push(err);
+                    break;
+                }
+            };
+
+            if let LineInstruction::SetFile(file) = instruction {
+                writeln!(out, "\n{}", file_label(dwarf, &header, file))?;
+            }
+
+            let Some(row) = state.execute(&instruction, &header) else {
+                continue;
+            };
+
+            let file = header.file(row.file).map(|file| file_name(dwarf, file));
+            let mut file = file.unwrap_or_else(|| "<unknown>".to_string());
+            if !wide && file.len() > MAX_FILE_NAME_LENGTH {
+                let mut start = file.len() - MAX_FILE_NAME_LENGTH;
+                while !file.is_char_boundary(start) {
+                    start += 1;
+                }
+                file = file[start..].to_string();
+            }
+
+            if row.end_sequence {
+                writeln!(out, "{file:<35}  {:>11}  {:>18}\n", "-", c_hex(row.address))?;
+                continue;
+            }
+
+            let view = if row.view != 0 {
+                format!("  {:>6}", row.view)
+            } else {
+                " ".repeat(8)
+            };
+            let stmt = if row.is_stmt { "       x" } else { "" };
+            writeln!(
+                out,
+                "{file:<35}  {:>11}  {:>18}{view}{stmt}",
+                row.line,
+                c_hex(row.address)
+            )?;
+        }
+        writeln!(out)?;
+    }
+
+    Ok(problems)
+}
+
+fn file_name(dwarf: &Dwarf, file: &LineEntry) -> String {
+    entry_string(dwarf, file.path())
+}
+
+// the name the rows of a unit start with, the one of its first file
+fn unit_label(dwarf: &Dwarf, header: &LineProgramHeader, wide: bool) -> String {
+    let Some(file) = header.files.first() else {
+        return "CU: No directory table".to_string();
+    };
+    let name = file_name(dwarf, file);
+
+    if header.directories.is_empty() {
+        return format!("CU: {name}:");
+    }
+    if !wide {
+        return format!("{name}:");
+    }
+
+    let index = file.directory_index();
+    let directory = if index == 0 && header.version < 5 {
+        ".".to_string()
+    } else {
+        match header.directory(index) {
+            Some(directory) => entry_string(dwarf, directory.path()),
+            None => "<unknown>".to_string(),
+        }
+    };
+    format!("CU: {directory}/{name}:")
+}
+
+// what DW_LNS_set_file shows, the file with its directory. Before DWARF 5
+// the directory 0 is the one of the compilation, that binutils shows as .
+fn file_label(dwarf: &Dwarf, header: &LineProgramHeader, file: u64) -> String {
+    let Some(entry) = header.file(file) else {
+        return format!("<file {file} is not in the File Name Table>:");
+    };
+    let name = file_name(dwarf, entry);
+
+    let index = entry.directory_index();
+    if index == 0 && header.version < 5 {
+        return format!("./{name}:[++]");
+    }
+    match header.directory(index) {
+        Some(directory) => format!("{}/{name}:", entry_string(dwarf, directory.path())),
+        None => format!("{name}:"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::print_debug_line_decoded;
+    use crate::output::dwarf::tests::create_dwarf;
+
+    // a DWARF 3 program for "u.c" and "inc/sq.h": set_address 0x10, special
+    // (address +1, line +1), set_file 2, copy, end_sequence
+    fn create_program() -> Vec<u8> {
+        let mut data = vec![0, 0, 0, 0, 3, 0, 0, 0, 0, 0];
+        data.extend([1, 1, 0xfb, 14, 13]);
+        data.extend([0, 1, 1, 1, 1, 0, 0, 0, 1, 0, 0, 1]);
+        data.extend(b"inc\0\0");
+        data.extend(b"u.c\0\0\0\0sq.h\0\x01\0\0\0");
+        let header_length = (data.len() - 10) as u32;
+        data[6..10].copy_from_slice(&header_length.to_le_bytes());
+
+        data.extend([0, 9, 2, 0x10, 0, 0, 0, 0, 0, 0, 0]);
+        data.extend([13 + 6 + 14, 4, 2, 1, 0, 1, 1]);
+        let length = (data.len() - 4) as u32;
+        data[0..4].copy_from_slice(&length.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn print_debug_line_decoded_shows_the_rows_with_their_files() {
+        let dwarf = create_dwarf(vec![(".debug_line", create_program())]);
+
+        let mut out = Vec::new();
+        let problems = print_debug_line_decoded(&mut out, &dwarf, ".debug_line", false).unwrap();
+
+        assert!(problems.is_empty());
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "Contents of the .debug_line section:\n\n\
+             u.c:\n\
+             File name                            Line number    Starting address    View    Stmt\n\
+             u.c                                            2                0x11               x\n\
+             \n\
+             inc/sq.h:\n\
+             sq.h                                           2                0x11       1       x\n\
+             sq.h                                           -                0x11\n\n\n"
+        );
+    }
+}
@@ -0,0 +1,510 @@
+use crate::elf::dwarf::lists::{
+    ListEntry, parse_list_table_header, parse_location_entry, parse_loclist_entry,
+    parse_range_entry, parse_rnglist_entry,
+};
+use crate::elf::dwarf::reader::DwarfReader;
+use crate::elf::dwarf::{Dwarf, ListKind, ListReference, UnitBases};
+use crate::elf::error::ElfError;
+use crate::output::dwarf::{c_hex, format_expression};
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::io::{self, Write};
+
+// what the entries of a list are read with: the *x ones are indexes in
+// .debug_addr, from the base of the unit that refers to the list
+struct ListContext<'a> {
+    dwarf: &'a Dwarf,
+    section: &'static str,
+    bases: UnitBases,
+    address_size: u8,
+}
+
+impl ListContext<'_> {
+    // the digits of an address
+    fn width(&self) -> usize {
+        2 * self.address_size as usize
+    }
+
+    // the (0, 0) entries before DWARF 5 with relocations are not the end of
+    // their list
+    fn is_relocated(&self, offset: u64) -> bool {
+        self.dwarf.is_relocated(self.section, offset)
+            || self
+                .dwarf
+                .is_relocated(self.section, offset + self.address_size as u64)
+    }
+
+    fn address(&self, offset: u64, index: u64, problems: &mut Vec<ElfError>) -> u64 {
+        match self
+            .dwarf
+            .address(index, self.bases.addr_base, self.address_size)
+        {
+            Some(address) => address,
+            None => {
+                problems.push(ElfError::invalid_value(
+                    offset,
+                    self.section,
+                    "index",
+                    "an index in .debug_addr",
+                    index,
+                ));
+                0
+            }
+        }
+    }
+
+    // the addresses an entry covers, the base address is the one of the
+    // offset pairs
+    fn range(
+        &self,
+        offset: u64,
+        entry: &ListEntry,
+        base: u64,
+        problems: &mut Vec<ElfError>,
+    ) -> Option<(u64, u64)> {
+        Some(match *entry {
+            ListEntry::StartxEndx(start, end) => (
+                self.address(offset, start, problems),
+                self.address(offset, end, problems),
+            ),
+            ListEntry::StartxLength(start, length) => {
+                let start = self.address(offset, start, problems);
+                (start, start.wrapping_add(length))
+            }
+            ListEntry::OffsetPair(start, end) => (base.wrapping_add(start), base.wrapping_add(end)),
+            ListEntry::StartEnd(start, end) => (start, end),
+            ListEntry::StartLength(start, length) => (start, start.wrapping_add(length)),
+            _ => return None,
+        })
+    }
+}
+
+// the units that refer to the lists of a section. A list that no unit refers
+// to gets the bases of the one before it
+fn list_references(
+    dwarf: &Dwarf,
+    kind: ListKind,
+    dwo: bool,
+    problems: &mut Vec<ElfError>,
+) -> Vec<ListReference> {
+    dwarf.list_references(kind, dwo).unwrap_or_else(|err| {
+        problems.push(err);
+        Vec::new()
+    })
+}
+
+fn reference_at(references: &[ListReference], offset: u64) -> Option<&ListReference> {
+    references
+        .iter()
+        .take_while(|reference| reference.offset <= offset)
+        .last()
+        .or(references.first())
+}
+
+// binutils marks the ranges that cover nothing
+fn empty_range(begin: u64, end: u64) -> &'static str {
+    match begin.cmp(&end) {
+        Ordering::Equal => " (start == end)",
+        Ordering::Greater => " (start > end)",
+        Ordering::Less => "",
+    }
+}
+
+// -wR of .debug_ranges, the lists before DWARF 5, one after the other
+pub fn print_debug_ranges(
+    out: &mut dyn Write,
+    dwarf: &Dwarf,
+    name: &'static str,
+) -> io::Result<Vec<ElfError>> {
+    let mut problems = Vec::new();
+    let references = list_references(dwarf, ListKind::Ranges, false, &mut problems);
+    let mut reader = dwarf.reader(name);
+
+    writeln!(out, "Contents of the {} section:\n\n", dwarf.title(name))?;
+    writeln!(out, "    Offset   Begin    End")?;
+
+    'lists: while !reader.is_empty() {
+        let start = reader.offset();
+        let reference = reference_at(&references, start);
+        let context = ListContext {
+            dwarf,
+            section: name,
+            bases: reference.map_or_else(UnitBases::default, |reference| reference.bases),
+            address_size: reference.map_or(8, |reference| reference.encoding.address_size),
+        };
+        let width = context.width();
+
+        let mut base = context.bases.low_pc;
+        loop {
+            let offset = reader.offset();
+            let entry = match parse_range_entry(
+                &mut reader,
+                context.address_size,
+                context.is_relocated(offset),
+            ) {
+                Ok(entry) => entry,
+                Err(err) => {
+                    problems.push(err);
+                    break 'lists;
+                }
+            };
+
+            match entry {
+                ListEntry::EndOfList => {
+                    writeln!(out, "    {start:08x} <End of list>")?;
+                    break;
+                }
+                ListEntry::BaseAddress(address) => {
+                    base = address;
+                    writeln!(out, "    {start:08x} {address:0width$x} (base address)")?;
+                }
+                ref entry => {
+                    if let Some((begin, end)) = context.range(offset, entry, base, &mut problems) {
+                        writeln!(
+                            out,
+                            "    {start:08x} {begin:0width$x} {end:0width$x}{}",
+                            empty_range(begin, end)
+                        )?;
+                    }
+                }
+            }
+        }
+    }
+
+    writeln!(out)?;
+    Ok(problems)
+}
+
+// -wR of .debug_rnglists(.dwo), the tables with their headers and every
+// list of them
+pub fn print_debug_rnglists(
+    out: &mut dyn Write,
+    dwarf: &Dwarf,
+    name: &'static str,
+) -> io::Result<Vec<ElfError>> {
+    let mut problems = Vec::new();
+    let references = list_references(
+        dwarf,
+        ListKind::Ranges,
+        name.ends_with(".dwo"),
+        &mut problems,
+    );
+    let mut reader = dwarf.reader(name);
+
+    writeln!(out, "Contents of the {} section:\n", dwarf.title(name))?;
+
+    while !reader.is_empty() {
+        let header = match parse_list_table_header(&mut reader.clone()) {
+            Ok(header) => header,
+            Err(err) => {
+                problems.push(err);
+                match reader.clone().initial_length() {
+                    Ok((length, format)) => {
+                        reader.split(length.saturating_add(format.length_size() as u64));
+                        continue;
+                    }
+                    Err(_) => break,
+                }
+            }
+        };
+
+        writeln!(out, " Table at Offset: {}:", c_hex(header.offset))?;
+        writeln!(out, "  Length:          0x{:x}", header.length)?;
+        writeln!(out, "  DWARF version:   {}", header.version)?;
+        writeln!(out, "  Address size:    {}", header.address_size)?;
+        writeln!(out, "  Segment size:    {}", header.segment_selector_size)?;
+        writeln!(out, "  Offset entries:  {}\n", header.offset_entry_count)?;
+
+        if !header.offsets.is_empty() {
+            writeln!(
+                out,
+                "   Offsets starting at {}:",
+                c_hex(header.offsets_offset)
+            )?;
+            for (index, offset) in header.offsets.iter().enumerate() {
+                writeln!(out, "    [{index:6}] {}", c_hex(*offset))?;
+            }
+            writeln!(out)?;
+        }
+
+        let mut lists = reader.split(header.end - header.offset);
+        lists.seek(header.lists_offset);
+
+        let mut ordinal = 0;
+        'lists: while !lists.is_empty() {
+            let start = lists.offset();
+            // the index of the list in the offsets, when the table has them
+            let index = header
+                .offsets
+                .iter()
+                .position(|&offset| header.offsets_offset.wrapping_add(offset) == start)
+                .unwrap_or(ordinal);
+            ordinal += 1;
+
+            let context = ListContext {
+                dwarf,
+                section: name,
+                bases: reference_at(&references, start)
+                    .map_or_else(UnitBases::default, |reference| reference.bases),
+                address_size: header.address_size,
+            };
+            let width = context.width();
+
+            writeln!(out, "  Offset: {start:#x}, Index: {index}")?;
+            writeln!(out, "    Offset   Begin    End")?;
+
+            let mut base = context.bases.low_pc;
+            loop {
+                let offset = lists.offset();
+                let entry = match parse_rnglist_entry(&mut lists, header.address_size) {
+                    Ok(entry) => entry,
+                    Err(err) => {
+                        problems.push(err);
+                        break 'lists;
+                    }
+                };
+
+                match entry {
+                    ListEntry::EndOfList => {
+                        writeln!(out, "    {offset:08x} <End of list>\n")?;
+                        break;
+                    }
+                    ListEntry::BaseAddress(address) => {
+                        base = address;
+                        writeln!(out, "    {offset:08x} {address:0width$x} (base address)")?;
+                    }
+                    ListEntry::BaseAddressx(index) => {
+                        base = context.address(offset, index, &mut problems);
+                        writeln!(
+                            out,
+                            "    {offset:08x} {index:0width$x} (base address index) \
+                             {base:0width$x} (base address)"
+                        )?;
+                    }
+                    ref entry => {
+                        if let Some((begin, end)) =
+                            context.range(offset, entry, base, &mut problems)
+                        {
+                            writeln!(
+                                out,
+                                "    {offset:08x} {begin:0width$x} {end:0width$x} {}",
+                                empty_range(begin, end)
+                            )?;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(problems)
+}
+
+// -wo, the location lists that the units refer to, with their views and
+// expressions. .debug_loc has the lists before DWARF 5, .debug_loclists(.dwo)
+// the ones after
+pub fn print_debug_loc(
+    out: &mut dyn Write,
+    dwarf: &Dwarf,
+    name: &'static str,
+) -> io::Result<Vec<ElfError>> {
+    let mut problems = Vec::new();
+    let is_loclists = name.starts_with(".debug_loclists");
+    let references = list_references(
+        dwarf,
+        ListKind::Locations,
+        name.ends_with(".dwo"),
+        &mut problems,
+    );
+
+    writeln!(out, "Contents of the {} section:\n", dwarf.title(name))?;
+    writeln!(
+        out,
+        "    Offset   Begin            End              Expression"
+    )?;
+
+    for reference in &references {
+        let context = ListContext {
+            dwarf,
+            section: name,
+            bases: reference.bases,
+            address_size: reference.encoding.address_size,
+        };
+        let width = context.width();
+        let mut reader = dwarf.reader(name);
+
+        // the views of the entries are pairs before the list, in the order
+        // of the entries
+        let mut views = VecDeque::new();
+        if let Some(views_offset) = reference.views.filter(|&views| views < reference.offset) {
+            writeln!(out)?;
+            reader.seek(views_offset);
+            if let Err(err) = read_view_pairs(&mut reader, reference.offset, &mut views) {
+                problems.push(err);
+            }
+            for &(offset, begin, end) in &views {
+                writeln!(
+                    out,
+                    "    {offset:08x} v{begin:0view_width$x} v{end:0view_width$x} location view pair",
+                    view_width = width - 1
+                )?;
+            }
+            writeln!(out)?;
+        }
+
+        reader.seek(reference.offset);
+        let mut base = context.bases.low_pc;
+        loop {
+            let offset = reader.offset();
+            let entry = if is_loclists {
+                parse_loclist_entry(&mut reader, context.address_size)
+            } else {
+                parse_location_entry(
+                    &mut reader,
+                    context.address_size,
+                    context.is_relocated(offset),
+                )
+            };
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(err) => {
+                    problems.push(err);
+                    break;
+                }
+            };
+
+            let expression = entry.expression.as_deref().map(|expression| {
+                format_expression(
+                    expression,
+                    dwarf,
+                    &reference.encoding,
+                    reference.unit_offset,
+                )
+            });
+
+            match entry.entry {
+                ListEntry::EndOfList => {
+                    writeln!(out, "    {offset:08x} <End of list>")?;
+                    break;
+                }
+                ListEntry::BaseAddress(address) => {
+                    base = address;
+                    writeln!(out, "    {offset:08x} {address:0width$x} (base address)")?;
+                }
+                ListEntry::BaseAddressx(index) => {
+                    base = context.address(offset, index, &mut problems);
+                    writeln!(
+                        out,
+                        "    {offset:08x} {index:0width$x} (base address index) \
+                         {base:0width$x} (base address)"
+                    )?;
+                }
+                // the views of the next entry
+                ListEntry::ViewPair(begin, end) => views.push_back((offset, begin, end)),
+                ListEntry::DefaultLocation => writeln!(
+                    out,
+                    "    {offset:08x} <default location> ({})",
+                    expression.unwrap_or_default()
+                )?,
+                ref range => {
+                    let Some((begin, end)) = context.range(offset, range, base, &mut problems)
+                    else {
+                        continue;
+                    };
+                    let expression = expression.unwrap_or_default();
+                    match views.pop_front() {
+                        Some((views_offset, view_begin, view_end)) => writeln!(
+                            out,
+                            "    {offset:08x} v{view_begin:0view_width$x} v{view_end:0view_width$x} \
+                             views at {views_offset:08x} for:\n             \
+                             {begin:0width$x} {end:0width$x} ({expression})",
+                            view_width = width - 1
+                        )?,
+                        None => writeln!(
+                            out,
+                            "    {offset:08x} {begin:0width$x} {end:0width$x} ({expression})"
+                        )?,
+                    }
+                }
+            }
+        }
+    }
+
+    writeln!(out)?;
+    Ok(problems)
+}
+
+// the view pairs from the current offset of the reader to the list at end
+fn read_view_pairs(
+    reader: &mut DwarfReader,
+    end: u64,
+    views: &mut VecDeque<(u64, u64, u64)>,
+) -> Result<(), ElfError> {
+    while reader.offset() < end {
+        let offset = reader.offset();
+        let begin = reader.uleb128("view_begin")?;
+        let end = reader.uleb128("view_end")?;
+        views.push_back((offset, begin, end));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{print_debug_ranges, print_debug_rnglists};
+    use crate::output::dwarf::tests::create_dwarf;
+
+    #[test]
+    fn print_debug_ranges_adds_the_base_address_to_the_pairs() {
+        let mut data = vec![0xff; 8];
+        for value in [0x1000u64, 0x10, 0x20, 0, 0] {
+            data.extend(value.to_le_bytes());
+        }
+        let dwarf = create_dwarf(vec![(".debug_ranges", data)]);
+
+        let mut out = Vec::new();
+        let problems = print_debug_ranges(&mut out, &dwarf, ".debug_ranges").unwrap();
+
+        assert!(problems.is_empty());
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "Contents of the .debug_ranges section:\n\n\n    \
+             Offset   Begin    End\n    \
+             00000000 0000000000001000 (base address)\n    \
+             00000000 0000000000001010 0000000000001020\n    \
+             00000000 <End of list>\n\n"
+        );
+    }
+
+    #[test]
+    fn print_debug_rnglists_marks_the_empty_ranges() {
+        // a table without offsets and a list of three DW_RLE_offset_pair,
+        // from a compressed section
+        let mut data = vec![0x12, 0, 0, 0, 5, 0, 8, 0, 0, 0, 0, 0];
+        data.extend([4, 0x10, 0x10, 4, 0x20, 0x30, 4, 0x40, 0x30, 0]);
+        let mut dwarf = create_dwarf(vec![(".debug_rnglists", data)]);
+        dwarf.sections[0].file_name = ".zdebug_rnglists".to_string();
+
+        let mut out = Vec::new();
+        let problems = print_debug_rnglists(&mut out, &dwarf, ".debug_rnglists").unwrap();
+
+        assert!(problems.is_empty());
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "Contents of the .zdebug_rnglists section:\n\n \
+             Table at Offset: 0:\n  \
+             Length:          0x12\n  \
+             DWARF version:   5\n  \
+             Address size:    8\n  \
+             Segment size:    0\n  \
+             Offset entries:  0\n\n  \
+             Offset: 0xc, Index: 0\n    \
+             Offset   Begin    End\n    \
+             0000000c 0000000000000010 0000000000000010  (start == end)\n    \
+             0000000f 0000000000000020 0000000000000030 \n    \
+             00000012 0000000000000040 0000000000000030  (start > end)\n    \
+             00000015 <End of list>\n\n"
+        );
+    }
+}
@@ -0,0 +1,445 @@
+use crate::elf::def::elf64strc::{Elf64_Dyn, Elf64_Ehdr};
+use crate::elf::def::elfconsts::*;
+use crate::elf::dynamic::{Elf64Dynamic, dynamic_name};
+use crate::elf::osabi::is_solaris;
+use std::io::{self, Write};
+
+pub fn print_dynamic_section(
+    out: &mut dyn Write,
+    header: &Elf64_Ehdr,
+    dynamic: Option<&Elf64Dynamic>,
+    strings: Option<&[u8]>,
+    interpreter: Option<&str>,
+) -> io::Result<()> {
+    let Some(dynamic) = dynamic.filter(|dynamic| !dynamic.entries.is_empty()) else {
+        return writeln!(out, "\nThere is no dynamic section in this file.");
+    };
+
+    let count = dynamic.entries.len();
+    writeln!(
+        out,
+        "\nDynamic section at offset {:#x} contains {count} {}:",
+        dynamic.offset,
+        if count == 1 { "entry" } else { "entries" }
+    )?;
+    writeln!(out, "  Tag        Type                         Name/Value")?;
+
+    let class = header.class();
+    let width = class.address_width();
+    // the names are padded to the end of the column, that is wider in the
+    // 32-bit files since their tags are narrower
+    let column: usize = if class.is_64bit() { 19 } else { 27 };
+
+    for entry in &dynamic.entries {
+        let type_name = dynamic_type_name(header, entry.d_tag);
+        // but there is always one space. The longer names are padded by the
+        // same amount that they went past the column, since binutils uses a
+        // negative "%*s" width for them
+        let padding = column.abs_diff(type_name.len()).max(1);
+        // the 32-bit tags were sign extended at parse time
+        let tag = if class.is_64bit() {
+            entry.d_tag as u64
+        } else {
+            entry.d_tag as u32 as u64
+        };
+
+        write!(out, " 0x{tag:0width$x} ({type_name}){:padding$}", "")?;
+        writeln!(
+            out,
+            "{}",
+            dynamic_value(header, entry, strings, interpreter)
+        )?;
+    }
+
+    Ok(())
+}
+
+// how the value is shown depends on the tag: names, sizes, counts, flags and,
+// for everything else, an address in hex
+#[allow(clippy::match_overlapping_arm)]
+fn dynamic_value(
+    header: &Elf64_Ehdr,
+    entry: &Elf64_Dyn,
+    strings: Option<&[u8]>,
+    interpreter: Option<&str>,
+) -> String {
+    let value = entry.d_val();
+    let name = dynamic_name(strings, value);
+
+    match entry.tag() {
+        DT_FLAGS => dynamic_flags(value),
+        DT_FLAGS_1 => flag_names(value, DT_FLAGS_1_NAMES),
+        DT_FEATURE_1 => flag_names(
+            value,
+            &[(DTF_1_PARINIT, "PARINIT"), (DTF_1_CONFEXP, "CONFEXP")],
+        ),
+        DT_POSFLAG_1 => flag_names(
+            value,
+            &[(DF_P1_LAZYLOAD, "LAZYLOAD"), (DF_P1_GROUPPERM, "GROUPPERM")],
+        ),
+        DT_AUXILIARY | DT_FILTER | DT_CONFIG | DT_DEPAUDIT | DT_AUDIT => {
+            let kind = match entry.tag() {
+                DT_AUXILIARY => "Auxiliary library",
+                DT_FILTER => "Filter library",
+                DT_CONFIG => "Configuration file",
+                DT_DEPAUDIT => "Dependency audit library",
+                _ => "Audit library",
+            };
+
+            match name {
+                Some(name) => format!("{kind}: [{name}]"),
+                None => format!("{kind}: {value:#x}"),
+            }
+        }
+        DT_PLTREL => dynamic_type_name(header, value as i64),
+        DT_NEEDED => match name {
+            Some(name) if interpreter == Some(name.as_ref()) => {
+                format!("Shared library: [{name}] program interpreter")
+            }
+            Some(name) => format!("Shared library: [{name}]"),
+            None => format!("{value:#x}"),
+        },
+        DT_SONAME | DT_RPATH | DT_RUNPATH => {
+            let kind = match entry.tag() {
+                DT_SONAME => "Library soname",
+                DT_RPATH => "Library rpath",
+                _ => "Library runpath",
+            };
+
+            match name {
+                Some(name) => format!("{kind}: [{name}]"),
+                None => format!("{value:#x}"),
+            }
+        }
+        DT_PLTRELSZ | DT_RELASZ | DT_STRSZ | DT_RELSZ | DT_RELAENT | DT_RELRENT | DT_RELRSZ
+        | DT_SYMENT | DT_RELENT | DT_PLTPADSZ | DT_MOVEENT | DT_MOVESZ | DT_PREINIT_ARRAYSZ
+        | DT_INIT_ARRAYSZ | DT_FINI_ARRAYSZ | DT_GNU_CONFLICTSZ | DT_GNU_LIBLISTSZ => {
+            format!("{value} (bytes)")
+        }
+        DT_VERDEFNUM | DT_VERNEEDNUM | DT_RELACOUNT | DT_RELCOUNT => value.to_string(),
+        // the value of this one is ignored
+        DT_BIND_NOW => String::new(),
+        DT_GNU_PRELINKED => prelink_time(value),
+        DT_AARCH64_BTI_PLT | DT_AARCH64_PAC_PLT if header.e_machine as u32 == EM_AARCH64 => {
+            String::new()
+        }
+        _ => format!("{value:#x}"),
+    }
+}
+
+const DT_FLAGS_1_NAMES: &[(u32, &str)] = &[
+    (DF_1_NOW, "NOW"),
+    (DF_1_GLOBAL, "GLOBAL"),
+    (DF_1_GROUP, "GROUP"),
+    (DF_1_NODELETE, "NODELETE"),
+    (DF_1_LOADFLTR, "LOADFLTR"),
+    (DF_1_INITFIRST, "INITFIRST"),
+    (DF_1_NOOPEN, "NOOPEN"),
+    (DF_1_ORIGIN, "ORIGIN"),
+    (DF_1_DIRECT, "DIRECT"),
+    (DF_1_TRANS, "TRANS"),
+    (DF_1_INTERPOSE, "INTERPOSE"),
+    (DF_1_NODEFLIB, "NODEFLIB"),
+    (DF_1_NODUMP, "NODUMP"),
+    (DF_1_CONFALT, "CONFALT"),
+    (DF_1_ENDFILTEE, "ENDFILTEE"),
+    (DF_1_DISPRELDNE, "DISPRELDNE"),
+    (DF_1_DISPRELPND, "DISPRELPND"),
+    (DF_1_NODIRECT, "NODIRECT"),
+    (DF_1_IGNMULDEF, "IGNMULDEF"),
+    (DF_1_NOKSYMS, "NOKSYMS"),
+    (DF_1_NOHDR, "NOHDR"),
+    (DF_1_EDITED, "EDITED"),
+    (DF_1_NORELOC, "NORELOC"),
+    (DF_1_SYMINTPOSE, "SYMINTPOSE"),
+    (DF_1_GLOBAUDIT, "GLOBAUDIT"),
+    (DF_1_SINGLETON, "SINGLETON"),
+    (DF_1_STUB, "STUB"),
+    (DF_1_PIE, "PIE"),
+    (DF_1_KMOD, "KMOD"),
+    (DF_1_WEAKFILTER, "WEAKFILTER"),
+    (DF_1_NOCOMMON, "NOCOMMON"),
+];
+
+// DT_FLAGS_1 and friends are shown as "Flags: NOW PIE", the bits without a
+// name are shown together in hex at the end
+fn flag_names(value: u64, names: &[(u32, &str)]) -> String {
+    if value == 0 {
+        return "Flags: None".to_string();
+    }
+
+    let mut flags = "Flags:".to_string();
+    let mut remaining = value;
+
+    for &(flag, name) in names {
+        if remaining & flag as u64 != 0 {
+            flags.push(' ');
+            flags.push_str(name);
+            remaining ^= flag as u64;
+        }
+    }
+
+    if remaining != 0 {
+        flags.push_str(&format!(" {remaining:x}"));
+    }
+
+    flags
+}
+
+// DT_FLAGS has no "Flags:" prefix, and every unknown bit is shown by itself
+fn dynamic_flags(value: u64) -> String {
+    let mut names = Vec::new();
+    let mut remaining = value;
+
+    while remaining != 0 {
+        let flag = remaining & remaining.wrapping_neg();
+        remaining &= !flag;
+
+        names.push(match u32::try_from(flag).unwrap_or(0) {
+            DF_ORIGIN => "ORIGIN",
+            DF_SYMBOLIC => "SYMBOLIC",
+            DF_TEXTREL => "TEXTREL",
+            DF_BIND_NOW => "BIND_NOW",
+            DF_STATIC_TLS => "STATIC_TLS",
+            _ => "unknown",
+        });
+    }
+
+    names.join(" ")
+}
+
+// DT_GNU_PRELINKED holds the time (in seconds, UTC) of the prelink
+fn prelink_time(seconds: u64) -> String {
+    let days = seconds / 86400;
+    let time = seconds % 86400;
+
+    // days to a civil date, from the "chrono-compatible low-level date
+    // algorithms" of Howard Hinnant
+    let z = days + 719468;
+    let era = z / 146097;
+    let doe = z % 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}",
+        time / 3600,
+        time % 3600 / 60,
+        time % 60
+    )
+}
+
+#[allow(clippy::match_overlapping_arm)]
+pub fn dynamic_type_name(header: &Elf64_Ehdr, d_tag: i64) -> String {
+    let tag = u32::try_from(d_tag).unwrap_or(u32::MAX);
+
+    let name = match tag {
+        DT_NULL => "NULL",
+        DT_NEEDED => "NEEDED",
+        DT_PLTRELSZ => "PLTRELSZ",
+        DT_PLTGOT => "PLTGOT",
+        DT_HASH => "HASH",
+        DT_STRTAB => "STRTAB",
+        DT_SYMTAB => "SYMTAB",
+        DT_RELA => "RELA",
+        DT_RELASZ => "RELASZ",
+        DT_RELAENT => "RELAENT",
+        DT_STRSZ => "STRSZ",
+        DT_SYMENT => "SYMENT",
+        DT_INIT => "INIT",
+        DT_FINI => "FINI",
+        DT_SONAME => "SONAME",
+        DT_RPATH => "RPATH",
+        DT_SYMBOLIC => "SYMBOLIC",
+        DT_REL => "REL",
+        DT_RELSZ => "RELSZ",
+        DT_RELENT => "RELENT",
+        DT_RELR => "RELR",
+        DT_RELRSZ => "RELRSZ",
+        DT_RELRENT => "RELRENT",
+        DT_PLTREL => "PLTREL",
+        DT_DEBUG => "DEBUG",
+        DT_TEXTREL => "TEXTREL",
+        DT_JMPREL => "JMPREL",
+        DT_BIND_NOW => "BIND_NOW",
+        DT_INIT_ARRAY => "INIT_ARRAY",
+        DT_FINI_ARRAY => "FINI_ARRAY",
+        DT_INIT_ARRAYSZ => "INIT_ARRAYSZ",
+        DT_FINI_ARRAYSZ => "FINI_ARRAYSZ",
+        DT_RUNPATH => "RUNPATH",
+        DT_FLAGS => "FLAGS",
+        DT_PREINIT_ARRAY => "PREINIT_ARRAY",
+        DT_PREINIT_ARRAYSZ => "PREINIT_ARRAYSZ",
+        DT_SYMTAB_SHNDX => "SYMTAB_SHNDX",
+        DT_CHECKSUM => "CHECKSUM",
+        DT_PLTPADSZ => "PLTPADSZ",
+        DT_MOVEENT => "MOVEENT",
+        DT_MOVESZ => "MOVESZ",
+        DT_FEATURE_1 => "FEATURE_1",
+        DT_POSFLAG_1 => "POSFLAG_1",
+        DT_SYMINSZ => "SYMINSZ",
+        DT_SYMINENT => "SYMINENT",
+        DT_CONFIG => "CONFIG",
+        DT_DEPAUDIT => "DEPAUDIT",
+        DT_AUDIT => "AUDIT",
+        DT_PLTPAD => "PLTPAD",
+        DT_MOVETAB => "MOVETAB",
+        DT_SYMINFO => "SYMINFO",
+        DT_VERSYM => "VERSYM",
+        DT_TLSDESC_GOT => "TLSDESC_GOT",
+        DT_TLSDESC_PLT => "TLSDESC_PLT",
+        DT_RELACOUNT => "RELACOUNT",
+        DT_RELCOUNT => "RELCOUNT",
+        DT_FLAGS_1 => "FLAGS_1",
+        DT_VERDEF => "VERDEF",
+        DT_VERDEFNUM => "VERDEFNUM",
+        DT_VERNEED => "VERNEED",
+        DT_VERNEEDNUM => "VERNEEDNUM",
+        DT_AUXILIARY => "AUXILIARY",
+        DT_FILTER => "FILTER",
+        DT_GNU_PRELINKED => "GNU_PRELINKED",
+        DT_GNU_CONFLICT => "GNU_CONFLICT",
+        DT_GNU_CONFLICTSZ => "GNU_CONFLICTSZ",
+        DT_GNU_LIBLIST => "GNU_LIBLIST",
+        DT_GNU_LIBLISTSZ => "GNU_LIBLISTSZ",
+        DT_GNU_HASH => "GNU_HASH",
+        DT_LOPROC..=DT_HIPROC => {
+            return match processor_dynamic_type_name(header.e_machine as u32, tag) {
+                Some(name) => name.to_string(),
+                None => format!("Processor Specific: {d_tag:x}"),
+            };
+        }
+        DT_LOOS..=DT_HIOS => {
+            return match os_dynamic_type_name(header, tag) {
+                Some(name) => name.to_string(),
+                None => format!("Operating System specific: {d_tag:x}"),
+            };
+        }
+        _ => return format!("<unknown>: {d_tag:x}"),
+    };
+
+    name.to_string()
+}
+
+// the GNU tags above are used by everyone, only Solaris gives a meaning to
+// the rest of the DT_LOOS..DT_HIOS range
+fn os_dynamic_type_name(header: &Elf64_Ehdr, tag: u32) -> Option<&'static str> {
+    if !is_solaris(header) {
+        return None;
+    }
+
+    let name = match tag {
+        DT_SUNW_AUXILIARY => "SUNW_AUXILIARY",
+        DT_SUNW_RTLDINF => "SUNW_RTLDINF",
+        DT_SUNW_FILTER => "SUNW_FILTER",
+        DT_SUNW_CAP => "SUNW_CAP",
+        DT_SUNW_SYMTAB => "SUNW_SYMTAB",
+        DT_SUNW_SYMSZ => "SUNW_SYMSZ",
+        DT_SUNW_SORTENT => "SUNW_SORTENT",
+        DT_SUNW_SYMSORT => "SUNW_SYMSORT",
+        DT_SUNW_SYMSORTSZ => "SUNW_SYMSORTSZ",
+        DT_SUNW_TLSSORT => "SUNW_TLSSORT",
+        DT_SUNW_TLSSORTSZ => "SUNW_TLSSORTSZ",
+        DT_SUNW_CAPINFO => "SUNW_CAPINFO",
+        DT_SUNW_STRPAD => "SUNW_STRPAD",
+        DT_SUNW_CAPCHAIN => "SUNW_CAPCHAIN",
+        DT_SUNW_LDMACH => "SUNW_LDMACH",
+        DT_SUNW_CAPCHAINENT => "SUNW_CAPCHAINENT",
+        DT_SUNW_CAPCHAINSZ => "SUNW_CAPCHAINSZ",
+        DT_SUNW_PARENT => "SUNW_PARENT",
+        DT_SUNW_ASLR => "SUNW_ASLR",
+        DT_SUNW_RELAX => "SUNW_RELAX",
+        DT_SUNW_NXHEAP => "SUNW_NXHEAP",
+        DT_SUNW_NXSTACK => "SUNW_NXSTACK",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+// same idea of the section types, the DT_LOPROC..DT_HIPROC range is
+// different for each machine
+fn processor_dynamic_type_name(e_machine: u32, tag: u32) -> Option<&'static str> {
+    let name = match (e_machine, tag) {
+        (EM_AARCH64, DT_AARCH64_BTI_PLT) => "AARCH64_BTI_PLT",
+        (EM_AARCH64, DT_AARCH64_PAC_PLT) => "AARCH64_PAC_PLT",
+        (EM_AARCH64, DT_AARCH64_VARIANT_PCS) => "AARCH64_VARIANT_PCS",
+        (EM_PPC, DT_PPC_GOT) => "PPC_GOT",
+        (EM_PPC, DT_PPC_OPT) => "PPC_OPT",
+        (EM_PPC64, DT_PPC64_GLINK) => "PPC64_GLINK",
+        (EM_PPC64, DT_PPC64_OPD) => "PPC64_OPD",
+        (EM_PPC64, DT_PPC64_OPDSZ) => "PPC64_OPDSZ",
+        (EM_PPC64, DT_PPC64_OPT) => "PPC64_OPT",
+        (EM_RISCV, DT_RISCV_VARIANT_CC) => "RISCV_VARIANT_CC",
+        (EM_SPARCV9, DT_SPARC_REGISTER) => "SPARC_REGISTER",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DT_FLAGS_1_NAMES, dynamic_flags, dynamic_type_name, flag_names, prelink_time};
+    use crate::test_utils::create_header;
+
+    #[test]
+    fn flag_names_shows_known_bits_and_the_rest_in_hex() {
+        assert_eq!(flag_names(0x8000001, DT_FLAGS_1_NAMES), "Flags: NOW PIE");
+        assert_eq!(flag_names(0, DT_FLAGS_1_NAMES), "Flags: None");
+        assert_eq!(
+            flag_names(0x80000008, DT_FLAGS_1_NAMES),
+            "Flags: NODELETE 80000000"
+        );
+    }
+
+    #[test]
+    fn dynamic_flags_are_shown_from_the_lowest_bit() {
+        assert_eq!(dynamic_flags(0x8), "BIND_NOW");
+        assert_eq!(dynamic_flags(0x1c), "TEXTREL BIND_NOW STATIC_TLS");
+        assert_eq!(dynamic_flags(0x21), "ORIGIN unknown");
+    }
+
+    #[test]
+    fn dynamic_type_name_depends_on_the_machine_for_processor_tags() {
+        assert_eq!(
+            dynamic_type_name(&create_header(3, 183), 0x70000001),
+            "AARCH64_BTI_PLT"
+        );
+        assert_eq!(
+            dynamic_type_name(&create_header(3, 21), 0x70000000),
+            "PPC64_GLINK"
+        );
+        assert_eq!(
+            dynamic_type_name(&create_header(3, 62), 0x70000001),
+            "Processor Specific: 70000001"
+        );
+        assert_eq!(
+            dynamic_type_name(&create_header(3, 62), 0x6ffffef5),
+            "GNU_HASH"
+        );
+        assert_eq!(dynamic_type_name(&create_header(3, 62), 0x50), "<unknown>: 50");
+    }
+
+    #[test]
+    fn dynamic_type_name_uses_solaris_names_only_for_solaris() {
+        let mut header = create_header(3, 62);
+        assert_eq!(
+            dynamic_type_name(&header, 0x60000010),
+            "Operating System specific: 60000010"
+        );
+
+        header.e_ident[7] = 6;
+        assert_eq!(dynamic_type_name(&header, 0x60000010), "SUNW_CAP");
+    }
+
+    #[test]
+    fn prelink_time_is_shown_as_an_utc_date() {
+        assert_eq!(prelink_time(0), "1970-01-01T00:00:00");
+        assert_eq!(prelink_time(1700000000), "2023-11-14T22:13:20");
+    }
+}
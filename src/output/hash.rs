@@ -0,0 +1,95 @@
+use std::io::{self, Write};
+
+// -I, how many buckets of the hash table have each length of chain, and how
+// many of the symbols are in the buckets up to that length. The SysV table
+// has no name in the title (binutils finds it by DT_HASH), the GNU one has
+pub fn print_histogram(
+    out: &mut dyn Write,
+    name: Option<&str>,
+    lengths: &[usize],
+) -> io::Result<()> {
+    let buckets = if lengths.len() == 1 {
+        "bucket"
+    } else {
+        "buckets"
+    };
+    let table = name.map(|name| format!("`{name}' ")).unwrap_or_default();
+
+    writeln!(
+        out,
+        "\nHistogram for {table}bucket list length (total of {} {buckets}):",
+        lengths.len()
+    )?;
+    writeln!(out, " Length  Number     % of total  Coverage")?;
+
+    if lengths.is_empty() {
+        return Ok(());
+    }
+
+    let longest = lengths.iter().copied().max().unwrap_or_default();
+    let mut counts = vec![0usize; longest + 1];
+    for &length in lengths {
+        counts[length] += 1;
+    }
+
+    let total = lengths.len() as f64;
+    let symbols: usize = lengths.iter().sum();
+
+    writeln!(
+        out,
+        "      0  {:<10} ({:5.1}%)",
+        counts[0],
+        counts[0] as f64 * 100.0 / total
+    )?;
+
+    let mut covered = 0;
+    for (length, &count) in counts.iter().enumerate().skip(1) {
+        covered += count * length;
+        writeln!(
+            out,
+            "{length:7}  {count:<10} ({:5.1}%)    {:5.1}%",
+            count as f64 * 100.0 / total,
+            covered as f64 * 100.0 / symbols as f64
+        )?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::print_histogram;
+
+    #[test]
+    fn print_histogram_counts_the_buckets_of_each_length() {
+        let mut out = Vec::new();
+
+        print_histogram(&mut out, Some(".gnu.hash"), &[0, 1, 4]).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\nHistogram for `.gnu.hash' bucket list length (total of 3 buckets):
+ Length  Number     % of total  Coverage
+      0  1          ( 33.3%)
+      1  1          ( 33.3%)     20.0%
+      2  0          (  0.0%)     20.0%
+      3  0          (  0.0%)     20.0%
+      4  1          ( 33.3%)    100.0%
+"
+        );
+    }
+
+    #[test]
+    fn print_histogram_has_no_rows_without_buckets() {
+        let mut out = Vec::new();
+
+        print_histogram(&mut out, None, &[]).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\nHistogram for bucket list length (total of 0 buckets):
+ Length  Number     % of total  Coverage
+"
+        );
+    }
+}
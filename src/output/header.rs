@@ -0,0 +1,278 @@
+use crate::elf::def::elf64strc::{Elf64_Ehdr, Elf64_Shdr};
+use crate::elf::def::elfconsts::*;
+use crate::elf::machine::{machine_info, machine_name};
+use crate::elf::osabi;
+use std::io::{self, Write};
+
+// first_section is the section 0, that has the counts that dont fit in the
+// header (None when the file has no sections)
+pub fn print_file_header(
+    out: &mut dyn Write,
+    header: &Elf64_Ehdr,
+    first_section: Option<&Elf64_Shdr>,
+    is_pie: bool,
+) -> io::Result<()> {
+    let e_ident = &header.e_ident;
+
+    writeln!(out, "ELF Header:")?;
+    write!(out, "  Magic:   ")?;
+    for byte in e_ident {
+        write!(out, "{byte:02x} ")?;
+    }
+    writeln!(out)?;
+
+    write_field(out, "Class:", class_name(e_ident[4]))?;
+    write_field(out, "Data:", data_name(e_ident[5]))?;
+    write_field(out, "Version:", ident_version_name(e_ident[6]))?;
+    write_field(out, "OS/ABI:", osabi_name(e_ident[7], header.e_machine))?;
+    write_field(out, "ABI Version:", e_ident[8].to_string())?;
+    write_field(out, "Type:", file_type_name(header.e_type, is_pie))?;
+    write_field(out, "Machine:", machine_name(header.e_machine))?;
+    write_field(out, "Version:", format!("{:#x}", header.e_version))?;
+    write_field(
+        out,
+        "Entry point address:",
+        format!("{:#x}", header.e_entry),
+    )?;
+    write_field(
+        out,
+        "Start of program headers:",
+        format!("{} (bytes into file)", header.e_phoff),
+    )?;
+    write_field(
+        out,
+        "Start of section headers:",
+        format!("{} (bytes into file)", header.e_shoff),
+    )?;
+    write_field(out, "Flags:", flags(header))?;
+    write_field(
+        out,
+        "Size of this header:",
+        format!("{} (bytes)", header.e_ehsize),
+    )?;
+    write_field(
+        out,
+        "Size of program headers:",
+        format!("{} (bytes)", header.e_phentsize),
+    )?;
+    write_field(
+        out,
+        "Number of program headers:",
+        format!(
+            "{}{}",
+            header.e_phnum,
+            phnum_extension(header, first_section)
+        ),
+    )?;
+    write_field(
+        out,
+        "Size of section headers:",
+        format!("{} (bytes)", header.e_shentsize),
+    )?;
+    write_field(
+        out,
+        "Number of section headers:",
+        format!(
+            "{}{}",
+            header.e_shnum,
+            shnum_extension(header, first_section)
+        ),
+    )?;
+    write_field(
+        out,
+        "Section header string table index:",
+        format!(
+            "{}{}",
+            header.e_shstrndx,
+            shstrndx_extension(header, first_section)
+        ),
+    )
+}
+
+// when the counts dont fit in the header, the real ones (from the section 0)
+// are shown after the raw values, like "0 (70000)"
+fn phnum_extension(header: &Elf64_Ehdr, first_section: Option<&Elf64_Shdr>) -> String {
+    match first_section {
+        Some(shdr) if header.e_phnum as u32 == PN_XNUM && shdr.sh_info != 0 => {
+            format!(" ({})", shdr.sh_info)
+        }
+        _ => String::new(),
+    }
+}
+
+fn shnum_extension(header: &Elf64_Ehdr, first_section: Option<&Elf64_Shdr>) -> String {
+    match first_section {
+        Some(shdr) if header.e_shnum as u32 == SHN_UNDEF => format!(" ({})", shdr.sh_size),
+        _ => String::new(),
+    }
+}
+
+fn shstrndx_extension(header: &Elf64_Ehdr, first_section: Option<&Elf64_Shdr>) -> String {
+    match first_section {
+        Some(shdr) if header.e_shstrndx as u32 == SHN_XINDEX => format!(" ({})", shdr.sh_link),
+        _ => String::new(),
+    }
+}
+
+// GNU readelf aligns every value at the column 37, so the labels are padded
+// to 35 chars after the two spaces of indentation
+fn write_field(out: &mut dyn Write, label: &str, value: impl AsRef<str>) -> io::Result<()> {
+    writeln!(out, "  {label:<35}{}", value.as_ref())
+}
+
+// the flags are machine specific, the ones that we know are decoded after
+// the raw value
+// nothing is decoded when every flag is clear, not even the defaults (like
+// the soft-float ABI of RISC-V)
+fn flags(header: &Elf64_Ehdr) -> String {
+    let decoded = machine_info(header.e_machine)
+        .filter(|_| header.e_flags != 0)
+        .and_then(|info| info.flags)
+        .map(|decode| decode(header.e_flags))
+        .unwrap_or_default();
+
+    format!("{:#x}{decoded}", header.e_flags)
+}
+
+fn class_name(class: u8) -> String {
+    match class {
+        0 => "none".to_string(),
+        1 => "ELF32".to_string(),
+        2 => "ELF64".to_string(),
+        _ => format!("<unknown: {class:x}>"),
+    }
+}
+
+fn data_name(data: u8) -> String {
+    match data {
+        0 => "none".to_string(),
+        1 => "2's complement, little endian".to_string(),
+        2 => "2's complement, big endian".to_string(),
+        _ => format!("<unknown: {data:x}>"),
+    }
+}
+
+fn ident_version_name(version: u8) -> String {
+    match version {
+        0 => "0".to_string(),
+        1 => "1 (current)".to_string(),
+        _ => format!("{version} <unknown>"),
+    }
+}
+
+// the names of the values from 64 depend on the machine
+fn osabi_name(osabi: u8, e_machine: u16) -> String {
+    match osabi::osabi_name(osabi, e_machine) {
+        Some(name) => name.to_string(),
+        None => format!("<unknown: {osabi:x}>"),
+    }
+}
+
+// ET_DYN is used by both shared objects and PIEs, that are told apart by the
+// DF_1_PIE flag of the dynamic section
+pub fn file_type_name(e_type: u16, is_pie: bool) -> String {
+    match e_type {
+        0 => "NONE (None)".to_string(),
+        1 => "REL (Relocatable file)".to_string(),
+        2 => "EXEC (Executable file)".to_string(),
+        3 if is_pie => "DYN (Position-Independent Executable file)".to_string(),
+        3 => "DYN (Shared object file)".to_string(),
+        4 => "CORE (Core file)".to_string(),
+        0xfe00..=0xfeff => format!("OS Specific: ({e_type:x})"),
+        0xff00..=0xffff => format!("Processor Specific: ({e_type:x})"),
+        _ => format!("<unknown>: {e_type:x}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::print_file_header;
+    use crate::elf::def::elf64strc::{Elf64_Ehdr, Elf64_Shdr};
+
+    #[test]
+    fn print_file_header_matches_gnu_readelf_layout() {
+        let header = Elf64_Ehdr {
+            e_ident: [0x7f, 0x45, 0x4c, 0x46, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            e_type: 2,
+            e_machine: 62,
+            e_version: 1,
+            e_entry: 0x401000,
+            e_phoff: 64,
+            e_shoff: 14880,
+            e_flags: 0,
+            e_ehsize: 64,
+            e_phentsize: 56,
+            e_phnum: 14,
+            e_shentsize: 64,
+            e_shnum: 38,
+            e_shstrndx: 37,
+        };
+        let mut out = Vec::new();
+
+        print_file_header(&mut out, &header, None, false).unwrap();
+
+        let expected = "\
+ELF Header:
+  Magic:   7f 45 4c 46 02 01 01 00 00 00 00 00 00 00 00 00 
+  Class:                             ELF64
+  Data:                              2's complement, little endian
+  Version:                           1 (current)
+  OS/ABI:                            UNIX - System V
+  ABI Version:                       0
+  Type:                              EXEC (Executable file)
+  Machine:                           Advanced Micro Devices X86-64
+  Version:                           0x1
+  Entry point address:               0x401000
+  Start of program headers:          64 (bytes into file)
+  Start of section headers:          14880 (bytes into file)
+  Flags:                             0x0
+  Size of this header:               64 (bytes)
+  Size of program headers:           56 (bytes)
+  Number of program headers:         14
+  Size of section headers:           64 (bytes)
+  Number of section headers:         38
+  Section header string table index: 37
+";
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+    }
+
+    #[test]
+    fn print_file_header_shows_the_counts_from_the_section_0() {
+        let header = Elf64_Ehdr {
+            e_ident: [0x7f, 0x45, 0x4c, 0x46, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            e_type: 1,
+            e_machine: 62,
+            e_version: 1,
+            e_entry: 0,
+            e_phoff: 0,
+            e_shoff: 64,
+            e_flags: 0,
+            e_ehsize: 64,
+            e_phentsize: 0,
+            e_phnum: 0,
+            e_shentsize: 64,
+            e_shnum: 0,
+            e_shstrndx: 0xffff,
+        };
+        let first = Elf64_Shdr {
+            sh_name: 0,
+            sh_type: 0,
+            sh_flags: 0,
+            sh_addr: 0,
+            sh_offset: 0,
+            sh_size: 70000,
+            sh_link: 69999,
+            sh_info: 0,
+            sh_addralign: 0,
+            sh_entsize: 0,
+        };
+        let mut out = Vec::new();
+
+        print_file_header(&mut out, &header, Some(&first), false).unwrap();
+
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("  Number of program headers:         0\n"));
+        assert!(out.contains("  Number of section headers:         0 (70000)\n"));
+        assert!(out.contains("  Section header string table index: 65535 (69999)\n"));
+    }
+}
@@ -0,0 +1,54 @@
+use crate::elf::error::ElfError;
+use std::io::{self, Write};
+
+// GNU readelf has nothing like it, so the problems are just listed one per
+// line, with the structure that they are about in front of the message
+pub fn print_lint(out: &mut dyn Write, problems: &[ElfError]) -> io::Result<()> {
+    match problems.len() {
+        0 => return writeln!(out, "\nLint: no problems found"),
+        1 => writeln!(out, "\nLint: 1 problem found:")?,
+        count => writeln!(out, "\nLint: {count} problems found:")?,
+    }
+
+    for problem in problems {
+        writeln!(out, "  {}: {problem}", problem.structure)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::print_lint;
+    use crate::elf::error::ElfError;
+
+    #[test]
+    fn print_lint_lists_every_problem_with_its_structure() {
+        let problems = [
+            ElfError::invalid_value(0x34, "Elf64_Ehdr", "e_ehsize", "64", 0x38u16),
+            ElfError::out_of_bounds(0x28, "Elf64_Shdr", "e_shoff", (0x1000, 0x40), 0x800),
+        ];
+        let mut out = Vec::new();
+
+        print_lint(&mut out, &problems).unwrap();
+
+        let expected = "
+Lint: 2 problems found:
+  Elf64_Ehdr: at offset 0x34 (e_ehsize): expected 64, found 0x38
+  Elf64_Shdr: at offset 0x28 (e_shoff): 0x40 bytes at 0x1000 run past the end of the file (0x800 bytes)
+";
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+    }
+
+    #[test]
+    fn print_lint_says_when_the_file_is_fine() {
+        let mut out = Vec::new();
+
+        print_lint(&mut out, &[]).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\nLint: no problems found\n"
+        );
+    }
+}
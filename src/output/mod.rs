@@ -0,0 +1,100 @@
+// everything that is printed to the user lives here. The parsing side
+// (crate::elf) only knows about raw values (and the names that binutils gives
+// to the machines and relocations), and this module is the one that turns
+// them into the same text that GNU readelf shows
+pub mod archive;
+pub mod build_attributes;
+pub mod dump;
+pub mod dwarf;
+pub mod dynamic;
+pub mod hash;
+pub mod header;
+pub mod lint;
+pub mod notes;
+pub mod relocations;
+pub mod sections;
+pub mod segments;
+pub mod symbols;
+pub mod versions;
+
+// names (of sections, symbols...) are shown in fixed width columns. Outside of
+// the wide mode, the names that dont fit are cut and get a "[...]" at the end,
+// and control chars are shown as ^X, like binutils does
+pub fn fit_name(name: &str, width: usize, wide: bool) -> String {
+    let mut shown = truncate_name(name, width, wide);
+
+    let used = shown.chars().count();
+    if used < width {
+        shown.push_str(&" ".repeat(width - used));
+    }
+
+    shown
+}
+
+// the same of fit_name, but without the padding, for the names that are in
+// the last column
+pub fn truncate_name(name: &str, width: usize, wide: bool) -> String {
+    let mut shown = String::new();
+    let mut used = 0;
+
+    let truncate = !wide && name.chars().count() > width;
+    let limit = if truncate { width.saturating_sub(5) } else { usize::MAX };
+
+    for c in name.chars() {
+        let repr = if (c as u32) < 0x20 {
+            format!("^{}", ((c as u8) + 0x40) as char)
+        } else {
+            c.to_string()
+        };
+
+        if used + repr.chars().count() > limit {
+            break;
+        }
+
+        used += repr.chars().count();
+        shown.push_str(&repr);
+    }
+
+    if truncate {
+        shown.push_str("[...]");
+    }
+
+    shown
+}
+
+// C's "%#x" shows 0 without the prefix, and binutils uses it in a lot of
+// places
+pub fn alternate_hex(value: u64) -> String {
+    if value == 0 {
+        "0".to_string()
+    } else {
+        format!("{value:#x}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fit_name, truncate_name};
+
+    #[test]
+    fn fit_name_pads_short_names() {
+        assert_eq!(fit_name(".text", 8, false), ".text   ");
+    }
+
+    #[test]
+    fn fit_name_truncates_long_names_only_outside_of_wide_mode() {
+        let name = ".a_really_long_section_name_here";
+
+        assert_eq!(fit_name(name, 17, false), ".a_really_lo[...]");
+        assert_eq!(fit_name(name, 17, true), name);
+    }
+
+    #[test]
+    fn truncate_name_does_not_pad() {
+        assert_eq!(truncate_name("main", 21, false), "main");
+        assert_eq!(
+            truncate_name("_ITM_deregisterTMCloneTable", 21, false),
+            "_ITM_deregisterT[...]"
+        );
+    }
+}
@@ -0,0 +1,886 @@
+use crate::elf::def::elf64strc::Elf64_Ehdr;
+use crate::elf::def::elfconsts::*;
+use crate::elf::note::{Elf64Note, Elf64NoteArea};
+use crate::elf::symbol::Elf64Symbol;
+use crate::output::build_attributes::{
+    BuildAttributeRanges, build_attribute_owner, is_build_attribute,
+};
+use crate::output::{alternate_hex, fit_name, truncate_name};
+use crate::utils::endianess::EndianRead;
+use std::io::{self, Write};
+
+pub fn print_notes(
+    out: &mut dyn Write,
+    header: &Elf64_Ehdr,
+    area: &Elf64NoteArea,
+    notes: &[Elf64Note],
+    symbols: &[Elf64Symbol],
+    wide: bool,
+) -> io::Result<()> {
+    match &area.section {
+        Some(name) => writeln!(
+            out,
+            "\nDisplaying notes found in: {}",
+            truncate_name(name, 0, true)
+        )?,
+        None => writeln!(
+            out,
+            "\nDisplaying notes found at file offset 0x{:08x} with length 0x{:08x}:",
+            area.offset, area.size
+        )?,
+    }
+
+    writeln!(out, "  {:<20} {:<10}\tDescription", "Owner", "Data size")?;
+
+    // the build attributes without a range use the one of the last note,
+    // and the symbols give a name to them
+    let mut ranges = BuildAttributeRanges::new(symbols);
+
+    for note in notes {
+        print_note(out, header, note, &mut ranges, wide)?;
+    }
+
+    Ok(())
+}
+
+// core files dont have any notes without the segments
+pub fn print_no_notes(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "No notes found file.")
+}
+
+fn print_note(
+    out: &mut dyn Write,
+    header: &Elf64_Ehdr,
+    note: &Elf64Note,
+    ranges: &mut BuildAttributeRanges,
+    wide: bool,
+) -> io::Result<()> {
+    let owner = if is_build_attribute(note) {
+        build_attribute_owner(note, wide)
+    } else if note.nhdr.n_namesz == 0 {
+        fit_name("(NONE)", 20, wide)
+    } else {
+        fit_name(&note.name, 20, wide)
+    };
+
+    write!(
+        out,
+        "  {owner} 0x{:08x}\t{}",
+        note.nhdr.n_descsz,
+        note_type_name(header, note)
+    )?;
+
+    // in the wide mode the description stays in the same line
+    if wide {
+        write!(out, "\t")?;
+    } else {
+        writeln!(out)?;
+    }
+
+    let is_core = header.e_type as u32 == ET_CORE;
+    let name = note.name.as_str();
+
+    if name.starts_with("GNU") {
+        print_gnu_note(out, header, note, wide)
+    } else if name.starts_with("stapsdt") {
+        print_stapsdt_note(out, header, &note.desc)
+    } else if name.starts_with("CORE") {
+        match note.nhdr.n_type {
+            NT_FILE => print_mapped_files(out, header, &note.desc),
+            _ => print_nothing(out, wide),
+        }
+    } else if name.starts_with("FDO") {
+        match note.nhdr.n_type {
+            NT_FDO_PACKAGING_METADATA if !note.desc.is_empty() => {
+                writeln!(out, "    Packaging Metadata: {}", desc_string(&note.desc))
+            }
+            _ => Ok(()),
+        }
+    } else if is_build_attribute(note) {
+        ranges.print(out, header, note)
+    } else if name == "Go" && note.nhdr.n_type == NT_GO_BUILDID && !is_core {
+        // the go linker writes its own build id, that is a plain string
+        writeln!(out, "    Build ID: {}", desc_string(&note.desc))
+    } else if name.starts_with("FreeBSD") && !is_core {
+        print_freebsd_note(out, header, note, wide)
+    } else {
+        print_hex_dump(out, &note.desc, wide)
+    }
+}
+
+// the names given by binutils (the FreeBSD tags of the executables, that it
+// confuses with NT_VERSION, are named like llvm-readelf does)
+fn note_type_name(header: &Elf64_Ehdr, note: &Elf64Note) -> String {
+    let name = note.name.as_str();
+    let n_type = note.nhdr.n_type;
+    let is_core = header.e_type as u32 == ET_CORE;
+
+    let known = if note.nhdr.n_namesz == 0 {
+        None
+    } else if name.starts_with("GNU") {
+        gnu_note_type_name(n_type)
+    } else if name.starts_with("FreeBSD") {
+        freebsd_note_type_name(n_type, is_core)
+    } else if name.starts_with("stapsdt") {
+        match n_type {
+            NT_STAPSDT => Some("NT_STAPSDT (SystemTap probe descriptors)"),
+            _ => return unknown_note_type_name(n_type),
+        }
+    } else {
+        None
+    };
+
+    known
+        .or_else(|| default_note_type_name(n_type, is_core))
+        .map(str::to_string)
+        .unwrap_or_else(|| unknown_note_type_name(n_type))
+}
+
+fn unknown_note_type_name(n_type: u32) -> String {
+    format!("Unknown note type: (0x{n_type:08x})")
+}
+
+fn gnu_note_type_name(n_type: u32) -> Option<&'static str> {
+    let name = match n_type {
+        NT_GNU_ABI_TAG => "NT_GNU_ABI_TAG (ABI version tag)",
+        NT_GNU_HWCAP => "NT_GNU_HWCAP (DSO-supplied software HWCAP info)",
+        NT_GNU_BUILD_ID => "NT_GNU_BUILD_ID (unique build ID bitstring)",
+        NT_GNU_GOLD_VERSION => "NT_GNU_GOLD_VERSION (gold version)",
+        NT_GNU_PROPERTY_TYPE_0 => "NT_GNU_PROPERTY_TYPE_0",
+        NT_GNU_BUILD_ATTRIBUTE_OPEN => "NT_GNU_BUILD_ATTRIBUTE_OPEN",
+        NT_GNU_BUILD_ATTRIBUTE_FUNC => "NT_GNU_BUILD_ATTRIBUTE_FUNC",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+// the executables have tags, and the cores have the process information
+fn freebsd_note_type_name(n_type: u32, is_core: bool) -> Option<&'static str> {
+    let name = match n_type {
+        NT_FREEBSD_ABI_TAG if !is_core => "NT_FREEBSD_ABI_TAG (ABI version tag)",
+        NT_FREEBSD_NOINIT_TAG if !is_core => "NT_FREEBSD_NOINIT_TAG (no .init tag)",
+        NT_FREEBSD_ARCH_TAG if !is_core => "NT_FREEBSD_ARCH_TAG (architecture tag)",
+        NT_FREEBSD_FEATURE_CTL if !is_core => "NT_FREEBSD_FEATURE_CTL (FreeBSD feature control)",
+        NT_FREEBSD_THRMISC => "NT_THRMISC (thrmisc structure)",
+        NT_FREEBSD_PROCSTAT_PROC => "NT_PROCSTAT_PROC (proc data)",
+        NT_FREEBSD_PROCSTAT_FILES => "NT_PROCSTAT_FILES (files data)",
+        NT_FREEBSD_PROCSTAT_VMMAP => "NT_PROCSTAT_VMMAP (vmmap data)",
+        NT_FREEBSD_PROCSTAT_GROUPS => "NT_PROCSTAT_GROUPS (groups data)",
+        NT_FREEBSD_PROCSTAT_UMASK => "NT_PROCSTAT_UMASK (umask data)",
+        NT_FREEBSD_PROCSTAT_RLIMIT => "NT_PROCSTAT_RLIMIT (rlimit data)",
+        NT_FREEBSD_PROCSTAT_OSREL => "NT_PROCSTAT_OSREL (osreldate data)",
+        NT_FREEBSD_PROCSTAT_PSSTRINGS => "NT_PROCSTAT_PSSTRINGS (ps_strings data)",
+        NT_FREEBSD_PROCSTAT_AUXV => "NT_PROCSTAT_AUXV (auxv data)",
+        NT_FREEBSD_PTLWPINFO => "NT_PTLWPINFO (ptrace_lwpinfo structure)",
+        NT_FREEBSD_X86_SEGBASES => "NT_X86_SEGBASES (x86 segment base registers)",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+// used for the owners without their own types, like "CORE" and "LINUX"
+fn default_note_type_name(n_type: u32, is_core: bool) -> Option<&'static str> {
+    let name = if is_core {
+        match n_type {
+            NT_AUXV => "NT_AUXV (auxiliary vector)",
+            NT_PRSTATUS => "NT_PRSTATUS (prstatus structure)",
+            NT_FPREGSET => "NT_FPREGSET (floating point registers)",
+            NT_PRPSINFO => "NT_PRPSINFO (prpsinfo structure)",
+            NT_TASKSTRUCT => "NT_TASKSTRUCT (task structure)",
+            NT_PRXFPREG => "NT_PRXFPREG (user_xfpregs structure)",
+            NT_PPC_VMX => "NT_PPC_VMX (ppc Altivec registers)",
+            NT_PPC_VSX => "NT_PPC_VSX (ppc VSX registers)",
+            NT_386_TLS => "NT_386_TLS (x86 TLS information)",
+            NT_386_IOPERM => "NT_386_IOPERM (x86 I/O permissions)",
+            NT_X86_XSTATE => "NT_X86_XSTATE (x86 XSAVE extended state)",
+            NT_S390_HIGH_GPRS => "NT_S390_HIGH_GPRS (s390 upper register halves)",
+            NT_ARM_VFP => "NT_ARM_VFP (arm VFP registers)",
+            NT_ARM_TLS => "NT_ARM_TLS (AArch TLS registers)",
+            NT_ARM_HW_BREAK => "NT_ARM_HW_BREAK (AArch hardware breakpoint registers)",
+            NT_ARM_HW_WATCH => "NT_ARM_HW_WATCH (AArch hardware watchpoint registers)",
+            NT_ARM_SYSTEM_CALL => "NT_ARM_SYSTEM_CALL (AArch system call number)",
+            NT_ARM_SVE => "NT_ARM_SVE (AArch SVE registers)",
+            NT_ARM_PAC_MASK => "NT_ARM_PAC_MASK (AArch pointer authentication code masks)",
+            NT_ARM_TAGGED_ADDR_CTRL => "NT_ARM_TAGGED_ADDR_CTRL (AArch tagged address control)",
+            NT_PSTATUS => "NT_PSTATUS (pstatus structure)",
+            NT_FPREGS => "NT_FPREGS (floating point registers)",
+            NT_PSINFO => "NT_PSINFO (psinfo structure)",
+            NT_LWPSTATUS => "NT_LWPSTATUS (lwpstatus_t structure)",
+            NT_LWPSINFO => "NT_LWPSINFO (lwpsinfo_t structure)",
+            NT_WIN32PSTATUS => "NT_WIN32PSTATUS (win32_pstatus structure)",
+            NT_SIGINFO => "NT_SIGINFO (siginfo_t data)",
+            NT_FILE => "NT_FILE (mapped files)",
+            _ => return None,
+        }
+    } else {
+        match n_type {
+            NT_VERSION => "NT_VERSION (version)",
+            NT_ARCH => "NT_ARCH (architecture)",
+            NT_GNU_BUILD_ATTRIBUTE_OPEN => "OPEN",
+            NT_GNU_BUILD_ATTRIBUTE_FUNC => "func",
+            NT_GO_BUILDID => "GO BUILDID",
+            NT_FDO_PACKAGING_METADATA => "FDO_PACKAGING_METADATA",
+            _ => return None,
+        }
+    };
+
+    Some(name)
+}
+
+// the descriptors that are strings can have a NUL (and padding) at the end
+fn desc_string(desc: &[u8]) -> String {
+    let string = desc.split(|&byte| byte == 0).next().unwrap_or_default();
+    String::from_utf8_lossy(string).into_owned()
+}
+
+fn print_nothing(out: &mut dyn Write, wide: bool) -> io::Result<()> {
+    if wide { writeln!(out) } else { Ok(()) }
+}
+
+fn print_hex_dump(out: &mut dyn Write, desc: &[u8], wide: bool) -> io::Result<()> {
+    if !desc.is_empty() {
+        write!(out, "   description data: ")?;
+        for byte in desc {
+            write!(out, "{byte:02x} ")?;
+        }
+
+        if !wide {
+            writeln!(out)?;
+        }
+    }
+
+    print_nothing(out, wide)
+}
+
+fn print_gnu_note(
+    out: &mut dyn Write,
+    header: &Elf64_Ehdr,
+    note: &Elf64Note,
+    wide: bool,
+) -> io::Result<()> {
+    let desc = &note.desc;
+    let is_little_endian = header.is_little_endian();
+    let word = |index: usize| u32::read_from(&desc[index * 4..index * 4 + 4], is_little_endian);
+
+    match note.nhdr.n_type {
+        NT_GNU_BUILD_ID => {
+            write!(out, "    Build ID: ")?;
+            for byte in desc {
+                write!(out, "{byte:02x}")?;
+            }
+            writeln!(out)
+        }
+        NT_GNU_ABI_TAG if desc.len() < 16 => writeln!(out, "    <corrupt GNU_ABI_TAG>"),
+        NT_GNU_ABI_TAG => {
+            let os = match word(0) {
+                ELF_NOTE_OS_LINUX => "Linux",
+                ELF_NOTE_OS_GNU => "Hurd",
+                ELF_NOTE_OS_SOLARIS2 => "Solaris",
+                ELF_NOTE_OS_FREEBSD => "FreeBSD",
+                ELF_NOTE_OS_NETBSD => "NetBSD",
+                ELF_NOTE_OS_SYLLABLE => "Syllable",
+                ELF_NOTE_OS_NACL => "NaCl",
+                _ => "Unknown",
+            };
+
+            writeln!(
+                out,
+                "    OS: {os}, ABI: {}.{}.{}",
+                word(1),
+                word(2),
+                word(3)
+            )
+        }
+        NT_GNU_GOLD_VERSION => writeln!(out, "    Version: {}", desc_string(desc)),
+        NT_GNU_HWCAP => {
+            write!(out, "      Hardware Capabilities: ")?;
+            if desc.len() < 8 {
+                return writeln!(out, "<corrupt GNU_HWCAP>");
+            }
+
+            // the entries after the mask (a bit number and a name) are not
+            // shown by binutils either
+            writeln!(out, "num entries: {}, enabled mask: {:x}", word(0), word(1))
+        }
+        NT_GNU_PROPERTY_TYPE_0 => print_gnu_properties(out, header, desc, wide),
+        _ => {
+            write!(out, "    Description data: ")?;
+            for byte in desc {
+                write!(out, "{byte:02x} ")?;
+            }
+            writeln!(out)
+        }
+    }
+}
+
+// every property is a type, the size of its data, and the data padded to
+// the size of an address
+fn print_gnu_properties(
+    out: &mut dyn Write,
+    header: &Elf64_Ehdr,
+    desc: &[u8],
+    wide: bool,
+) -> io::Result<()> {
+    write!(out, "      Properties: ")?;
+
+    // the properties are padded to the size of an address of the class
+    let address_size = header.class().address_size();
+
+    if desc.len() < 8 || !desc.len().is_multiple_of(address_size) {
+        return writeln!(
+            out,
+            "<corrupt GNU_PROPERTY_TYPE, size = {}>",
+            alternate_hex(desc.len() as u64)
+        );
+    }
+
+    let is_little_endian = header.is_little_endian();
+    let mut position = 0;
+
+    while position < desc.len() {
+        if desc.len() - position < 8 {
+            write!(
+                out,
+                "<corrupt descsz: {}>",
+                alternate_hex(desc.len() as u64)
+            )?;
+            break;
+        }
+
+        let pr_type = u32::read_from(&desc[position..position + 4], is_little_endian);
+        let pr_datasz =
+            u32::read_from(&desc[position + 4..position + 8], is_little_endian) as usize;
+        position += 8;
+
+        if pr_datasz > desc.len() - position {
+            write!(
+                out,
+                "<corrupt type ({}) datasz: {}>",
+                alternate_hex(pr_type as u64),
+                alternate_hex(pr_datasz as u64)
+            )?;
+            break;
+        }
+
+        let data = &desc[position..position + pr_datasz];
+        write!(out, "{}", gnu_property(header, pr_type, data))?;
+
+        position += pr_datasz.next_multiple_of(address_size);
+        if position == desc.len() {
+            break;
+        }
+
+        if wide {
+            write!(out, ", ")?;
+        } else {
+            write!(out, "\n\t")?;
+        }
+    }
+
+    writeln!(out)
+}
+
+// the specific values must come before the ranges that contain them
+#[allow(clippy::match_overlapping_arm)]
+fn gnu_property(header: &Elf64_Ehdr, pr_type: u32, data: &[u8]) -> String {
+    let e_machine = header.e_machine as u32;
+    let is_little_endian = header.is_little_endian();
+    let corrupt = || format!("<corrupt length: {}> ", alternate_hex(data.len() as u64));
+
+    // most of the properties are a single word with a bitmask
+    let bitmask = |label: &str, decode: fn(u32) -> String| {
+        if data.len() == 4 {
+            format!(
+                "{label}: {}",
+                decode(u32::read_from(data, is_little_endian))
+            )
+        } else {
+            format!("{label}: {}", corrupt())
+        }
+    };
+
+    let is_x86 = matches!(e_machine, EM_X86_64 | EM_386 | EM_IAMCU);
+
+    match pr_type {
+        GNU_PROPERTY_X86_ISA_1_USED if is_x86 => bitmask("x86 ISA used", x86_isa),
+        GNU_PROPERTY_X86_ISA_1_NEEDED if is_x86 => bitmask("x86 ISA needed", x86_isa),
+        GNU_PROPERTY_X86_FEATURE_1_AND if is_x86 => bitmask("x86 feature", x86_feature_1),
+        GNU_PROPERTY_X86_FEATURE_2_USED if is_x86 => bitmask("x86 feature used", x86_feature_2),
+        GNU_PROPERTY_X86_FEATURE_2_NEEDED if is_x86 => bitmask("x86 feature needed", x86_feature_2),
+        GNU_PROPERTY_X86_COMPAT_ISA_1_USED if is_x86 => bitmask("x86 ISA used", x86_compat_isa),
+        GNU_PROPERTY_X86_COMPAT_ISA_1_NEEDED if is_x86 => bitmask("x86 ISA needed", x86_compat_isa),
+        GNU_PROPERTY_X86_COMPAT_2_ISA_1_USED if is_x86 => bitmask("x86 ISA used", x86_compat_2_isa),
+        GNU_PROPERTY_X86_COMPAT_2_ISA_1_NEEDED if is_x86 => {
+            bitmask("x86 ISA needed", x86_compat_2_isa)
+        }
+        GNU_PROPERTY_AARCH64_FEATURE_1_AND if e_machine == EM_AARCH64 => {
+            bitmask("AArch64 feature", aarch64_feature_1)
+        }
+        GNU_PROPERTY_STACK_SIZE if data.len() == header.class().address_size() => {
+            let size = header.class().read_address(data, is_little_endian);
+            format!("stack size: {}", alternate_hex(size))
+        }
+        GNU_PROPERTY_STACK_SIZE => format!("stack size: {}", corrupt()),
+        GNU_PROPERTY_NO_COPY_ON_PROTECTED if data.is_empty() => "no copy on protected ".to_string(),
+        GNU_PROPERTY_NO_COPY_ON_PROTECTED => format!("no copy on protected {}", corrupt()),
+        GNU_PROPERTY_1_NEEDED => bitmask("1_needed", needed_1),
+        GNU_PROPERTY_UINT32_AND_LO..=GNU_PROPERTY_UINT32_OR_HI => {
+            let kind = if pr_type <= GNU_PROPERTY_UINT32_AND_HI {
+                "UINT32_AND"
+            } else {
+                "UINT32_OR"
+            };
+            let value = if data.len() == 4 {
+                alternate_hex(u32::read_from(data, is_little_endian) as u64)
+            } else {
+                corrupt()
+            };
+
+            format!("{kind} ({}): {value}", alternate_hex(pr_type as u64))
+        }
+        _ => {
+            let kind = match pr_type {
+                ..GNU_PROPERTY_LOPROC => "unknown",
+                ..GNU_PROPERTY_LOUSER => "processor-specific",
+                _ => "application-specific",
+            };
+            let bytes: String = data.iter().map(|byte| format!("{byte:02x} ")).collect();
+
+            format!(
+                "<{kind} type {} data: {bytes}>",
+                alternate_hex(pr_type as u64)
+            )
+        }
+    }
+}
+
+// the bits are shown from the lowest one, with the unknown ones in hex
+fn property_bits(bitmask: u32, names: &[(u32, &str)]) -> String {
+    let mut shown = Vec::new();
+    let mut remaining = bitmask;
+
+    while remaining != 0 {
+        let bit = remaining & remaining.wrapping_neg();
+        remaining &= !bit;
+
+        match names.iter().find(|(flag, _)| *flag == bit) {
+            Some((_, name)) => shown.push(name.to_string()),
+            None => shown.push(format!("<unknown: {bit:x}>")),
+        }
+    }
+
+    shown.join(", ")
+}
+
+// some of the decoders show an empty mask as "<None>", and some dont show
+// anything at all (binutils is not consistent here)
+fn property_bits_or_none(bitmask: u32, names: &[(u32, &str)]) -> String {
+    if bitmask == 0 {
+        "<None>".to_string()
+    } else {
+        property_bits(bitmask, names)
+    }
+}
+
+fn x86_isa(bitmask: u32) -> String {
+    property_bits(
+        bitmask,
+        &[
+            (GNU_PROPERTY_X86_ISA_1_BASELINE, "x86-64-baseline"),
+            (GNU_PROPERTY_X86_ISA_1_V2, "x86-64-v2"),
+            (GNU_PROPERTY_X86_ISA_1_V3, "x86-64-v3"),
+            (GNU_PROPERTY_X86_ISA_1_V4, "x86-64-v4"),
+        ],
+    )
+}
+
+fn x86_feature_1(bitmask: u32) -> String {
+    property_bits_or_none(
+        bitmask,
+        &[
+            (GNU_PROPERTY_X86_FEATURE_1_IBT, "IBT"),
+            (GNU_PROPERTY_X86_FEATURE_1_SHSTK, "SHSTK"),
+            (GNU_PROPERTY_X86_FEATURE_1_LAM_U48, "LAM_U48"),
+            (GNU_PROPERTY_X86_FEATURE_1_LAM_U57, "LAM_U57"),
+        ],
+    )
+}
+
+fn x86_feature_2(bitmask: u32) -> String {
+    property_bits_or_none(
+        bitmask,
+        &[
+            (GNU_PROPERTY_X86_FEATURE_2_X86, "x86"),
+            (GNU_PROPERTY_X86_FEATURE_2_X87, "x87"),
+            (GNU_PROPERTY_X86_FEATURE_2_MMX, "MMX"),
+            (GNU_PROPERTY_X86_FEATURE_2_XMM, "XMM"),
+            (GNU_PROPERTY_X86_FEATURE_2_YMM, "YMM"),
+            (GNU_PROPERTY_X86_FEATURE_2_ZMM, "ZMM"),
+            (GNU_PROPERTY_X86_FEATURE_2_FXSR, "FXSR"),
+            (GNU_PROPERTY_X86_FEATURE_2_XSAVE, "XSAVE"),
+            (GNU_PROPERTY_X86_FEATURE_2_XSAVEOPT, "XSAVEOPT"),
+            (GNU_PROPERTY_X86_FEATURE_2_XSAVEC, "XSAVEC"),
+            (GNU_PROPERTY_X86_FEATURE_2_TMM, "TMM"),
+            (GNU_PROPERTY_X86_FEATURE_2_MASK, "MASK"),
+        ],
+    )
+}
+
+// the old (and now unused) ISA properties, every bit is an extension
+const X86_COMPAT_ISA_1_NAMES: &[&str] = &[
+    "i486", "586", "686", "SSE", "SSE2", "SSE3", "SSSE3", "SSE4_1", "SSE4_2", "AVX", "AVX2",
+    "AVX512F", "AVX512CD", "AVX512ER", "AVX512PF", "AVX512VL", "AVX512DQ", "AVX512BW",
+];
+
+const X86_COMPAT_2_ISA_1_NAMES: &[&str] = &[
+    "CMOV",
+    "SSE",
+    "SSE2",
+    "SSE3",
+    "SSSE3",
+    "SSE4_1",
+    "SSE4_2",
+    "AVX",
+    "AVX2",
+    "FMA",
+    "AVX512F",
+    "AVX512CD",
+    "AVX512ER",
+    "AVX512PF",
+    "AVX512VL",
+    "AVX512DQ",
+    "AVX512BW",
+    "AVX512_4FMAPS",
+    "AVX512_4VNNIW",
+    "AVX512_BITALG",
+    "AVX512_IFMA",
+    "AVX512_VBMI",
+    "AVX512_VBMI2",
+    "AVX512_VNNI",
+    "AVX512_BF16",
+];
+
+fn numbered_bits(names: &[&'static str]) -> Vec<(u32, &'static str)> {
+    names
+        .iter()
+        .enumerate()
+        .map(|(bit, name)| (1 << bit, *name))
+        .collect()
+}
+
+fn x86_compat_isa(bitmask: u32) -> String {
+    property_bits(bitmask, &numbered_bits(X86_COMPAT_ISA_1_NAMES))
+}
+
+fn x86_compat_2_isa(bitmask: u32) -> String {
+    property_bits_or_none(bitmask, &numbered_bits(X86_COMPAT_2_ISA_1_NAMES))
+}
+
+fn aarch64_feature_1(bitmask: u32) -> String {
+    property_bits(
+        bitmask,
+        &[
+            (GNU_PROPERTY_AARCH64_FEATURE_1_BTI, "BTI"),
+            (GNU_PROPERTY_AARCH64_FEATURE_1_PAC, "PAC"),
+        ],
+    )
+}
+
+fn needed_1(bitmask: u32) -> String {
+    property_bits(
+        bitmask,
+        &[(
+            GNU_PROPERTY_1_NEEDED_INDIRECT_EXTERN_ACCESS,
+            "indirect external access",
+        )],
+    )
+}
+
+// a SystemTap (USDT) probe: 3 addresses and then the provider, the name and
+// the arguments of the probe, as NUL terminated strings
+fn print_stapsdt_note(out: &mut dyn Write, header: &Elf64_Ehdr, desc: &[u8]) -> io::Result<()> {
+    let is_little_endian = header.is_little_endian();
+    let class = header.class();
+    let address_size = class.address_size();
+    let width = class.address_width();
+
+    if desc.len() < 3 * address_size {
+        return writeln!(out, "  <corrupt - note is too small>");
+    }
+
+    let address =
+        |index: usize| class.read_address(&desc[index * address_size..], is_little_endian);
+
+    let mut strings = Vec::new();
+    let mut rest = &desc[3 * address_size..];
+
+    for _ in 0..3 {
+        let Some(end) = rest.iter().position(|&byte| byte == 0) else {
+            return writeln!(out, "  <corrupt - note is too small>");
+        };
+
+        strings.push(String::from_utf8_lossy(&rest[..end]));
+        rest = &rest[end + 1..];
+    }
+
+    writeln!(out, "    Provider: {}", strings[0])?;
+    writeln!(out, "    Name: {}", strings[1])?;
+    writeln!(
+        out,
+        "    Location: 0x{:0width$x}, Base: 0x{:0width$x}, Semaphore: 0x{:0width$x}",
+        address(0),
+        address(1),
+        address(2)
+    )?;
+    writeln!(out, "    Arguments: {}", strings[2])
+}
+
+// NT_FILE has the count of mapped files and the page size, then the start,
+// end and offset (in pages) of every file, and then all of their names
+fn print_mapped_files(out: &mut dyn Write, header: &Elf64_Ehdr, desc: &[u8]) -> io::Result<()> {
+    let is_little_endian = header.is_little_endian();
+    let class = header.class();
+    let address_size = class.address_size();
+    let width = class.address_width();
+    let word = |index: usize| class.read_address(&desc[index * address_size..], is_little_endian);
+
+    if desc.len() < 2 * address_size || desc.last() != Some(&0) {
+        return writeln!(out, "    <corrupt NT_FILE>");
+    }
+
+    let count = word(0);
+    let page_size = word(1);
+
+    let table_size = count
+        .checked_mul(3 * address_size as u64)
+        .and_then(|size| size.checked_add(2 * address_size as u64))
+        .filter(|&size| size <= desc.len() as u64);
+    let Some(table_size) = table_size else {
+        return writeln!(out, "    <corrupt NT_FILE>");
+    };
+
+    writeln!(out, "    Page size: {page_size}")?;
+    writeln!(
+        out,
+        "    {:>width$}{:>end_width$}{:>offset_width$}",
+        "Start",
+        "End",
+        "Page Offset",
+        width = 2 + width,
+        end_width = 4 + width,
+        offset_width = 1 + width
+    )?;
+
+    let mut names = desc[table_size as usize..].split(|&byte| byte == 0);
+
+    for index in 0..count as usize {
+        let Some(name) = names.next() else {
+            return writeln!(out, "    <corrupt NT_FILE>");
+        };
+
+        let entry = 2 + index * 3;
+        writeln!(
+            out,
+            "    0x{:0width$x}  0x{:0width$x}  0x{:0width$x}",
+            word(entry),
+            word(entry + 1),
+            word(entry + 2)
+        )?;
+        writeln!(out, "        {}", String::from_utf8_lossy(name))?;
+    }
+
+    Ok(())
+}
+
+// the tags that the FreeBSD toolchain puts in every executable
+fn print_freebsd_note(
+    out: &mut dyn Write,
+    header: &Elf64_Ehdr,
+    note: &Elf64Note,
+    wide: bool,
+) -> io::Result<()> {
+    let desc = &note.desc;
+    let is_little_endian = header.is_little_endian();
+
+    match note.nhdr.n_type {
+        NT_FREEBSD_ABI_TAG if desc.len() == 4 => {
+            writeln!(
+                out,
+                "    ABI tag: {}",
+                u32::read_from(desc, is_little_endian)
+            )
+        }
+        NT_FREEBSD_ARCH_TAG => writeln!(out, "    Arch tag: {}", desc_string(desc)),
+        NT_FREEBSD_NOINIT_TAG => print_nothing(out, wide),
+        NT_FREEBSD_FEATURE_CTL if desc.len() == 4 => {
+            let flags = u32::read_from(desc, is_little_endian);
+            let names = [
+                (NT_FREEBSD_FCTL_ASLR_DISABLE, "ASLR_DISABLE"),
+                (NT_FREEBSD_FCTL_PROTMAX_DISABLE, "PROTMAX_DISABLE"),
+                (NT_FREEBSD_FCTL_STKGAP_DISABLE, "STKGAP_DISABLE"),
+                (NT_FREEBSD_FCTL_WXNEEDED, "WXNEEDED"),
+                (NT_FREEBSD_FCTL_LA48, "LA48"),
+                (NT_FREEBSD_FCTL_ASG_DISABLE, "ASG_DISABLE"),
+            ];
+
+            write!(out, "    Feature flags:")?;
+            for (flag, name) in names {
+                if flags & flag != 0 {
+                    write!(out, " {name}")?;
+                }
+            }
+            writeln!(out, " (0x{flags:08x})")
+        }
+        _ => print_hex_dump(out, desc, wide),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{gnu_property, print_notes, x86_isa};
+    use crate::elf::def::elf64strc::{Elf64_Ehdr, Elf64_Nhdr};
+    use crate::elf::note::{Elf64Note, Elf64NoteArea};
+    use crate::test_utils::create_header;
+
+    fn create_note(name: &str, n_type: u32, desc: &[u8]) -> Elf64Note {
+        Elf64Note {
+            nhdr: Elf64_Nhdr {
+                n_namesz: name.len() as u32 + 1,
+                n_descsz: desc.len() as u32,
+                n_type,
+            },
+            name: name.to_string(),
+            raw_name: format!("{name}\0").into_bytes(),
+            desc: desc.to_vec(),
+        }
+    }
+
+    fn create_area(section: Option<&str>) -> Elf64NoteArea {
+        Elf64NoteArea {
+            section: section.map(str::to_string),
+            offset: 0x338,
+            size: 0x20,
+            align: 4,
+        }
+    }
+
+    fn print(header: &Elf64_Ehdr, area: &Elf64NoteArea, notes: &[Elf64Note], wide: bool) -> String {
+        let mut out = Vec::new();
+        print_notes(&mut out, header, area, notes, &[], wide).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn print_notes_shows_the_gnu_build_id_and_abi_tag() {
+        let mut abi_tag = Vec::new();
+        for word in [0u32, 3, 2, 0] {
+            abi_tag.extend_from_slice(&word.to_le_bytes());
+        }
+        let notes = [
+            create_note("GNU", 3, &[0x65, 0x9c, 0xe3, 0xc8]),
+            create_note("GNU", 1, &abi_tag),
+        ];
+
+        let shown = print(
+            &create_header(3, 62),
+            &create_area(Some(".notes")),
+            &notes,
+            false,
+        );
+
+        let expected = "
+Displaying notes found in: .notes
+  Owner                Data size \tDescription
+  GNU                  0x00000004\tNT_GNU_BUILD_ID (unique build ID bitstring)
+    Build ID: 659ce3c8
+  GNU                  0x00000010\tNT_GNU_ABI_TAG (ABI version tag)
+    OS: Linux, ABI: 3.2.0
+";
+        assert_eq!(shown, expected);
+    }
+
+    #[test]
+    fn print_notes_dumps_unknown_notes_in_hex() {
+        let notes = [create_note("XYZ", 0x99, &[0x44, 0x33, 0x22, 0x11])];
+
+        let shown = print(&create_header(1, 62), &create_area(None), &notes, true);
+
+        let expected = "
+Displaying notes found at file offset 0x00000338 with length 0x00000020:
+  Owner                Data size \tDescription
+  XYZ                  0x00000004\tUnknown note type: (0x00000099)\t   description data: 44 33 22 11 \n";
+        assert_eq!(shown, expected);
+    }
+
+    #[test]
+    fn print_notes_shows_the_stapsdt_probes() {
+        let mut desc = Vec::new();
+        for address in [0x1139u64, 0x2004, 0] {
+            desc.extend_from_slice(&address.to_le_bytes());
+        }
+        desc.extend_from_slice(b"libc\0setjmp\08@%rdi -4@%esi\0");
+        let notes = [create_note("stapsdt", 3, &desc)];
+
+        let shown = print(
+            &create_header(3, 62),
+            &create_area(Some(".note.stapsdt")),
+            &notes,
+            false,
+        );
+
+        assert!(shown.ends_with(
+            "  stapsdt              0x00000033\tNT_STAPSDT (SystemTap probe descriptors)
+    Provider: libc
+    Name: setjmp
+    Location: 0x0000000000001139, Base: 0x0000000000002004, Semaphore: 0x0000000000000000
+    Arguments: 8@%rdi -4@%esi
+"
+        ));
+    }
+
+    #[test]
+    fn print_notes_decodes_the_go_and_freebsd_tags() {
+        let notes = [
+            create_note("Go", 4, b"abc/def"),
+            create_note("FreeBSD", 1, &1400097u32.to_le_bytes()),
+            create_note("FreeBSD", 4, &5u32.to_le_bytes()),
+        ];
+
+        let shown = print(
+            &create_header(2, 62),
+            &create_area(Some(".note.tag")),
+            &notes,
+            false,
+        );
+
+        assert!(shown.contains("GO BUILDID\n    Build ID: abc/def\n"));
+        assert!(shown.contains("(ABI version tag)\n    ABI tag: 1400097\n"));
+        assert!(shown.contains("    Feature flags: ASLR_DISABLE STKGAP_DISABLE (0x00000005)\n"));
+    }
+
+    #[test]
+    fn gnu_property_depends_on_the_machine_for_processor_properties() {
+        let data = 3u32.to_le_bytes();
+
+        assert_eq!(
+            gnu_property(&create_header(3, 62), 0xc0000002, &data),
+            "x86 feature: IBT, SHSTK"
+        );
+        assert_eq!(
+            gnu_property(&create_header(3, 183), 0xc0000000, &data),
+            "AArch64 feature: BTI, PAC"
+        );
+        assert_eq!(
+            gnu_property(&create_header(3, 183), 0xc0000002, &data),
+            "<processor-specific type 0xc0000002 data: 03 00 00 00 >"
+        );
+    }
+
+    #[test]
+    fn x86_isa_shows_the_unknown_bits_in_hex() {
+        assert_eq!(x86_isa(0x11), "x86-64-baseline, <unknown: 10>");
+        assert_eq!(x86_isa(0), "");
+    }
+}
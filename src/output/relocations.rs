@@ -0,0 +1,410 @@
+use crate::elf::class::ClassParseable;
+use crate::elf::class::ElfClass;
+use crate::elf::def::elf64strc::{Elf64_Dyn, Elf64_Ehdr, Elf64_Rel, Elf64_Rela};
+use crate::elf::def::elfconsts::*;
+use crate::elf::relocation::{Elf64Relocations, decode_relr, file_r_info, r_sym, r_type};
+use crate::elf::section::Elf64Section;
+use crate::elf::symbol::Elf64Symbol;
+use crate::elf::relocation_types::relocation_type_name;
+use crate::output::truncate_name;
+use crate::output::versions::version_suffix;
+use std::io::{self, Write};
+
+// REL and RELA entries are printed the same way, RELA only adds the addend
+struct Relocation {
+    offset: u64,
+    info: u64,
+    addend: Option<i64>,
+}
+
+pub fn print_relocation_section(
+    out: &mut dyn Write,
+    header: &Elf64_Ehdr,
+    sections: &[Elf64Section],
+    section: &Elf64Section,
+    relocations: &Elf64Relocations,
+    symbols: Option<&[Elf64Symbol]>,
+    wide: bool,
+) -> io::Result<()> {
+    let shdr = &section.header;
+    let class = header.class();
+    let entsize = match (shdr.sh_entsize, relocations) {
+        (0, Elf64Relocations::Rel(_)) => Elf64_Rel::size_of(class) as u64,
+        (0, Elf64Relocations::Rela(_)) => Elf64_Rela::size_of(class) as u64,
+        (0, Elf64Relocations::Relr(_)) => class.address_size() as u64,
+        (entsize, _) => entsize,
+    };
+    let count = shdr.sh_size / entsize;
+
+    writeln!(
+        out,
+        "\nRelocation section '{}' at offset {:#x} contains {count} {}:",
+        truncate_name(&section.name, 0, true),
+        shdr.sh_offset,
+        if count == 1 { "entry" } else { "entries" }
+    )?;
+
+    let is_rela = matches!(relocations, Elf64Relocations::Rela(_));
+    let relocations: Vec<Relocation> = match relocations {
+        Elf64Relocations::Relr(entries) => return print_relr(out, entries, class),
+        Elf64Relocations::Rel(entries) => entries
+            .iter()
+            .map(|rel| Relocation {
+                offset: rel.r_offset,
+                info: rel.r_info,
+                addend: None,
+            })
+            .collect(),
+        Elf64Relocations::Rela(entries) => entries
+            .iter()
+            .map(|rela| Relocation {
+                offset: rela.r_offset,
+                info: rela.r_info,
+                addend: Some(rela.r_addend),
+            })
+            .collect(),
+    };
+
+    let last_column = if is_rela { " + Addend" } else { "" };
+
+    if !class.is_64bit() {
+        // the 32-bit values always fit, so only the titles change in the
+        // wide mode
+        if wide {
+            writeln!(
+                out,
+                " Offset     Info    Type                Sym. Value  Symbol's Name{last_column}"
+            )?;
+        } else {
+            writeln!(
+                out,
+                " Offset     Info    Type            Sym.Value  Sym. Name{last_column}"
+            )?;
+        }
+    } else if wide {
+        writeln!(
+            out,
+            "    Offset             Info             Type               Symbol's Value  Symbol's Name{last_column}"
+        )?;
+    } else {
+        writeln!(
+            out,
+            "  Offset          Info           Type           Sym. Value    Sym. Name{last_column}"
+        )?;
+    }
+
+    for relocation in &relocations {
+        print_relocation(out, header, sections, relocation, symbols, wide)?;
+    }
+
+    Ok(())
+}
+
+fn print_relocation(
+    out: &mut dyn Write,
+    header: &Elf64_Ehdr,
+    sections: &[Elf64Section],
+    relocation: &Relocation,
+    symbols: Option<&[Elf64Symbol]>,
+    wide: bool,
+) -> io::Result<()> {
+    let class = header.class();
+
+    // the columns only get narrower outside of the wide mode, the values
+    // are never cut
+    if !class.is_64bit() {
+        let info = file_r_info(relocation.info, class);
+        write!(out, "{:08x}  {info:08x} ", relocation.offset)?;
+    } else if wide {
+        write!(out, "{:016x}  {:016x} ", relocation.offset, relocation.info)?;
+    } else {
+        write!(out, "{:012x}  {:012x} ", relocation.offset, relocation.info)?;
+    }
+
+    let r_type = r_type(relocation.info);
+    match relocation_type_name(header.e_machine, r_type) {
+        Some(name) if wide => write!(out, "{name:<22}")?,
+        Some(name) => {
+            // but the type names are cut at 17 chars
+            let name: String = name.chars().take(17).collect();
+            write!(out, "{name:<17}")?;
+        }
+        None => write!(out, "unrecognized: {r_type:<7x}")?,
+    }
+
+    let index = r_sym(relocation.info);
+
+    if index == 0 {
+        // without a symbol, only the addend is left
+        if let Some(addend) = relocation.addend {
+            let padding = if class.is_64bit() { 20 } else { 12 };
+            write!(out, "{:padding$}{}", "", signed_hex(addend, ""))?;
+        }
+        return writeln!(out);
+    }
+
+    // a bad index has nothing to show (binutils reports it as an error)
+    let Some(symbol) = symbols.and_then(|symbols| symbols.get(index as usize)) else {
+        return writeln!(out);
+    };
+    let sym = &symbol.sym;
+
+    write!(out, " ")?;
+
+    if sym.st_type() == STT_GNU_IFUNC {
+        // the value of an IFUNC is not used, the function is called and its
+        // result is the address, so "name()" is shown in its place
+        let width = if class.is_64bit() { 14 } else { 8 };
+        let name = truncate_name(symbol.name.as_deref().unwrap_or(""), width, wide);
+        let used = name.chars().count();
+        let padding = if used <= width { width + 1 - used } else { 1 };
+        // the version goes between the name and the "()", and is not counted
+        let version = symbol.version.as_ref().map(version_suffix).unwrap_or_default();
+        write!(out, "{name}{version}(){:padding$}", "")?;
+    } else if class.is_64bit() {
+        write!(out, "{:016x} ", sym.st_value)?;
+    } else {
+        write!(out, "{:08x}   ", sym.st_value)?;
+    }
+
+    if sym.st_name == 0 {
+        let name = section_symbol_name(header, sections, symbol);
+        write!(out, "{}", truncate_name(&name, 22, wide))?;
+    } else if let Some(name) = &symbol.name {
+        write!(out, "{}", truncate_name(name, 22, wide))?;
+
+        // unlike the symbol tables, the version is never in the width and
+        // the needed ones dont show their index
+        if let Some(version) = &symbol.version {
+            write!(out, "{}", version_suffix(version))?;
+        }
+    }
+
+    if let Some(addend) = relocation.addend {
+        write!(out, "{}", signed_hex(addend, " "))?;
+    }
+
+    writeln!(out)
+}
+
+// the relocations against a section use an unnamed STT_SECTION symbol, so
+// the name of the section (or of the special index) is shown instead
+fn section_symbol_name(
+    header: &Elf64_Ehdr,
+    sections: &[Elf64Section],
+    symbol: &Elf64Symbol,
+) -> String {
+    let sym = &symbol.sym;
+    if sym.st_type() != STT_SECTION {
+        return "<null>".to_string();
+    }
+
+    let e_machine = header.e_machine as u32;
+    let st_shndx = sym.st_shndx as u32;
+
+    if let Some(section) = symbol.section_index().and_then(|index| sections.get(index)) {
+        return section.name.clone();
+    }
+
+    let name = match st_shndx {
+        SHN_ABS => "ABS",
+        SHN_COMMON => "COMMON",
+        SHN_MIPS_SCOMMON if e_machine == EM_MIPS => "SCOMMON",
+        SHN_MIPS_SUNDEFINED if e_machine == EM_MIPS => "SUNDEF",
+        SHN_X86_64_LCOMMON if e_machine == EM_X86_64 => "LARGE_COMMON",
+        _ => return format!("<section {st_shndx:#x}>"),
+    };
+
+    name.to_string()
+}
+
+// the addends are signed, binutils shows them as "+ 10" and "- 4"
+fn signed_hex(value: i64, separator: &str) -> String {
+    if value < 0 {
+        format!("{separator}-{separator}{:x}", value.unsigned_abs())
+    } else if separator.is_empty() {
+        format!("{value:x}")
+    } else {
+        format!("{separator}+{separator}{value:x}")
+    }
+}
+
+// the RELR entries themselves are not useful, so the addresses that they
+// relocate are shown instead
+fn print_relr(out: &mut dyn Write, entries: &[u64], class: ElfClass) -> io::Result<()> {
+    let addresses = decode_relr(entries, class);
+    let width = class.address_width();
+
+    writeln!(
+        out,
+        "  {} {}",
+        addresses.len(),
+        if addresses.len() == 1 {
+            "offset"
+        } else {
+            "offsets"
+        }
+    )?;
+
+    for address in addresses {
+        writeln!(out, "{address:0width$x}")?;
+    }
+
+    Ok(())
+}
+
+// files that only have the relocations of the loader (and no sections for
+// them) still tell about them in the dynamic section
+pub fn print_no_relocations(out: &mut dyn Write, dynamic: &[Elf64_Dyn]) -> io::Result<()> {
+    let has_dynamic_relocations = dynamic.iter().any(|entry| {
+        matches!(entry.tag(), DT_RELSZ | DT_RELASZ | DT_RELRSZ | DT_PLTRELSZ) && entry.d_val() != 0
+    });
+
+    if has_dynamic_relocations {
+        write!(out, "\nThere are no static relocations in this file.")?;
+        writeln!(
+            out,
+            "\nTo see the dynamic relocations add --use-dynamic to the command line."
+        )
+    } else {
+        writeln!(out, "\nThere are no relocations in this file.")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{print_relocation_section, signed_hex};
+    use crate::elf::def::elf64strc::{Elf64_Rela, Elf64_Shdr, Elf64_Sym};
+    use crate::elf::relocation::Elf64Relocations;
+    use crate::elf::symbol::Elf64Symbol;
+    use crate::elf::version::{SymbolVersion, VersionKind};
+    use crate::test_utils::{create_header, create_section};
+
+    #[test]
+    fn signed_hex_shows_the_sign_of_the_addend() {
+        assert_eq!(signed_hex(-4, " "), " - 4");
+        assert_eq!(signed_hex(0x10, " "), " + 10");
+        assert_eq!(signed_hex(0x1130, ""), "1130");
+        assert_eq!(signed_hex(-1, ""), "-1");
+    }
+
+    #[test]
+    fn print_relocation_section_shows_relative_relocations_without_symbol() {
+        let mut section = create_section(".rela.dyn", 4);
+        section.header = Elf64_Shdr {
+            sh_offset: 0x598,
+            sh_size: 24,
+            sh_entsize: 24,
+            ..section.header
+        };
+        let relocations = Elf64Relocations::Rela(vec![Elf64_Rela {
+            r_offset: 0x3de8,
+            r_info: 8,
+            r_addend: 0x1130,
+        }]);
+        let mut out = Vec::new();
+
+        print_relocation_section(
+            &mut out,
+            &create_header(3, 62),
+            &[],
+            &section,
+            &relocations,
+            None,
+            false,
+        )
+        .unwrap();
+
+        let expected = "
+Relocation section '.rela.dyn' at offset 0x598 contains 1 entry:
+  Offset          Info           Type           Sym. Value    Sym. Name + Addend
+000000003de8  000000000008 R_X86_64_RELATIVE                    1130
+";
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+    }
+
+    #[test]
+    fn print_relocation_section_shows_the_relr_addresses() {
+        let mut section = create_section(".relr.dyn", 19);
+        section.header = Elf64_Shdr {
+            sh_offset: 0x598,
+            sh_size: 16,
+            sh_entsize: 8,
+            ..section.header
+        };
+        let relocations = Elf64Relocations::Relr(vec![0x3da0, 0b11]);
+        let mut out = Vec::new();
+
+        print_relocation_section(
+            &mut out,
+            &create_header(3, 62),
+            &[],
+            &section,
+            &relocations,
+            None,
+            false,
+        )
+        .unwrap();
+
+        let expected = "
+Relocation section '.relr.dyn' at offset 0x598 contains 2 entries:
+  2 offsets
+0000000000003da0
+0000000000003da8
+";
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+    }
+
+    #[test]
+    fn print_relocation_section_shows_the_version_of_an_ifunc_value() {
+        let mut section = create_section(".rela.plt", 4);
+        section.header = Elf64_Shdr {
+            sh_offset: 0x598,
+            sh_size: 24,
+            sh_entsize: 24,
+            ..section.header
+        };
+        let relocations = Elf64Relocations::Rela(vec![Elf64_Rela {
+            r_offset: 0x8000,
+            r_info: 0x1_0000_0007,
+            r_addend: 0,
+        }]);
+        let symbol = Elf64Symbol {
+            sym: Elf64_Sym {
+                st_name: 1,
+                st_info: 0x1a, // GLOBAL IFUNC
+                st_other: 0,
+                st_shndx: 12,
+                st_value: 0x2f40,
+                st_size: 0,
+            },
+            name: Some("__atomic_exchange_16".to_string()),
+            xindex: None,
+            version: Some(SymbolVersion {
+                name: "LIBATOMIC_1.0".to_string(),
+                index: 2,
+                kind: VersionKind::Default,
+            }),
+        };
+        let symbols = [symbol.clone(), symbol];
+        let mut out = Vec::new();
+
+        print_relocation_section(
+            &mut out,
+            &create_header(3, 62),
+            &[],
+            &section,
+            &relocations,
+            Some(&symbols),
+            false,
+        )
+        .unwrap();
+
+        let expected = "
+Relocation section '.rela.plt' at offset 0x598 contains 1 entry:
+  Offset          Info           Type           Sym. Value    Sym. Name + Addend
+000000008000  000100000007 R_X86_64_JUMP_SLO __atomic_[...]@@LIBATOMIC_1.0() __atomic_exchange_16@@LIBATOMIC_1.0 + 0
+";
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+    }
+}
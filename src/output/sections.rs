@@ -0,0 +1,410 @@
+use crate::elf::class::ElfClass;
+use crate::elf::def::elf64strc::{Elf64_Chdr, Elf64_Ehdr};
+use crate::elf::def::elfconsts::*;
+use crate::elf::osabi::{is_gnu_or_freebsd, is_solaris};
+use crate::elf::section::Elf64Section;
+use crate::output::{alternate_hex, fit_name};
+use std::io::{self, Write};
+
+pub fn print_section_headers(
+    out: &mut dyn Write,
+    header: &Elf64_Ehdr,
+    sections: &[Elf64Section],
+    // the Elf*_Chdr of each compressed section (by index)
+    compression: &[Option<Elf64_Chdr>],
+    wide: bool,
+    show_intro: bool,
+) -> io::Result<()> {
+    if sections.is_empty() {
+        return writeln!(out, "\nThere are no sections in this file.");
+    }
+
+    // when the file header was already shown, the number of sections and the
+    // offset of the table are already on the screen
+    if show_intro {
+        writeln!(
+            out,
+            "There are {} section headers, starting at offset {:#x}:",
+            sections.len(),
+            header.e_shoff
+        )?;
+    }
+
+    let class = header.class();
+    let is_64bit = class.is_64bit();
+
+    writeln!(out, "\nSection Headers:")?;
+    if !is_64bit {
+        // every 32-bit value fits in one line, even outside of the wide mode
+        writeln!(
+            out,
+            "  [Nr] Name              Type            Addr     Off    Size   ES Flg Lk Inf Al"
+        )?;
+    } else if wide {
+        writeln!(
+            out,
+            "  [Nr] Name              Type            Address          Off    Size   ES Flg Lk Inf Al"
+        )?;
+    } else {
+        writeln!(
+            out,
+            "  [Nr] Name              Type             Address           Offset"
+        )?;
+        writeln!(
+            out,
+            "       Size              EntSize          Flags  Link  Info  Align"
+        )?;
+    }
+
+    for (index, section) in sections.iter().enumerate() {
+        let shdr = &section.header;
+        let type_name = section_type_name(header, shdr.sh_type);
+
+        write!(out, "  [{index:2}] {} ", fit_name(&section.name, 17, wide))?;
+
+        // outside of the wide mode the type name is cut at 15 chars
+        let type_name: String = if wide {
+            type_name
+        } else {
+            type_name.chars().take(15).collect()
+        };
+
+        // the 32-bit files use the layout of the wide mode, only with a
+        // narrower address
+        if wide || !is_64bit {
+            let width = class.address_width();
+            write!(out, "{type_name:<15} ")?;
+            writeln!(
+                out,
+                "{:0width$x} {:06x} {:06x} {:02x} {:>3} {:2} {:3} {:2}",
+                shdr.sh_addr,
+                shdr.sh_offset,
+                shdr.sh_size,
+                shdr.sh_entsize,
+                section_flags(header, shdr.sh_flags),
+                shdr.sh_link,
+                shdr.sh_info,
+                shdr.sh_addralign
+            )?;
+        } else {
+            writeln!(
+                out,
+                "{type_name:<15}  {:016x}  {:08x}",
+                shdr.sh_addr, shdr.sh_offset
+            )?;
+            writeln!(
+                out,
+                "       {:016x}  {:016x} {:>3}      {:2}   {:3}     {}",
+                shdr.sh_size,
+                shdr.sh_entsize,
+                section_flags(header, shdr.sh_flags),
+                shdr.sh_link,
+                shdr.sh_info,
+                shdr.sh_addralign
+            )?;
+        }
+
+        if let Some(Some(chdr)) = compression.get(index) {
+            print_compression_header(out, chdr, class)?;
+        }
+    }
+
+    print_flags_key(out, header)
+}
+
+// the header of the compressed data, below the section, in the same format
+// of the section details of GNU readelf (-t)
+fn print_compression_header(
+    out: &mut dyn Write,
+    chdr: &Elf64_Chdr,
+    class: ElfClass,
+) -> io::Result<()> {
+    match chdr.ch_type {
+        ELFCOMPRESS_ZLIB => write!(out, "       ZLIB, ")?,
+        ELFCOMPRESS_ZSTD => write!(out, "       ZSTD, ")?,
+        ch_type => write!(out, "       [<unknown>: {ch_type:#x}], ")?,
+    }
+
+    let width = class.address_width();
+    writeln!(out, "{:0width$x}, {}", chdr.ch_size, chdr.ch_addralign)
+}
+
+fn print_flags_key(out: &mut dyn Write, header: &Elf64_Ehdr) -> io::Result<()> {
+    let osabi = header.e_ident[7] as u32;
+    let e_machine = header.e_machine as u32;
+
+    writeln!(out, "Key to Flags:")?;
+    writeln!(
+        out,
+        "  W (write), A (alloc), X (execute), M (merge), S (strings), I (info),"
+    )?;
+    writeln!(
+        out,
+        "  L (link order), O (extra OS processing required), G (group), T (TLS),"
+    )?;
+    writeln!(
+        out,
+        "  C (compressed), x (unknown), o (OS specific), E (exclude),"
+    )?;
+    write!(out, "  ")?;
+
+    if is_gnu_or_freebsd(header) {
+        write!(out, "R (retain), ")?;
+    }
+    if is_gnu_or_freebsd(header) || osabi == ELFOSABI_NONE {
+        write!(out, "D (mbind), ")?;
+    }
+
+    match e_machine {
+        EM_X86_64 => write!(out, "l (large), ")?,
+        EM_ARM => write!(out, "y (purecode), ")?,
+        EM_PPC => write!(out, "v (VLE), ")?,
+        _ => {}
+    }
+
+    writeln!(out, "p (processor specific)")
+}
+
+#[allow(non_upper_case_globals)]
+pub fn section_type_name(header: &Elf64_Ehdr, sh_type: u32) -> String {
+    let name = match sh_type {
+        SHT_NULL => "NULL",
+        SHT_PROGBITS => "PROGBITS",
+        SHT_SYMTAB => "SYMTAB",
+        SHT_STRTAB => "STRTAB",
+        SHT_RELA => "RELA",
+        SHT_RELR => "RELR",
+        SHT_HASH => "HASH",
+        SHT_DYNAMIC => "DYNAMIC",
+        SHT_NOTE => "NOTE",
+        SHT_NOBITS => "NOBITS",
+        SHT_REL => "REL",
+        SHT_SHLIB => "SHLIB",
+        SHT_DYNSYM => "DYNSYM",
+        SHT_INIT_ARRAY => "INIT_ARRAY",
+        SHT_FINI_ARRAY => "FINI_ARRAY",
+        SHT_PREINIT_ARRAY => "PREINIT_ARRAY",
+        SHT_GNU_HASH => "GNU_HASH",
+        SHT_GROUP => "GROUP",
+        SHT_SYMTAB_SHNDX => "SYMTAB SECTION INDICES",
+        SHT_GNU_verdef => "VERDEF",
+        SHT_GNU_verneed => "VERNEED",
+        SHT_GNU_versym => "VERSYM",
+        0x6ffffff0 => "VERSYM",
+        0x6ffffffc => "VERDEF",
+        0x7ffffffd => "AUXILIARY",
+        0x7fffffff => "FILTER",
+        SHT_GNU_LIBLIST => "GNU_LIBLIST",
+        SHT_LOPROC..=SHT_HIPROC => {
+            return match processor_section_type_name(header.e_machine as u32, sh_type) {
+                Some(name) => name.to_string(),
+                None => format!("LOPROC+{}", alternate_hex((sh_type - SHT_LOPROC) as u64)),
+            };
+        }
+        SHT_LOOS..=SHT_HIOS => {
+            return match os_section_type_name(header, sh_type) {
+                Some(name) => name.to_string(),
+                None => format!("LOOS+{}", alternate_hex((sh_type - SHT_LOOS) as u64)),
+            };
+        }
+        SHT_LOUSER..=SHT_HIUSER => {
+            return format!("LOUSER+{}", alternate_hex((sh_type - SHT_LOUSER) as u64));
+        }
+        _ => return format!("{sh_type:08x}"),
+    };
+
+    name.to_string()
+}
+
+// the types above are the same for every ABI, what is left of the
+// SHT_LOOS..SHT_HIOS range is either from Solaris or from GNU
+#[allow(non_upper_case_globals)]
+fn os_section_type_name(header: &Elf64_Ehdr, sh_type: u32) -> Option<&'static str> {
+    if !is_solaris(header) {
+        return match sh_type {
+            SHT_GNU_INCREMENTAL_INPUTS => Some("GNU_INCREMENTAL_INPUTS"),
+            SHT_GNU_ATTRIBUTES => Some("GNU_ATTRIBUTES"),
+            _ => None,
+        };
+    }
+
+    let name = match sh_type {
+        SHT_SUNW_ancillary => "SUNW_ancillary",
+        SHT_SUNW_capchain => "SUNW_capchain",
+        SHT_SUNW_capinfo => "SUNW_capinfo",
+        SHT_SUNW_symsort => "SUNW_symsort",
+        SHT_SUNW_tlssort => "SUNW_tlssort",
+        SHT_SUNW_LDYNSYM => "SUNW_LDYNSYM",
+        SHT_SUNW_dof => "SUNW_dof",
+        SHT_SUNW_cap => "SUNW_cap",
+        SHT_SUNW_SIGNATURE => "SUNW_SIGNATURE",
+        SHT_SUNW_ANNOTATE => "SUNW_ANNOTATE",
+        SHT_SUNW_DEBUGSTR => "SUNW_DEBUGSTR",
+        SHT_SUNW_DEBUG => "SUNW_DEBUG",
+        SHT_SUNW_move => "SUNW_move",
+        SHT_SUNW_COMDAT => "SUNW_COMDAT",
+        SHT_SUNW_syminfo => "SUNW_syminfo",
+        SHT_SUNW_verdef => "SUNW_verdef",
+        SHT_SUNW_verneed => "SUNW_verneed",
+        SHT_SUNW_versym => "SUNW_versym",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+// the SHT_LOPROC..SHT_HIPROC range means different things for each machine
+fn processor_section_type_name(e_machine: u32, sh_type: u32) -> Option<&'static str> {
+    let name = match (e_machine, sh_type - SHT_LOPROC) {
+        (EM_X86_64, 1) => "X86_64_UNWIND",
+        (EM_ARM, 1) => "ARM_EXIDX",
+        (EM_ARM, 2) => "ARM_PREEMPTMAP",
+        (EM_ARM, 3) => "ARM_ATTRIBUTES",
+        (EM_ARM, 4) => "ARM_DEBUGOVERLAY",
+        (EM_ARM, 5) => "ARM_OVERLAYSECTION",
+        (EM_AARCH64, 3) => "AARCH64_ATTRIBUTES",
+        (EM_RISCV, 3) => "RISCV_ATTRIBUTES",
+        (EM_MIPS, 0) => "MIPS_LIBLIST",
+        (EM_MIPS, 1) => "MIPS_MSYM",
+        (EM_MIPS, 2) => "MIPS_CONFLICT",
+        (EM_MIPS, 3) => "MIPS_GPTAB",
+        (EM_MIPS, 4) => "MIPS_UCODE",
+        (EM_MIPS, 5) => "MIPS_DEBUG",
+        (EM_MIPS, 6) => "MIPS_REGINFO",
+        (EM_MIPS, 0xd) => "MIPS_OPTIONS",
+        (EM_MIPS, 0x1e) => "MIPS_DWARF",
+        (EM_MIPS, 0x2a) => "MIPS_ABIFLAGS",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+// the flags are shown as letters, from the lowest bit to the highest one
+pub fn section_flags(header: &Elf64_Ehdr, sh_flags: u64) -> String {
+    let osabi = header.e_ident[7] as u32;
+    let e_machine = header.e_machine as u32;
+    // same rules used by the key printed after the table
+    let has_retain = is_gnu_or_freebsd(header);
+    let has_mbind = has_retain || osabi == ELFOSABI_NONE;
+
+    let mut letters = String::new();
+    let mut remaining = sh_flags;
+
+    while remaining != 0 {
+        let flag = remaining & remaining.wrapping_neg();
+        remaining &= !flag;
+
+        let letter = match u32::try_from(flag).unwrap_or(0) {
+            SHF_WRITE => 'W',
+            SHF_ALLOC => 'A',
+            SHF_EXECINSTR => 'X',
+            SHF_MERGE => 'M',
+            SHF_STRINGS => 'S',
+            SHF_INFO_LINK => 'I',
+            SHF_LINK_ORDER => 'L',
+            SHF_OS_NONCONFORMING => 'O',
+            SHF_GROUP => 'G',
+            SHF_TLS => 'T',
+            SHF_EXCLUDE => 'E',
+            SHF_COMPRESSED => 'C',
+            SHF_GNU_MBIND if has_mbind => 'D',
+            SHF_GNU_RETAIN if has_retain => 'R',
+            SHF_X86_64_LARGE if e_machine == EM_X86_64 => 'l',
+            SHF_ARM_PURECODE if e_machine == EM_ARM => 'y',
+            SHF_PPC_VLE if e_machine == EM_PPC => 'v',
+            os if os & SHF_MASKOS != 0 => {
+                // every os specific bit is shown only once
+                remaining &= !(SHF_MASKOS as u64);
+                'o'
+            }
+            proc if proc & SHF_MASKPROC != 0 => {
+                remaining &= !(SHF_MASKPROC as u64);
+                'p'
+            }
+            _ => 'x',
+        };
+
+        letters.push(letter);
+    }
+
+    letters
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{print_compression_header, section_flags, section_type_name};
+    use crate::elf::class::ElfClass;
+    use crate::elf::def::elf64strc::Elf64_Chdr;
+    use crate::test_utils::create_header;
+
+    #[test]
+    fn section_flags_are_shown_from_the_lowest_bit() {
+        let header = create_header(1, 62);
+
+        assert_eq!(section_flags(&header, 0x6), "AX");
+        assert_eq!(section_flags(&header, 0x42), "AI");
+        assert_eq!(section_flags(&header, 0x30), "MS");
+        assert_eq!(section_flags(&header, 0x80000000), "E");
+        assert_eq!(section_flags(&header, 0x10000003), "WAl");
+    }
+
+    #[test]
+    fn section_type_name_depends_on_the_machine_for_processor_types() {
+        assert_eq!(
+            section_type_name(&create_header(1, 62), 0x70000001),
+            "X86_64_UNWIND"
+        );
+        assert_eq!(
+            section_type_name(&create_header(1, 40), 0x70000001),
+            "ARM_EXIDX"
+        );
+        assert_eq!(
+            section_type_name(&create_header(1, 183), 0x70000001),
+            "LOPROC+0x1"
+        );
+    }
+
+    #[test]
+    fn section_type_name_names_os_and_user_ranges() {
+        let header = create_header(1, 62);
+
+        assert_eq!(section_type_name(&header, 0x6ffffff6), "GNU_HASH");
+        assert_eq!(section_type_name(&header, 0x6fff4c03), "LOOS+0xfff4c03");
+        assert_eq!(section_type_name(&header, 0x80000001), "LOUSER+0x1");
+        assert_eq!(section_type_name(&header, 0x60000000), "LOOS+0");
+    }
+
+    #[test]
+    fn section_type_name_depends_on_the_osabi_for_os_types() {
+        let mut header = create_header(1, 62);
+        assert_eq!(section_type_name(&header, 0x6ffffff5), "GNU_ATTRIBUTES");
+        assert_eq!(section_type_name(&header, 0x6ffffffa), "LOOS+0xffffffa");
+
+        header.e_ident[7] = 6;
+        assert_eq!(section_type_name(&header, 0x6ffffff5), "SUNW_cap");
+        assert_eq!(section_type_name(&header, 0x6ffffffa), "SUNW_move");
+        // the GNU versioning types are the same for everyone
+        assert_eq!(section_type_name(&header, 0x6ffffffd), "VERDEF");
+    }
+
+    #[test]
+    fn print_compression_header_shows_the_type_the_size_and_the_alignment() {
+        let mut chdr = Elf64_Chdr {
+            ch_type: 1,
+            ch_reserved: 0,
+            ch_size: 0x74,
+            ch_addralign: 1,
+        };
+
+        let mut out = Vec::new();
+        print_compression_header(&mut out, &chdr, ElfClass::Elf64).unwrap();
+        chdr.ch_type = 9;
+        print_compression_header(&mut out, &chdr, ElfClass::Elf32).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "       ZLIB, 0000000000000074, 1\n       [<unknown>: 0x9], 00000074, 1\n"
+        );
+    }
+}
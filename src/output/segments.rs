@@ -0,0 +1,341 @@
+use crate::elf::def::elf64strc::{Elf64_Ehdr, Elf64_Phdr};
+use crate::elf::def::elfconsts::*;
+use crate::elf::dynamic::is_pie;
+use crate::elf::osabi::is_gnu_or_freebsd;
+use crate::elf::section::Elf64Section;
+use crate::elf::segment::{interpreter, section_in_segment};
+use crate::output::alternate_hex;
+use crate::output::header::file_type_name;
+use std::io::{self, Write};
+
+pub fn print_program_headers(
+    out: &mut dyn Write,
+    bytes: &[u8],
+    header: &Elf64_Ehdr,
+    segments: &[Elf64_Phdr],
+    sections: &[Elf64Section],
+    wide: bool,
+    show_intro: bool,
+) -> io::Result<()> {
+    if segments.is_empty() {
+        return writeln!(out, "\nThere are no program headers in this file.");
+    }
+
+    // like in the section headers, this is already on the screen when the
+    // file header was shown
+    if show_intro {
+        let is_pie = is_pie(bytes, header);
+        writeln!(
+            out,
+            "\nElf file type is {}",
+            file_type_name(header.e_type, is_pie)
+        )?;
+        writeln!(out, "Entry point {:#x}", header.e_entry)?;
+        writeln!(
+            out,
+            "There are {} program headers, starting at offset {}",
+            segments.len(),
+            header.e_phoff
+        )?;
+    }
+
+    let is_64bit = header.class().is_64bit();
+
+    writeln!(out, "\nProgram Headers:")?;
+    if !is_64bit {
+        writeln!(
+            out,
+            "  Type           Offset   VirtAddr   PhysAddr   FileSiz MemSiz  Flg Align"
+        )?;
+    } else if wide {
+        writeln!(
+            out,
+            "  Type           Offset   VirtAddr           PhysAddr           FileSiz  MemSiz   Flg Align"
+        )?;
+    } else {
+        writeln!(
+            out,
+            "  Type           Offset             VirtAddr           PhysAddr"
+        )?;
+        writeln!(
+            out,
+            "                 FileSiz            MemSiz              Flags  Align"
+        )?;
+    }
+
+    for phdr in segments {
+        let type_name: String = segment_type_name(header, phdr.p_type)
+            .chars()
+            .take(14)
+            .collect();
+
+        write!(out, "  {type_name:<14} ")?;
+
+        // like in the section headers, the 32-bit files always fit in one
+        // line
+        if !is_64bit {
+            writeln!(
+                out,
+                "0x{:06x} 0x{:08x} 0x{:08x} 0x{:05x} 0x{:05x} {} {}",
+                phdr.p_offset,
+                phdr.p_vaddr,
+                phdr.p_paddr,
+                phdr.p_filesz,
+                phdr.p_memsz,
+                segment_flags(phdr.p_flags),
+                alternate_hex(phdr.p_align)
+            )?;
+        } else if wide {
+            writeln!(
+                out,
+                "{:#08x} {:#018x} {:#018x} {:#08x} {:#08x} {} {}",
+                phdr.p_offset,
+                phdr.p_vaddr,
+                phdr.p_paddr,
+                phdr.p_filesz,
+                phdr.p_memsz,
+                segment_flags(phdr.p_flags),
+                alternate_hex(phdr.p_align)
+            )?;
+        } else {
+            writeln!(
+                out,
+                "{:#018x} {:#018x} {:#018x}",
+                phdr.p_offset, phdr.p_vaddr, phdr.p_paddr
+            )?;
+            // unlike the other rows, binutils always has the prefix here,
+            // so an alignment of 0 is "0x0"
+            writeln!(
+                out,
+                "                 {:#018x} {:#018x}  {}    {:#x}",
+                phdr.p_filesz,
+                phdr.p_memsz,
+                segment_flags(phdr.p_flags),
+                phdr.p_align
+            )?;
+        }
+
+        if let Some(path) = interpreter(bytes, phdr) {
+            writeln!(out, "      [Requesting program interpreter: {path}]")?;
+        }
+    }
+
+    // the mapping needs the section names, so without them there is nothing
+    // to show
+    if sections.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(out, "\n Section to Segment mapping:")?;
+    writeln!(out, "  Segment Sections...")?;
+
+    for (index, phdr) in segments.iter().enumerate() {
+        write!(out, "   {index:02}     ")?;
+
+        // the NULL section (index 0) is never part of anything
+        for section in sections.iter().skip(1) {
+            if section_in_segment(&section.header, phdr) {
+                write!(out, "{} ", section.name)?;
+            }
+        }
+
+        writeln!(out)?;
+    }
+
+    Ok(())
+}
+
+fn segment_flags(p_flags: u32) -> String {
+    let r = if p_flags & PF_R != 0 { 'R' } else { ' ' };
+    let w = if p_flags & PF_W != 0 { 'W' } else { ' ' };
+    let e = if p_flags & PF_X != 0 { 'E' } else { ' ' };
+
+    format!("{r}{w}{e}")
+}
+
+pub fn segment_type_name(header: &Elf64_Ehdr, p_type: u32) -> String {
+    let name = match p_type {
+        PT_NULL => "NULL",
+        PT_LOAD => "LOAD",
+        PT_DYNAMIC => "DYNAMIC",
+        PT_INTERP => "INTERP",
+        PT_NOTE => "NOTE",
+        PT_SHLIB => "SHLIB",
+        PT_PHDR => "PHDR",
+        PT_TLS => "TLS",
+        PT_GNU_EH_FRAME => "GNU_EH_FRAME",
+        PT_GNU_STACK => "GNU_STACK",
+        PT_GNU_RELRO => "GNU_RELRO",
+        PT_GNU_PROPERTY => "GNU_PROPERTY",
+        PT_GNU_SFRAME => "GNU_SFRAME",
+        PT_OPENBSD_RANDOMIZE => "OPENBSD_RANDOMIZE",
+        PT_OPENBSD_WXNEEDED => "OPENBSD_WXNEEDED",
+        PT_OPENBSD_BOOTDATA => "OPENBSD_BOOTDATA",
+        PT_LOPROC..=PT_HIPROC => {
+            return match processor_segment_type_name(header.e_machine as u32, p_type) {
+                Some(name) => name.to_string(),
+                None => format!("LOPROC+{}", alternate_hex((p_type - PT_LOPROC) as u64)),
+            };
+        }
+        PT_LOOS..=PT_HIOS => {
+            return match os_segment_type_name(header, p_type) {
+                Some(name) => name,
+                None => format!("LOOS+{}", alternate_hex((p_type - PT_LOOS) as u64)),
+            };
+        }
+        _ => return format!("<unknown>: {p_type:x}"),
+    };
+
+    name.to_string()
+}
+
+// the GNU_MBIND range is shared by GNU and FreeBSD, the other OS types
+// have a meaning only for HP-UX (and only in its machines) or Solaris
+fn os_segment_type_name(header: &Elf64_Ehdr, p_type: u32) -> Option<String> {
+    if is_gnu_or_freebsd(header) && (PT_GNU_MBIND_LO..=PT_GNU_MBIND_HI).contains(&p_type) {
+        let offset = alternate_hex((p_type - PT_GNU_MBIND_LO) as u64);
+        return Some(format!("GNU_MBIND+{offset}"));
+    }
+
+    let name = match header.e_ident[7] as u32 {
+        ELFOSABI_HPUX => hpux_segment_type_name(header.e_machine as u32, p_type)?,
+        ELFOSABI_SOLARIS => match p_type {
+            PT_SUNW_UNWIND => "PT_SUNW_UNWIND",
+            PT_SUNWBSS => "PT_SUNWBSS",
+            PT_SUNWSTACK => "PT_SUNWSTACK",
+            PT_SUNWDTRACE => "PT_SUNWDTRACE",
+            PT_SUNWCAP => "PT_SUNWCAP",
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    Some(name.to_string())
+}
+
+// IA-64 only has some of the PA-RISC ones
+fn hpux_segment_type_name(e_machine: u32, p_type: u32) -> Option<&'static str> {
+    let name = match (e_machine, p_type) {
+        (EM_PARISC | EM_IA_64, PT_HP_TLS) => "HP_TLS",
+        (EM_PARISC | EM_IA_64, PT_HP_OPT_ANNOT) => "HP_OPT_ANNOT",
+        (EM_PARISC | EM_IA_64, PT_HP_HSL_ANNOT) => "HP_HSL_ANNOT",
+        (EM_PARISC | EM_IA_64, PT_HP_STACK) => "HP_STACK",
+        (EM_PARISC, PT_HP_CORE_NONE) => "HP_CORE_NONE",
+        (EM_PARISC, PT_HP_CORE_VERSION) => "HP_CORE_VERSION",
+        (EM_PARISC, PT_HP_CORE_KERNEL) => "HP_CORE_KERNEL",
+        (EM_PARISC, PT_HP_CORE_COMM) => "HP_CORE_COMM",
+        (EM_PARISC, PT_HP_CORE_PROC) => "HP_CORE_PROC",
+        (EM_PARISC, PT_HP_CORE_LOADABLE) => "HP_CORE_LOADABLE",
+        (EM_PARISC, PT_HP_CORE_STACK) => "HP_CORE_STACK",
+        (EM_PARISC, PT_HP_CORE_SHM) => "HP_CORE_SHM",
+        (EM_PARISC, PT_HP_CORE_MMF) => "HP_CORE_MMF",
+        (EM_PARISC, PT_HP_PARALLEL) => "HP_PARALLEL",
+        (EM_PARISC, PT_HP_FASTBIND) => "HP_FASTBIND",
+        (EM_PARISC, PT_HP_CORE_UTSNAME) => "HP_CORE_UTSNAME",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+// PT_LOPROC..PT_HIPROC are different for each machine
+fn processor_segment_type_name(e_machine: u32, p_type: u32) -> Option<&'static str> {
+    let name = match (e_machine, p_type - PT_LOPROC) {
+        (EM_ARM, 1) => "EXIDX",
+        (EM_AARCH64, 2) => "AARCH64_MEMTAG_MTE",
+        (EM_MIPS, 0) => "REGINFO",
+        (EM_MIPS, 1) => "RTPROC",
+        (EM_MIPS, 2) => "OPTIONS",
+        (EM_MIPS, 3) => "ABIFLAGS",
+        (EM_RISCV, 3) => "RISCV_ATTRIBUTES",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{print_program_headers, segment_flags, segment_type_name};
+    use crate::elf::def::elf64strc::Elf64_Phdr;
+    use crate::test_utils::create_header;
+
+    fn create_stack_segment() -> Elf64_Phdr {
+        Elf64_Phdr {
+            p_type: 0x6474e551, // PT_GNU_STACK
+            p_flags: 6,
+            p_offset: 0,
+            p_vaddr: 0,
+            p_paddr: 0,
+            p_filesz: 0,
+            p_memsz: 0,
+            p_align: 0,
+        }
+    }
+
+    #[test]
+    fn segment_type_name_knows_the_gnu_segments() {
+        let header = create_header(2, 62);
+
+        assert_eq!(segment_type_name(&header, 0x6474e550), "GNU_EH_FRAME");
+        assert_eq!(segment_type_name(&header, 0x6474e551), "GNU_STACK");
+        assert_eq!(segment_type_name(&header, 0x6474e552), "GNU_RELRO");
+        assert_eq!(segment_type_name(&header, 0x6474e553), "GNU_PROPERTY");
+    }
+
+    #[test]
+    fn segment_type_name_depends_on_the_osabi_for_os_types() {
+        let mut header = create_header(2, 62);
+        assert_eq!(segment_type_name(&header, 0x6474e555), "LOOS+0x474e555");
+        assert_eq!(segment_type_name(&header, 0x6ffffffa), "LOOS+0xffffffa");
+
+        header.e_ident[7] = 3;
+        assert_eq!(segment_type_name(&header, 0x6474e555), "GNU_MBIND+0");
+
+        header.e_ident[7] = 6;
+        assert_eq!(segment_type_name(&header, 0x6ffffffa), "PT_SUNWBSS");
+
+        // HP-UX only names them in its own machines
+        header.e_ident[7] = 1;
+        assert_eq!(segment_type_name(&header, 0x60000000), "LOOS+0");
+        header.e_machine = 15;
+        assert_eq!(segment_type_name(&header, 0x60000000), "HP_TLS");
+    }
+
+    #[test]
+    fn segment_type_name_depends_on_the_machine_for_processor_types() {
+        assert_eq!(segment_type_name(&create_header(2, 40), 0x70000001), "EXIDX");
+        assert_eq!(
+            segment_type_name(&create_header(2, 62), 0x70000001),
+            "LOPROC+0x1"
+        );
+    }
+
+    #[test]
+    fn segment_flags_use_rwe_letters() {
+        assert_eq!(segment_flags(0x5), "R E");
+        assert_eq!(segment_flags(0x6), "RW ");
+        assert_eq!(segment_flags(0x0), "   ");
+    }
+
+    #[test]
+    fn print_program_headers_shows_a_zero_alignment_like_binutils() {
+        let header = create_header(2, 62);
+        let segments = [create_stack_segment()];
+
+        let mut out = Vec::new();
+        print_program_headers(&mut out, &[], &header, &segments, &[], true, false).unwrap();
+        let wide = String::from_utf8(out).unwrap();
+        assert!(wide.ends_with(
+            "  GNU_STACK      0x000000 0x0000000000000000 0x0000000000000000 0x000000 0x000000 RW  0\n"
+        ));
+
+        let mut out = Vec::new();
+        print_program_headers(&mut out, &[], &header, &segments, &[], false, false).unwrap();
+        let narrow = String::from_utf8(out).unwrap();
+        assert!(
+            narrow
+                .ends_with("                 0x0000000000000000 0x0000000000000000  RW     0x0\n")
+        );
+    }
+}
@@ -0,0 +1,292 @@
+use crate::elf::def::elf64strc::Elf64_Ehdr;
+use crate::elf::def::elfconsts::*;
+use crate::elf::osabi::is_gnu_or_freebsd;
+use crate::elf::section::Elf64Section;
+use crate::elf::symbol::Elf64Symbol;
+use crate::output::versions::versioned_name;
+use std::io::{self, Write};
+
+pub fn print_symbol_table(
+    out: &mut dyn Write,
+    header: &Elf64_Ehdr,
+    sections: &[Elf64Section],
+    table: &Elf64Section,
+    symbols: &[Elf64Symbol],
+    wide: bool,
+) -> io::Result<()> {
+    let entries = if symbols.len() == 1 {
+        "entry"
+    } else {
+        "entries"
+    };
+
+    writeln!(
+        out,
+        "\nSymbol table '{}' contains {} {entries}:",
+        table.name,
+        symbols.len()
+    )?;
+    // the value column has the size of an address of the class
+    let width = header.class().address_width();
+    writeln!(
+        out,
+        "   Num:    {:<title$}Size Type    Bind   Vis      Ndx Name",
+        "Value",
+        title = width - 1
+    )?;
+
+    for (index, symbol) in symbols.iter().enumerate() {
+        let sym = &symbol.sym;
+
+        write!(
+            out,
+            "{index:6}: {:0width$x} {} {:<7} {:<6} {:<7}",
+            sym.st_value,
+            symbol_size(sym.st_size),
+            symbol_type_name(header, sym.st_type()),
+            symbol_bind_name(header, sym.st_bind()),
+            symbol_visibility_name(sym.st_visibility())
+        )?;
+
+        // the bits of st_other above the visibility are machine specific
+        let other = sym.st_other as u32 & !0x3;
+        if other != 0 {
+            write!(out, " [{}] ", symbol_other_name(header, other))?;
+        }
+
+        writeln!(
+            out,
+            " {:>4} {}",
+            symbol_section_name(header, symbol),
+            versioned_name(&symbol_name(symbol, sections), symbol.version.as_ref(), 21, wide)
+        )?;
+    }
+
+    Ok(())
+}
+
+// symbols of STT_SECTION type usually have no name, so the name of the
+// section that they represent is shown instead
+pub fn symbol_name(symbol: &Elf64Symbol, sections: &[Elf64Section]) -> String {
+    let sym = &symbol.sym;
+
+    if sym.st_type() == STT_SECTION
+        && sym.st_name == 0
+        && let Some(section) = symbol.section_index().and_then(|index| sections.get(index))
+    {
+        return section.name.clone();
+    }
+
+    symbol
+        .name
+        .clone()
+        .unwrap_or_else(|| "<corrupt>".to_string())
+}
+
+// small sizes are shown in decimal, but the huge ones would break the
+// table, so they are shown in hex
+fn symbol_size(size: u64) -> String {
+    if size <= 99999 {
+        format!("{size:5}")
+    } else {
+        format!("{size:#x}")
+    }
+}
+
+// the specific values must come before the ranges that contain them
+#[allow(clippy::match_overlapping_arm)]
+pub fn symbol_type_name(header: &Elf64_Ehdr, st_type: u32) -> String {
+    let e_machine = header.e_machine as u32;
+
+    let name = match st_type {
+        STT_NOTYPE => "NOTYPE",
+        STT_OBJECT => "OBJECT",
+        STT_FUNC => "FUNC",
+        STT_SECTION => "SECTION",
+        STT_FILE => "FILE",
+        STT_COMMON => "COMMON",
+        STT_TLS => "TLS",
+        STT_RELC => "RELC",
+        STT_SRELC => "SRELC",
+        STT_GNU_IFUNC if is_gnu_or_freebsd(header) => "IFUNC",
+        STT_HP_OPAQUE if e_machine == EM_PARISC => "HP_OPAQUE",
+        STT_HP_STUB if e_machine == EM_PARISC => "HP_STUB",
+        STT_ARM_TFUNC if e_machine == EM_ARM => "THUMB_FUNC",
+        STT_SPARC_REGISTER if e_machine == EM_SPARCV9 || e_machine == EM_SPARC => "REGISTER",
+        STT_LOPROC..=STT_HIPROC => return format!("<processor specific>: {st_type}"),
+        STT_LOOS..=STT_HIOS => return format!("<OS specific>: {st_type}"),
+        _ => return format!("<unknown>: {st_type}"),
+    };
+
+    name.to_string()
+}
+
+// unique symbols are a GNU extension, FreeBSD does not have them
+#[allow(clippy::match_overlapping_arm)]
+pub fn symbol_bind_name(header: &Elf64_Ehdr, st_bind: u32) -> String {
+    let name = match st_bind {
+        STB_LOCAL => "LOCAL",
+        STB_GLOBAL => "GLOBAL",
+        STB_WEAK => "WEAK",
+        STB_GNU_UNIQUE if header.e_ident[7] as u32 == ELFOSABI_GNU => "UNIQUE",
+        STB_LOPROC..=STB_HIPROC => return format!("<processor specific>: {st_bind}"),
+        STB_LOOS..=STB_HIOS => return format!("<OS specific>: {st_bind}"),
+        _ => return format!("<unknown>: {st_bind}"),
+    };
+
+    name.to_string()
+}
+
+fn symbol_visibility_name(st_visibility: u32) -> &'static str {
+    match st_visibility {
+        STV_DEFAULT => "DEFAULT",
+        STV_INTERNAL => "INTERNAL",
+        STV_HIDDEN => "HIDDEN",
+        STV_PROTECTED => "PROTECTED",
+        _ => unreachable!("the visibility only have 2 bits"),
+    }
+}
+
+fn symbol_other_name(header: &Elf64_Ehdr, other: u32) -> String {
+    match (header.e_machine as u32, other) {
+        (EM_AARCH64, STO_AARCH64_VARIANT_PCS) => "VARIANT_PCS".to_string(),
+        (EM_RISCV, STO_RISCV_VARIANT_CC) => "VARIANT_CC".to_string(),
+        _ => format!("<other>: {other:x}"),
+    }
+}
+
+// the index from SHT_SYMTAB_SHNDX is always a section, even when it is in
+// the range of the special ones
+fn symbol_section_name(header: &Elf64_Ehdr, symbol: &Elf64Symbol) -> String {
+    match symbol.xindex {
+        Some(index) => section_number(header, index),
+        None => section_index_name(header, symbol.sym.st_shndx as u32),
+    }
+}
+
+#[allow(clippy::match_overlapping_arm)]
+pub fn section_index_name(header: &Elf64_Ehdr, st_shndx: u32) -> String {
+    let e_machine = header.e_machine as u32;
+
+    match st_shndx {
+        SHN_UNDEF => "UND".to_string(),
+        SHN_ABS => "ABS".to_string(),
+        SHN_COMMON => "COM".to_string(),
+        SHN_X86_64_LCOMMON if e_machine == EM_X86_64 => "LARGE_COM".to_string(),
+        SHN_MIPS_SCOMMON if e_machine == EM_MIPS => "SCOM".to_string(),
+        SHN_MIPS_SUNDEFINED if e_machine == EM_MIPS => "SUND".to_string(),
+        SHN_LOPROC..=SHN_HIPROC => format!("PRC[{st_shndx:#06x}]"),
+        SHN_LOOS..=SHN_HIOS => format!("OS [{st_shndx:#06x}]"),
+        SHN_LORESERVE.. => format!("RSV[{st_shndx:#06x}]"),
+        _ => section_number(header, st_shndx),
+    }
+}
+
+fn section_number(header: &Elf64_Ehdr, index: u32) -> String {
+    if header.e_shnum != 0 && index >= header.e_shnum as u32 {
+        format!("bad section index[{index:3}]")
+    } else {
+        format!("{index:3}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        section_index_name, symbol_bind_name, symbol_name, symbol_section_name, symbol_size,
+        symbol_type_name,
+    };
+    use crate::elf::def::elf64strc::{Elf64_Ehdr, Elf64_Sym};
+    use crate::elf::symbol::Elf64Symbol;
+    use crate::test_utils::{create_header, create_section};
+
+    #[test]
+    fn section_index_name_names_the_special_indices() {
+        let header = Elf64_Ehdr {
+            e_shnum: 14,
+            e_shstrndx: 13,
+            ..create_header(1, 62)
+        };
+
+        assert_eq!(section_index_name(&header, 0), "UND");
+        assert_eq!(section_index_name(&header, 0xfff1), "ABS");
+        assert_eq!(section_index_name(&header, 0xfff2), "COM");
+        assert_eq!(section_index_name(&header, 0xff02), "LARGE_COM");
+        assert_eq!(section_index_name(&header, 0xff03), "PRC[0xff03]");
+        assert_eq!(section_index_name(&header, 0xffff), "RSV[0xffff]");
+        assert_eq!(section_index_name(&header, 5), "  5");
+        assert_eq!(section_index_name(&header, 20), "bad section index[ 20]");
+    }
+
+    #[test]
+    fn symbol_type_name_uses_the_machine_for_processor_types() {
+        assert_eq!(symbol_type_name(&create_header(1, 40), 13), "THUMB_FUNC");
+        assert_eq!(
+            symbol_type_name(&create_header(1, 62), 13),
+            "<processor specific>: 13"
+        );
+    }
+
+    #[test]
+    fn symbol_type_and_bind_names_depend_on_the_osabi_for_gnu_extensions() {
+        let mut header = create_header(1, 62);
+        assert_eq!(symbol_type_name(&header, 10), "<OS specific>: 10");
+        assert_eq!(symbol_bind_name(&header, 10), "<OS specific>: 10");
+
+        header.e_ident[7] = 9;
+        assert_eq!(symbol_type_name(&header, 10), "IFUNC");
+        assert_eq!(symbol_bind_name(&header, 10), "<OS specific>: 10");
+
+        header.e_ident[7] = 3;
+        assert_eq!(symbol_type_name(&header, 10), "IFUNC");
+        assert_eq!(symbol_bind_name(&header, 10), "UNIQUE");
+    }
+
+    #[test]
+    fn symbol_size_switches_to_hex_for_huge_sizes() {
+        assert_eq!(symbol_size(57), "   57");
+        assert_eq!(symbol_size(100000), "0x186a0");
+    }
+
+    #[test]
+    fn symbol_name_uses_the_section_name_for_section_symbols() {
+        let sections = vec![create_section(".text", 1)];
+        let symbol = Elf64Symbol {
+            sym: Elf64_Sym {
+                st_name: 0,
+                st_info: 0x03, // LOCAL SECTION
+                st_other: 0,
+                st_shndx: 0,
+                st_value: 0,
+                st_size: 0,
+            },
+            name: Some(String::new()),
+            xindex: None,
+            version: None,
+        };
+
+        assert_eq!(symbol_name(&symbol, &sections), ".text");
+    }
+
+    #[test]
+    fn symbol_section_name_shows_the_extended_index_as_a_section() {
+        // a file with extended numbering, e_shnum is 0
+        let mut header = create_header(1, 62);
+        header.e_shnum = 0;
+        let symbol = Elf64Symbol {
+            sym: Elf64_Sym {
+                st_name: 0,
+                st_info: 0x12, // GLOBAL FUNC
+                st_other: 0,
+                st_shndx: 0xffff,
+                st_value: 0,
+                st_size: 0,
+            },
+            name: Some("f".to_string()),
+            xindex: Some(0xfff1),
+            version: None,
+        };
+
+        assert_eq!(symbol_section_name(&header, &symbol), "65521");
+    }
+}
@@ -0,0 +1,380 @@
+use crate::elf::def::elf64fields::Elf64_Versym;
+use crate::elf::def::elfconsts::*;
+use crate::elf::section::Elf64Section;
+use crate::elf::symbol::Elf64Symbol;
+use crate::elf::version::{
+    Elf64SymbolVersions, Elf64VersionDefinition, Elf64VersionNeed, SymbolVersion, VersionKind,
+};
+use crate::output::truncate_name;
+use std::io::{self, Write};
+
+// .gnu.version, with the version of each symbol of the table in its sh_link.
+// The names come from the definitions and needs of versions
+pub fn print_version_symbols(
+    out: &mut dyn Write,
+    sections: &[Elf64Section],
+    section: &Elf64Section,
+    versym: &[Elf64_Versym],
+    versions: &Elf64SymbolVersions,
+    symbols: &[Elf64Symbol],
+) -> io::Result<()> {
+    let entries = if versym.len() == 1 {
+        "entry"
+    } else {
+        "entries"
+    };
+    writeln!(
+        out,
+        "\nVersion symbols section '{}' contains {} {entries}:",
+        section.name,
+        versym.len()
+    )?;
+    print_section_address(out, sections, section)?;
+
+    // 4 symbols in each row
+    for (row, values) in versym.chunks(4).enumerate() {
+        write!(out, "  {:03x}:", row * 4)?;
+
+        for (column, &value) in values.iter().enumerate() {
+            let symbol = symbols.get(row * 4 + column);
+            write!(out, "{}", version_symbol_cell(value, symbol, versions))?;
+        }
+
+        writeln!(out)?;
+    }
+
+    Ok(())
+}
+
+pub fn print_version_definitions(
+    out: &mut dyn Write,
+    sections: &[Elf64Section],
+    section: &Elf64Section,
+    definitions: &[Elf64VersionDefinition],
+) -> io::Result<()> {
+    let count = section.header.sh_info;
+    let entries = if count == 1 { "entry" } else { "entries" };
+    writeln!(
+        out,
+        "\nVersion definition section '{}' contains {count} {entries}:",
+        section.name
+    )?;
+    print_section_address(out, sections, section)?;
+
+    for definition in definitions {
+        let verdef = &definition.verdef;
+        write!(
+            out,
+            "  {}: Rev: {}  Flags: {}  Index: {}  Cnt: {}  ",
+            entry_offset(definition.offset),
+            verdef.vd_version,
+            version_flags(verdef.vd_flags),
+            verdef.vd_ndx,
+            verdef.vd_cnt
+        )?;
+
+        let Some((first, parents)) = definition.names.split_first() else {
+            writeln!(out)?;
+            continue;
+        };
+
+        match &first.name {
+            Some(name) => writeln!(out, "Name: {name}")?,
+            None => writeln!(out, "Name index: {}", first.verdaux.vda_name)?,
+        }
+
+        // the versions that this one inherits from
+        for (index, parent) in parents.iter().enumerate() {
+            let offset = entry_offset(parent.offset);
+            match &parent.name {
+                Some(name) => writeln!(out, "  {offset}: Parent {}: {name}", index + 1)?,
+                None => writeln!(
+                    out,
+                    "  {offset}: Parent {}, name index: {}",
+                    index + 1,
+                    parent.verdaux.vda_name
+                )?,
+            }
+        }
+    }
+
+    Ok(())
+}
+
+pub fn print_version_needs(
+    out: &mut dyn Write,
+    sections: &[Elf64Section],
+    section: &Elf64Section,
+    needs: &[Elf64VersionNeed],
+) -> io::Result<()> {
+    let count = section.header.sh_info;
+    let entries = if count == 1 { "entry" } else { "entries" };
+    writeln!(
+        out,
+        "\nVersion needs section '{}' contains {count} {entries}:",
+        section.name
+    )?;
+    print_section_address(out, sections, section)?;
+
+    for need in needs {
+        let verneed = &need.verneed;
+        write!(
+            out,
+            "  {}: Version: {}",
+            entry_offset(need.offset),
+            verneed.vn_version
+        )?;
+        match &need.file {
+            Some(file) => write!(out, "  File: {file}")?,
+            None => write!(out, "  File: {:x}", verneed.vn_file)?,
+        }
+        writeln!(out, "  Cnt: {}", verneed.vn_cnt)?;
+
+        for version in &need.versions {
+            let vernaux = &version.vernaux;
+            let offset = entry_offset(version.offset);
+            match &version.name {
+                Some(name) => write!(out, "  {offset}:   Name: {name}")?,
+                None => write!(out, "  {offset}:   Name index: {:x}", vernaux.vna_name)?,
+            }
+            writeln!(
+                out,
+                "  Flags: {}  Version: {}",
+                version_flags(vernaux.vna_flags),
+                vernaux.vna_other
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn print_no_version_info(out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "\nNo version information found in this file.")
+}
+
+// the name of the symbol with its version, "memcpy@GLIBC_2.14 (3)" for the
+// needed ones and "foo@@VERS_2" for the default version of a definition.
+// Outside of the wide mode the name is cut so the whole thing fits in width,
+// but the version is always shown whole
+pub fn versioned_name(
+    name: &str,
+    version: Option<&SymbolVersion>,
+    width: usize,
+    wide: bool,
+) -> String {
+    let Some(version) = version else {
+        return truncate_name(name, width, wide);
+    };
+
+    let mut suffix = version_suffix(version);
+    if version.kind == VersionKind::Needed {
+        suffix.push_str(&format!(" ({})", version.index));
+    }
+
+    let used = suffix.chars().count();
+    let name = if wide || used < width {
+        truncate_name(name, width - used.min(width), wide)
+    } else if used == width {
+        // binutils prints nothing for a width of 0
+        String::new()
+    } else {
+        // a version longer than the width leaves a negative width, that
+        // binutils takes as the width to cut the name at and to pad it to
+        let width = used - width;
+        format!("{:<width$}", truncate_name(name, width, wide))
+    };
+
+    format!("{name}{suffix}")
+}
+
+// "@@VERS_2" for the default version of a definition and "@VERS_1" for the
+// others, without the index that the symbol tables add to the needed ones
+pub fn version_suffix(version: &SymbolVersion) -> String {
+    match version.kind {
+        VersionKind::Default => format!("@@{}", version.name),
+        VersionKind::Hidden | VersionKind::Needed => format!("@{}", version.name),
+    }
+}
+
+fn print_section_address(
+    out: &mut dyn Write,
+    sections: &[Elf64Section],
+    section: &Elf64Section,
+) -> io::Result<()> {
+    let shdr = &section.header;
+    let link = sections
+        .get(shdr.sh_link as usize)
+        .map_or("<corrupt>", |link| link.name.as_str());
+
+    writeln!(
+        out,
+        " Addr: 0x{:016x}  Offset: 0x{:08x}  Link: {} ({link})",
+        shdr.sh_addr, shdr.sh_offset, shdr.sh_link
+    )
+}
+
+// a cell of 18 chars with the index and the name of its version, from the
+// same lookup that --dyn-syms uses for the symbol
+fn version_symbol_cell(
+    value: Elf64_Versym,
+    symbol: Option<&Elf64Symbol>,
+    versions: &Elf64SymbolVersions,
+) -> String {
+    match value as u32 {
+        VER_NDX_LOCAL => return "   0 (*local*)    ".to_string(),
+        VER_NDX_GLOBAL => return "   1 (*global*)   ".to_string(),
+        _ => {}
+    }
+
+    let number = value as u32 & VERSYM_VERSION;
+    let hidden = if value as u32 & VERSYM_HIDDEN != 0 {
+        'h'
+    } else {
+        ' '
+    };
+    let mut cell = format!("{number:4x}{hidden}");
+
+    // more values than symbols, binutils only shows the index
+    let Some(symbol) = symbol else {
+        return cell;
+    };
+
+    let is_defined = symbol.sym.st_shndx as u32 != SHN_UNDEF;
+    let name = versions.lookup(value, is_defined).map(|entry| entry.name());
+
+    if let Some(name) = name {
+        let name = name.unwrap_or("<corrupt>");
+        // "(%s%-*s" with ")" and 12 - len, a longer name (the negative
+        // width) still gets the padding
+        let padding = (12 - name.chars().count() as isize).unsigned_abs();
+        cell.push_str(&format!("({name}{:<padding$}", ")"));
+    }
+
+    let used = cell.chars().count();
+    if used < 18 {
+        cell.push_str(&" ".repeat(18 - used));
+    }
+
+    cell
+}
+
+// C's "%#06x", that has no prefix for 0 (but the zeros are still there)
+fn entry_offset(offset: u64) -> String {
+    if offset == 0 {
+        "000000".to_string()
+    } else {
+        format!("{offset:#06x}")
+    }
+}
+
+// the VER_FLG_* of a definition or a needed version
+fn version_flags(flags: u16) -> String {
+    if flags == 0 {
+        return "none".to_string();
+    }
+
+    let mut names = Vec::new();
+    let flags = flags as u32;
+    if flags & VER_FLG_BASE != 0 {
+        names.push("BASE");
+    }
+    if flags & VER_FLG_WEAK != 0 {
+        names.push("WEAK");
+    }
+    if flags & VER_FLG_INFO != 0 {
+        names.push("INFO");
+    }
+    if flags & !(VER_FLG_BASE | VER_FLG_WEAK | VER_FLG_INFO) != 0 {
+        names.push("<unknown>");
+    }
+
+    names.join(" | ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{entry_offset, version_flags, versioned_name};
+    use crate::elf::version::{SymbolVersion, VersionKind};
+
+    fn create_version(name: &str, index: u16, kind: VersionKind) -> SymbolVersion {
+        SymbolVersion {
+            name: name.to_string(),
+            index,
+            kind,
+        }
+    }
+
+    #[test]
+    fn versioned_name_appends_the_version_of_each_kind() {
+        let default = create_version("VERS_2", 3, VersionKind::Default);
+        let hidden = create_version("VERS_1", 2, VersionKind::Hidden);
+        let needed = create_version("GLIBC_2.34", 3, VersionKind::Needed);
+
+        assert_eq!(
+            versioned_name("foo", Some(&default), 21, false),
+            "foo@@VERS_2"
+        );
+        assert_eq!(
+            versioned_name("foo", Some(&hidden), 21, false),
+            "foo@VERS_1"
+        );
+        assert_eq!(
+            versioned_name("bar", Some(&needed), 21, false),
+            "bar@GLIBC_2.34 (3)"
+        );
+        assert_eq!(versioned_name("bar", None, 21, false), "bar");
+    }
+
+    #[test]
+    fn versioned_name_only_cuts_the_name() {
+        let needed = create_version("GLIBC_2.34", 3, VersionKind::Needed);
+
+        assert_eq!(
+            versioned_name("__libc_start_main", Some(&needed), 21, false),
+            "_[...]@GLIBC_2.34 (3)"
+        );
+        assert_eq!(
+            versioned_name("__libc_start_main", Some(&needed), 21, true),
+            "__libc_start_main@GLIBC_2.34 (3)"
+        );
+    }
+
+    #[test]
+    fn versioned_name_pads_the_name_when_the_version_is_longer_than_the_width() {
+        let needed = create_version("NCURSES6_TINFO_5.0.19991023", 6, VersionKind::Needed);
+
+        assert_eq!(
+            versioned_name("tputs", Some(&needed), 21, false),
+            "tputs      @NCURSES6_TINFO_5.0.19991023 (6)"
+        );
+        assert_eq!(
+            versioned_name("_nc_home_terminfo", Some(&needed), 21, false),
+            "_nc_ho[...]@NCURSES6_TINFO_5.0.19991023 (6)"
+        );
+        assert_eq!(
+            versioned_name("tputs", Some(&needed), 21, true),
+            "tputs@NCURSES6_TINFO_5.0.19991023 (6)"
+        );
+
+        let needed = create_version("libjansson.so.4", 15, VersionKind::Needed);
+        assert_eq!(
+            versioned_name("json_object", Some(&needed), 21, false),
+            "@libjansson.so.4 (15)"
+        );
+    }
+
+    #[test]
+    fn version_flags_joins_the_known_flags() {
+        assert_eq!(version_flags(0), "none");
+        assert_eq!(version_flags(1), "BASE");
+        assert_eq!(version_flags(3), "BASE | WEAK");
+        assert_eq!(version_flags(0x14), "INFO | <unknown>");
+    }
+
+    #[test]
+    fn entry_offset_has_no_prefix_for_zero() {
+        assert_eq!(entry_offset(0), "000000");
+        assert_eq!(entry_offset(0x1c), "0x001c");
+    }
+}
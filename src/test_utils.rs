@@ -0,0 +1,48 @@
+// the fixtures that the tests of both the library and the binary use. This
+// file is a module of each one of them (the tests of the binary cant see the
+// cfg(test) items of the library), so it only uses what both have as crate::
+use crate::elf::def::elf64strc::{Elf64_Ehdr, Elf64_Shdr};
+use crate::elf::section::Elf64Section;
+
+// a 64-bit little endian header of e_type for e_machine, without any table.
+// The tests that need more of it override the fields they care about, like
+// Elf64_Ehdr { e_shnum: 14, ..create_header(1, 62) }
+pub fn create_header(e_type: u16, e_machine: u16) -> Elf64_Ehdr {
+    Elf64_Ehdr {
+        e_ident: [0x7f, 0x45, 0x4c, 0x46, 2, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        e_type,
+        e_machine,
+        e_version: 1,
+        e_entry: 0,
+        e_phoff: 0,
+        e_shoff: 0,
+        e_flags: 0,
+        e_ehsize: 64,
+        e_phentsize: 56,
+        e_phnum: 0,
+        e_shentsize: 64,
+        e_shnum: 0,
+        e_shstrndx: 0,
+    }
+}
+
+// a section called name of sh_type, with every other field at 0. Like with
+// the header, the tests set what they care about with
+// Elf64_Shdr { sh_size: 24, ..section.header }
+pub fn create_section(name: &str, sh_type: u32) -> Elf64Section {
+    Elf64Section {
+        header: Elf64_Shdr {
+            sh_name: 0,
+            sh_type,
+            sh_flags: 0,
+            sh_addr: 0,
+            sh_offset: 0,
+            sh_size: 0,
+            sh_link: 0,
+            sh_info: 0,
+            sh_addralign: 0,
+            sh_entsize: 0,
+        },
+        name: name.to_string(),
+    }
+}
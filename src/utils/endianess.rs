@@ -37,3 +37,14 @@ impl EndianRead for u64 {
     }
 }
 
+impl EndianRead for i64 {
+    fn read_from(bytes: &[u8], is_little_endian: bool) -> Self {
+        let arr: [u8; 8] = bytes.try_into().expect(MSG_SLC_INV_LEN);
+        if is_little_endian {
+            Self::from_le_bytes(arr)
+        } else {
+            Self::from_be_bytes(arr)
+        }
+    }
+}
+
@@ -1,5 +1,12 @@
-use std::error::Error;
+use crate::elf::error::ElfError;
 
 pub trait Parseable : Sized {
-    fn parse(bytes: &[u8]) -> Result<Self, Box<dyn Error>>;
+    fn parse(bytes: &[u8]) -> Result<Self, ElfError>;
+}
+
+// the ELF header is the only structure that can tell its own endianness (by
+// EI_DATA). Everything else (section headers, symbols etc.) needs to be told
+// how to read its bytes, so it implements this one instead
+pub trait EndianParseable : Sized {
+    fn parse_endian(bytes: &[u8], is_little_endian: bool) -> Result<Self, ElfError>;
 }
\ No newline at end of file